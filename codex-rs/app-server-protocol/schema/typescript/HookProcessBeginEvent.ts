@@ -18,4 +18,8 @@ event_type: string,
 /**
  * The hook command that was spawned.
  */
-command: Array<string>, };
+command: Array<string>, 
+/**
+ * Timeout applied to this hook process in milliseconds, when bounded.
+ */
+timeout_ms?: bigint, };
@@ -10,4 +10,8 @@ hook_id: string,
 /**
  * Exit code if available.
  */
-exit_code: number | null, };
+exit_code: number | null, 
+/**
+ * Captured stdout/stderr (bounded), when `hooks.echo_to_ui` is enabled.
+ */
+output?: string, };
@@ -12,4 +12,9 @@ call_id: string,
  * Turn ID that this request belongs to.
  * Uses `#[serde(default)]` for backwards compatibility.
  */
-turn_id: string, questions: Array<RequestUserInputQuestion>, };
+turn_id: string, questions: Array<RequestUserInputQuestion>, 
+/**
+ * Seconds to wait before the current answers (including defaults) are
+ * submitted automatically. `None` waits indefinitely.
+ */
+timeout_sec?: number, };
@@ -1,6 +1,22 @@
 // GENERATED CODE! DO NOT MODIFY BY HAND!
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RequestUserInputCondition } from "./RequestUserInputCondition";
 import type { RequestUserInputQuestionOption } from "./RequestUserInputQuestionOption";
+import type { RequestUserInputValidation } from "./RequestUserInputValidation";
 
-export type RequestUserInputQuestion = { id: string, header: string, question: string, isOther: boolean, isSecret: boolean, options: Array<RequestUserInputQuestionOption> | null, };
+export type RequestUserInputQuestion = { id: string, header: string, question: string, isOther: boolean, isSecret: boolean, options: Array<RequestUserInputQuestionOption> | null, 
+/**
+ * When set, the question is only asked if the referenced question was
+ * answered with `equals`.
+ */
+showIf?: RequestUserInputCondition, 
+/**
+ * Index of the option that starts out selected. Without it the first
+ * option is highlighted but the question stays unanswered.
+ */
+defaultOptionIndex?: number, 
+/**
+ * Checks applied to freeform answers (questions without options).
+ */
+validation?: RequestUserInputValidation, };
@@ -0,0 +1,18 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Constraints a freeform answer must satisfy before the user can move on.
+ * `pattern` and `maxLen` only apply to non-empty answers; use `minLen` to
+ * require one.
+ */
+export type RequestUserInputValidation = { 
+/**
+ * Regular expression the answer must match.
+ */
+pattern?: string, minLen?: number, maxLen?: number, 
+/**
+ * Shown instead of the generated message when validation fails.
+ */
+errorMessage?: string, };
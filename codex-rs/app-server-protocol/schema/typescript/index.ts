@@ -161,9 +161,11 @@ export type { RemoteSkillSummary } from "./RemoteSkillSummary";
 export type { RemoveConversationListenerParams } from "./RemoveConversationListenerParams";
 export type { RemoveConversationSubscriptionResponse } from "./RemoveConversationSubscriptionResponse";
 export type { RequestId } from "./RequestId";
+export type { RequestUserInputCondition } from "./RequestUserInputCondition";
 export type { RequestUserInputEvent } from "./RequestUserInputEvent";
 export type { RequestUserInputQuestion } from "./RequestUserInputQuestion";
 export type { RequestUserInputQuestionOption } from "./RequestUserInputQuestionOption";
+export type { RequestUserInputValidation } from "./RequestUserInputValidation";
 export type { Resource } from "./Resource";
 export type { ResourceTemplate } from "./ResourceTemplate";
 export type { ResponseItem } from "./ResponseItem";
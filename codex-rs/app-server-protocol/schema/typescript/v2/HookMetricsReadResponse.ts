@@ -0,0 +1,10 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HookProviderMetrics } from "./HookProviderMetrics";
+
+export type HookMetricsReadResponse = { 
+/**
+ * Hook events dispatched to the configured providers.
+ */
+eventsEmitted: number, providers: Array<HookProviderMetrics>, };
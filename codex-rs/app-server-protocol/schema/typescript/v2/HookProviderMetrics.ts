@@ -0,0 +1,9 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type HookProviderMetrics = { 
+/**
+ * One of `inproc`, `host`, `webhook`, or `external_command`.
+ */
+provider: string, commandsSpawned: number, failures: number, timeouts: number, breakerTrips: number, };
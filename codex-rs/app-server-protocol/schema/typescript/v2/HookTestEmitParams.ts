@@ -0,0 +1,14 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "../serde_json/JsonValue";
+
+export type HookTestEmitParams = { threadId: string, 
+/**
+ * Hook event type in camelCase, e.g. `toolCallFinished`.
+ */
+eventType: string, 
+/**
+ * Event fields using camelCase names, e.g. `toolName`.
+ */
+payload: JsonValue, };
@@ -0,0 +1,16 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HookProviderMetrics } from "./HookProviderMetrics";
+
+export type HookTestEmitResponse = { 
+/**
+ * 1 when the event was dispatched; 0 when it was skipped (disabled, deduplicated, or no
+ * providers are configured).
+ */
+eventsEmitted: number, 
+/**
+ * Per-provider counters for this dispatch only. Providers that queue events to shared
+ * workers (`inproc`, `host`, `webhook`) are omitted.
+ */
+providers: Array<HookProviderMetrics>, };
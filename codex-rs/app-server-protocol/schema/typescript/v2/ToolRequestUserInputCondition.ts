@@ -0,0 +1,8 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * EXPERIMENTAL. Shows a request_user_input question only when another question's answer matches `equals`.
+ */
+export type ToolRequestUserInputCondition = { questionId: string, equals: string, };
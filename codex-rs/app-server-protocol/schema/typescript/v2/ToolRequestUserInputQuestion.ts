@@ -1,9 +1,25 @@
 // GENERATED CODE! DO NOT MODIFY BY HAND!
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ToolRequestUserInputCondition } from "./ToolRequestUserInputCondition";
 import type { ToolRequestUserInputOption } from "./ToolRequestUserInputOption";
+import type { ToolRequestUserInputValidation } from "./ToolRequestUserInputValidation";
 
 /**
  * EXPERIMENTAL. Represents one request_user_input question and its required options.
  */
-export type ToolRequestUserInputQuestion = { id: string, header: string, question: string, isOther: boolean, isSecret: boolean, options: Array<ToolRequestUserInputOption> | null, };
+export type ToolRequestUserInputQuestion = { id: string, header: string, question: string, isOther: boolean, isSecret: boolean, options: Array<ToolRequestUserInputOption> | null, 
+/**
+ * When set, the question is only asked if the referenced question was
+ * answered with `equals`.
+ */
+showIf: ToolRequestUserInputCondition | null, 
+/**
+ * Index of the option that starts out selected. Without it the first
+ * option is highlighted but the question stays unanswered.
+ */
+defaultOptionIndex: number | null, 
+/**
+ * Checks applied to freeform answers (questions without options).
+ */
+validation: ToolRequestUserInputValidation | null, };
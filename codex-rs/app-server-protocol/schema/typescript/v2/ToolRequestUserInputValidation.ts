@@ -0,0 +1,18 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * EXPERIMENTAL. Constraints a freeform request_user_input answer must satisfy.
+ * `pattern` and `maxLen` only apply to non-empty answers; use `minLen` to
+ * require one.
+ */
+export type ToolRequestUserInputValidation = { 
+/**
+ * Regular expression the answer must match.
+ */
+pattern: string | null, minLen: number | null, maxLen: number | null, 
+/**
+ * Shown instead of the generated message when validation fails.
+ */
+errorMessage: string | null, };
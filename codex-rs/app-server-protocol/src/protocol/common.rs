@@ -364,6 +364,18 @@ client_request_definitions! {
         response: v2::GetAccountResponse,
     },
 
+    /// Read hook dispatch counters for a loaded thread.
+    HookMetricsRead => "hook/metrics/read" {
+        params: v2::HookMetricsReadParams,
+        response: v2::HookMetricsReadResponse,
+    },
+
+    /// Dispatch a synthetic hook event through a loaded thread's hooks.
+    HookTestEmit => "hook/test/emit" {
+        params: v2::HookTestEmitParams,
+        response: v2::HookTestEmitResponse,
+    },
+
     /// DEPRECATED APIs below
     NewConversation {
         params: v1::NewConversationParams,
@@ -1100,6 +1100,57 @@ pub struct GetAccountResponse {
     pub requires_openai_auth: bool,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct HookMetricsReadParams {
+    pub thread_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct HookMetricsReadResponse {
+    /// Hook events dispatched to the configured providers.
+    pub events_emitted: i64,
+    pub providers: Vec<HookProviderMetrics>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct HookTestEmitParams {
+    pub thread_id: String,
+    /// Hook event type in camelCase, e.g. `toolCallFinished`.
+    pub event_type: String,
+    /// Event fields using camelCase names, e.g. `toolName`.
+    pub payload: JsonValue,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct HookTestEmitResponse {
+    /// 1 when the event was dispatched; 0 when it was skipped (disabled, deduplicated, or no
+    /// providers are configured).
+    pub events_emitted: i64,
+    /// Per-provider counters for this dispatch only. Providers that queue events to shared
+    /// workers (`inproc`, `host`, `webhook`) are omitted.
+    pub providers: Vec<HookProviderMetrics>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct HookProviderMetrics {
+    /// One of `inproc`, `host`, `webhook`, or `external_command`.
+    pub provider: String,
+    pub commands_spawned: i64,
+    pub failures: i64,
+    pub timeouts: i64,
+    pub breaker_trips: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -3176,6 +3227,30 @@ pub struct ToolRequestUserInputOption {
     pub description: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+/// EXPERIMENTAL. Shows a request_user_input question only when another question's answer matches `equals`.
+pub struct ToolRequestUserInputCondition {
+    pub question_id: String,
+    pub equals: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+/// EXPERIMENTAL. Constraints a freeform request_user_input answer must satisfy.
+/// `pattern` and `maxLen` only apply to non-empty answers; use `minLen` to
+/// require one.
+pub struct ToolRequestUserInputValidation {
+    /// Regular expression the answer must match.
+    pub pattern: Option<String>,
+    pub min_len: Option<usize>,
+    pub max_len: Option<usize>,
+    /// Shown instead of the generated message when validation fails.
+    pub error_message: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -3189,6 +3264,14 @@ pub struct ToolRequestUserInputQuestion {
     #[serde(default)]
     pub is_secret: bool,
     pub options: Option<Vec<ToolRequestUserInputOption>>,
+    /// When set, the question is only asked if the referenced question was
+    /// answered with `equals`.
+    pub show_if: Option<ToolRequestUserInputCondition>,
+    /// Index of the option that starts out selected. Without it the first
+    /// option is highlighted but the question stays unanswered.
+    pub default_option_index: Option<usize>,
+    /// Checks applied to freeform answers (questions without options).
+    pub validation: Option<ToolRequestUserInputValidation>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
@@ -3200,6 +3283,9 @@ pub struct ToolRequestUserInputParams {
     pub turn_id: String,
     pub item_id: String,
     pub questions: Vec<ToolRequestUserInputQuestion>,
+    /// Seconds to wait before the client should submit the current answers
+    /// (including default options) automatically. `null` waits indefinitely.
+    pub timeout_sec: Option<u32>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
@@ -56,10 +56,12 @@ use codex_app_server_protocol::ThreadNameUpdatedNotification;
 use codex_app_server_protocol::ThreadRollbackResponse;
 use codex_app_server_protocol::ThreadTokenUsage;
 use codex_app_server_protocol::ThreadTokenUsageUpdatedNotification;
+use codex_app_server_protocol::ToolRequestUserInputCondition;
 use codex_app_server_protocol::ToolRequestUserInputOption;
 use codex_app_server_protocol::ToolRequestUserInputParams;
 use codex_app_server_protocol::ToolRequestUserInputQuestion;
 use codex_app_server_protocol::ToolRequestUserInputResponse;
+use codex_app_server_protocol::ToolRequestUserInputValidation;
 use codex_app_server_protocol::Turn;
 use codex_app_server_protocol::TurnCompletedNotification;
 use codex_app_server_protocol::TurnDiffUpdatedNotification;
@@ -288,6 +290,21 @@ pub(crate) async fn apply_bespoke_event_handling(
                                 })
                                 .collect()
                         }),
+                        show_if: question
+                            .show_if
+                            .map(|condition| ToolRequestUserInputCondition {
+                                question_id: condition.question_id,
+                                equals: condition.equals,
+                            }),
+                        default_option_index: question.default_option_index,
+                        validation: question.validation.map(|validation| {
+                            ToolRequestUserInputValidation {
+                                pattern: validation.pattern,
+                                min_len: validation.min_len,
+                                max_len: validation.max_len,
+                                error_message: validation.error_message,
+                            }
+                        }),
                     })
                     .collect();
                 let params = ToolRequestUserInputParams {
@@ -295,6 +312,7 @@ pub(crate) async fn apply_bespoke_event_handling(
                     turn_id: request.turn_id,
                     item_id: request_id.clone(),
                     questions,
+                    timeout_sec: request.timeout_sec,
                 };
                 let rx = outgoing
                     .send_request(ServerRequestPayload::ToolRequestUserInput(params))
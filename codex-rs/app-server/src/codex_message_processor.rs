@@ -66,6 +66,10 @@ use codex_app_server_protocol::GetUserAgentResponse;
 use codex_app_server_protocol::GetUserSavedConfigResponse;
 use codex_app_server_protocol::GitDiffToRemoteResponse;
 use codex_app_server_protocol::GitInfo as ApiGitInfo;
+use codex_app_server_protocol::HookMetricsReadParams;
+use codex_app_server_protocol::HookMetricsReadResponse;
+use codex_app_server_protocol::HookTestEmitParams;
+use codex_app_server_protocol::HookTestEmitResponse;
 use codex_app_server_protocol::InputItem as WireInputItem;
 use codex_app_server_protocol::InterruptConversationParams;
 use codex_app_server_protocol::JSONRPCErrorError;
@@ -654,6 +658,14 @@ impl CodexMessageProcessor {
                 self.get_account(to_connection_request_id(request_id), params)
                     .await;
             }
+            ClientRequest::HookMetricsRead { request_id, params } => {
+                self.hook_metrics_read(to_connection_request_id(request_id), params)
+                    .await;
+            }
+            ClientRequest::HookTestEmit { request_id, params } => {
+                self.hook_test_emit(to_connection_request_id(request_id), params)
+                    .await;
+            }
             ClientRequest::ResumeConversation { request_id, params } => {
                 self.handle_resume_conversation(to_connection_request_id(request_id), params)
                     .await;
@@ -2135,6 +2147,85 @@ impl CodexMessageProcessor {
             .await;
     }
 
+    async fn hook_metrics_read(
+        &self,
+        request_id: ConnectionRequestId,
+        params: HookMetricsReadParams,
+    ) {
+        let (_, thread) = match self.load_thread(&params.thread_id).await {
+            Ok(v) => v,
+            Err(error) => {
+                self.outgoing.send_error(request_id, error).await;
+                return;
+            }
+        };
+
+        let to_i64 = |value: u64| i64::try_from(value).unwrap_or(i64::MAX);
+        let metrics = thread.hook_metrics();
+        let response = HookMetricsReadResponse {
+            events_emitted: to_i64(metrics.events_emitted),
+            providers: metrics
+                .providers
+                .into_iter()
+                .map(|provider| codex_app_server_protocol::HookProviderMetrics {
+                    provider: provider.provider,
+                    commands_spawned: to_i64(provider.commands_spawned),
+                    failures: to_i64(provider.failures),
+                    timeouts: to_i64(provider.timeouts),
+                    breaker_trips: to_i64(provider.breaker_trips),
+                })
+                .collect(),
+        };
+        self.outgoing.send_response(request_id, response).await;
+    }
+
+    async fn hook_test_emit(&self, request_id: ConnectionRequestId, params: HookTestEmitParams) {
+        let (_, thread) = match self.load_thread(&params.thread_id).await {
+            Ok(v) => v,
+            Err(error) => {
+                self.outgoing.send_error(request_id, error).await;
+                return;
+            }
+        };
+
+        // Bounded command hooks may take a while; don't block other requests on them.
+        let outgoing = self.outgoing.clone();
+        tokio::spawn(async move {
+            let metrics = match thread
+                .emit_test_hook_event(&params.event_type, params.payload)
+                .await
+            {
+                Ok(metrics) => metrics,
+                Err(message) => {
+                    let error = JSONRPCErrorError {
+                        code: INVALID_REQUEST_ERROR_CODE,
+                        message,
+                        data: None,
+                    };
+                    outgoing.send_error(request_id, error).await;
+                    return;
+                }
+            };
+
+            let to_i64 = |value: u64| i64::try_from(value).unwrap_or(i64::MAX);
+            let response = HookTestEmitResponse {
+                events_emitted: to_i64(metrics.events_emitted),
+                providers: metrics
+                    .providers
+                    .into_iter()
+                    .map(|provider| codex_app_server_protocol::HookProviderMetrics {
+                        provider: provider.provider,
+                        commands_spawned: to_i64(provider.commands_spawned),
+                        failures: to_i64(provider.failures),
+                        timeouts: to_i64(provider.timeouts),
+                        breaker_trips: to_i64(provider.breaker_trips),
+                    })
+                    .collect(),
+            };
+            outgoing.send_response(request_id, response).await;
+        });
+    }
+
     async fn thread_unarchive(
         &mut self,
         request_id: ConnectionRequestId,
@@ -0,0 +1,159 @@
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Result;
+use app_test_support::McpProcess;
+use app_test_support::create_mock_responses_server_repeating_assistant;
+use app_test_support::to_response;
+use codex_app_server_protocol::HookProviderMetrics;
+use codex_app_server_protocol::HookTestEmitParams;
+use codex_app_server_protocol::HookTestEmitResponse;
+use codex_app_server_protocol::JSONRPCError;
+use codex_app_server_protocol::JSONRPCResponse;
+use codex_app_server_protocol::RequestId;
+use codex_app_server_protocol::ThreadStartParams;
+use codex_app_server_protocol::ThreadStartResponse;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use serde_json::json;
+use tempfile::TempDir;
+use tokio::time::timeout;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[cfg(unix)]
+#[tokio::test]
+async fn hook_test_emit_runs_configured_command_hooks() -> Result<()> {
+    let server = create_mock_responses_server_repeating_assistant("Done").await;
+    let codex_home = TempDir::new()?;
+    let payload_path = codex_home.path().join("tool-call-finished.json");
+    create_config_toml(codex_home.path(), &server.uri(), &payload_path)?;
+
+    let mut mcp = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+    let thread_id = start_thread(&mut mcp).await?;
+
+    let request_id = mcp
+        .send_hook_test_emit_request(HookTestEmitParams {
+            thread_id: thread_id.clone(),
+            event_type: "toolCallFinished".to_string(),
+            payload: json!({
+                "threadId": thread_id,
+                "turnId": "turn-1",
+                "cwd": "/tmp",
+                "modelRequestId": "00000000-0000-0000-0000-000000000000",
+                "attempt": 1,
+                "toolName": "shell",
+                "callId": "call-1",
+                "status": "completed",
+                "durationMs": 12,
+                "success": true,
+                "outputBytes": 3,
+            }),
+        })
+        .await?;
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    let response: HookTestEmitResponse = to_response(response)?;
+
+    assert_eq!(
+        response,
+        HookTestEmitResponse {
+            events_emitted: 1,
+            providers: vec![HookProviderMetrics {
+                provider: "external_command".to_string(),
+                commands_spawned: 1,
+                failures: 0,
+                timeouts: 0,
+                breaker_trips: 0,
+            }],
+        }
+    );
+    // Bounded hooks have finished by the time the response is sent.
+    let payload: Value = serde_json::from_str(&std::fs::read_to_string(&payload_path)?)?;
+    assert_eq!(payload["xcodex_event_type"], "tool-call-finished");
+    assert_eq!(payload["tool_name"], "shell");
+    assert_eq!(payload["tool_use_id"], "call-1");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hook_test_emit_rejects_unknown_event_type() -> Result<()> {
+    let server = create_mock_responses_server_repeating_assistant("Done").await;
+    let codex_home = TempDir::new()?;
+    let payload_path = codex_home.path().join("unused.json");
+    create_config_toml(codex_home.path(), &server.uri(), &payload_path)?;
+
+    let mut mcp = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_TIMEOUT, mcp.initialize()).await??;
+    let thread_id = start_thread(&mut mcp).await?;
+
+    let request_id = mcp
+        .send_hook_test_emit_request(HookTestEmitParams {
+            thread_id,
+            event_type: "notAnEvent".to_string(),
+            payload: json!({}),
+        })
+        .await?;
+    let error: JSONRPCError = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_error_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+
+    assert!(
+        error.error.message.contains("not-an-event"),
+        "unexpected error: {}",
+        error.error.message
+    );
+    assert!(!payload_path.exists());
+
+    Ok(())
+}
+
+async fn start_thread(mcp: &mut McpProcess) -> Result<String> {
+    let request_id = mcp
+        .send_thread_start_request(ThreadStartParams::default())
+        .await?;
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    let ThreadStartResponse { thread, .. } = to_response(response)?;
+    Ok(thread.id)
+}
+
+fn create_config_toml(
+    codex_home: &Path,
+    server_uri: &str,
+    payload_path: &Path,
+) -> std::io::Result<()> {
+    let hook_command = format!("cat > '{}'", payload_path.display());
+    std::fs::write(
+        codex_home.join("config.toml"),
+        format!(
+            r#"
+model = "mock-model"
+approval_policy = "never"
+sandbox_mode = "read-only"
+
+model_provider = "mock_provider"
+
+[model_providers.mock_provider]
+name = "Mock provider for test"
+base_url = "{server_uri}/v1"
+wire_api = "responses"
+request_max_retries = 0
+stream_max_retries = 0
+
+[hooks]
+tool_call_finished = [["sh", "-c", "{hook_command}"]]
+"#
+        ),
+    )
+}
@@ -7,9 +7,14 @@ use anyhow::Context;
 use anyhow::Result;
 use clap::Parser;
 use codex_common::CliConfigOverrides;
+use codex_core::auth::get_auth_file;
 use codex_core::config::CONFIG_TOML_FILE;
+use codex_core::config::Config;
 use codex_core::config::ConfigToml;
+use codex_core::config::edit::ConfigEdit;
+use codex_core::config::edit::ConfigEditsBuilder;
 use codex_core::config::find_codex_home;
+use codex_core::config::initialize_xcodex_home;
 use codex_core::config::is_xcodex_invocation;
 use codex_core::config::schema::config_schema_json;
 use codex_core::config_loader::CloudRequirementsLoader;
@@ -17,11 +22,16 @@ use codex_core::config_loader::ConfigLayerEntry;
 use codex_core::config_loader::ConfigLayerStackOrdering;
 use codex_core::config_loader::LoaderOverrides;
 use codex_core::config_loader::load_config_layers_state;
+use codex_core::themes::ThemeCatalog;
+use codex_core::themes::themes_dir;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use serde_json::Value;
 use tokio::process::Command;
 use toml::Value as TomlValue;
 
+use crate::xcodex_subcommands::hooks_logs_dir;
+use crate::xcodex_subcommands::hooks_payloads_dir;
+
 #[derive(Debug, Parser)]
 pub struct ConfigCli {
     #[clap(flatten)]
@@ -33,12 +43,27 @@ pub struct ConfigCli {
 
 #[derive(Debug, clap::Subcommand)]
 pub enum ConfigSubcommand {
-    /// Print the resolved config paths and layer precedence.
-    Path,
+    /// Print the resolved config, auth, hooks, and export paths plus layer precedence.
+    Path(PathArgs),
     /// Open the user config file in $VISUAL/$EDITOR (fallback: print the path).
     Edit(EditArgs),
     /// Validate config parsing and print common issues.
     Doctor,
+    /// Load every custom theme in `themes.dir` and report files that fail to parse or validate.
+    ValidateThemes,
+    /// Print a single value from the effective merged config (exits non-zero when unset).
+    Get(GetArgs),
+    /// Set a value in the user config, preserving comments and formatting.
+    Set(SetArgs),
+    /// Initialize CODEX_HOME non-interactively (default config plus first-run marker).
+    Init,
+}
+
+#[derive(Debug, Parser)]
+pub struct PathArgs {
+    /// Print the paths as JSON.
+    #[arg(long, default_value_t = false)]
+    json: bool,
 }
 
 #[derive(Debug, Parser)]
@@ -48,17 +73,60 @@ pub struct EditArgs {
     project: bool,
 }
 
+#[derive(Debug, Parser)]
+pub struct GetArgs {
+    /// Dotted key path, e.g. `sandbox_mode` or `hooks.host.enabled`.
+    #[arg(value_name = "KEY")]
+    key: String,
+
+    /// Print the value as JSON instead of TOML.
+    #[arg(long, default_value_t = false)]
+    json: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct SetArgs {
+    /// Dotted key path, e.g. `sandbox_mode` or `hooks.host.enabled`.
+    #[arg(value_name = "KEY")]
+    key: String,
+
+    /// New value. Parsed as a boolean, then an integer, then a string unless `--type` is given.
+    #[arg(value_name = "VALUE")]
+    value: String,
+
+    /// Parse the value as this type instead of inferring it.
+    #[arg(long = "type", value_enum)]
+    value_type: Option<ConfigValueType>,
+
+    /// Print the resulting diff without writing the file.
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ConfigValueType {
+    Bool,
+    Int,
+    String,
+}
+
 impl ConfigCli {
     pub async fn run(self) -> Result<()> {
         match self.subcommand {
-            ConfigSubcommand::Path => run_config_path(self.config_overrides).await,
+            ConfigSubcommand::Path(args) => run_config_path(self.config_overrides, args).await,
             ConfigSubcommand::Edit(args) => run_config_edit(args).await,
             ConfigSubcommand::Doctor => run_config_doctor(self.config_overrides).await,
+            ConfigSubcommand::ValidateThemes => {
+                run_config_validate_themes(self.config_overrides).await
+            }
+            ConfigSubcommand::Get(args) => run_config_get(self.config_overrides, args).await,
+            ConfigSubcommand::Set(args) => run_config_set(args),
+            ConfigSubcommand::Init => run_config_init(),
         }
     }
 }
 
-async fn run_config_path(config_overrides: CliConfigOverrides) -> Result<()> {
+async fn run_config_path(config_overrides: CliConfigOverrides, args: PathArgs) -> Result<()> {
     let codex_home = find_codex_home()?;
     let resolved_cwd = AbsolutePathBuf::current_dir()?;
     let cli_overrides = config_overrides
@@ -74,16 +142,42 @@ async fn run_config_path(config_overrides: CliConfigOverrides) -> Result<()> {
     )
     .await?;
 
+    let config_toml = codex_home.join(CONFIG_TOML_FILE);
+    let auth_file = get_auth_file(&codex_home);
+    let hooks_logs = hooks_logs_dir(&codex_home);
+    let hooks_payloads = hooks_payloads_dir(&codex_home);
+    let exports = codex_home.join("exports");
+    let layer_paths = layers
+        .get_layers(ConfigLayerStackOrdering::HighestPrecedenceFirst, false)
+        .into_iter()
+        .map(format_layer_path)
+        .collect::<Vec<_>>();
+
+    if args.json {
+        let paths = serde_json::json!({
+            "codex_home": codex_home,
+            "config_toml": config_toml,
+            "auth_file": auth_file,
+            "hooks_logs_dir": hooks_logs,
+            "hooks_payloads_dir": hooks_payloads,
+            "exports_dir": exports,
+            "layers": layer_paths,
+        });
+        println!("{}", serde_json::to_string_pretty(&paths)?);
+        return Ok(());
+    }
+
     println!("CODEX_HOME: {}", codex_home.display());
-    println!(
-        "User config: {}",
-        codex_home.join(CONFIG_TOML_FILE).display()
-    );
+    println!("User config: {}", config_toml.display());
+    println!("Auth file: {}", auth_file.display());
+    println!("Hooks logs: {}", hooks_logs.display());
+    println!("Hooks payloads: {}", hooks_payloads.display());
+    println!("Exports: {}", exports.display());
 
     println!();
     println!("Layers (highest precedence first):");
-    for layer in layers.get_layers(ConfigLayerStackOrdering::HighestPrecedenceFirst, false) {
-        println!("- {}", format_layer_path(layer));
+    for layer in layer_paths {
+        println!("- {layer}");
     }
 
     Ok(())
@@ -288,6 +382,185 @@ async fn run_config_doctor(config_overrides: CliConfigOverrides) -> Result<()> {
     Ok(())
 }
 
+async fn run_config_validate_themes(config_overrides: CliConfigOverrides) -> Result<()> {
+    let cli_overrides = config_overrides
+        .parse_overrides()
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let config = Config::load_with_cli_overrides(cli_overrides)
+        .await
+        .context("failed to load configuration")?;
+    let exe = command_name();
+
+    let dir = themes_dir(&config.codex_home, &config.xcodex.themes);
+    println!("Themes directory: {}", dir.display());
+    if !dir.exists() {
+        println!("Note: themes directory does not exist; only built-in themes are available.");
+        return Ok(());
+    }
+
+    let catalog = ThemeCatalog::load(&config)?;
+    let warnings = catalog.load_warnings();
+    for warning in warnings {
+        println!("Error: {}: {}", warning.path.display(), warning.message);
+    }
+    if !warnings.is_empty() {
+        println!(
+            "Next step: fix the theme files above (see `/theme help` or `docs/xcodex/themes.md`), then re-run `{exe} config validate-themes`."
+        );
+        anyhow::bail!("{} theme file(s) failed validation", warnings.len());
+    }
+
+    let custom = catalog
+        .list_names()
+        .filter(|(name, _)| catalog.user_theme_path(name).is_some())
+        .count();
+    println!("OK: {custom} custom theme(s) loaded successfully.");
+    Ok(())
+}
+
+async fn run_config_get(config_overrides: CliConfigOverrides, args: GetArgs) -> Result<()> {
+    let codex_home = find_codex_home()?;
+    let resolved_cwd = AbsolutePathBuf::current_dir()?;
+    let cli_overrides = config_overrides
+        .parse_overrides()
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    let layers = load_config_layers_state(
+        &codex_home,
+        Some(resolved_cwd),
+        &cli_overrides,
+        LoaderOverrides::default(),
+        CloudRequirementsLoader::default(),
+    )
+    .await?;
+    let effective_cfg: ConfigToml = layers
+        .effective_config()
+        .try_into()
+        .context("failed to parse merged effective config")?;
+    let effective =
+        serde_json::to_value(&effective_cfg).context("failed to serialize effective config")?;
+
+    let value = args
+        .key
+        .split('.')
+        .try_fold(&effective, |value, segment| match value {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|idx| items.get(idx)),
+            _ => None,
+        })
+        .filter(|value| !value.is_null());
+    let Some(value) = value else {
+        anyhow::bail!("config key `{}` is not set", args.key);
+    };
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(value)?);
+        return Ok(());
+    }
+    match value {
+        Value::String(text) => println!("{text}"),
+        Value::Object(_) => print!("{}", toml::to_string(&without_nulls(value))?),
+        _ => println!("{value}"),
+    }
+    Ok(())
+}
+
+fn run_config_set(args: SetArgs) -> Result<()> {
+    let SetArgs {
+        key,
+        value,
+        value_type,
+        dry_run,
+    } = args;
+
+    let known_paths = known_key_paths()?;
+    if !is_known_key_path(&key, &known_paths) {
+        match best_key_suggestion(&key, &known_paths) {
+            Some(suggestion) => {
+                anyhow::bail!("unknown config key `{key}` (did you mean `{suggestion}`?)")
+            }
+            None => anyhow::bail!("unknown config key `{key}`"),
+        }
+    }
+
+    let value_type = value_type.unwrap_or_else(|| {
+        if value.parse::<bool>().is_ok() {
+            ConfigValueType::Bool
+        } else if value.parse::<i64>().is_ok() {
+            ConfigValueType::Int
+        } else {
+            ConfigValueType::String
+        }
+    });
+    let new_value = match value_type {
+        ConfigValueType::Bool => toml_edit::value(
+            value
+                .parse::<bool>()
+                .with_context(|| format!("`{value}` is not a boolean"))?,
+        ),
+        ConfigValueType::Int => toml_edit::value(
+            value
+                .parse::<i64>()
+                .with_context(|| format!("`{value}` is not an integer"))?,
+        ),
+        ConfigValueType::String => toml_edit::value(value.as_str()),
+    };
+
+    let codex_home = find_codex_home()?;
+    let config_path = codex_home.join(CONFIG_TOML_FILE);
+    // Validate and preview the exact document the write below produces.
+    let preview = ConfigEditsBuilder::new(&codex_home)
+        .with_edits([ConfigEdit::SetPath {
+            segments: key.split('.').map(ToString::to_string).collect(),
+            value: new_value,
+        }])
+        .preview_blocking()
+        .with_context(|| format!("failed to read {}", config_path.display()))?;
+    if !preview.mutated {
+        anyhow::bail!("cannot set `{key}`: one of its parent keys is not a table");
+    }
+    toml::from_str::<ConfigToml>(&preview.updated)
+        .with_context(|| format!("`{key} = {value}` is not a valid config value"))?;
+
+    if dry_run {
+        let diff = similar::TextDiff::from_lines(&preview.original, &preview.updated);
+        print!(
+            "{}",
+            diff.unified_diff()
+                .header(CONFIG_TOML_FILE, CONFIG_TOML_FILE)
+        );
+        return Ok(());
+    }
+
+    preview
+        .write()
+        .with_context(|| format!("failed to write {}", config_path.display()))?;
+    println!("Set `{key}` in {}", config_path.display());
+    Ok(())
+}
+
+fn run_config_init() -> Result<()> {
+    let codex_home = find_codex_home()?;
+    initialize_xcodex_home(&codex_home)
+        .with_context(|| format!("failed to initialize {}", codex_home.display()))?;
+    println!("Initialized {}", codex_home.display());
+    Ok(())
+}
+
+/// TOML has no null, so unset optional fields are dropped before rendering a table.
+fn without_nulls(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key.clone(), without_nulls(value)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(without_nulls).collect()),
+        _ => value.clone(),
+    }
+}
+
 fn format_layer_path(layer: &ConfigLayerEntry) -> String {
     use codex_app_server_protocol::ConfigLayerSource;
     match &layer.name {
@@ -385,6 +658,85 @@ fn resolve_editor_command() -> std::result::Result<Vec<String>, &'static str> {
     Ok(parts)
 }
 
+/// Reject `-c key=value` overrides whose key does not appear in the config
+/// schema. Used by `--strict-config` so typos fail instead of being ignored.
+pub(crate) fn validate_override_keys(config_overrides: &CliConfigOverrides) -> Result<()> {
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let known_paths = known_key_paths()?;
+    let unknown = overrides
+        .iter()
+        .flat_map(|(key, value)| unknown_override_paths(key, value, &known_paths))
+        .map(|key| match best_key_suggestion(&key, &known_paths) {
+            Some(suggestion) => format!("`{key}` (did you mean `{suggestion}`?)"),
+            None => format!("`{key}`"),
+        })
+        .collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        anyhow::bail!("unknown config override key(s): {}", unknown.join(", "));
+    }
+    Ok(())
+}
+
+/// Returns the key paths an override sets that the schema does not know.
+///
+/// Table and array values (`-c 'hooks.host={enabled=true}'`) are checked leaf by leaf; an empty
+/// one only needs `key` to name a known table or list.
+fn unknown_override_paths(
+    key: &str,
+    value: &TomlValue,
+    known_paths: &BTreeSet<String>,
+) -> Vec<String> {
+    match value {
+        TomlValue::Table(_) | TomlValue::Array(_) => {
+            let leaves = collect_leaf_paths(value);
+            if leaves.is_empty() {
+                return if is_known_key_prefix(key, known_paths) {
+                    Vec::new()
+                } else {
+                    vec![key.to_string()]
+                };
+            }
+            leaves
+                .into_iter()
+                .map(|leaf| format!("{key}.{leaf}"))
+                .filter(|path| !is_known_key_path(path, known_paths))
+                .collect()
+        }
+        _ if is_known_key_path(key, known_paths) => Vec::new(),
+        _ => vec![key.to_string()],
+    }
+}
+
+/// A key is known when it exactly names a schema path, with `*` schema
+/// segments (map entries, array items) matching any key segment.
+fn is_known_key_path(key: &str, known_paths: &BTreeSet<String>) -> bool {
+    let segments = key.split('.').collect::<Vec<_>>();
+    known_paths.iter().any(|known| {
+        let known = known.split('.').collect::<Vec<_>>();
+        known.len() == segments.len()
+            && known
+                .iter()
+                .zip(&segments)
+                .all(|(known, segment)| *known == "*" || known == segment)
+    })
+}
+
+/// Like [`is_known_key_path`], but also accepts keys naming a table or list
+/// that contains known paths.
+fn is_known_key_prefix(key: &str, known_paths: &BTreeSet<String>) -> bool {
+    let segments = key.split('.').collect::<Vec<_>>();
+    known_paths.iter().any(|known| {
+        let known = known.split('.').collect::<Vec<_>>();
+        known.len() >= segments.len()
+            && known
+                .iter()
+                .zip(&segments)
+                .all(|(known, segment)| *known == "*" || known == segment)
+    })
+}
+
 fn known_key_paths() -> Result<BTreeSet<String>> {
     let schema_bytes = config_schema_json().context("failed to load config schema json")?;
     let schema: Value =
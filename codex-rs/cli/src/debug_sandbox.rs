@@ -6,6 +6,7 @@ mod seatbelt;
 use std::path::PathBuf;
 
 use codex_core::config::Config;
+use codex_core::config::ConfigBuilder;
 use codex_core::config::ConfigOverrides;
 use codex_core::exec_env::create_env;
 use codex_core::landlock::spawn_command_under_linux_sandbox;
@@ -14,8 +15,10 @@ use codex_core::seatbelt::spawn_command_under_seatbelt;
 use codex_core::spawn::StdioPolicy;
 use codex_protocol::config_types::SandboxMode;
 use codex_utils_cli::CliConfigOverrides;
+use codex_utils_sandbox_summary::summarize_sandbox_policy;
 
 use crate::LandlockCommand;
+use crate::SandboxPolicyCommand;
 use crate::SeatbeltCommand;
 use crate::WindowsCommand;
 use crate::exit_status::handle_exit_status;
@@ -31,12 +34,14 @@ pub async fn run_command_under_seatbelt(
     let SeatbeltCommand {
         full_auto,
         log_denials,
+        add_dir,
         config_overrides,
         command,
     } = command;
     run_command_under_sandbox(
         full_auto,
         command,
+        add_dir,
         config_overrides,
         codex_linux_sandbox_exe,
         SandboxType::Seatbelt,
@@ -59,12 +64,14 @@ pub async fn run_command_under_landlock(
 ) -> anyhow::Result<()> {
     let LandlockCommand {
         full_auto,
+        add_dir,
         config_overrides,
         command,
     } = command;
     run_command_under_sandbox(
         full_auto,
         command,
+        add_dir,
         config_overrides,
         codex_linux_sandbox_exe,
         SandboxType::Landlock,
@@ -79,12 +86,14 @@ pub async fn run_command_under_windows(
 ) -> anyhow::Result<()> {
     let WindowsCommand {
         full_auto,
+        add_dir,
         config_overrides,
         command,
     } = command;
     run_command_under_sandbox(
         full_auto,
         command,
+        add_dir,
         config_overrides,
         codex_linux_sandbox_exe,
         SandboxType::Windows,
@@ -93,6 +102,59 @@ pub async fn run_command_under_windows(
     .await
 }
 
+/// Resolve the effective sandbox policy for the requested mode and cwd and
+/// print it without running anything.
+pub async fn print_sandbox_policy(
+    command: SandboxPolicyCommand,
+    codex_linux_sandbox_exe: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let SandboxPolicyCommand {
+        sandbox_mode,
+        full_auto,
+        cwd,
+        config_overrides,
+    } = command;
+    let sandbox_mode = sandbox_mode
+        .map(SandboxMode::from)
+        .unwrap_or_else(|| create_sandbox_mode(full_auto));
+    let config = Config::load_with_cli_overrides_and_harness_overrides(
+        config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?,
+        ConfigOverrides {
+            sandbox_mode: Some(sandbox_mode),
+            cwd,
+            codex_linux_sandbox_exe,
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    let sandbox_policy = config.permissions.sandbox_policy.get();
+    println!("workdir: {}", config.cwd.display());
+    println!("sandbox: {}", summarize_sandbox_policy(sandbox_policy));
+    let network = if sandbox_policy.has_full_network_access() {
+        "enabled"
+    } else {
+        "restricted"
+    };
+    println!("network access: {network}");
+
+    let writable_roots = sandbox_policy.get_writable_roots_with_cwd(&config.cwd);
+    if writable_roots.is_empty() {
+        println!("writable roots: (none)");
+        return Ok(());
+    }
+    println!("writable roots:");
+    for writable_root in writable_roots {
+        println!("  {}", writable_root.root.display());
+        for subpath in writable_root.read_only_subpaths {
+            println!("    read-only: {}", subpath.display());
+        }
+    }
+    Ok(())
+}
+
 enum SandboxType {
     #[cfg(target_os = "macos")]
     Seatbelt,
@@ -103,22 +165,19 @@ enum SandboxType {
 async fn run_command_under_sandbox(
     full_auto: bool,
     command: Vec<String>,
+    add_dir: Vec<PathBuf>,
     config_overrides: CliConfigOverrides,
     codex_linux_sandbox_exe: Option<PathBuf>,
     sandbox_type: SandboxType,
     log_denials: bool,
 ) -> anyhow::Result<()> {
-    let sandbox_mode = create_sandbox_mode(full_auto);
-    let config = Config::load_with_cli_overrides_and_harness_overrides(
-        config_overrides
-            .parse_overrides()
-            .map_err(anyhow::Error::msg)?,
-        ConfigOverrides {
-            sandbox_mode: Some(sandbox_mode),
-            codex_linux_sandbox_exe,
-            ..Default::default()
-        },
-    )
+    let config = sandbox_config_builder(
+        full_auto,
+        add_dir,
+        config_overrides,
+        codex_linux_sandbox_exe,
+    )?
+    .build()
     .await?;
 
     // In practice, this should be `std::env::current_dir()` because this CLI
@@ -288,6 +347,30 @@ async fn run_command_under_sandbox(
     handle_exit_status(status);
 }
 
+/// Config builder for the sandbox debug subcommands. `--add-dir` entries are
+/// folded into the workspace-write writable roots the same way the
+/// interactive and exec CLIs do.
+fn sandbox_config_builder(
+    full_auto: bool,
+    add_dir: Vec<PathBuf>,
+    config_overrides: CliConfigOverrides,
+    codex_linux_sandbox_exe: Option<PathBuf>,
+) -> anyhow::Result<ConfigBuilder> {
+    let sandbox_mode = create_sandbox_mode(full_auto);
+    Ok(ConfigBuilder::default()
+        .cli_overrides(
+            config_overrides
+                .parse_overrides()
+                .map_err(anyhow::Error::msg)?,
+        )
+        .harness_overrides(ConfigOverrides {
+            sandbox_mode: Some(sandbox_mode),
+            codex_linux_sandbox_exe,
+            additional_writable_roots: add_dir,
+            ..Default::default()
+        }))
+}
+
 pub fn create_sandbox_mode(full_auto: bool) -> SandboxMode {
     if full_auto {
         SandboxMode::WorkspaceWrite
@@ -295,3 +378,53 @@ pub fn create_sandbox_mode(full_auto: bool) -> SandboxMode {
         SandboxMode::ReadOnly
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn landlock_add_dir_extends_writable_roots() -> anyhow::Result<()> {
+        let codex_home = TempDir::new()?;
+        let cwd = TempDir::new()?;
+        let extra = TempDir::new()?;
+        let extra_dir = extra.path().to_path_buf();
+
+        let LandlockCommand {
+            full_auto,
+            add_dir,
+            config_overrides,
+            command,
+        } = LandlockCommand::try_parse_from([
+            "landlock".into(),
+            "--full-auto".into(),
+            "--add-dir".into(),
+            extra_dir.clone().into_os_string(),
+            "true".into(),
+        ])?;
+        assert_eq!(command, vec!["true".to_string()]);
+
+        let config = sandbox_config_builder(full_auto, add_dir, config_overrides, None)?
+            .codex_home(codex_home.path().to_path_buf())
+            .fallback_cwd(Some(cwd.path().to_path_buf()))
+            .build()
+            .await?;
+
+        let writable_roots: Vec<PathBuf> = config
+            .permissions
+            .sandbox_policy
+            .get()
+            .get_writable_roots_with_cwd(cwd.path())
+            .into_iter()
+            .map(|root| root.root.to_path_buf())
+            .collect();
+        assert!(
+            writable_roots.contains(&extra_dir),
+            "expected {extra_dir:?} in {writable_roots:?}"
+        );
+        Ok(())
+    }
+}
@@ -2,8 +2,11 @@ pub mod debug_sandbox;
 mod exit_status;
 pub mod login;
 
+use std::path::PathBuf;
+
 use clap::Parser;
 use codex_utils_cli::CliConfigOverrides;
+use codex_utils_cli::SandboxModeCliArg;
 
 #[derive(Debug, Parser)]
 pub struct SeatbeltCommand {
@@ -15,6 +18,10 @@ pub struct SeatbeltCommand {
     #[arg(long = "log-denials", default_value_t = false)]
     pub log_denials: bool,
 
+    /// Additional directories that should be writable alongside the primary workspace.
+    #[arg(long = "add-dir", value_name = "DIR", value_hint = clap::ValueHint::DirPath)]
+    pub add_dir: Vec<PathBuf>,
+
     #[clap(skip)]
     pub config_overrides: CliConfigOverrides,
 
@@ -29,6 +36,10 @@ pub struct LandlockCommand {
     #[arg(long = "full-auto", default_value_t = false)]
     pub full_auto: bool,
 
+    /// Additional directories that should be writable alongside the primary workspace.
+    #[arg(long = "add-dir", value_name = "DIR", value_hint = clap::ValueHint::DirPath)]
+    pub add_dir: Vec<PathBuf>,
+
     #[clap(skip)]
     pub config_overrides: CliConfigOverrides,
 
@@ -43,6 +54,10 @@ pub struct WindowsCommand {
     #[arg(long = "full-auto", default_value_t = false)]
     pub full_auto: bool,
 
+    /// Additional directories that should be writable alongside the primary workspace.
+    #[arg(long = "add-dir", value_name = "DIR", value_hint = clap::ValueHint::DirPath)]
+    pub add_dir: Vec<PathBuf>,
+
     #[clap(skip)]
     pub config_overrides: CliConfigOverrides,
 
@@ -50,3 +65,26 @@ pub struct WindowsCommand {
     #[arg(trailing_var_arg = true)]
     pub command: Vec<String>,
 }
+
+#[derive(Debug, Parser)]
+pub struct SandboxPolicyCommand {
+    /// Sandbox mode to resolve. Defaults to read-only.
+    #[arg(
+        long = "sandbox-mode",
+        short = 's',
+        value_enum,
+        conflicts_with = "full_auto"
+    )]
+    pub sandbox_mode: Option<SandboxModeCliArg>,
+
+    /// Resolve the policy used by `--full-auto` (workspace-write).
+    #[arg(long = "full-auto", default_value_t = false)]
+    pub full_auto: bool,
+
+    /// Working directory the policy is resolved against. Defaults to the current directory.
+    #[arg(long = "cd", short = 'C', value_name = "DIR")]
+    pub cwd: Option<PathBuf>,
+
+    #[clap(skip)]
+    pub config_overrides: CliConfigOverrides,
+}
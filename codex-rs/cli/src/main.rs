@@ -7,6 +7,7 @@ use codex_arg0::arg0_dispatch_or_else;
 use codex_chatgpt::apply_command::ApplyCommand;
 use codex_chatgpt::apply_command::run_apply_command;
 use codex_cli::LandlockCommand;
+use codex_cli::SandboxPolicyCommand;
 use codex_cli::SeatbeltCommand;
 use codex_cli::WindowsCommand;
 use codex_cli::login::read_api_key_from_stdin;
@@ -29,8 +30,12 @@ use codex_tui2 as tui2;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use codex_utils_cli::CliConfigOverrides;
 use owo_colors::OwoColorize;
+use std::ffi::OsString;
 use std::io::IsTerminal;
+use std::num::NonZeroU64;
+use std::path::Path;
 use std::path::PathBuf;
+use std::time::Duration;
 use supports_color::Stream;
 
 #[cfg(target_os = "macos")]
@@ -53,7 +58,9 @@ use codex_core::config::ConfigOverrides;
 use codex_core::config::edit::ConfigEditsBuilder;
 use codex_core::config::find_codex_home;
 use codex_core::config::load_config_as_toml_with_cli_overrides;
+use codex_core::config::mark_xcodex_first_run_complete;
 use codex_core::config::should_run_xcodex_first_run_wizard;
+use codex_core::config_loader::merge_toml_values;
 use codex_core::features::Feature;
 use codex_core::features::FeatureOverrides;
 use codex_core::features::Features;
@@ -90,6 +97,24 @@ struct MultitoolCli {
     #[arg(long = "no-hooks", default_value_t = false, global = true)]
     pub no_hooks: bool,
 
+    /// Reject `-c key=value` overrides whose key is not a known config key.
+    #[arg(long = "strict-config", default_value_t = false, global = true)]
+    pub strict_config: bool,
+
+    /// Print the config resolved from config files, `--profile`, `-c` overrides, and feature
+    /// toggles as TOML, then exit without running anything.
+    #[arg(
+        long = "print-effective-config",
+        default_value_t = false,
+        global = true
+    )]
+    pub print_effective_config: bool,
+
+    /// Print the token usage and resume hint shown on exit without color. A non-empty
+    /// `NO_COLOR` environment variable has the same effect.
+    #[arg(long = "no-color", default_value_t = false, global = true)]
+    pub no_color: bool,
+
     #[clap(flatten)]
     interactive: TuiCli,
 
@@ -101,7 +126,7 @@ struct MultitoolCli {
 enum Subcommand {
     /// Run Codex non-interactively.
     #[clap(visible_alias = "e")]
-    Exec(ExecCli),
+    Exec(ExecArgs),
 
     /// Run a code review non-interactively.
     Review(ReviewArgs),
@@ -210,6 +235,18 @@ struct DebugAppServerSendMessageV2Command {
     user_message: String,
 }
 
+#[derive(Debug, Parser)]
+struct ExecArgs {
+    /// Treat CODEX_HOME as initialized instead of requiring the interactive first-run wizard,
+    /// recording that so later runs skip it too. Setting `XCODEX_SKIP_FIRST_RUN=1` has the
+    /// same effect.
+    #[arg(long = "assume-initialized", default_value_t = false)]
+    assume_initialized: bool,
+
+    #[clap(flatten)]
+    exec_cli: ExecCli,
+}
+
 #[derive(Debug, Parser)]
 struct ResumeCommand {
     /// Conversation/session id (UUID) or thread name. UUIDs take precedence if it parses.
@@ -266,6 +303,9 @@ enum SandboxCommand {
 
     /// Run a command under Windows restricted token (Windows only).
     Windows(WindowsCommand),
+
+    /// Print the effective sandbox policy for a mode and cwd without running anything.
+    Policy(SandboxPolicyCommand),
 }
 
 #[derive(Debug, Parser)]
@@ -402,32 +442,43 @@ struct StdioToUdsCommand {
     /// Path to the Unix domain socket to connect to.
     #[arg(value_name = "SOCKET_PATH")]
     socket_path: PathBuf,
+
+    /// Write a newline to the socket after this many idle seconds so the relay is not dropped
+    /// by idle timeouts. Off by default.
+    #[arg(long = "keepalive-sec", value_name = "SECONDS")]
+    keepalive_sec: Option<NonZeroU64>,
 }
 
-fn format_exit_messages(exit_info: AppExitInfo, color_enabled: bool) -> Vec<String> {
-    let AppExitInfo {
-        token_usage,
-        thread_id: conversation_id,
-        thread_name,
-        ..
-    } = exit_info;
+fn resume_hint_command(exit_info: &AppExitInfo, bin_name: &str) -> Option<String> {
+    if !exit_info.resumable {
+        return None;
+    }
+    let resume_cmd =
+        codex_core::util::resume_command(exit_info.thread_name.as_deref(), exit_info.thread_id)?;
+    Some(
+        resume_cmd
+            .strip_prefix("codex ")
+            .map(|suffix| format!("{bin_name} {suffix}"))
+            .unwrap_or(resume_cmd),
+    )
+}
 
-    if token_usage.is_zero() {
+fn format_exit_messages(
+    exit_info: AppExitInfo,
+    color_enabled: bool,
+    bin_name: &str,
+) -> Vec<String> {
+    if exit_info.token_usage.is_zero() {
         return Vec::new();
     }
 
+    let resume_cmd = resume_hint_command(&exit_info, bin_name);
     let mut lines = vec![format!(
         "{}",
-        codex_core::protocol::FinalOutput::from(token_usage)
+        codex_core::protocol::FinalOutput::from(exit_info.token_usage)
     )];
 
-    if let Some(resume_cmd) =
-        codex_core::util::resume_command(thread_name.as_deref(), conversation_id)
-    {
-        let resume_cmd = resume_cmd
-            .strip_prefix("codex ")
-            .map(|suffix| format!("xcodex {suffix}"))
-            .unwrap_or(resume_cmd);
+    if let Some(resume_cmd) = resume_cmd {
         let command = if color_enabled {
             resume_cmd.cyan().to_string()
         } else {
@@ -439,8 +490,39 @@ fn format_exit_messages(exit_info: AppExitInfo, color_enabled: bool) -> Vec<Stri
     lines
 }
 
+/// Whether exit messages may use color. `--no-color` and a non-empty `NO_COLOR` override
+/// terminal color detection.
+fn exit_color_enabled(
+    no_color_flag: bool,
+    no_color_env: Option<OsString>,
+    terminal_supports_color: bool,
+) -> bool {
+    !no_color_flag && no_color_env.is_none_or(|value| value.is_empty()) && terminal_supports_color
+}
+
+/// Fail non-interactive runs that would need the first-run wizard, unless the caller asked to
+/// treat `codex_home` as initialized, in which case the wizard marker is written instead.
+fn ensure_first_run_complete(
+    codex_home: &Path,
+    needs_first_run: bool,
+    assume_initialized: bool,
+    skip_first_run_env: Option<OsString>,
+) -> anyhow::Result<()> {
+    if !needs_first_run {
+        return Ok(());
+    }
+    if assume_initialized || skip_first_run_env.is_some_and(|value| value == "1") {
+        mark_xcodex_first_run_complete(codex_home)?;
+        return Ok(());
+    }
+    anyhow::bail!(
+        "xcodex first-run setup required: run `xcodex` once to initialize {} (or pass --assume-initialized, or set CODEX_HOME to an initialized directory)",
+        codex_home.display()
+    );
+}
+
 /// Handle the app exit and print the results. Optionally run the update action.
-fn handle_app_exit(exit_info: AppExitInfo) -> anyhow::Result<()> {
+fn handle_app_exit(exit_info: AppExitInfo, no_color: bool) -> anyhow::Result<()> {
     match exit_info.exit_reason {
         ExitReason::Fatal(message) => {
             eprintln!("ERROR: {message}");
@@ -450,16 +532,23 @@ fn handle_app_exit(exit_info: AppExitInfo) -> anyhow::Result<()> {
     }
 
     let update_action = exit_info.update_action;
-    let color_enabled = supports_color::on(Stream::Stdout).is_some();
+    let bin_name = codex_core::util::resume_bin_name(std::env::args_os().next());
+    let color_enabled = exit_color_enabled(
+        no_color,
+        std::env::var_os("NO_COLOR"),
+        supports_color::on(Stream::Stdout).is_some(),
+    );
     if color_enabled {
         let terminal_width = crossterm::terminal::size()
             .map(|(w, _)| w)
             .unwrap_or_default();
-        for line in codex_tui::themed_exit_footer(&exit_info, terminal_width) {
+        let resume_cmd = resume_hint_command(&exit_info, &bin_name);
+        for line in codex_tui::themed_exit_footer(&exit_info, resume_cmd.as_deref(), terminal_width)
+        {
             println!("{line}");
         }
     } else {
-        for line in format_exit_messages(exit_info, false) {
+        for line in format_exit_messages(exit_info, false, &bin_name) {
             println!("{line}");
         }
     }
@@ -603,10 +692,17 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
         config_overrides: mut root_config_overrides,
         feature_toggles,
         no_hooks,
+        strict_config,
+        print_effective_config,
+        no_color,
         mut interactive,
         subcommand,
     } = MultitoolCli::parse();
 
+    if strict_config {
+        config_cmd::validate_override_keys(&root_config_overrides)?;
+    }
+
     // Fold --enable/--disable into config overrides so they flow to all subcommands.
     let toggle_overrides = feature_toggles.to_overrides()?;
     root_config_overrides.raw_overrides.extend(toggle_overrides);
@@ -625,11 +721,16 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                 "hooks.inproc_tool_call_summary=false",
                 "hooks.inproc=[]",
                 "hooks.host.enabled=false",
+                "hooks.webhook.url=\"\"",
             ]
             .map(ToString::to_string),
         );
     }
 
+    if print_effective_config {
+        return print_effective_config_toml(&interactive, &root_config_overrides).await;
+    }
+
     match subcommand {
         None => {
             if !std::io::stdin().is_terminal() {
@@ -654,12 +755,12 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                     root_config_overrides.clone(),
                 );
                 let codex_home = find_codex_home()?;
-                if should_run_xcodex_first_run_wizard(&codex_home)? {
-                    anyhow::bail!(
-                        "xcodex first-run setup required: run `xcodex` once to initialize {} (or set CODEX_HOME to an initialized directory)",
-                        codex_home.display()
-                    );
-                }
+                ensure_first_run_complete(
+                    &codex_home,
+                    should_run_xcodex_first_run_wizard(&codex_home)?,
+                    false,
+                    std::env::var_os("XCODEX_SKIP_FIRST_RUN"),
+                )?;
                 codex_exec::run_main(exec_cli, codex_linux_sandbox_exe).await?;
                 return Ok(());
             }
@@ -669,20 +770,23 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                 root_config_overrides.clone(),
             );
             let exit_info = run_interactive_tui(interactive, codex_linux_sandbox_exe).await?;
-            handle_app_exit(exit_info)?;
+            handle_app_exit(exit_info, no_color)?;
         }
-        Some(Subcommand::Exec(mut exec_cli)) => {
+        Some(Subcommand::Exec(ExecArgs {
+            assume_initialized,
+            mut exec_cli,
+        })) => {
             prepend_config_flags(
                 &mut exec_cli.config_overrides,
                 root_config_overrides.clone(),
             );
             let codex_home = find_codex_home()?;
-            if should_run_xcodex_first_run_wizard(&codex_home)? {
-                anyhow::bail!(
-                    "xcodex first-run setup required: run `xcodex` once to initialize {} (or set CODEX_HOME to an initialized directory)",
-                    codex_home.display()
-                );
-            }
+            ensure_first_run_complete(
+                &codex_home,
+                should_run_xcodex_first_run_wizard(&codex_home)?,
+                assume_initialized,
+                std::env::var_os("XCODEX_SKIP_FIRST_RUN"),
+            )?;
             codex_exec::run_main(exec_cli, codex_linux_sandbox_exe).await?;
         }
         Some(Subcommand::Review(review_args)) => {
@@ -752,10 +856,15 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                 config_overrides,
             );
             let exit_info = run_interactive_tui(interactive, codex_linux_sandbox_exe).await?;
-            handle_app_exit(exit_info)?;
+            handle_app_exit(exit_info, no_color)?;
         }
         Some(Subcommand::Hooks(cmd)) => {
-            xcodex_subcommands::run_hooks_command(&root_config_overrides, cmd).await?;
+            xcodex_subcommands::run_hooks_command(
+                &root_config_overrides,
+                cmd,
+                codex_linux_sandbox_exe,
+            )
+            .await?;
         }
         Some(Subcommand::Plan(cmd)) => {
             xcodex_subcommands::run_plan_command(&root_config_overrides, cmd).await?;
@@ -776,7 +885,7 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                 config_overrides,
             );
             let exit_info = run_interactive_tui(interactive, codex_linux_sandbox_exe).await?;
-            handle_app_exit(exit_info)?;
+            handle_app_exit(exit_info, no_color)?;
         }
         Some(Subcommand::Login(mut login_cli)) => {
             prepend_config_flags(
@@ -860,6 +969,14 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                 )
                 .await?;
             }
+            SandboxCommand::Policy(mut policy_cli) => {
+                prepend_config_flags(
+                    &mut policy_cli.config_overrides,
+                    root_config_overrides.clone(),
+                );
+                codex_cli::debug_sandbox::print_sandbox_policy(policy_cli, codex_linux_sandbox_exe)
+                    .await?;
+            }
         },
         Some(Subcommand::Debug(DebugCommand { subcommand })) => match subcommand {
             DebugSubcommand::AppServer(cmd) => {
@@ -882,8 +999,13 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
         }
         Some(Subcommand::StdioToUds(cmd)) => {
             let socket_path = cmd.socket_path;
-            tokio::task::spawn_blocking(move || codex_stdio_to_uds::run(socket_path.as_path()))
-                .await??;
+            let keepalive = cmd
+                .keepalive_sec
+                .map(|seconds| Duration::from_secs(seconds.get()));
+            tokio::task::spawn_blocking(move || {
+                codex_stdio_to_uds::run(socket_path.as_path(), keepalive)
+            })
+            .await??;
         }
         Some(Subcommand::Features(FeaturesCli { sub })) => match sub {
             FeaturesSubcommand::List => {
@@ -1084,6 +1206,31 @@ async fn is_tui2_enabled(cli: &TuiCli) -> std::io::Result<bool> {
     Ok(features.enabled(Feature::Tui2))
 }
 
+/// Print the merged `config.toml` view (with `-c` overrides applied and the selected profile
+/// folded into the top level) without loading the full runtime config.
+async fn print_effective_config_toml(
+    interactive: &TuiCli,
+    root_config_overrides: &CliConfigOverrides,
+) -> anyhow::Result<()> {
+    let cli_kv_overrides = root_config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+
+    let codex_home = find_codex_home()?;
+    let config_cwd = match interactive.cwd.as_deref() {
+        Some(path) => AbsolutePathBuf::from_absolute_path(path)?,
+        None => AbsolutePathBuf::current_dir()?,
+    };
+    let config_toml =
+        load_config_as_toml_with_cli_overrides(&codex_home, &config_cwd, cli_kv_overrides).await?;
+    let config_profile = config_toml.get_config_profile(interactive.config_profile.clone())?;
+
+    let mut effective = toml::Value::try_from(&config_toml)?;
+    merge_toml_values(&mut effective, &toml::Value::try_from(&config_profile)?);
+    print!("{}", toml::to_string_pretty(&effective)?);
+    Ok(())
+}
+
 /// Build the final `TuiCli` for a `codex resume` invocation.
 fn finalize_resume_interactive(
     mut interactive: TuiCli,
@@ -1200,6 +1347,7 @@ fn print_completion(cmd: CompletionCommand) {
 mod tests {
     use super::*;
     use assert_matches::assert_matches;
+    use codex_core::config::xcodex_first_run_wizard_marker_path;
     use codex_core::protocol::TokenUsage;
     use codex_protocol::ThreadId;
     use pretty_assertions::assert_eq;
@@ -1212,6 +1360,9 @@ mod tests {
             subcommand,
             feature_toggles: _,
             no_hooks: _,
+            strict_config: _,
+            print_effective_config: _,
+            no_color: _,
         } = cli;
 
         let Subcommand::Resume(ResumeCommand {
@@ -1262,7 +1413,7 @@ mod tests {
             MultitoolCli::try_parse_from(["codex", "exec", "--json", "resume", "--last", "2+2"])
                 .expect("parse should succeed");
 
-        let Some(Subcommand::Exec(exec)) = cli.subcommand else {
+        let Some(Subcommand::Exec(ExecArgs { exec_cli: exec, .. })) = cli.subcommand else {
             panic!("expected exec subcommand");
         };
         let Some(codex_exec::Command::Resume(args)) = exec.command else {
@@ -1294,6 +1445,7 @@ mod tests {
                 .map(ThreadId::from_string)
                 .map(Result::unwrap),
             thread_name: thread_name.map(str::to_string),
+            resumable: true,
             update_action: None,
             exit_reason: ExitReason::UserRequested,
         }
@@ -1305,17 +1457,18 @@ mod tests {
             token_usage: TokenUsage::default(),
             thread_id: None,
             thread_name: None,
+            resumable: true,
             update_action: None,
             exit_reason: ExitReason::UserRequested,
         };
-        let lines = format_exit_messages(exit_info, false);
+        let lines = format_exit_messages(exit_info, false, "xcodex");
         assert!(lines.is_empty());
     }
 
     #[test]
     fn format_exit_messages_includes_resume_hint_without_color() {
         let exit_info = sample_exit_info(Some("123e4567-e89b-12d3-a456-426614174000"), None);
-        let lines = format_exit_messages(exit_info, false);
+        let lines = format_exit_messages(exit_info, false, "xcodex");
         assert_eq!(
             lines,
             vec![
@@ -1329,18 +1482,122 @@ mod tests {
     #[test]
     fn format_exit_messages_applies_color_when_enabled() {
         let exit_info = sample_exit_info(Some("123e4567-e89b-12d3-a456-426614174000"), None);
-        let lines = format_exit_messages(exit_info, true);
+        let lines = format_exit_messages(exit_info, true, "xcodex");
         assert_eq!(lines.len(), 2);
         assert!(lines[1].contains("\u{1b}[36m"));
     }
 
+    #[test]
+    fn no_color_disables_resume_hint_color() {
+        let disabled = [
+            exit_color_enabled(true, None, true),
+            exit_color_enabled(false, Some(OsString::from("1")), true),
+        ];
+        assert_eq!(disabled, [false, false]);
+        assert!(exit_color_enabled(false, Some(OsString::new()), true));
+
+        let cli = MultitoolCli::try_parse_from(["codex", "resume", "--no-color"]).expect("parse");
+        let exit_info = sample_exit_info(Some("123e4567-e89b-12d3-a456-426614174000"), None);
+        let lines = format_exit_messages(
+            exit_info,
+            exit_color_enabled(cli.no_color, None, true),
+            "xcodex",
+        );
+        assert_eq!(
+            lines.last().map(String::as_str),
+            Some(
+                "To continue this session, run xcodex resume 123e4567-e89b-12d3-a456-426614174000"
+            )
+        );
+        assert!(!lines.iter().any(|line| line.contains('\u{1b}')));
+    }
+
+    #[test]
+    fn assume_initialized_skips_first_run_bail_and_writes_marker() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let codex_home = dir.path().join(".xcodex");
+
+        let cli = MultitoolCli::try_parse_from(["codex", "exec", "--assume-initialized", "hi"])
+            .expect("parse");
+        let Some(Subcommand::Exec(ExecArgs {
+            assume_initialized, ..
+        })) = cli.subcommand
+        else {
+            panic!("expected exec subcommand");
+        };
+        ensure_first_run_complete(&codex_home, true, assume_initialized, None)?;
+        assert!(xcodex_first_run_wizard_marker_path(&codex_home).exists());
+
+        let env_home = dir.path().join("env-home");
+        ensure_first_run_complete(&env_home, true, false, Some(OsString::from("1")))?;
+        assert!(xcodex_first_run_wizard_marker_path(&env_home).exists());
+
+        let uninitialized = dir.path().join("uninitialized");
+        assert!(ensure_first_run_complete(&uninitialized, true, false, None).is_err());
+        assert!(!uninitialized.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn format_exit_messages_uses_invoked_binary_name() {
+        let bin_name = codex_core::util::resume_bin_name(Some(OsString::from("/opt/tools/bin/xc")));
+        let exit_info = sample_exit_info(Some("123e4567-e89b-12d3-a456-426614174000"), None);
+        let lines = format_exit_messages(exit_info, false, &bin_name);
+        assert_eq!(
+            lines.last().map(String::as_str),
+            Some("To continue this session, run xc resume 123e4567-e89b-12d3-a456-426614174000")
+        );
+        assert_eq!(
+            [
+                codex_core::util::resume_bin_name(Some(OsString::from("xcodex.exe"))),
+                codex_core::util::resume_bin_name(None),
+            ],
+            ["xcodex".to_string(), "xcodex".to_string()]
+        );
+    }
+
+    #[test]
+    fn format_exit_messages_omits_resume_hint_when_not_resumable() {
+        let exit_info = AppExitInfo {
+            resumable: false,
+            ..sample_exit_info(Some("123e4567-e89b-12d3-a456-426614174000"), None)
+        };
+        let lines = format_exit_messages(exit_info, false, "xcodex");
+        assert_eq!(
+            lines,
+            vec!["Token usage: total=2 input=0 output=2".to_string()]
+        );
+    }
+
+    #[test]
+    fn format_exit_messages_breaks_out_cached_and_reasoning_tokens() {
+        let exit_info = AppExitInfo {
+            token_usage: TokenUsage {
+                input_tokens: 120,
+                cached_input_tokens: 20,
+                output_tokens: 30,
+                reasoning_output_tokens: 5,
+                total_tokens: 150,
+            },
+            ..sample_exit_info(None, None)
+        };
+        let lines = format_exit_messages(exit_info, false, "xcodex");
+        assert_eq!(
+            lines,
+            vec![
+                "Token usage: total=130 input=100 (+ 20 cached) output=30 (reasoning 5)"
+                    .to_string()
+            ]
+        );
+    }
+
     #[test]
     fn format_exit_messages_prefers_thread_name() {
         let exit_info = sample_exit_info(
             Some("123e4567-e89b-12d3-a456-426614174000"),
             Some("my-thread"),
         );
-        let lines = format_exit_messages(exit_info, false);
+        let lines = format_exit_messages(exit_info, false, "xcodex");
         assert_eq!(
             lines,
             vec![
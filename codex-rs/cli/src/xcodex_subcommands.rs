@@ -1,13 +1,20 @@
 use clap::Parser;
 use codex_common::CliConfigOverrides;
+use codex_core::config::Config;
 use codex_core::config::find_codex_home;
 use codex_core::config::load_config_as_toml_with_cli_overrides;
 use codex_core::git_info::get_git_repo_root;
 use codex_core::plan_file;
+use codex_core::protocol::SandboxPolicy;
+use codex_core::protocol_config_types::SandboxMode;
+use codex_core::xcodex::hooks::HOOK_HOST_WINDOWS_SANDBOX_UNSUPPORTED;
+use codex_core::xcodex::hooks::preview_hook_host_sandbox;
 use codex_utils_absolute_path::AbsolutePathBuf;
+use codex_utils_cli::SandboxModeCliArg;
 use rand::Rng;
 use std::ffi::OsStr;
 use std::io::IsTerminal;
+use std::num::NonZeroUsize;
 use std::path::Path;
 use std::path::PathBuf;
 use std::time::Duration;
@@ -31,6 +38,9 @@ enum HooksSubcommand {
     /// Install hook SDK helpers/templates or runnable sample scripts.
     Install(HooksInstallCommand),
 
+    /// Inspect installed hook sample sets.
+    Samples(HooksSamplesCommand),
+
     /// Print diagnostics and next steps for a hook mode.
     Doctor(HooksDoctorCommand),
 
@@ -49,6 +59,12 @@ enum HooksSubcommand {
     /// Print where hook logs and payload files are written under CODEX_HOME.
     Paths(HooksPathsCommand),
 
+    /// Print the environment external command hooks are spawned with.
+    Env(HooksEnvCommand),
+
+    /// Print (or write) JSON Schema for each hook event payload.
+    Schema(HooksSchemaCommand),
+
     /// Legacy (will be removed): use `xcodex hooks doctor pyo3` / `xcodex hooks build pyo3`.
     #[clap(hide = true)]
     Pyo3(HooksPyo3Command),
@@ -60,6 +76,10 @@ struct HooksInitCommand {
     #[arg(value_name = "MODE")]
     mode: Option<String>,
 
+    /// Initialize every hook mode (external, python-host, and pyo3) at once.
+    #[arg(long = "all", default_value_t = false, conflicts_with = "mode")]
+    all: bool,
+
     /// Print planned changes and exit without writing.
     #[arg(long = "dry-run", default_value_t = false)]
     dry_run: bool,
@@ -153,6 +173,22 @@ struct HooksInstallSamplesCommand {
     yes: bool,
 }
 
+#[derive(Debug, Parser)]
+#[command(disable_help_subcommand = true)]
+struct HooksSamplesCommand {
+    #[command(subcommand)]
+    sub: HooksSamplesSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum HooksSamplesSubcommand {
+    /// Show whether each sample set is installed and referenced by config.toml.
+    Status(HooksSamplesStatusCommand),
+}
+
+#[derive(Debug, Parser)]
+struct HooksSamplesStatusCommand {}
+
 #[derive(Debug, Parser)]
 #[command(disable_help_subcommand = true)]
 struct HooksDoctorCommand {
@@ -175,7 +211,11 @@ enum HooksDoctorSubcommand {
 struct HooksDoctorExternalCommand {}
 
 #[derive(Debug, Parser)]
-struct HooksDoctorPythonHostCommand {}
+struct HooksDoctorPythonHostCommand {
+    /// Preview the host sandbox as if `hooks.host.sandbox_mode` were set to this mode.
+    #[arg(long = "sandbox-mode", value_enum)]
+    sandbox_mode: Option<SandboxModeCliArg>,
+}
 
 #[derive(Debug, Parser)]
 #[command(disable_help_subcommand = true)]
@@ -259,6 +299,15 @@ struct HooksPyo3BootstrapCommand {
     #[arg(long = "profile", value_enum, default_value_t = HooksPyo3Profile::Release)]
     profile: HooksPyo3Profile,
 
+    /// Extra cargo feature to enable (repeatable); `codex-core/pyo3-hooks` is always enabled.
+    #[arg(long = "cargo-feature", value_name = "FEATURE")]
+    cargo_features: Vec<String>,
+
+    /// Number of parallel cargo build jobs (default: cargo's default). Lower it to reduce memory
+    /// pressure on small machines.
+    #[arg(long = "jobs", value_name = "N")]
+    jobs: Option<NonZeroUsize>,
+
     /// Directory to install the resulting binary into (default: CODEX_HOME/bin).
     #[arg(long = "install-dir", value_name = "PATH")]
     install_dir: Option<PathBuf>,
@@ -267,6 +316,19 @@ struct HooksPyo3BootstrapCommand {
     #[arg(long = "bin-name", default_value = "xcodex-pyo3")]
     bin_name: String,
 
+    /// Restore files edited by earlier bootstrap patches in the repo dir, then exit without building.
+    #[arg(long = "revert-patches", default_value_t = false)]
+    revert_patches: bool,
+
+    /// Build without network access: requires an existing clone, skips `git fetch`, and passes
+    /// `--offline` to cargo (dependencies must already be in the local cargo cache).
+    #[arg(long = "offline", default_value_t = false)]
+    offline: bool,
+
+    /// Ignore progress saved by a previous run and redo the fetch and checkout.
+    #[arg(long = "clean", default_value_t = false)]
+    clean: bool,
+
     /// Path to write a report file to on failure (default: CODEX_HOME/tmp/pyo3-bootstrap-report.txt).
     #[arg(long = "report-path", value_name = "PATH")]
     report_path: Option<PathBuf>,
@@ -281,11 +343,29 @@ struct HooksListCommand {
     /// Show all hook event keys, even if no commands are configured for them.
     #[arg(long = "all", default_value_t = false)]
     all: bool,
+
+    /// Show only event keys that have commands configured, without the summary header.
+    #[arg(
+        long = "only-configured",
+        default_value_t = false,
+        conflicts_with = "all"
+    )]
+    only_configured: bool,
 }
 
 #[derive(Debug, Parser)]
 struct HooksPathsCommand {}
 
+#[derive(Debug, Parser)]
+struct HooksEnvCommand {}
+
+#[derive(Debug, Parser)]
+struct HooksSchemaCommand {
+    /// Write one `<event-type>.schema.json` file per event into DIR instead of printing.
+    #[arg(long = "out", value_name = "DIR")]
+    out: Option<PathBuf>,
+}
+
 #[derive(Debug, Parser)]
 #[command(disable_help_subcommand = true)]
 struct HooksTestCommand {
@@ -426,12 +506,26 @@ struct PlanArchiveCommand {}
 pub(crate) async fn run_hooks_command(
     root_config_overrides: &CliConfigOverrides,
     cmd: HooksCommand,
+    codex_linux_sandbox_exe: Option<PathBuf>,
 ) -> anyhow::Result<()> {
     match cmd.sub {
         HooksSubcommand::Init(args) => {
             let codex_home = find_codex_home()?;
             run_hooks_init(&codex_home, args)?;
         }
+        HooksSubcommand::Samples(cmd) => match cmd.sub {
+            HooksSamplesSubcommand::Status(_args) => {
+                let codex_home = find_codex_home()?;
+                let config_cwd = AbsolutePathBuf::current_dir()?;
+                let cli_overrides = root_config_overrides
+                    .parse_overrides()
+                    .map_err(|e| anyhow::anyhow!(e))?;
+                let config_toml =
+                    load_config_as_toml_with_cli_overrides(&codex_home, &config_cwd, cli_overrides)
+                        .await?;
+                print_hooks_samples_status(&codex_home, &config_toml.hooks)?;
+            }
+        },
         HooksSubcommand::Install(args) => {
             let codex_home = find_codex_home()?;
             run_hooks_install(&codex_home, args)?;
@@ -479,21 +573,18 @@ pub(crate) async fn run_hooks_command(
                         println!("- xcodex hooks test external --configured-only");
                     }
                 }
-                Some(HooksDoctorSubcommand::PythonHost(_args)) => {
-                    let config_cwd = AbsolutePathBuf::current_dir()?;
+                Some(HooksDoctorSubcommand::PythonHost(args)) => {
                     let cli_overrides = root_config_overrides
                         .parse_overrides()
                         .map_err(|e| anyhow::anyhow!(e))?;
-                    let config_toml = load_config_as_toml_with_cli_overrides(
-                        &codex_home,
-                        &config_cwd,
-                        cli_overrides,
-                    )
-                    .await?;
+                    let config = Config::load_with_cli_overrides(cli_overrides).await?;
+                    let mut hooks = config.xcodex.hooks.clone();
+                    if let Some(sandbox_mode) = args.sandbox_mode {
+                        hooks.host.sandbox_mode = Some(sandbox_mode.into());
+                    }
                     println!("Python Host hooks (long-lived):");
                     println!("- Config: {}", codex_home.join("config.toml").display());
-                    if !config_toml.hooks.host.enabled || config_toml.hooks.host.command.is_empty()
-                    {
+                    if !hooks.host.enabled || hooks.host.command.is_empty() {
                         println!("- Status: not configured");
                         println!();
                         println!("Try:");
@@ -501,7 +592,28 @@ pub(crate) async fn run_hooks_command(
                         println!("- xcodex hooks install samples python-host");
                     } else {
                         println!("- Status: enabled");
-                        println!("- hooks.host.command={:?}", config_toml.hooks.host.command);
+                        println!("- hooks.host.command={:?}", hooks.host.command);
+                        let preview = preview_hook_host_sandbox(
+                            &codex_home,
+                            config.permissions.sandbox_policy.get(),
+                            &hooks,
+                            &codex_linux_sandbox_exe,
+                        );
+                        let sandbox = match preview.sandbox_policy {
+                            SandboxPolicy::ReadOnly { .. } => "read-only",
+                            SandboxPolicy::WorkspaceWrite { .. } => "workspace-write",
+                            SandboxPolicy::DangerFullAccess => "full-access",
+                            SandboxPolicy::ExternalSandbox { .. } => "external-sandbox",
+                        };
+                        let source = if hooks.host.sandbox_mode.is_some() {
+                            "hooks.host.sandbox_mode"
+                        } else {
+                            "session default"
+                        };
+                        println!("- Sandbox: {sandbox} (from {source})");
+                        if let Some(warning) = preview.warning {
+                            println!("- Warning: {warning}");
+                        }
                         println!("Try:");
                         println!("- xcodex hooks test python-host --configured-only");
                     }
@@ -548,7 +660,12 @@ pub(crate) async fn run_hooks_command(
             let config_toml =
                 load_config_as_toml_with_cli_overrides(&codex_home, &config_cwd, cli_overrides)
                     .await?;
-            print_hooks_list(&codex_home, &config_toml.hooks, args.all);
+            print_hooks_list(
+                &codex_home,
+                &config_toml.hooks,
+                args.all,
+                args.only_configured,
+            );
         }
         HooksSubcommand::Paths(_args) => {
             let codex_home = find_codex_home()?;
@@ -561,6 +678,20 @@ pub(crate) async fn run_hooks_command(
                     .await?;
             print_hooks_paths(&codex_home, &config_toml.hooks);
         }
+        HooksSubcommand::Env(_args) => {
+            let codex_home = find_codex_home()?;
+            let config_cwd = AbsolutePathBuf::current_dir()?;
+            let cli_overrides = root_config_overrides
+                .parse_overrides()
+                .map_err(|e| anyhow::anyhow!(e))?;
+            let config_toml =
+                load_config_as_toml_with_cli_overrides(&codex_home, &config_cwd, cli_overrides)
+                    .await?;
+            print_hooks_env(&codex_home, &config_toml.hooks);
+        }
+        HooksSubcommand::Schema(args) => {
+            write_hooks_schema(args.out.as_deref())?;
+        }
         HooksSubcommand::Test(cmd) => {
             let codex_home = find_codex_home()?;
             let resolved_cwd = AbsolutePathBuf::current_dir()?;
@@ -1720,11 +1851,11 @@ fn format_path(path: Option<PathBuf>) -> String {
         .unwrap_or_else(|| "(none)".to_string())
 }
 
-fn hooks_logs_dir(codex_home: &Path) -> PathBuf {
+pub(crate) fn hooks_logs_dir(codex_home: &Path) -> PathBuf {
     codex_home.join("tmp").join("hooks").join("logs")
 }
 
-fn hooks_payloads_dir(codex_home: &Path) -> PathBuf {
+pub(crate) fn hooks_payloads_dir(codex_home: &Path) -> PathBuf {
     codex_home.join("tmp").join("hooks").join("payloads")
 }
 
@@ -1737,11 +1868,14 @@ fn run_hooks_help() -> anyhow::Result<()> {
     println!(
         "- xcodex hooks install samples <external|python-host|pyo3|all> [--dry-run] [--force] [--yes]"
     );
+    println!("- xcodex hooks samples status");
     println!("- xcodex hooks doctor <external|python-host|pyo3>");
     println!("- xcodex hooks test <external|python-host|pyo3|all>");
     println!("- xcodex hooks build pyo3");
     println!("- xcodex hooks list");
     println!("- xcodex hooks paths");
+    println!("- xcodex hooks env");
+    println!("- xcodex hooks schema [--out DIR]");
     println!();
     println!("Supported SDKs:");
     for sdk in codex_common::hooks_sdk_install::all_hook_sdks() {
@@ -1917,16 +2051,102 @@ fn format_command(cmd: &std::process::Command) -> String {
     parts.join(" ")
 }
 
+/// Transcript marker recording how long a bootstrap step took, e.g. `step_elapsed_ms build=1234`.
+const PYO3_STEP_ELAPSED_PREFIX: &str = "step_elapsed_ms ";
+
 fn write_pyo3_bootstrap_report(report_path: &Path, contents: &str) -> anyhow::Result<()> {
     if let Some(parent) = report_path.parent() {
         std::fs::create_dir_all(parent)?;
     }
-    std::fs::write(report_path, contents)?;
+    let summary = format_pyo3_bootstrap_summary(contents);
+    std::fs::write(report_path, format!("{summary}\n{contents}"))?;
     Ok(())
 }
 
+/// Summarizes cargo diagnostics and per-step timings recorded in a bootstrap transcript.
+fn format_pyo3_bootstrap_summary(transcript: &str) -> String {
+    let mut warnings = None;
+    let mut errors = None;
+    let mut steps = Vec::new();
+    for line in transcript.lines() {
+        if let Some(count) = line.strip_prefix("cargo_warnings=") {
+            warnings = Some(count);
+        } else if let Some(count) = line.strip_prefix("cargo_errors=") {
+            errors = Some(count);
+        } else if let Some((step, ms)) = line
+            .strip_prefix(PYO3_STEP_ELAPSED_PREFIX)
+            .and_then(|rest| rest.split_once('='))
+            && let Ok(ms) = ms.parse::<u64>()
+        {
+            steps.push((step, Duration::from_millis(ms)));
+        }
+    }
+
+    let mut out = String::from("Summary:\n");
+    out.push_str(&format!(
+        "- cargo errors: {}\n",
+        errors.unwrap_or("n/a (build did not run)")
+    ));
+    out.push_str(&format!(
+        "- cargo warnings: {}\n",
+        warnings.unwrap_or("n/a (build did not run)")
+    ));
+    for (step, elapsed) in steps {
+        out.push_str(&format!("- step {step}: {:.1}s\n", elapsed.as_secs_f64()));
+    }
+    out
+}
+
+/// Counts compiler diagnostics in cargo output, skipping cargo's own roll-up lines such as
+/// ``warning: `crate` (lib) generated 2 warnings`` and ``error: could not compile `crate` ``.
+fn count_cargo_diagnostics(output: &str) -> (usize, usize) {
+    let is_rollup = |message: &str| {
+        (message.starts_with('`') && message.contains(" generated "))
+            || message.starts_with("build failed")
+            || message.starts_with("could not compile")
+            || message.starts_with("aborting due to")
+    };
+
+    let mut warnings = 0;
+    let mut errors = 0;
+    for line in output.lines() {
+        let (is_warning, rest) = if let Some(rest) = line.strip_prefix("warning") {
+            (true, rest)
+        } else if let Some(rest) = line.strip_prefix("error") {
+            (false, rest)
+        } else {
+            continue;
+        };
+        // Diagnostics look like `warning: ...` or `error[E0308]: ...`.
+        let message = match rest.split_once(": ") {
+            Some((code, message)) if code.is_empty() || code.starts_with('[') => message,
+            _ => continue,
+        };
+        if is_rollup(message) {
+            continue;
+        }
+        if is_warning {
+            warnings += 1;
+        } else {
+            errors += 1;
+        }
+    }
+    (warnings, errors)
+}
+
+/// Records what `apply_pyo3_bootstrap_patches` changed so `--revert-patches` can undo it.
+fn pyo3_bootstrap_patch_manifest_path(repo_dir: &Path) -> PathBuf {
+    repo_dir
+        .join(".git")
+        .join("xcodex-pyo3-bootstrap-patches.json")
+}
+
+/// `head_commit` is the checked-out commit; a manifest recorded for a different commit is
+/// discarded so the patches are re-recorded against the new checkout.
 fn apply_pyo3_bootstrap_patches(
+    repo_dir: &Path,
     codex_rs_dir: &Path,
+    head_commit: &str,
     transcript: &mut String,
 ) -> anyhow::Result<()> {
     use toml_edit::DocumentMut;
@@ -1959,7 +2179,23 @@ fn apply_pyo3_bootstrap_patches(
             )
         })?;
 
-    let mut changed = false;
+    let manifest_path = pyo3_bootstrap_patch_manifest_path(repo_dir);
+    let recorded_commit = std::fs::read_to_string(&manifest_path)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok())
+        .map(|manifest| manifest["commit"].as_str().unwrap_or_default().to_string());
+    if let Some(recorded_commit) = recorded_commit
+        && recorded_commit != head_commit
+    {
+        std::fs::remove_file(&manifest_path).map_err(|err| {
+            anyhow::anyhow!("failed to remove {}: {err}", manifest_path.display())
+        })?;
+        transcript.push_str(&format!(
+            "discarded_stale_patch_manifest recorded_commit={recorded_commit}\n"
+        ));
+    }
+
+    let mut added = Vec::new();
     for crate_name in ["ctor", "codex-process-hardening"] {
         if deps.contains_key(crate_name) {
             continue;
@@ -1968,10 +2204,29 @@ fn apply_pyo3_bootstrap_patches(
         let mut tbl = InlineTable::new();
         tbl.insert("workspace", Value::from(true));
         deps[crate_name] = Item::Value(Value::InlineTable(tbl));
-        changed = true;
-    }
+        added.push(crate_name);
+    }
+
+    if !added.is_empty() {
+        // Keep the manifest from the first run at this commit so reverting restores the
+        // pristine file.
+        if !manifest_path.exists() {
+            let relative_path = cli_cargo_toml_path
+                .strip_prefix(repo_dir)
+                .unwrap_or(&cli_cargo_toml_path);
+            let manifest = serde_json::json!({
+                "commit": head_commit,
+                "files": [{
+                    "path": relative_path,
+                    "added_dependencies": added,
+                    "original_contents": cli_cargo_toml,
+                }],
+            });
+            std::fs::write(&manifest_path, format!("{manifest:#}\n")).map_err(|err| {
+                anyhow::anyhow!("failed to write {}: {err}", manifest_path.display())
+            })?;
+        }
 
-    if changed {
         std::fs::write(&cli_cargo_toml_path, doc.to_string()).map_err(|err| {
             anyhow::anyhow!("failed to write {}: {err}", cli_cargo_toml_path.display())
         })?;
@@ -1986,6 +2241,35 @@ fn apply_pyo3_bootstrap_patches(
     Ok(())
 }
 
+/// Restores files recorded in the patch manifest and removes it, returning the restored paths.
+fn revert_pyo3_bootstrap_patches(repo_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let manifest_path = pyo3_bootstrap_patch_manifest_path(repo_dir);
+    let manifest = match std::fs::read_to_string(&manifest_path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            anyhow::bail!("failed to read {}: {err}", manifest_path.display())
+        }
+    };
+    let manifest: serde_json::Value = serde_json::from_str(&manifest)
+        .map_err(|err| anyhow::anyhow!("failed to parse {}: {err}", manifest_path.display()))?;
+
+    let mut restored = Vec::new();
+    for file in manifest["files"].as_array().into_iter().flatten() {
+        let (Some(path), Some(original)) =
+            (file["path"].as_str(), file["original_contents"].as_str())
+        else {
+            anyhow::bail!("malformed entry in {}", manifest_path.display());
+        };
+        let path = repo_dir.join(path);
+        std::fs::write(&path, original)
+            .map_err(|err| anyhow::anyhow!("failed to restore {}: {err}", path.display()))?;
+        restored.push(path);
+    }
+    std::fs::remove_file(&manifest_path)?;
+    Ok(restored)
+}
+
 fn resolve_default_pyo3_python(
     python_arg: Option<PathBuf>,
     interactive: bool,
@@ -2015,6 +2299,74 @@ fn resolve_default_pyo3_python(
     }
 }
 
+/// Oldest Python the PyO3 hooks build supports.
+const PYO3_MIN_PYTHON_VERSION: (u32, u32) = (3, 8);
+
+/// Checks that `python` is an executable Python interpreter new enough for PyO3 hooks and returns
+/// its `--version` output.
+fn validate_pyo3_python(python: &Path) -> anyhow::Result<String> {
+    // Bare names such as `python3` are resolved through PATH when spawned.
+    if python.components().count() > 1 || python.is_absolute() {
+        let metadata = std::fs::metadata(python)
+            .map_err(|err| anyhow::anyhow!("{} does not exist: {err}", python.display()))?;
+        if metadata.is_dir() {
+            anyhow::bail!(
+                "{} is a directory; pass the path to a Python interpreter",
+                python.display()
+            );
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if metadata.permissions().mode() & 0o111 == 0 {
+                anyhow::bail!("{} is not executable", python.display());
+            }
+        }
+    }
+
+    let mut cmd = std::process::Command::new(python);
+    cmd.arg("--version");
+    let printed = format_command(&cmd);
+    let output = run_command_capture(cmd)?;
+    if !output.status.success() {
+        anyhow::bail!("{printed} exited with {}", output.status);
+    }
+
+    // Python 2 prints its version to stderr; Python 3 prints it to stdout.
+    let version = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    )
+    .trim()
+    .to_string();
+    check_pyo3_python_version(&version)?;
+    Ok(version)
+}
+
+fn check_pyo3_python_version(version: &str) -> anyhow::Result<()> {
+    let (min_major, min_minor) = PYO3_MIN_PYTHON_VERSION;
+    let parsed = version.strip_prefix("Python ").and_then(|number| {
+        let mut parts = number.split('.');
+        let major = parts.next()?.parse::<u32>().ok()?;
+        let minor: String = parts
+            .next()?
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        Some((major, minor.parse::<u32>().ok()?))
+    });
+    match parsed {
+        Some((major, minor)) if (major, minor) >= (min_major, min_minor) => Ok(()),
+        Some(_) => anyhow::bail!("expected Python {min_major}.{min_minor}+, got {version}"),
+        None => {
+            anyhow::bail!(
+                "expected Python {min_major}.{min_minor}+, got `{version}` (not a Python interpreter?)"
+            )
+        }
+    }
+}
+
 fn run_hooks_pyo3_doctor(codex_home: &Path, args: HooksPyo3DoctorCommand) -> anyhow::Result<()> {
     let interactive = is_interactive_stdin();
     let repo_url = pyo3_bootstrap_default_repo_url();
@@ -2069,26 +2421,11 @@ fn run_hooks_pyo3_doctor(codex_home: &Path, args: HooksPyo3DoctorCommand) -> any
     };
 
     if python.as_os_str() != "<missing>" {
-        let mut cmd = std::process::Command::new(&python);
-        cmd.arg("--version");
-        let printed = format_command(&cmd);
-        match run_command_capture(cmd) {
-            Ok(output) if output.status.success() => {
-                println!(
-                    "- python (PYO3_PYTHON): ok ({})",
-                    String::from_utf8_lossy(&output.stderr).trim()
-                );
-            }
-            Ok(output) => {
-                ok = false;
-                println!("- python (PYO3_PYTHON): failed ({printed})");
-                print!("{}", String::from_utf8_lossy(&output.stdout));
-                eprint!("{}", String::from_utf8_lossy(&output.stderr));
-            }
+        match validate_pyo3_python(&python) {
+            Ok(version) => println!("- python (PYO3_PYTHON): ok ({version})"),
             Err(err) => {
                 ok = false;
-                println!("- python (PYO3_PYTHON): failed ({printed})");
-                eprintln!("{err:#}");
+                println!("- python (PYO3_PYTHON): failed: {err:#}");
             }
         }
     }
@@ -2131,6 +2468,88 @@ fn pyo3_bootstrap_fail(report_path: &Path, transcript: &str, err: anyhow::Error)
     err
 }
 
+struct Pyo3CargoBuildArgs {
+    /// Comma-separated feature list passed to `--features`.
+    features: String,
+    args: Vec<String>,
+}
+
+fn pyo3_bootstrap_cargo_build_args(
+    profile: HooksPyo3Profile,
+    extra_features: &[String],
+    jobs: Option<NonZeroUsize>,
+    offline: bool,
+) -> Pyo3CargoBuildArgs {
+    let mut features = vec!["codex-core/pyo3-hooks".to_string()];
+    for feature in extra_features {
+        if !features.contains(feature) {
+            features.push(feature.clone());
+        }
+    }
+    let features = features.join(",");
+
+    let mut args: Vec<String> = ["build", "-p", "codex-cli", "--bin", "codex"]
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    match profile {
+        HooksPyo3Profile::Release => args.push("--release".to_string()),
+        HooksPyo3Profile::Debug => {}
+    }
+    if let Some(jobs) = jobs {
+        args.push("--jobs".to_string());
+        args.push(jobs.to_string());
+    }
+    // `--frozen` would also require an unchanged Cargo.lock, which bootstrap patches may update.
+    if offline {
+        args.push("--offline".to_string());
+    }
+    args.push("--features".to_string());
+    args.push(features.clone());
+    Pyo3CargoBuildArgs { features, args }
+}
+
+/// Progress from earlier runs lives inside `.git` so it never dirties the checkout.
+fn pyo3_bootstrap_state_path(repo_dir: &Path) -> PathBuf {
+    repo_dir
+        .join(".git")
+        .join("xcodex-pyo3-bootstrap-state.json")
+}
+
+/// Returns the commit recorded by an earlier run when its clone and checkout can be reused: the
+/// state must match `repo_url`/`git_ref`, HEAD must still be at the recorded commit, and
+/// `git_ref` must still resolve to it.
+fn resumable_pyo3_checkout(
+    repo_dir: &Path,
+    state_path: &Path,
+    repo_url: &str,
+    git_ref: &str,
+) -> Option<String> {
+    let state: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(state_path).ok()?).ok()?;
+    let checked_out = state["completed_steps"]
+        .as_array()
+        .is_some_and(|steps| steps.iter().any(|step| step == "checkout"));
+    if !checked_out || state["repo_url"] != repo_url || state["git_ref"] != git_ref {
+        return None;
+    }
+    let commit = state["resolved_commit"].as_str()?;
+
+    let rev_parse = |rev: &str| {
+        let mut cmd = std::process::Command::new("git");
+        cmd.current_dir(repo_dir)
+            .args(["rev-parse", "--verify", "--quiet", rev]);
+        let output = run_command_capture(cmd).ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let head = rev_parse("HEAD")?;
+    let requested = rev_parse(&format!("{git_ref}^{{commit}}"))?;
+    (head == commit && requested == commit).then(|| commit.to_string())
+}
+
 fn run_hooks_pyo3_bootstrap(
     codex_home: &Path,
     mut args: HooksPyo3BootstrapCommand,
@@ -2146,6 +2565,17 @@ fn run_hooks_pyo3_bootstrap(
         .take()
         .unwrap_or_else(|| codex_home.join("src").join("xcodex"));
 
+    if args.revert_patches {
+        let restored = revert_pyo3_bootstrap_patches(&repo_dir)?;
+        if restored.is_empty() {
+            println!("No bootstrap patches recorded for {}.", repo_dir.display());
+        }
+        for path in restored {
+            println!("Restored {}", path.display());
+        }
+        return Ok(());
+    }
+
     let install_dir = args
         .install_dir
         .take()
@@ -2163,6 +2593,11 @@ fn run_hooks_pyo3_bootstrap(
         .unwrap_or_else(|| pyo3_bootstrap_default_git_ref().to_string());
 
     let profile = args.profile;
+    let cargo_build_args =
+        pyo3_bootstrap_cargo_build_args(profile, &args.cargo_features, args.jobs, args.offline);
+    let cargo_jobs = args
+        .jobs
+        .map_or_else(|| "default".to_string(), |jobs| jobs.to_string());
     let bin_name = args.bin_name;
     let dest_path = install_dir.join(&bin_name);
 
@@ -2179,6 +2614,8 @@ fn run_hooks_pyo3_bootstrap(
             HooksPyo3Profile::Debug => "debug",
         }
     );
+    println!("- Cargo features: {}", cargo_build_args.features);
+    println!("- Cargo jobs: {cargo_jobs}");
     println!("- Install dir: {}", install_dir.display());
     println!("- Installed binary: {}", dest_path.display());
     println!("- Failure report path: {}", report_path.display());
@@ -2210,6 +2647,9 @@ fn run_hooks_pyo3_bootstrap(
             HooksPyo3Profile::Debug => "debug",
         }
     ));
+    transcript.push_str(&format!("cargo_features={}\n", cargo_build_args.features));
+    transcript.push_str(&format!("cargo_jobs={cargo_jobs}\n"));
+    transcript.push_str(&format!("offline={}\n", args.offline));
     transcript.push_str(&format!("install_dir={}\n", install_dir.display()));
     transcript.push_str(&format!("bin_name={bin_name}\n"));
     transcript.push_str(&format!("dest_path={}\n", dest_path.display()));
@@ -2234,9 +2674,29 @@ fn run_hooks_pyo3_bootstrap(
             repo_dir.display()
         );
         transcript.push_str(&format!("reuse_repo_dir={}\n\n", repo_dir.display()));
+    } else if args.offline {
+        return Err(pyo3_bootstrap_fail(
+            &report_path,
+            &transcript,
+            anyhow::anyhow!(
+                "--offline needs an existing clone at {}; run once without --offline first",
+                repo_dir.display()
+            ),
+        ));
     }
 
+    let state_path = pyo3_bootstrap_state_path(&repo_dir);
+    if args.clean {
+        let _ = std::fs::remove_file(&state_path);
+    }
+    let resumed_commit = if reuse_repo_dir && !args.clean {
+        resumable_pyo3_checkout(&repo_dir, &state_path, &repo_url, &git_ref)
+    } else {
+        None
+    };
+
     // 1) Prereqs (minimal).
+    let step_started = std::time::Instant::now();
     println!("Step 1/4: Checking prerequisites...");
     for (label, program, args) in [
         ("git", "git", vec!["--version"]),
@@ -2279,107 +2739,150 @@ fn run_hooks_pyo3_bootstrap(
                         anyhow::anyhow!("python prerequisite failed: {printed}"),
                     ));
                 }
+                let version = format!(
+                    "{}{}",
+                    String::from_utf8_lossy(&output.stdout),
+                    String::from_utf8_lossy(&output.stderr)
+                );
+                if let Err(err) = check_pyo3_python_version(version.trim()) {
+                    return Err(pyo3_bootstrap_fail(&report_path, &transcript, err));
+                }
             }
             Err(err) => return Err(pyo3_bootstrap_fail(&report_path, &transcript, err)),
         }
         transcript.push('\n');
     }
 
+    transcript.push_str(&format!(
+        "{PYO3_STEP_ELAPSED_PREFIX}prerequisites={}\n",
+        step_started.elapsed().as_millis()
+    ));
+
     // 2) Clone + checkout.
-    println!();
-    println!("Step 2/4: Cloning and checking out {git_ref}...");
-    if !reuse_repo_dir {
-        let mut cmd = std::process::Command::new("git");
-        cmd.args([
-            "clone",
-            repo_url.as_str(),
-            repo_dir
-                .to_str()
-                .ok_or_else(|| anyhow::anyhow!("repo dir is not valid utf-8"))?,
-        ]);
-        let printed = format_command(&cmd);
-        transcript.push_str(&format!("$ {printed}\n"));
-        match run_command_capture_with_echo(cmd, interactive) {
-            Ok(output) => {
-                transcript.push_str(&String::from_utf8_lossy(&output.stdout));
-                transcript.push_str(&String::from_utf8_lossy(&output.stderr));
-                if !output.status.success() {
-                    return Err(pyo3_bootstrap_fail(
-                        &report_path,
-                        &transcript,
-                        anyhow::anyhow!("git clone failed"),
-                    ));
+    let step_started = std::time::Instant::now();
+    let resolved_commit = if let Some(commit) = resumed_commit {
+        println!();
+        println!(
+            "Step 2/4: Skipping clone and checkout; {} is already at {commit}.",
+            repo_dir.display()
+        );
+        transcript.push_str(&format!("resumed_checkout={commit}\n\n"));
+        commit
+    } else {
+        println!();
+        println!("Step 2/4: Cloning and checking out {git_ref}...");
+        if !reuse_repo_dir {
+            let mut cmd = std::process::Command::new("git");
+            cmd.args([
+                "clone",
+                repo_url.as_str(),
+                repo_dir
+                    .to_str()
+                    .ok_or_else(|| anyhow::anyhow!("repo dir is not valid utf-8"))?,
+            ]);
+            let printed = format_command(&cmd);
+            transcript.push_str(&format!("$ {printed}\n"));
+            match run_command_capture_with_echo(cmd, interactive) {
+                Ok(output) => {
+                    transcript.push_str(&String::from_utf8_lossy(&output.stdout));
+                    transcript.push_str(&String::from_utf8_lossy(&output.stderr));
+                    if !output.status.success() {
+                        return Err(pyo3_bootstrap_fail(
+                            &report_path,
+                            &transcript,
+                            anyhow::anyhow!("git clone failed"),
+                        ));
+                    }
                 }
+                Err(err) => return Err(pyo3_bootstrap_fail(&report_path, &transcript, err)),
             }
-            Err(err) => return Err(pyo3_bootstrap_fail(&report_path, &transcript, err)),
+            transcript.push('\n');
         }
-        transcript.push('\n');
-    }
 
-    if reuse_repo_dir {
-        let mut cmd = std::process::Command::new("git");
-        cmd.current_dir(&repo_dir)
-            .args(["fetch", "--all", "--tags"]);
-        let printed = format_command(&cmd);
-        transcript.push_str(&format!("$ {printed}\n"));
-        match run_command_capture_with_echo(cmd, interactive) {
-            Ok(output) => {
-                transcript.push_str(&String::from_utf8_lossy(&output.stdout));
-                transcript.push_str(&String::from_utf8_lossy(&output.stderr));
-                if !output.status.success() {
-                    eprintln!("Warning: git fetch failed; continuing with local refs.");
+        if reuse_repo_dir && args.offline {
+            transcript.push_str("skipped_git_fetch=offline\n\n");
+        } else if reuse_repo_dir {
+            let mut cmd = std::process::Command::new("git");
+            cmd.current_dir(&repo_dir)
+                .args(["fetch", "--all", "--tags"]);
+            let printed = format_command(&cmd);
+            transcript.push_str(&format!("$ {printed}\n"));
+            match run_command_capture_with_echo(cmd, interactive) {
+                Ok(output) => {
+                    transcript.push_str(&String::from_utf8_lossy(&output.stdout));
+                    transcript.push_str(&String::from_utf8_lossy(&output.stderr));
+                    if !output.status.success() {
+                        eprintln!("Warning: git fetch failed; continuing with local refs.");
+                    }
+                }
+                Err(err) => {
+                    eprintln!("Warning: git fetch failed: {err:#}; continuing with local refs.");
                 }
             }
-            Err(err) => {
-                eprintln!("Warning: git fetch failed: {err:#}; continuing with local refs.");
-            }
+            transcript.push('\n');
         }
-        transcript.push('\n');
-    }
 
-    {
-        let mut cmd = std::process::Command::new("git");
-        cmd.current_dir(&repo_dir).args(["checkout", &git_ref]);
-        let printed = format_command(&cmd);
-        transcript.push_str(&format!("$ {printed}\n"));
-        match run_command_capture_with_echo(cmd, interactive) {
-            Ok(output) => {
-                transcript.push_str(&String::from_utf8_lossy(&output.stdout));
-                transcript.push_str(&String::from_utf8_lossy(&output.stderr));
-                if !output.status.success() {
-                    return Err(pyo3_bootstrap_fail(
-                        &report_path,
-                        &transcript,
-                        anyhow::anyhow!("git checkout failed"),
-                    ));
+        {
+            let mut cmd = std::process::Command::new("git");
+            cmd.current_dir(&repo_dir).args(["checkout", &git_ref]);
+            let printed = format_command(&cmd);
+            transcript.push_str(&format!("$ {printed}\n"));
+            match run_command_capture_with_echo(cmd, interactive) {
+                Ok(output) => {
+                    transcript.push_str(&String::from_utf8_lossy(&output.stdout));
+                    transcript.push_str(&String::from_utf8_lossy(&output.stderr));
+                    if !output.status.success() {
+                        return Err(pyo3_bootstrap_fail(
+                            &report_path,
+                            &transcript,
+                            anyhow::anyhow!("git checkout failed"),
+                        ));
+                    }
                 }
+                Err(err) => return Err(pyo3_bootstrap_fail(&report_path, &transcript, err)),
             }
-            Err(err) => return Err(pyo3_bootstrap_fail(&report_path, &transcript, err)),
+            transcript.push('\n');
         }
-        transcript.push('\n');
-    }
 
-    let resolved_commit = {
-        let mut cmd = std::process::Command::new("git");
-        cmd.current_dir(&repo_dir).args(["rev-parse", "HEAD"]);
-        let printed = format_command(&cmd);
-        transcript.push_str(&format!("$ {printed}\n"));
-        let output = run_command_capture(cmd)
-            .map_err(|err| pyo3_bootstrap_fail(&report_path, &transcript, err))?;
-        transcript.push_str(&String::from_utf8_lossy(&output.stdout));
-        transcript.push_str(&String::from_utf8_lossy(&output.stderr));
-        transcript.push('\n');
-        if !output.status.success() {
-            return Err(pyo3_bootstrap_fail(
-                &report_path,
-                &transcript,
-                anyhow::anyhow!("failed to resolve checked out commit"),
-            ));
+        let resolved_commit = {
+            let mut cmd = std::process::Command::new("git");
+            cmd.current_dir(&repo_dir).args(["rev-parse", "HEAD"]);
+            let printed = format_command(&cmd);
+            transcript.push_str(&format!("$ {printed}\n"));
+            let output = run_command_capture(cmd)
+                .map_err(|err| pyo3_bootstrap_fail(&report_path, &transcript, err))?;
+            transcript.push_str(&String::from_utf8_lossy(&output.stdout));
+            transcript.push_str(&String::from_utf8_lossy(&output.stderr));
+            transcript.push('\n');
+            if !output.status.success() {
+                return Err(pyo3_bootstrap_fail(
+                    &report_path,
+                    &transcript,
+                    anyhow::anyhow!("failed to resolve checked out commit"),
+                ));
+            }
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+
+        let state = serde_json::json!({
+            "repo_url": repo_url,
+            "git_ref": git_ref,
+            "completed_steps": ["clone", "checkout"],
+            "resolved_commit": resolved_commit,
+        });
+        if let Err(err) = std::fs::write(&state_path, format!("{state:#}\n")) {
+            eprintln!("Warning: failed to save build progress: {err}");
         }
-        String::from_utf8_lossy(&output.stdout).trim().to_string()
+        resolved_commit
     };
 
+    transcript.push_str(&format!(
+        "{PYO3_STEP_ELAPSED_PREFIX}checkout={}\n",
+        step_started.elapsed().as_millis()
+    ));
+
     // 3) Build.
+    let step_started = std::time::Instant::now();
     println!();
     println!("Step 3/4: Building {bin_name} (this may take a few minutes)...");
     let codex_rs_dir = repo_dir.join("codex-rs");
@@ -2395,7 +2898,7 @@ fn run_hooks_pyo3_bootstrap(
     }
 
     println!("Preparing build (applying bootstrap patches if needed)...");
-    apply_pyo3_bootstrap_patches(&codex_rs_dir, &mut transcript)
+    apply_pyo3_bootstrap_patches(&repo_dir, &codex_rs_dir, &resolved_commit, &mut transcript)
         .map_err(|err| pyo3_bootstrap_fail(&report_path, &transcript, err))?;
     transcript.push('\n');
 
@@ -2403,25 +2906,30 @@ fn run_hooks_pyo3_bootstrap(
         let mut cmd = std::process::Command::new("cargo");
         cmd.current_dir(&codex_rs_dir)
             .env("PYO3_PYTHON", &python)
-            .args(["build", "-p", "codex-cli", "--bin", "codex"])
-            .args(match profile {
-                HooksPyo3Profile::Release => vec!["--release"],
-                HooksPyo3Profile::Debug => Vec::<&str>::new(),
-            })
-            .args(["--features", "codex-core/pyo3-hooks"]);
+            .args(&cargo_build_args.args);
 
         let printed = format_command(&cmd);
         transcript.push_str(&format!("$ {printed}\n"));
         match run_command_capture_with_echo(cmd, interactive) {
             Ok(output) => {
-                transcript.push_str(&String::from_utf8_lossy(&output.stdout));
-                transcript.push_str(&String::from_utf8_lossy(&output.stderr));
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                transcript.push_str(&stdout);
+                transcript.push_str(&stderr);
+                let (warnings, errors) = count_cargo_diagnostics(&format!("{stdout}{stderr}"));
+                transcript.push_str(&format!(
+                    "cargo_warnings={warnings}\ncargo_errors={errors}\n"
+                ));
                 if !output.status.success() {
-                    return Err(pyo3_bootstrap_fail(
-                        &report_path,
-                        &transcript,
-                        anyhow::anyhow!("cargo build failed"),
-                    ));
+                    let err = if args.offline && stderr.contains("--offline") {
+                        anyhow::anyhow!(
+                            "cargo build failed in --offline mode because some dependencies are not in the local cargo cache; run `cargo fetch` in {} (or build once without --offline) and retry",
+                            codex_rs_dir.display()
+                        )
+                    } else {
+                        anyhow::anyhow!("cargo build failed")
+                    };
+                    return Err(pyo3_bootstrap_fail(&report_path, &transcript, err));
                 }
             }
             Err(err) => return Err(pyo3_bootstrap_fail(&report_path, &transcript, err)),
@@ -2429,7 +2937,13 @@ fn run_hooks_pyo3_bootstrap(
         transcript.push('\n');
     }
 
+    transcript.push_str(&format!(
+        "{PYO3_STEP_ELAPSED_PREFIX}build={}\n",
+        step_started.elapsed().as_millis()
+    ));
+
     // 4) Install side-by-side binary.
+    let step_started = std::time::Instant::now();
     println!();
     println!("Step 4/4: Installing {bin_name}...");
     let built_bin = codex_rs_dir
@@ -2459,6 +2973,10 @@ fn run_hooks_pyo3_bootstrap(
             .map_err(|err| pyo3_bootstrap_fail(&report_path, &transcript, err.into()))?;
     }
 
+    transcript.push_str(&format!(
+        "{PYO3_STEP_ELAPSED_PREFIX}install={}\n",
+        step_started.elapsed().as_millis()
+    ));
     transcript.push_str(&format!("resolved_commit={resolved_commit}\n\n"));
 
     println!("Installed {bin_name} to: {}", dest_path.display());
@@ -2482,36 +3000,59 @@ fn run_hooks_init(codex_home: &Path, args: HooksInitCommand) -> anyhow::Result<(
 
     let interactive = is_interactive_stdin();
 
-    let selected = match args.mode.as_deref() {
-        Some(raw) => parse_hook_sample(raw),
-        None => {
-            print_hooks_init_menu();
-            if !interactive {
-                println!();
-                println!("Run one of:");
-                println!("- xcodex hooks init external");
-                println!("- xcodex hooks init python-host");
-                println!("- xcodex hooks init pyo3");
-                return Ok(());
+    let samples = if args.all {
+        vec![
+            HookSample::External,
+            HookSample::PythonHost,
+            HookSample::Pyo3,
+        ]
+    } else {
+        let selected = match args.mode.as_deref() {
+            Some(raw) => parse_hook_sample(raw),
+            None if interactive && std::io::stdout().is_terminal() => {
+                let Some(sample) = select_hook_sample_with_arrow_keys()? else {
+                    return Ok(());
+                };
+                Some(sample)
             }
+            None => {
+                print_hooks_init_menu();
+                if !interactive {
+                    println!();
+                    println!("Run one of:");
+                    println!("- xcodex hooks init external");
+                    println!("- xcodex hooks init python-host");
+                    println!("- xcodex hooks init pyo3");
+                    println!("- xcodex hooks init --all");
+                    return Ok(());
+                }
 
-            println!();
-            let choice = prompt_line("Select a hook mode (1-3): ")?;
-            if choice.trim().is_empty() {
-                return Ok(());
+                println!();
+                print!("Select a hook mode (1-3): ");
+                std::io::Write::flush(&mut std::io::stdout())?;
+                let Some(choice) = read_hook_sample_choice(&mut std::io::stdin().lock())? else {
+                    return Ok(());
+                };
+                parse_hook_sample(&choice)
             }
-            parse_hook_sample(&choice)
-        }
-    };
+        };
 
-    let Some(sample) = selected else {
-        anyhow::bail!("unknown hook mode; try: xcodex hooks init");
+        let Some(sample) = selected else {
+            anyhow::bail!("unknown hook mode; try: xcodex hooks init");
+        };
+        vec![sample]
     };
 
-    let plan =
-        codex_common::hooks_samples_install::plan_install_samples(codex_home, sample, args.force)?;
-    let plan_text = codex_common::hooks_samples_install::format_sample_install_plan(&plan, sample)?;
-    println!("{plan_text}");
+    let mut plans = Vec::new();
+    for &sample in &samples {
+        let plan = codex_common::hooks_samples_install::plan_install_samples(
+            codex_home, sample, args.force,
+        )?;
+        let plan_text =
+            codex_common::hooks_samples_install::format_sample_install_plan(&plan, sample)?;
+        println!("{plan_text}");
+        plans.push((sample, plan));
+    }
 
     if args.dry_run {
         return Ok(());
@@ -2519,7 +3060,7 @@ fn run_hooks_init(codex_home: &Path, args: HooksInitCommand) -> anyhow::Result<(
 
     if !args.yes {
         if interactive {
-            if sample == HookSample::Pyo3 {
+            if samples.contains(&HookSample::Pyo3) {
                 println!();
                 println!(
                     "Note: PyO3 hooks require a separately-built binary (not included by default)."
@@ -2537,36 +3078,58 @@ fn run_hooks_init(codex_home: &Path, args: HooksInitCommand) -> anyhow::Result<(
         }
     }
 
-    codex_common::hooks_samples_install::apply_install_samples(codex_home, sample, args.force)?;
+    for &sample in &samples {
+        codex_common::hooks_samples_install::apply_install_samples(codex_home, sample, args.force)?;
+    }
 
     if !args.no_print_config {
         println!();
         println!("Paste into {}/config.toml:", codex_home.display());
         println!();
-        print!("{}", plan.config_snippet);
+        if let [(_, plan)] = plans.as_slice() {
+            print!("{}", plan.config_snippet);
+        } else {
+            for (index, (sample, plan)) in plans.iter().enumerate() {
+                if index > 0 {
+                    println!();
+                }
+                println!("# {} ({})", sample.id(), sample.title());
+                print!("{}", plan.config_snippet);
+            }
+        }
         println!();
         println!("Next:");
-        match sample {
-            HookSample::External => println!("- xcodex hooks test external --configured-only"),
-            HookSample::PythonHost => println!("- xcodex hooks test python-host --configured-only"),
-            HookSample::Pyo3 => {
-                println!("- xcodex hooks doctor pyo3");
-                println!("- xcodex hooks build pyo3");
+        for &sample in &samples {
+            match sample {
+                HookSample::External => println!("- xcodex hooks test external --configured-only"),
+                HookSample::PythonHost => {
+                    println!("- xcodex hooks test python-host --configured-only")
+                }
+                HookSample::Pyo3 => {
+                    println!("- xcodex hooks doctor pyo3");
+                    println!("- xcodex hooks build pyo3");
+                }
             }
         }
     }
 
     if args.edit_config {
-        let edited = edit_hooks_init_config_toml(codex_home, sample, &plan.config_snippet)?;
-        if edited {
+        for (sample, plan) in &plans {
+            let edited = edit_hooks_init_config_toml(codex_home, *sample, &plan.config_snippet)?;
             println!();
-            println!("Updated {}/config.toml.", codex_home.display());
-        } else {
-            println!();
-            println!(
-                "Skipped editing {}/config.toml because it already has the relevant section.",
-                codex_home.display()
-            );
+            if edited {
+                println!(
+                    "Updated {}/config.toml for {}.",
+                    codex_home.display(),
+                    sample.id()
+                );
+            } else {
+                println!(
+                    "Skipped editing {}/config.toml for {} because it already has the relevant section.",
+                    codex_home.display(),
+                    sample.id()
+                );
+            }
         }
     }
 
@@ -2865,6 +3428,52 @@ fn print_hooks_install_samples_list() {
     println!("- all: install everything");
 }
 
+fn print_hooks_samples_status(
+    codex_home: &Path,
+    hooks: &codex_core::config::HooksConfig,
+) -> anyhow::Result<()> {
+    use codex_common::hooks_samples_install::HookSample;
+
+    println!("CODEX_HOME: {}", codex_home.display());
+    for sample in [
+        HookSample::External,
+        HookSample::PythonHost,
+        HookSample::Pyo3,
+    ] {
+        let plan =
+            codex_common::hooks_samples_install::plan_install_samples(codex_home, sample, false)?;
+        let total = plan.files.len();
+        let present = plan.files.iter().filter(|file| file.path.exists()).count();
+        let files = if present == 0 {
+            "not installed".to_string()
+        } else if present == total {
+            format!("installed ({present}/{total} files)")
+        } else {
+            format!("partially installed ({present}/{total} files)")
+        };
+
+        let (config_key, referenced) = match sample {
+            HookSample::External => ("hooks.command", !hooks.command.events.is_empty()),
+            HookSample::PythonHost => ("hooks.host", hooks.host.enabled),
+            HookSample::Pyo3 => (
+                "hooks.inproc",
+                hooks.inproc.iter().any(|name| name == "pyo3"),
+            ),
+        };
+        let config = if referenced {
+            format!("referenced ({config_key})")
+        } else {
+            format!("not referenced ({config_key})")
+        };
+
+        println!();
+        println!("{} ({})", sample.id(), sample.title());
+        println!("  files: {files}");
+        println!("  config: {config}");
+    }
+    Ok(())
+}
+
 fn print_hooks_init_menu() {
     use codex_common::hooks_samples_install::HookSample;
     println!("Hooks init:");
@@ -2891,6 +3500,95 @@ fn print_hooks_init_menu() {
     println!("   {}", HookSample::Pyo3.description());
 }
 
+/// Arrow-key menu for `hooks init` on a TTY. Returns `None` when the user cancels.
+fn select_hook_sample_with_arrow_keys()
+-> anyhow::Result<Option<codex_common::hooks_samples_install::HookSample>> {
+    use codex_common::hooks_samples_install::HookSample;
+    use crossterm::cursor::MoveUp;
+    use crossterm::event::Event;
+    use crossterm::event::KeyCode;
+    use crossterm::event::KeyEventKind;
+    use crossterm::event::KeyModifiers;
+    use crossterm::terminal::Clear;
+    use crossterm::terminal::ClearType;
+    use std::io::Write;
+
+    let samples = [
+        HookSample::External,
+        HookSample::PythonHost,
+        HookSample::Pyo3,
+    ];
+    // Header, one row per sample, a blank line, and the selected sample's description.
+    let menu_lines = samples.len() as u16 + 3;
+    let mut selected = 0;
+    let mut stdout = std::io::stdout();
+
+    crossterm::terminal::enable_raw_mode()?;
+    let result = (|| -> anyhow::Result<Option<HookSample>> {
+        let mut first_render = true;
+        loop {
+            if !first_render {
+                crossterm::queue!(stdout, MoveUp(menu_lines))?;
+            }
+            first_render = false;
+            crossterm::queue!(stdout, Clear(ClearType::FromCursorDown))?;
+            // Raw mode disables newline translation, so every line ends with an explicit `\r\n`.
+            write!(
+                stdout,
+                "Select a hook mode (up/down to move, enter to select, esc to cancel):\r\n"
+            )?;
+            for (index, sample) in samples.iter().enumerate() {
+                let marker = if index == selected { ">" } else { " " };
+                write!(
+                    stdout,
+                    "{marker} {}) {}  (id: {})\r\n",
+                    index + 1,
+                    sample.title(),
+                    sample.id()
+                )?;
+            }
+            write!(stdout, "\r\n   {}\r\n", samples[selected].description())?;
+            stdout.flush()?;
+
+            let Event::Key(key) = crossterm::event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    selected = selected.checked_sub(1).unwrap_or(samples.len() - 1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    selected = (selected + 1) % samples.len();
+                }
+                KeyCode::Enter => return Ok(Some(samples[selected])),
+                KeyCode::Esc | KeyCode::Char('q') => return Ok(None),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Ok(None);
+                }
+                KeyCode::Char(digit @ '1'..='3') => {
+                    return Ok(parse_hook_sample(&digit.to_string()));
+                }
+                _ => {}
+            }
+        }
+    })();
+    crossterm::terminal::disable_raw_mode()?;
+    println!();
+    result
+}
+
+/// Numeric fallback for `hooks init` when the arrow-key menu is unavailable. Returns `None`
+/// when the user enters an empty line or closes stdin.
+fn read_hook_sample_choice(input: &mut impl std::io::BufRead) -> std::io::Result<Option<String>> {
+    let mut line = String::new();
+    input.read_line(&mut line)?;
+    let choice = line.trim();
+    Ok((!choice.is_empty()).then(|| choice.to_string()))
+}
+
 fn parse_hook_sample(raw: &str) -> Option<codex_common::hooks_samples_install::HookSample> {
     use codex_common::hooks_samples_install::HookSample;
     let raw = raw.trim().to_ascii_lowercase();
@@ -2904,22 +3602,38 @@ fn parse_hook_sample(raw: &str) -> Option<codex_common::hooks_samples_install::H
     }
 }
 
-fn print_hooks_list(codex_home: &Path, hooks: &codex_core::config::HooksConfig, all: bool) {
-    println!("CODEX_HOME: {}", codex_home.display());
-    println!("Config: {}", codex_home.join("config.toml").display());
-    println!(
-        "hooks.max_stdin_payload_bytes={}",
-        hooks.max_stdin_payload_bytes
-    );
-    println!("hooks.keep_last_n_payloads={}", hooks.keep_last_n_payloads);
-    println!(
-        "hooks.inproc_tool_call_summary={}",
-        hooks.inproc_tool_call_summary
-    );
-    println!("hooks.inproc={:?}", hooks.inproc);
-    println!("hooks.host.enabled={}", hooks.host.enabled);
-    println!("hooks.host.command={:?}", hooks.host.command);
-    println!("hooks.host.sandbox_mode={:?}", hooks.host.sandbox_mode);
+fn print_hooks_list(
+    codex_home: &Path,
+    hooks: &codex_core::config::HooksConfig,
+    all: bool,
+    only_configured: bool,
+) {
+    if !only_configured {
+        println!("CODEX_HOME: {}", codex_home.display());
+        println!("Config: {}", codex_home.join("config.toml").display());
+        println!(
+            "hooks.max_stdin_payload_bytes={}",
+            hooks.max_stdin_payload_bytes
+        );
+        println!("hooks.keep_last_n_payloads={}", hooks.keep_last_n_payloads);
+        println!(
+            "hooks.inproc_tool_call_summary={}",
+            hooks.inproc_tool_call_summary
+        );
+        println!("hooks.inproc={:?}", hooks.inproc);
+        println!("hooks.host.enabled={}", hooks.host.enabled);
+        println!("hooks.host.command={:?}", hooks.host.command);
+        println!("hooks.host.sandbox_mode={:?}", hooks.host.sandbox_mode);
+        println!("hooks.host.working_dir={:?}", hooks.host.working_dir);
+        println!("hooks.webhook.url={:?}", hooks.webhook.url);
+        println!("hooks.webhook.events={:?}", hooks.webhook.events);
+        if cfg!(windows)
+            && hooks.host.enabled
+            && hooks.host.sandbox_mode != Some(SandboxMode::DangerFullAccess)
+        {
+            println!("Note: {HOOK_HOST_WINDOWS_SANDBOX_UNSUPPORTED}");
+        }
+    }
 
     let entries: [(&str, &Vec<Vec<String>>); 8] = [
         ("hooks.agent_turn_complete", &hooks.agent_turn_complete),
@@ -2935,6 +3649,22 @@ fn print_hooks_list(codex_home: &Path, hooks: &codex_core::config::HooksConfig,
         ("hooks.tool_call_finished", &hooks.tool_call_finished),
     ];
 
+    if only_configured {
+        let configured = entries
+            .iter()
+            .filter(|(_key, commands)| !commands.is_empty());
+        for (idx, (key, commands)) in configured.enumerate() {
+            if idx > 0 {
+                println!();
+            }
+            println!("{key}:");
+            for command in commands.iter() {
+                println!("- {command:?}");
+            }
+        }
+        return;
+    }
+
     let configured = entries
         .iter()
         .filter(|(_key, commands)| !commands.is_empty())
@@ -2959,6 +3689,85 @@ fn print_hooks_list(codex_home: &Path, hooks: &codex_core::config::HooksConfig,
     }
 }
 
+/// Substrings (matched case-insensitively) marking env keys whose values `hooks env` masks.
+const SENSITIVE_ENV_KEY_MARKERS: &[&str] = &["KEY", "TOKEN", "SECRET", "PASSWORD"];
+
+fn hooks_env_display_value(key: &str, value: &std::ffi::OsStr) -> String {
+    let upper_key = key.to_ascii_uppercase();
+    if SENSITIVE_ENV_KEY_MARKERS
+        .iter()
+        .any(|marker| upper_key.contains(marker))
+    {
+        "*****".to_string()
+    } else {
+        value.to_string_lossy().to_string()
+    }
+}
+
+/// Prints the environment an external command hook is spawned with: variables set by xcodex
+/// first, then those inherited from this process, each sorted by key. Values of keys that look
+/// like credentials are masked.
+fn print_hooks_env(codex_home: &Path, hooks: &codex_core::config::HooksConfig) {
+    let xcodex_env = codex_core::xcodex::hooks::command_hook_env(codex_home);
+    let mut inherited_env: Vec<_> = std::env::vars_os()
+        .filter(|(key, _)| {
+            !xcodex_env
+                .iter()
+                .any(|(xcodex_key, _)| key == xcodex_key.as_str())
+        })
+        .collect();
+    inherited_env.sort();
+
+    println!("Config: {}", codex_home.join("config.toml").display());
+    let configured = [
+        &hooks.agent_turn_complete,
+        &hooks.approval_requested,
+        &hooks.session_start,
+        &hooks.session_end,
+        &hooks.model_request_started,
+        &hooks.model_response_completed,
+        &hooks.tool_call_started,
+        &hooks.tool_call_finished,
+    ]
+    .into_iter()
+    .filter(|commands| !commands.is_empty())
+    .count()
+        + hooks.command.events.len();
+    println!("Configured command hook events: {configured}");
+    println!();
+    println!("Environment for external command hooks:");
+    for (key, value) in &xcodex_env {
+        println!("[xcodex] {key}={}", hooks_env_display_value(key, value));
+    }
+    for (key, value) in inherited_env {
+        let key = key.to_string_lossy();
+        println!(
+            "[inherited] {key}={}",
+            hooks_env_display_value(&key, &value)
+        );
+    }
+}
+
+fn write_hooks_schema(out: Option<&Path>) -> anyhow::Result<()> {
+    let schemas = codex_core::xcodex::hooks::hook_payload_schemas()?;
+    let Some(out) = out else {
+        let bundle: serde_json::Map<String, serde_json::Value> = schemas
+            .into_iter()
+            .map(|(event_type, schema)| (event_type.to_string(), schema))
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&bundle)?);
+        return Ok(());
+    };
+
+    std::fs::create_dir_all(out)?;
+    for (event_type, schema) in schemas {
+        let path = out.join(format!("{event_type}.schema.json"));
+        std::fs::write(&path, serde_json::to_string_pretty(&schema)? + "\n")?;
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
 fn print_hooks_paths(codex_home: &Path, hooks: &codex_core::config::HooksConfig) {
     println!("CODEX_HOME: {}", codex_home.display());
     println!("Logs: {}", hooks_logs_dir(codex_home).display());
@@ -2982,3 +3791,231 @@ fn print_hooks_paths(codex_home: &Path, hooks: &codex_core::config::HooksConfig)
         hooks.max_stdin_payload_bytes
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn hooks_init_numeric_fallback_parses_typed_choice() -> std::io::Result<()> {
+        use codex_common::hooks_samples_install::HookSample;
+
+        let parse = |input: &str| -> std::io::Result<Option<Option<HookSample>>> {
+            Ok(read_hook_sample_choice(&mut input.as_bytes())?
+                .map(|choice| parse_hook_sample(&choice)))
+        };
+
+        assert_eq!(parse("1\n")?, Some(Some(HookSample::External)));
+        assert_eq!(parse(" 2 \r\n")?, Some(Some(HookSample::PythonHost)));
+        assert_eq!(parse("3")?, Some(Some(HookSample::Pyo3)));
+        assert_eq!(parse("4\n")?, Some(None));
+        assert_eq!(parse("\n")?, None);
+        assert_eq!(parse("")?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn pyo3_bootstrap_summary_counts_cargo_diagnostics() {
+        let cargo_output = "\
+   Compiling codex-core v0.0.0
+warning: unused variable: `x`
+  --> core/src/lib.rs:1:5
+warning: unused import: `std::fs`
+error[E0308]: mismatched types
+error: cannot find value `y` in this scope
+warning: `codex-core` (lib) generated 2 warnings
+error: could not compile `codex-core` (lib) due to 2 previous errors; 2 warnings emitted
+";
+        assert_eq!(count_cargo_diagnostics(cargo_output), (2, 2));
+
+        let transcript = format!(
+            "{PYO3_STEP_ELAPSED_PREFIX}prerequisites=400\ncargo_warnings=2\ncargo_errors=2\n{PYO3_STEP_ELAPSED_PREFIX}build=61300\n"
+        );
+        assert_eq!(
+            format_pyo3_bootstrap_summary(&transcript),
+            "Summary:\n- cargo errors: 2\n- cargo warnings: 2\n- step prerequisites: 0.4s\n- step build: 61.3s\n"
+        );
+    }
+
+    #[test]
+    fn pyo3_bootstrap_resumes_checkout_from_valid_state() -> anyhow::Result<()> {
+        let repo = tempfile::TempDir::new()?;
+        let git = |args: &[&str]| -> anyhow::Result<String> {
+            let output = std::process::Command::new("git")
+                .current_dir(repo.path())
+                .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .output()?;
+            anyhow::ensure!(output.status.success(), "git {args:?} failed");
+            Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+        git(&["init", "--quiet"])?;
+        git(&["commit", "--quiet", "--allow-empty", "-m", "initial"])?;
+        git(&["tag", "v1"])?;
+        let commit = git(&["rev-parse", "HEAD"])?;
+
+        let state_path = pyo3_bootstrap_state_path(repo.path());
+        let state = serde_json::json!({
+            "repo_url": "https://example.com/xcodex.git",
+            "git_ref": "v1",
+            "completed_steps": ["clone", "checkout"],
+            "resolved_commit": commit,
+        });
+        std::fs::write(&state_path, state.to_string())?;
+
+        assert_eq!(
+            resumable_pyo3_checkout(
+                repo.path(),
+                &state_path,
+                "https://example.com/xcodex.git",
+                "v1"
+            ),
+            Some(commit)
+        );
+        assert_eq!(
+            resumable_pyo3_checkout(
+                repo.path(),
+                &state_path,
+                "https://example.com/xcodex.git",
+                "main"
+            ),
+            None
+        );
+
+        git(&["commit", "--quiet", "--allow-empty", "-m", "moved"])?;
+        assert_eq!(
+            resumable_pyo3_checkout(
+                repo.path(),
+                &state_path,
+                "https://example.com/xcodex.git",
+                "v1"
+            ),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn pyo3_bootstrap_patches_revert_to_original_bytes() -> anyhow::Result<()> {
+        let repo = tempfile::TempDir::new()?;
+        let codex_rs_dir = repo.path().join("codex-rs");
+        std::fs::create_dir_all(repo.path().join(".git"))?;
+        std::fs::create_dir_all(codex_rs_dir.join("cli"))?;
+        let cargo_toml = codex_rs_dir.join("cli").join("Cargo.toml");
+        let original = "[package]\nname = \"codex-cli\"  # keep\n\n[dependencies]\nanyhow = { workspace = true }\n";
+        std::fs::write(&cargo_toml, original)?;
+
+        let mut transcript = String::new();
+        apply_pyo3_bootstrap_patches(repo.path(), &codex_rs_dir, "commit-a", &mut transcript)?;
+        assert!(std::fs::read_to_string(&cargo_toml)?.contains("ctor"));
+
+        assert_eq!(
+            revert_pyo3_bootstrap_patches(repo.path())?,
+            vec![cargo_toml.clone()]
+        );
+        assert_eq!(std::fs::read(&cargo_toml)?, original.as_bytes());
+        assert!(!pyo3_bootstrap_patch_manifest_path(repo.path()).exists());
+        Ok(())
+    }
+
+    #[test]
+    fn pyo3_bootstrap_patch_manifest_is_rerecorded_after_checkout_changes() -> anyhow::Result<()> {
+        let repo = tempfile::TempDir::new()?;
+        let codex_rs_dir = repo.path().join("codex-rs");
+        std::fs::create_dir_all(repo.path().join(".git"))?;
+        std::fs::create_dir_all(codex_rs_dir.join("cli"))?;
+        let cargo_toml = codex_rs_dir.join("cli").join("Cargo.toml");
+        std::fs::write(&cargo_toml, "[package]\nname = \"codex-cli\"\n")?;
+
+        let mut transcript = String::new();
+        apply_pyo3_bootstrap_patches(repo.path(), &codex_rs_dir, "commit-a", &mut transcript)?;
+
+        // Simulate checking out another ref, which replaces the patched file.
+        let switched = "[package]\nname = \"codex-cli\"\nversion = \"2.0.0\"\n";
+        std::fs::write(&cargo_toml, switched)?;
+        apply_pyo3_bootstrap_patches(repo.path(), &codex_rs_dir, "commit-b", &mut transcript)?;
+        assert!(transcript.contains("discarded_stale_patch_manifest recorded_commit=commit-a"));
+
+        let manifest: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
+            pyo3_bootstrap_patch_manifest_path(repo.path()),
+        )?)?;
+        assert_eq!(manifest["commit"], "commit-b");
+        assert_eq!(manifest["files"][0]["original_contents"], switched);
+
+        revert_pyo3_bootstrap_patches(repo.path())?;
+        assert_eq!(std::fs::read_to_string(&cargo_toml)?, switched);
+        Ok(())
+    }
+
+    #[test]
+    fn pyo3_bootstrap_cargo_args_include_extra_features() {
+        let build_args = pyo3_bootstrap_cargo_build_args(
+            HooksPyo3Profile::Release,
+            &["tui2".to_string(), "codex-core/pyo3-hooks".to_string()],
+            None,
+            false,
+        );
+
+        assert_eq!(build_args.features, "codex-core/pyo3-hooks,tui2");
+        assert_eq!(
+            build_args.args,
+            vec![
+                "build",
+                "-p",
+                "codex-cli",
+                "--bin",
+                "codex",
+                "--release",
+                "--features",
+                "codex-core/pyo3-hooks,tui2",
+            ]
+        );
+    }
+
+    #[test]
+    fn pyo3_bootstrap_cargo_args_include_jobs() {
+        let build_args = pyo3_bootstrap_cargo_build_args(
+            HooksPyo3Profile::Debug,
+            &[],
+            NonZeroUsize::new(2),
+            false,
+        );
+
+        assert_eq!(
+            build_args.args,
+            vec![
+                "build",
+                "-p",
+                "codex-cli",
+                "--bin",
+                "codex",
+                "--jobs",
+                "2",
+                "--features",
+                "codex-core/pyo3-hooks",
+            ]
+        );
+    }
+
+    #[test]
+    fn pyo3_bootstrap_cargo_args_include_offline() {
+        let build_args =
+            pyo3_bootstrap_cargo_build_args(HooksPyo3Profile::Release, &[], None, true);
+
+        assert_eq!(
+            build_args.args,
+            vec![
+                "build",
+                "-p",
+                "codex-cli",
+                "--bin",
+                "codex",
+                "--release",
+                "--offline",
+                "--features",
+                "codex-core/pyo3-hooks",
+            ]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use assert_cmd::Command;
 use predicates::prelude::*;
+use pretty_assertions::assert_eq;
 use std::fs;
 
 #[test]
@@ -19,6 +20,39 @@ fn config_path_prints_user_config_path() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn config_path_lists_codex_home_files() -> anyhow::Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let codex_home = tmp.path();
+    let config_toml = codex_home.join("config.toml");
+
+    let mut cmd = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home)
+        .args(["config", "path"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "User config: {}",
+            config_toml.display()
+        )))
+        .stdout(predicate::str::contains("Auth file: "))
+        .stdout(predicate::str::contains("Hooks logs: "))
+        .stdout(predicate::str::contains("Exports: "));
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home)
+        .args(["config", "path", "--json"])
+        .output()?;
+    assert!(output.status.success());
+    let paths: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(
+        paths["config_toml"],
+        serde_json::json!(config_toml.display().to_string())
+    );
+
+    Ok(())
+}
+
 #[test]
 fn config_doctor_reports_unknown_keys() -> anyhow::Result<()> {
     let tmp = tempfile::tempdir()?;
@@ -141,3 +175,255 @@ fn config_doctor_reports_overrides_with_next_step() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn config_get_prints_nested_boolean() -> anyhow::Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let codex_home = tmp.path();
+    fs::write(
+        codex_home.join("config.toml"),
+        "[hooks.host]\nenabled = true\n",
+    )?;
+
+    let mut cmd = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home)
+        .args(["config", "get", "hooks.host.enabled"])
+        .assert()
+        .success()
+        .stdout("true\n");
+
+    Ok(())
+}
+
+#[test]
+fn config_get_fails_for_unknown_key() -> anyhow::Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let codex_home = tmp.path();
+
+    let mut cmd = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home)
+        .args(["config", "get", "hooks.host.no_such_key"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "`hooks.host.no_such_key` is not set",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn config_set_writes_value_that_config_get_reads_back() -> anyhow::Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let codex_home = tmp.path();
+    fs::write(
+        codex_home.join("config.toml"),
+        "# keep this comment\nmodel = \"gpt-5\"\n",
+    )?;
+
+    let mut cmd = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home)
+        .args(["config", "set", "sandbox_mode", "workspace-write"])
+        .assert()
+        .success();
+
+    let contents = fs::read_to_string(codex_home.join("config.toml"))?;
+    assert!(contents.contains("# keep this comment"));
+    assert!(contents.contains("sandbox_mode = \"workspace-write\""));
+
+    let mut cmd = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home)
+        .args(["config", "get", "sandbox_mode"])
+        .assert()
+        .success()
+        .stdout("workspace-write\n");
+
+    Ok(())
+}
+
+#[test]
+fn config_set_dry_run_prints_diff_without_writing() -> anyhow::Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let codex_home = tmp.path();
+    fs::write(codex_home.join("config.toml"), "")?;
+
+    let mut cmd = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home)
+        .args(["config", "set", "--dry-run", "hooks.host.enabled", "true"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("+enabled = true"));
+
+    assert_eq!(fs::read_to_string(codex_home.join("config.toml"))?, "");
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn config_set_dry_run_matches_write_through_symlink() -> anyhow::Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let codex_home = tmp.path();
+    fs::write(codex_home.join("real.toml"), "model = \"gpt-5\" # pinned\n")?;
+    std::os::unix::fs::symlink("real.toml", codex_home.join("config.toml"))?;
+
+    let mut cmd = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home)
+        .args(["config", "set", "--dry-run", "model", "o3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("+model = \"o3\" # pinned"));
+
+    let mut cmd = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home)
+        .args(["config", "set", "model", "o3"])
+        .assert()
+        .success();
+
+    assert!(
+        fs::symlink_metadata(codex_home.join("config.toml"))?
+            .file_type()
+            .is_symlink()
+    );
+    assert_eq!(
+        fs::read_to_string(codex_home.join("real.toml"))?,
+        "model = \"o3\" # pinned\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn config_init_creates_default_config_and_first_run_marker() -> anyhow::Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let codex_home = tmp.path().join("home");
+
+    for _ in 0..2 {
+        Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+            .env("CODEX_HOME", &codex_home)
+            .args(["config", "init"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(format!(
+                "Initialized {}",
+                codex_home.display()
+            )));
+    }
+
+    assert!(
+        codex_home
+            .join(".xcodex-first-run-wizard.complete")
+            .exists()
+    );
+    assert_eq!(
+        fs::read_to_string(codex_home.join("config.toml"))?,
+        codex_core::config::XCODEX_DEFAULT_CONFIG_TOML_STUB
+    );
+
+    Ok(())
+}
+
+#[test]
+fn strict_config_rejects_misspelled_override_key() -> anyhow::Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let codex_home = tmp.path();
+
+    let mut cmd = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home)
+        .args([
+            "--strict-config",
+            "-c",
+            "hooks.hot.enabled=true",
+            "config",
+            "path",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("`hooks.hot.enabled`"))
+        .stderr(predicate::str::contains("hooks.host.enabled"));
+
+    let mut cmd = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home)
+        .args([
+            "--strict-config",
+            "-c",
+            "hooks.host.enabled=true",
+            "config",
+            "path",
+        ])
+        .assert()
+        .success();
+
+    let mut cmd = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home)
+        .args(["--strict-config", "-c", "hooks.host=true", "config", "path"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("`hooks.host`"));
+
+    Ok(())
+}
+
+#[test]
+fn strict_config_checks_inline_table_overrides_by_leaf() -> anyhow::Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let codex_home = tmp.path();
+
+    for value in [
+        "hooks.host={enabled=true}",
+        r#"mcp_servers.foo={command="npx"}"#,
+        "hooks.provider_order=[]",
+    ] {
+        let mut cmd = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+        cmd.env("CODEX_HOME", codex_home)
+            .args(["--strict-config", "-c", value, "config", "path"])
+            .assert()
+            .success();
+    }
+
+    let mut cmd = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home)
+        .args([
+            "--strict-config",
+            "-c",
+            "hooks.host={enabeld=true}",
+            "config",
+            "path",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("`hooks.host.enabeld`"));
+
+    Ok(())
+}
+
+#[test]
+fn print_effective_config_reflects_overrides_and_profile() -> anyhow::Result<()> {
+    let tmp = tempfile::tempdir()?;
+    let codex_home = tmp.path();
+    fs::write(
+        codex_home.join("config.toml"),
+        "model = \"gpt-5\"\napproval_policy = \"on-request\"\n\n[profiles.review]\napproval_policy = \"never\"\n",
+    )?;
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home)
+        .args([
+            "--print-effective-config",
+            "--profile",
+            "review",
+            "-c",
+            "model=\"o4-mini\"",
+        ])
+        .output()?;
+    assert!(output.status.success());
+
+    let effective: toml::Value = toml::from_str(std::str::from_utf8(&output.stdout)?)?;
+    assert_eq!(effective.get("model"), Some(&toml::Value::from("o4-mini")));
+    assert_eq!(
+        effective.get("approval_policy"),
+        Some(&toml::Value::from("never"))
+    );
+
+    Ok(())
+}
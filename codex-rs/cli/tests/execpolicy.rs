@@ -117,3 +117,106 @@ prefix_rule(
 
     Ok(())
 }
+
+#[test]
+fn execpolicy_check_validate_only_reports_parse_errors() -> Result<(), Box<dyn std::error::Error>> {
+    let codex_home = TempDir::new()?;
+    let rules_dir = codex_home.path().join("rules");
+    fs::create_dir_all(&rules_dir)?;
+    let valid_path = rules_dir.join("valid.rules");
+    fs::write(
+        &valid_path,
+        r#"
+prefix_rule(
+    pattern = ["git", "push"],
+    decision = "forbidden",
+)
+"#,
+    )?;
+    let malformed_path = rules_dir.join("malformed.rules");
+    fs::write(&malformed_path, "prefix_rule(\n    pattern = [\"git\",\n")?;
+
+    let valid_output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args(["execpolicy", "check", "--validate-only", "--rules"])
+        .arg(&valid_path)
+        .output()?;
+    assert!(valid_output.status.success());
+
+    let malformed_output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args(["execpolicy", "check", "--validate-only", "--rules"])
+        .arg(&valid_path)
+        .arg("--rules")
+        .arg(&malformed_path)
+        .output()?;
+    assert!(!malformed_output.status.success());
+    let stderr = String::from_utf8_lossy(&malformed_output.stderr);
+    let malformed_prefix = format!("{}:", malformed_path.display());
+    assert!(
+        stderr
+            .lines()
+            .any(|line| line.starts_with(&malformed_prefix)),
+        "expected a diagnostic for the malformed file: {stderr}"
+    );
+    assert!(!stderr.contains(&format!("{}:", valid_path.display())));
+
+    Ok(())
+}
+
+#[test]
+fn execpolicy_check_json_reports_deny() -> Result<(), Box<dyn std::error::Error>> {
+    let codex_home = TempDir::new()?;
+    let policy_path = codex_home.path().join("rules").join("policy.rules");
+    fs::create_dir_all(
+        policy_path
+            .parent()
+            .expect("policy path should have a parent"),
+    )?;
+    fs::write(
+        &policy_path,
+        r#"
+prefix_rule(
+    pattern = ["git", "push"],
+    decision = "forbidden",
+    justification = "pushing is blocked in this repo",
+)
+"#,
+    )?;
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args([
+            "execpolicy",
+            "check",
+            "--json",
+            "--rules",
+            policy_path
+                .to_str()
+                .expect("policy path should be valid UTF-8"),
+            "git",
+            "push",
+            "origin",
+            "main",
+        ])
+        .output()?;
+
+    assert!(output.status.success());
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(
+        result,
+        json!({
+            "decision": "deny",
+            "matchedRule": {
+                "prefixRuleMatch": {
+                    "matchedPrefix": ["git", "push"],
+                    "decision": "forbidden",
+                    "justification": "pushing is blocked in this repo"
+                }
+            },
+            "reason": "pushing is blocked in this repo"
+        })
+    );
+
+    Ok(())
+}
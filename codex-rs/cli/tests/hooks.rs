@@ -42,6 +42,53 @@ fn hooks_init_is_idempotent_without_force() -> Result<(), Box<dyn std::error::Er
     Ok(())
 }
 
+#[test]
+fn hooks_init_without_tty_falls_back_to_numeric_selection() -> Result<(), Box<dyn std::error::Error>>
+{
+    let codex_home = TempDir::new()?;
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args(["hooks", "init"])
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("1) External hooks (spawn per event)  (id: external)"));
+    assert!(stdout.contains("- xcodex hooks init python-host"));
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args(["hooks", "init", "2", "--dry-run"])
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Sample: python-host ("));
+
+    Ok(())
+}
+
+#[test]
+fn hooks_init_all_dry_run_lists_files_for_every_mode() -> Result<(), Box<dyn std::error::Error>> {
+    let codex_home = TempDir::new()?;
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args(["hooks", "init", "--all", "--dry-run"])
+        .output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for sample in ["external", "python-host", "pyo3"] {
+        assert!(stdout.contains(&format!("Sample: {sample} (")));
+    }
+    for file in ["log_all_jsonl.py", "host.py", "pyo3_hook.py"] {
+        assert!(stdout.contains(file), "missing {file} in:\n{stdout}");
+    }
+    assert!(!codex_home.path().join("hooks").exists());
+
+    Ok(())
+}
+
 #[test]
 fn hooks_install_legacy_prints_redirect() -> Result<(), Box<dyn std::error::Error>> {
     let codex_home = TempDir::new()?;
@@ -126,6 +173,87 @@ fn hooks_install_samples_pyo3_writes_with_yes() -> Result<(), Box<dyn std::error
     Ok(())
 }
 
+#[test]
+fn hooks_samples_status_reports_not_installed_after_dry_run()
+-> Result<(), Box<dyn std::error::Error>> {
+    let codex_home = TempDir::new()?;
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args(["hooks", "install", "samples", "all", "--dry-run"])
+        .output()?;
+    assert!(output.status.success());
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args(["hooks", "samples", "status"])
+        .output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let statuses: Vec<&str> = stdout
+        .lines()
+        .filter(|line| line.starts_with("  "))
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            "  files: not installed",
+            "  config: not referenced (hooks.command)",
+            "  files: not installed",
+            "  config: not referenced (hooks.host)",
+            "  files: not installed",
+            "  config: not referenced (hooks.inproc)",
+        ]
+    );
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn run_pyo3_doctor_with_fake_python(
+    script_body: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let codex_home = TempDir::new()?;
+    let python = codex_home.path().join("fake-python");
+    fs::write(&python, format!("#!/bin/sh\n{script_body}\n"))?;
+    fs::set_permissions(&python, fs::Permissions::from_mode(0o755))?;
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args(["hooks", "doctor", "pyo3", "--python"])
+        .arg(&python)
+        .output()?;
+    assert!(output.status.success());
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(unix)]
+#[test]
+fn hooks_doctor_pyo3_rejects_too_old_python() -> Result<(), Box<dyn std::error::Error>> {
+    let stdout = run_pyo3_doctor_with_fake_python("echo 'Python 3.6.9'")?;
+
+    assert!(
+        stdout.contains("- python (PYO3_PYTHON): failed: expected Python 3.8+, got Python 3.6.9")
+    );
+    assert!(stdout.contains("Status: missing prerequisites"));
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn hooks_doctor_pyo3_rejects_non_python_binary() -> Result<(), Box<dyn std::error::Error>> {
+    let stdout = run_pyo3_doctor_with_fake_python("echo 'node v20.11.0'")?;
+
+    assert!(stdout.contains(
+        "- python (PYO3_PYTHON): failed: expected Python 3.8+, got `node v20.11.0` (not a Python interpreter?)"
+    ));
+    assert!(stdout.contains("Status: missing prerequisites"));
+    Ok(())
+}
+
 #[test]
 fn hooks_pyo3_legacy_subcommands_print_redirects() -> Result<(), Box<dyn std::error::Error>> {
     let codex_home = TempDir::new()?;
@@ -238,6 +366,114 @@ fn hooks_test_routing_is_non_fatal_when_unconfigured() -> Result<(), Box<dyn std
     Ok(())
 }
 
+#[test]
+fn hooks_env_marks_codex_home_as_set_by_xcodex() -> Result<(), Box<dyn std::error::Error>> {
+    let codex_home = TempDir::new()?;
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .env("XCODEX_HOOKS_ENV_TEST", "inherited-value")
+        .env("XCODEX_HOOKS_ENV_TEST_TOKEN", "super-secret")
+        .env("xcodex_hooks_env_test_api_key", "also-secret")
+        .args(["hooks", "env"])
+        .output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let codex_home_line = stdout
+        .lines()
+        .find(|line| line.starts_with("[xcodex] CODEX_HOME="))
+        .ok_or("CODEX_HOME line missing")?;
+    let printed_home = codex_home_line.trim_start_matches("[xcodex] CODEX_HOME=");
+    assert_eq!(
+        fs::canonicalize(printed_home)?,
+        fs::canonicalize(codex_home.path())?
+    );
+    assert!(stdout.contains("[inherited] XCODEX_HOOKS_ENV_TEST=inherited-value\n"));
+    assert!(!stdout.contains("[inherited] CODEX_HOME="));
+    assert!(stdout.contains("[inherited] XCODEX_HOOKS_ENV_TEST_TOKEN=*****\n"));
+    assert!(stdout.contains("[inherited] xcodex_hooks_env_test_api_key=*****\n"));
+    assert!(!stdout.contains("super-secret"));
+    assert!(!stdout.contains("also-secret"));
+
+    Ok(())
+}
+
+#[test]
+fn hooks_env_applies_config_overrides() -> Result<(), Box<dyn std::error::Error>> {
+    let codex_home = TempDir::new()?;
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args(["-c", r#"hooks.session_start=[["true"]]"#, "hooks", "env"])
+        .output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Configured command hook events: 1\n"));
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args(["-c", "hooks.session_start=oops", "hooks", "env"])
+        .output()?;
+    assert!(!output.status.success());
+
+    Ok(())
+}
+
+#[test]
+fn hooks_schema_lists_tool_call_finished_properties() -> Result<(), Box<dyn std::error::Error>> {
+    let codex_home = TempDir::new()?;
+    let out_dir = TempDir::new()?;
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args(["hooks", "schema", "--out"])
+        .arg(out_dir.path())
+        .output()?;
+    assert!(output.status.success());
+
+    let schema: serde_json::Value = serde_json::from_str(&fs::read_to_string(
+        out_dir.path().join("tool-call-finished.schema.json"),
+    )?)?;
+    assert_eq!(schema["title"], "ToolCallFinishedPayload");
+    let properties = schema["properties"]
+        .as_object()
+        .ok_or("schema properties missing")?;
+    assert!(properties.contains_key("success"));
+    assert!(properties.contains_key("duration_ms"));
+
+    Ok(())
+}
+
+#[cfg(windows)]
+#[test]
+fn hooks_list_reports_unsandboxed_host_on_windows() -> Result<(), Box<dyn std::error::Error>> {
+    let codex_home = TempDir::new()?;
+    fs::write(
+        codex_home.path().join("config.toml"),
+        r#"
+[hooks.host]
+enabled = true
+command = ["python", "-u", "hooks/host/python/host.py"]
+sandbox_mode = "read-only"
+"#,
+    )?;
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args(["hooks", "list"])
+        .output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains(
+        "Note: hook host sandboxing is not supported on Windows yet; the host runs without sandboxing"
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn hooks_list_prints_configured_events_in_stable_order() -> Result<(), Box<dyn std::error::Error>> {
     let codex_home = TempDir::new()?;
@@ -271,3 +507,31 @@ tool_call_finished = [["python3", "/tmp/hook2.py"]]
 
     Ok(())
 }
+
+#[test]
+fn hooks_list_only_configured_prints_just_configured_events()
+-> Result<(), Box<dyn std::error::Error>> {
+    let codex_home = TempDir::new()?;
+    fs::write(
+        codex_home.path().join("config.toml"),
+        r#"
+[hooks]
+session_start = [["python3", "/tmp/hook.py"]]
+"#,
+    )?;
+
+    let output = Command::new(codex_utils_cargo_bin::cargo_bin("codex")?)
+        .env("CODEX_HOME", codex_home.path())
+        .args(["hooks", "list", "--only-configured"])
+        .output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        stdout,
+        "hooks.session_start:\n- [\"python3\", \"/tmp/hook.py\"]\n"
+    );
+    assert!(!stdout.contains("(none)"));
+
+    Ok(())
+}
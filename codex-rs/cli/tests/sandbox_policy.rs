@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use anyhow::Result;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+#[test]
+fn sandbox_policy_lists_workspace_write_roots() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let workdir = TempDir::new()?;
+    let workdir = workdir.path().canonicalize()?;
+
+    let mut cmd = codex_command(codex_home.path())?;
+    cmd.args([
+        "sandbox",
+        "policy",
+        "--sandbox-mode",
+        "workspace-write",
+        "-C",
+    ])
+    .arg(&workdir)
+    .assert()
+    .success()
+    .stdout(contains("sandbox: workspace-write"))
+    .stdout(contains("writable roots:"))
+    .stdout(contains(format!("  {}\n", workdir.display())));
+
+    Ok(())
+}
+
+#[test]
+fn sandbox_policy_read_only_has_no_writable_roots() -> Result<()> {
+    let codex_home = TempDir::new()?;
+
+    let mut cmd = codex_command(codex_home.path())?;
+    cmd.args(["sandbox", "policy"])
+        .assert()
+        .success()
+        .stdout(contains("sandbox: read-only"))
+        .stdout(contains("writable roots: (none)"));
+
+    Ok(())
+}
@@ -61,6 +61,11 @@ fn generate_go_types(schema: &Value) -> Result<String, String> {
         })
         .unwrap_or_default();
 
+    let definitions = schema
+        .get("definitions")
+        .and_then(Value::as_object)
+        .ok_or("expected definitions object")?;
+
     let mut out = String::new();
     out.push_str(
         r#"// Code generated by `codex-core/src/bin/hooks_go_types.rs`. DO NOT EDIT.
@@ -89,9 +94,10 @@ type HookPayload struct {
 
     let mut keys: Vec<(&String, &Value)> = properties.iter().collect();
     keys.sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+    let mut enums = BTreeSet::new();
     for (key, prop_schema) in keys {
         let field_name = go_field_name(key);
-        let mut go_ty = go_type_for_schema(prop_schema);
+        let mut go_ty = go_type_for_schema(prop_schema, definitions, &mut enums);
 
         let is_required = required.contains(key.as_str());
         let is_nullable = schema_allows_null(prop_schema);
@@ -139,9 +145,44 @@ func ParseHookPayload(data []byte) (*HookPayload, error) {
 "#,
     );
 
+    for name in &enums {
+        write_string_enum(&mut out, name, &definitions[name])
+            .map_err(|_| "formatting failed".to_string())?;
+    }
+
     Ok(out)
 }
 
+/// Emits a named Go string type with one constant per value of a string-enum definition.
+#[cfg(feature = "hooks-schema")]
+fn write_string_enum(out: &mut String, name: &str, definition: &Value) -> std::fmt::Result {
+    writeln!(out)?;
+    if let Some(description) = definition.get("description").and_then(Value::as_str) {
+        for line in description.lines() {
+            writeln!(out, "// {line}")?;
+        }
+    }
+    writeln!(out, "type {name} string")?;
+    writeln!(out)?;
+    writeln!(out, "const (")?;
+    for value in string_enum_values(definition).unwrap_or_default() {
+        let variant = go_field_name(&value.replace('-', "_"));
+        writeln!(out, "\t{name}{variant} {name} = \"{value}\"")?;
+    }
+    writeln!(out, ")")
+}
+
+/// Values of a definition that is a plain string enum (`{"type": "string", "enum": [...]}`).
+#[cfg(feature = "hooks-schema")]
+fn string_enum_values(definition: &Value) -> Option<Vec<&str>> {
+    definition
+        .get("enum")?
+        .as_array()?
+        .iter()
+        .map(Value::as_str)
+        .collect()
+}
+
 #[cfg(feature = "hooks-schema")]
 fn go_field_name(key: &str) -> String {
     key.split('_')
@@ -171,21 +212,40 @@ fn schema_allows_null(schema: &Value) -> bool {
 }
 
 #[cfg(feature = "hooks-schema")]
-fn go_type_for_schema(schema: &Value) -> String {
-    if schema.get("$ref").is_some() {
+fn go_type_for_schema(
+    schema: &Value,
+    definitions: &serde_json::Map<String, Value>,
+    enums: &mut BTreeSet<String>,
+) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        if let Some((_, name)) = reference.rsplit_once('/')
+            && let Some(definition) = definitions.get(name)
+            && string_enum_values(definition).is_some()
+        {
+            enums.insert(name.to_string());
+            return name.to_string();
+        }
+        return "any".to_string();
+    }
+
+    if let Some(any_of) = schema.get("anyOf").and_then(Value::as_array) {
+        let non_null: Vec<&Value> = any_of.iter().filter(|s| !schema_allows_null(s)).collect();
+        if let [only] = non_null.as_slice() {
+            return go_type_for_schema(only, definitions, enums);
+        }
         return "any".to_string();
     }
 
     if let Some(ty) = schema.get("type") {
         if let Some(s) = ty.as_str() {
-            return go_type_for_primitive(s, schema);
+            return go_type_for_primitive(s, schema, definitions, enums);
         }
         if let Some(arr) = ty.as_array() {
             let mut types: Vec<String> = arr
                 .iter()
                 .filter_map(Value::as_str)
                 .filter(|t| *t != "null")
-                .map(|t| go_type_for_primitive(t, schema))
+                .map(|t| go_type_for_primitive(t, schema, definitions, enums))
                 .collect();
             types.sort();
             types.dedup();
@@ -197,7 +257,7 @@ fn go_type_for_schema(schema: &Value) -> String {
         let mut types: Vec<String> = one_of
             .iter()
             .filter(|s| !schema_allows_null(s))
-            .map(go_type_for_schema)
+            .map(|s| go_type_for_schema(s, definitions, enums))
             .collect();
         types.sort();
         types.dedup();
@@ -208,7 +268,12 @@ fn go_type_for_schema(schema: &Value) -> String {
 }
 
 #[cfg(feature = "hooks-schema")]
-fn go_type_for_primitive(ty: &str, schema: &Value) -> String {
+fn go_type_for_primitive(
+    ty: &str,
+    schema: &Value,
+    definitions: &serde_json::Map<String, Value>,
+    enums: &mut BTreeSet<String>,
+) -> String {
     match ty {
         "string" => "string".to_string(),
         "integer" | "number" => "int".to_string(),
@@ -216,7 +281,7 @@ fn go_type_for_primitive(ty: &str, schema: &Value) -> String {
         "array" => {
             let item_ty = schema
                 .get("items")
-                .map(go_type_for_schema)
+                .map(|items| go_type_for_schema(items, definitions, enums))
                 .unwrap_or_else(|| "any".to_string());
             format!("[]{item_ty}")
         }
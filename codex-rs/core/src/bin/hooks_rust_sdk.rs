@@ -61,6 +61,11 @@ fn generate_rust_sdk(schema: &Value) -> Result<String, String> {
         })
         .unwrap_or_default();
 
+    let definitions = schema
+        .get("definitions")
+        .and_then(Value::as_object)
+        .ok_or("expected definitions object")?;
+
     let mut out = String::new();
     out.push_str(
         r#"// Code generated by `codex-core/src/bin/hooks_rust_sdk.rs`. DO NOT EDIT.
@@ -90,9 +95,11 @@ pub type ExtraFields = BTreeMap<String, Value>;
 
     let mut keys: Vec<(&String, &Value)> = properties.iter().collect();
     keys.sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+    let mut fields = Vec::with_capacity(keys.len());
+    let mut enums = BTreeSet::new();
     for (key, prop_schema) in keys {
         let field_name = snake_case(key);
-        let mut rust_ty = rust_type_for_schema(prop_schema);
+        let mut rust_ty = rust_type_for_schema(prop_schema, definitions, &mut enums);
 
         let is_required = required.contains(key.as_str());
         let is_nullable = schema_allows_null(prop_schema);
@@ -104,15 +111,148 @@ pub type ExtraFields = BTreeMap<String, Value>;
             .map_err(|_| "formatting failed".to_string())?;
         writeln!(&mut out, "    pub {field_name}: {rust_ty},")
             .map_err(|_| "formatting failed".to_string())?;
+        fields.push((field_name, rust_ty));
     }
 
     out.push_str("    #[serde(flatten)]\n");
     out.push_str("    pub extra: ExtraFields,\n");
     out.push_str("}\n");
 
+    for name in &enums {
+        write_string_enum(&mut out, name, &definitions[name])
+            .map_err(|_| "formatting failed".to_string())?;
+    }
+
+    write_payload_builder(&mut out, &fields).map_err(|_| "formatting failed".to_string())?;
+
     Ok(out)
 }
 
+/// Emits `HookPayloadBuilder`, which fills required envelope fields with placeholders so tests
+/// only set the fields they care about.
+#[cfg(feature = "hooks-schema")]
+fn write_payload_builder(out: &mut String, fields: &[(String, String)]) -> std::fmt::Result {
+    out.push_str(
+        r#"
+#[cfg(any(test, feature = "test-support"))]
+#[derive(Debug, Clone)]
+pub struct HookPayloadBuilder {
+    payload: HookPayload,
+}
+
+#[cfg(any(test, feature = "test-support"))]
+impl HookPayloadBuilder {
+    /// Starts a payload with placeholder envelope fields (`event_id`, `timestamp`,
+    /// `schema_version`, ...) for the given event.
+    pub fn new(xcodex_event_type: impl Into<String>, hook_event_name: impl Into<String>) -> Self {
+        Self {
+            payload: HookPayload {
+"#,
+    );
+    for (field_name, rust_ty) in fields {
+        let default = match (field_name.as_str(), rust_ty.as_str()) {
+            ("xcodex_event_type" | "hook_event_name", _) => format!("{field_name}.into()"),
+            ("event_id", _) => "\"00000000-0000-0000-0000-000000000000\".to_string()".to_string(),
+            ("timestamp", _) => "\"1970-01-01T00:00:00Z\".to_string()".to_string(),
+            ("permission_mode", _) => "\"default\".to_string()".to_string(),
+            ("schema_version", _) => "1".to_string(),
+            (_, ty) if ty.starts_with("Option<") => "None".to_string(),
+            (_, "Value") => "Value::Null".to_string(),
+            _ => "Default::default()".to_string(),
+        };
+        writeln!(out, "                {field_name}: {default},")?;
+    }
+    out.push_str(
+        r#"                extra: ExtraFields::new(),
+            },
+        }
+    }
+"#,
+    );
+
+    for (field_name, rust_ty) in fields {
+        let (inner_ty, wrap) = match rust_ty
+            .strip_prefix("Option<")
+            .and_then(|ty| ty.strip_suffix('>'))
+        {
+            Some(inner_ty) => (inner_ty, true),
+            None => (rust_ty.as_str(), false),
+        };
+        let (param_ty, value) = if inner_ty == "String" {
+            ("impl Into<String>", format!("{field_name}.into()"))
+        } else {
+            (inner_ty, field_name.clone())
+        };
+        let value = if wrap {
+            format!("Some({value})")
+        } else {
+            value
+        };
+        writeln!(out)?;
+        // Match rustfmt, which wraps signatures past 100 columns.
+        let signature =
+            format!("    pub fn {field_name}(mut self, {field_name}: {param_ty}) -> Self {{");
+        if signature.len() > 100 {
+            writeln!(out, "    pub fn {field_name}(")?;
+            writeln!(out, "        mut self,")?;
+            writeln!(out, "        {field_name}: {param_ty},")?;
+            writeln!(out, "    ) -> Self {{")?;
+        } else {
+            writeln!(out, "{signature}")?;
+        }
+        writeln!(out, "        self.payload.{field_name} = {value};")?;
+        writeln!(out, "        self")?;
+        writeln!(out, "    }}")?;
+    }
+
+    out.push_str(
+        r#"
+    pub fn extra(mut self, key: impl Into<String>, value: Value) -> Self {
+        self.payload.extra.insert(key.into(), value);
+        self
+    }
+
+    pub fn build(self) -> HookPayload {
+        self.payload
+    }
+}
+"#,
+    );
+    Ok(())
+}
+
+/// Emits a Rust enum for a string-enum schema definition, keeping the wire values.
+#[cfg(feature = "hooks-schema")]
+fn write_string_enum(out: &mut String, name: &str, definition: &Value) -> std::fmt::Result {
+    writeln!(out)?;
+    if let Some(description) = definition.get("description").and_then(Value::as_str) {
+        for line in description.lines() {
+            writeln!(out, "/// {line}")?;
+        }
+    }
+    writeln!(
+        out,
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]"
+    )?;
+    writeln!(out, "pub enum {name} {{")?;
+    for value in string_enum_values(definition).unwrap_or_default() {
+        writeln!(out, "    #[serde(rename = \"{value}\")]")?;
+        writeln!(out, "    {},", pascal_case(value))?;
+    }
+    writeln!(out, "}}")
+}
+
+/// Values of a definition that is a plain string enum (`{"type": "string", "enum": [...]}`).
+#[cfg(feature = "hooks-schema")]
+fn string_enum_values(definition: &Value) -> Option<Vec<&str>> {
+    definition
+        .get("enum")?
+        .as_array()?
+        .iter()
+        .map(Value::as_str)
+        .collect()
+}
+
 #[cfg(feature = "hooks-schema")]
 fn schema_allows_null(schema: &Value) -> bool {
     if schema.get("type").and_then(Value::as_str) == Some("null") {
@@ -128,21 +268,40 @@ fn schema_allows_null(schema: &Value) -> bool {
 }
 
 #[cfg(feature = "hooks-schema")]
-fn rust_type_for_schema(schema: &Value) -> String {
-    if schema.get("$ref").is_some() {
+fn rust_type_for_schema(
+    schema: &Value,
+    definitions: &serde_json::Map<String, Value>,
+    enums: &mut BTreeSet<String>,
+) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        if let Some((_, name)) = reference.rsplit_once('/')
+            && let Some(definition) = definitions.get(name)
+            && string_enum_values(definition).is_some()
+        {
+            enums.insert(name.to_string());
+            return name.to_string();
+        }
+        return "Value".to_string();
+    }
+
+    if let Some(any_of) = schema.get("anyOf").and_then(Value::as_array) {
+        let non_null: Vec<&Value> = any_of.iter().filter(|s| !schema_allows_null(s)).collect();
+        if let [only] = non_null.as_slice() {
+            return rust_type_for_schema(only, definitions, enums);
+        }
         return "Value".to_string();
     }
 
     if let Some(ty) = schema.get("type") {
         if let Some(s) = ty.as_str() {
-            return rust_type_for_primitive(s, schema);
+            return rust_type_for_primitive(s, schema, definitions, enums);
         }
         if let Some(arr) = ty.as_array() {
             let mut types: Vec<String> = arr
                 .iter()
                 .filter_map(Value::as_str)
                 .filter(|t| *t != "null")
-                .map(|t| rust_type_for_primitive(t, schema))
+                .map(|t| rust_type_for_primitive(t, schema, definitions, enums))
                 .collect();
             types.sort();
             types.dedup();
@@ -161,7 +320,12 @@ fn rust_type_for_schema(schema: &Value) -> String {
 }
 
 #[cfg(feature = "hooks-schema")]
-fn rust_type_for_primitive(ty: &str, schema: &Value) -> String {
+fn rust_type_for_primitive(
+    ty: &str,
+    schema: &Value,
+    definitions: &serde_json::Map<String, Value>,
+    enums: &mut BTreeSet<String>,
+) -> String {
     match ty {
         "string" => "String".to_string(),
         "integer" => "u64".to_string(),
@@ -170,7 +334,7 @@ fn rust_type_for_primitive(ty: &str, schema: &Value) -> String {
         "array" => {
             let item_ty = schema
                 .get("items")
-                .map(rust_type_for_schema)
+                .map(|items| rust_type_for_schema(items, definitions, enums))
                 .unwrap_or_else(|| "Value".to_string());
             format!("Vec<{item_ty}>")
         }
@@ -191,3 +355,18 @@ fn snake_case(input: &str) -> String {
     }
     out
 }
+
+#[cfg(feature = "hooks-schema")]
+fn pascal_case(input: &str) -> String {
+    input
+        .split(['-', '_'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => format!("{}{}", first.to_ascii_uppercase(), chars.as_str()),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -41,6 +41,7 @@ use crate::truncate::TruncationPolicy;
 use crate::turn_metadata::build_turn_metadata_header;
 use crate::turn_metadata::resolve_turn_metadata_header_with_timeout;
 use crate::util::error_or_panic;
+use crate::xcodex::hooks::HookSessionStats;
 use crate::xcodex::hooks::UserHooks;
 use async_channel::Receiver;
 use async_channel::Sender;
@@ -945,9 +946,11 @@ async fn prompt_for_exclusion_match_selection(
         is_other: false,
         is_secret: false,
         options: Some(options),
+        ..Default::default()
     };
     let args = RequestUserInputArgs {
         questions: vec![question],
+        timeout_sec: None,
     };
     let response = sess
         .request_user_input(turn_context, turn_context.sub_id.clone(), args)
@@ -1055,9 +1058,11 @@ async fn maybe_prompt_for_exclusion_redaction(
             is_other: false,
             is_secret: false,
             options: Some(options),
+            ..Default::default()
         };
         let args = RequestUserInputArgs {
             questions: vec![question],
+            timeout_sec: None,
         };
 
         let response = sess
@@ -1713,7 +1718,8 @@ impl Session {
         }
         crate::xcodex::maybe_push_notify_deprecation(
             INITIAL_SUBMIT_ID,
-            &config,
+            &config.codex_home,
+            config.xcodex.notify.as_deref(),
             &mut post_session_configured_events,
         );
         for message in &config.startup_warnings {
@@ -1984,11 +1990,25 @@ impl Session {
         // record_initial_history can emit events. We record only after the SessionConfiguredEvent is emitted.
         sess.record_initial_history(initial_history).await;
 
-        sess.user_hooks().session_start(
-            sess.conversation_id.to_string(),
-            session_configuration.cwd.display().to_string(),
-            session_configuration.session_source.to_string(),
-        );
+        if sess.user_hooks().wants_session_start() {
+            let (git_branch, git_dirty, git_commit, git_remote) = tokio::join!(
+                crate::git_info::current_branch_name(&session_configuration.cwd),
+                crate::git_info::is_dirty(&session_configuration.cwd),
+                crate::git_info::head_sha(&session_configuration.cwd),
+                crate::git_info::primary_remote_url(&session_configuration.cwd),
+            );
+            sess.user_hooks()
+                .session_start(
+                    sess.conversation_id.to_string(),
+                    session_configuration.cwd.display().to_string(),
+                    session_configuration.session_source.to_string(),
+                    git_branch,
+                    git_dirty,
+                    git_commit,
+                    git_remote,
+                )
+                .await;
+        }
         memories::start_memories_startup_task(
             &sess,
             Arc::clone(&config),
@@ -2053,6 +2073,11 @@ impl Session {
         state.get_total_token_usage(state.server_reasoning_included())
     }
 
+    pub(crate) async fn record_turn_completed(&self) -> HookSessionStats {
+        let mut state = self.state.lock().await;
+        state.record_turn_completed()
+    }
+
     pub(crate) async fn get_total_token_usage_breakdown(&self) -> TotalTokenUsageBreakdown {
         let state = self.state.lock().await;
         state.history.get_total_token_usage_breakdown()
@@ -2912,6 +2937,7 @@ impl Session {
             call_id,
             turn_id: turn_context.sub_id.clone(),
             questions: args.questions,
+            timeout_sec: args.timeout_sec,
         });
         self.send_event(turn_context, event).await;
         rx_response.await.ok()
@@ -4781,11 +4807,6 @@ mod handlers {
 
     pub async fn compact(sess: &Arc<Session>, sub_id: String) {
         let turn_context = sess.new_default_turn_with_sub_id(sub_id).await;
-        sess.user_hooks().pre_compact(
-            sess.conversation_id.to_string(),
-            turn_context.cwd.display().to_string(),
-            "manual".to_string(),
-        );
 
         sess.spawn_task(
             Arc::clone(&turn_context),
@@ -5402,6 +5423,7 @@ pub(crate) async fn run_turn(
     // one instance across retries within this turn.
     let mut client_session =
         prewarmed_client_session.unwrap_or_else(|| sess.services.model_client.new_session());
+    let mut model_requests = 0usize;
 
     loop {
         // Note that pending_input would be something like a message the user
@@ -5467,6 +5489,7 @@ pub(crate) async fn run_turn(
                     needs_follow_up,
                     last_agent_message: sampling_request_last_agent_message,
                 } = sampling_request_output;
+                model_requests += 1;
                 let total_usage_tokens = sess.get_total_token_usage().await;
                 let token_limit_reached = total_usage_tokens >= auto_compact_limit;
 
@@ -5483,6 +5506,40 @@ pub(crate) async fn run_turn(
                     "post sampling token usage"
                 );
 
+                // Checked before auto-compaction so a compacting follow-up cannot exceed the cap.
+                if needs_follow_up
+                    && let Some(limit) = turn_context.config.max_model_requests_per_turn
+                    && model_requests >= limit.get()
+                {
+                    let event = EventMsg::Error(ErrorEvent {
+                        message: format!(
+                            "Turn limit reached ({limit}); stopping before the agent finished."
+                        ),
+                        codex_error_info: Some(CodexErrorInfo::Other),
+                    });
+                    sess.send_event(&turn_context, event).await;
+                    break;
+                }
+                if needs_follow_up && let Some(budget) = turn_context.config.token_budget {
+                    let used = sess
+                        .state
+                        .lock()
+                        .await
+                        .token_info()
+                        .map(|info| info.total_token_usage.total_tokens)
+                        .unwrap_or_default();
+                    if u64::try_from(used).unwrap_or_default() > budget {
+                        let event = EventMsg::Error(ErrorEvent {
+                            message: format!(
+                                "Token budget of {budget} exceeded; stopping before the agent finished."
+                            ),
+                            codex_error_info: Some(CodexErrorInfo::Other),
+                        });
+                        sess.send_event(&turn_context, event).await;
+                        break;
+                    }
+                }
+
                 // as long as compaction works well in getting us way below the token limit, we shouldn't worry about being in an infinite loop.
                 if token_limit_reached && needs_follow_up && sess.auto_compact_enabled().await {
                     if run_auto_compact(&sess, &turn_context).await.is_err() {
@@ -5493,12 +5550,14 @@ pub(crate) async fn run_turn(
 
                 if !needs_follow_up {
                     last_agent_message = sampling_request_last_agent_message;
+                    let session_stats = sess.record_turn_completed().await;
                     sess.user_hooks().agent_turn_complete(
                         sess.conversation_id.to_string(),
                         turn_context.sub_id.clone(),
                         turn_context.cwd.display().to_string(),
                         sampling_request_input_messages.clone(),
                         last_agent_message.clone(),
+                        session_stats,
                     );
 
                     sess.hooks()
@@ -5614,11 +5673,6 @@ async fn maybe_run_previous_model_inline_compact(
 }
 
 async fn run_auto_compact(sess: &Arc<Session>, turn_context: &Arc<TurnContext>) -> CodexResult<()> {
-    sess.user_hooks().pre_compact(
-        sess.conversation_id.to_string(),
-        turn_context.cwd.display().to_string(),
-        "auto".to_string(),
-    );
     if should_use_remote_compact_task(&turn_context.provider) {
         run_inline_remote_auto_compact_task(Arc::clone(sess), Arc::clone(turn_context)).await?;
     } else {
@@ -6544,6 +6598,7 @@ async fn try_run_sampling_request(
 
     sess.persist_rollout_items(&[rollout_item]).await;
     let model_request_id = Uuid::new_v4();
+    let rate_limits = sess.state.lock().await.token_info_and_rate_limits().1;
     sess.user_hooks().model_request_started(
         sess.conversation_id.to_string(),
         turn_context.sub_id.clone(),
@@ -6556,6 +6611,7 @@ async fn try_run_sampling_request(
         prompt.tools.len(),
         prompt.parallel_tool_calls,
         prompt.output_schema.is_some(),
+        rate_limits.as_ref(),
     );
     let prompt_to_send = if turn_context.exclusion.enabled
         && turn_context.exclusion.layer_request_interceptor_enabled()
@@ -6955,6 +7011,7 @@ async fn try_run_sampling_request(
             turn_context.cwd.display().to_string(),
             model_request_id,
             attempt,
+            turn_context.model_info.slug.clone(),
             response_id,
             token_usage,
             needs_follow_up,
@@ -44,6 +44,7 @@ pub(crate) async fn run_codex_thread_interactive(
     parent_ctx: Arc<TurnContext>,
     cancel_token: CancellationToken,
     initial_history: Option<InitialHistory>,
+    subagent_source: SubAgentSource,
 ) -> Result<Codex, CodexErr> {
     let (tx_sub, rx_sub) = async_channel::bounded(SUBMISSION_CHANNEL_CAPACITY);
     let (tx_ops, rx_ops) = async_channel::bounded(SUBMISSION_CHANNEL_CAPACITY);
@@ -55,7 +56,7 @@ pub(crate) async fn run_codex_thread_interactive(
         Arc::clone(&parent_session.services.skills_manager),
         Arc::clone(&parent_session.services.file_watcher),
         initial_history.unwrap_or(InitialHistory::New),
-        SessionSource::SubAgent(SubAgentSource::Review),
+        SessionSource::SubAgent(subagent_source),
         parent_session.services.agent_control.clone(),
         Vec::new(),
         false,
@@ -110,12 +111,14 @@ pub(crate) async fn run_codex_thread_one_shot(
     parent_ctx: Arc<TurnContext>,
     cancel_token: CancellationToken,
     initial_history: Option<InitialHistory>,
+    subagent_source: SubAgentSource,
 ) -> Result<Codex, CodexErr> {
     // Use a child token so we can stop the delegate after completion without
     // requiring the caller to cancel the parent token.
     let child_cancel = cancel_token.child_token();
     let parent_session_for_hooks = Arc::clone(&parent_session);
     let parent_ctx_for_hooks = Arc::clone(&parent_ctx);
+    let subagent_name = subagent_source.to_string();
     let io = run_codex_thread_interactive(
         config,
         auth_manager,
@@ -124,6 +127,7 @@ pub(crate) async fn run_codex_thread_one_shot(
         parent_ctx,
         child_cancel.clone(),
         initial_history,
+        subagent_source,
     )
     .await?;
 
@@ -139,6 +143,7 @@ pub(crate) async fn run_codex_thread_one_shot(
     let ops_tx = io.tx_sub.clone();
     let agent_status = io.agent_status.clone();
     let session = Arc::clone(&io.session);
+    let subagent_id = session.thread_id();
     let io_for_bridge = io;
     let child_cancel_for_bridge = child_cancel.clone();
     tokio::spawn(async move {
@@ -154,13 +159,20 @@ pub(crate) async fn run_codex_thread_one_shot(
             } else {
                 "other"
             };
+            let result_summary = match &event.msg {
+                EventMsg::TurnComplete(turn_complete) => turn_complete.last_agent_message.clone(),
+                _ => None,
+            };
             let _ = tx_bridge.send(event).await;
             if should_shutdown {
                 parent_session_for_hooks.user_hooks().subagent_stop(
                     parent_session_for_hooks.thread_id(),
                     parent_ctx_for_hooks.cwd.display().to_string(),
                     "review".to_string(),
+                    subagent_id.clone(),
+                    subagent_name.clone(),
                     status.to_string(),
+                    result_summary,
                 );
                 let _ = ops_tx
                     .send(Submission {
@@ -402,6 +414,7 @@ async fn handle_request_user_input(
 ) {
     let args = RequestUserInputArgs {
         questions: event.questions,
+        timeout_sec: event.timeout_sec,
     };
     let response_fut =
         parent_session.request_user_input(parent_ctx, parent_ctx.sub_id.clone(), args);
@@ -6,6 +6,8 @@ use crate::features::Feature;
 use crate::protocol::Event;
 use crate::protocol::Op;
 use crate::protocol::Submission;
+use crate::xcodex::hooks::HookMetrics;
+use crate::xcodex::hooks::HookNotification;
 use codex_protocol::config_types::Personality;
 use codex_protocol::openai_models::ReasoningEffort;
 use codex_protocol::protocol::AskForApproval;
@@ -88,4 +90,25 @@ impl CodexThread {
     pub fn enabled(&self, feature: Feature) -> bool {
         self.codex.enabled(feature)
     }
+
+    /// Snapshot of hook dispatch counters for this thread's session.
+    pub fn hook_metrics(&self) -> HookMetrics {
+        self.codex.session.user_hooks().metrics()
+    }
+
+    /// Dispatch a synthetic hook event built from the camelCase `event_type` and `payload`
+    /// through this thread's hooks, returning the metrics recorded for that dispatch alone.
+    pub async fn emit_test_hook_event(
+        &self,
+        event_type: &str,
+        payload: serde_json::Value,
+    ) -> Result<HookMetrics, String> {
+        let notification = HookNotification::from_camel_case_payload(event_type, payload)?;
+        Ok(self
+            .codex
+            .session
+            .user_hooks()
+            .emit_test_event(notification)
+            .await)
+    }
 }
@@ -7,6 +7,7 @@ use crate::client_common::ResponseEvent;
 use crate::codex::Session;
 use crate::codex::TurnContext;
 use crate::codex::get_last_assistant_message_from_turn;
+use crate::context_manager::ContextManager;
 use crate::error::CodexErr;
 use crate::error::Result as CodexResult;
 use crate::protocol::CompactedItem;
@@ -46,7 +47,7 @@ pub(crate) async fn run_inline_auto_compact_task(
         text_elements: Vec::new(),
     }];
 
-    run_compact_task_inner(sess, turn_context, input).await?;
+    run_compact_task_inner(sess, turn_context, input, "auto").await?;
     Ok(())
 }
 
@@ -61,13 +62,14 @@ pub(crate) async fn run_compact_task(
         collaboration_mode_kind: turn_context.collaboration_mode.mode,
     });
     sess.send_event(&turn_context, start_event).await;
-    run_compact_task_inner(sess.clone(), turn_context, input).await
+    run_compact_task_inner(sess.clone(), turn_context, input, "manual").await
 }
 
 async fn run_compact_task_inner(
     sess: Arc<Session>,
     turn_context: Arc<TurnContext>,
     input: Vec<UserInput>,
+    trigger: &str,
 ) -> CodexResult<()> {
     let compaction_item = TurnItem::ContextCompaction(ContextCompactionItem::new());
     sess.emit_turn_item_started(&turn_context, &compaction_item)
@@ -75,11 +77,29 @@ async fn run_compact_task_inner(
     let initial_input_for_turn: ResponseInputItem = ResponseInputItem::from(input);
 
     let mut history = sess.clone_history().await;
+    let previous_history = history.clone();
     history.record_items(
         &[initial_input_for_turn.into()],
         turn_context.truncation_policy,
     );
 
+    // Report the compaction before asking the model for a summary so hooks see it even when
+    // the summarization request fails. The summary is not known yet, so the projected history
+    // only holds what compaction keeps around it.
+    let initial_context = sess.build_initial_context(turn_context.as_ref()).await;
+    let projected_history = build_compacted_history(
+        initial_context.clone(),
+        &collect_user_messages(previous_history.raw_items()),
+        "",
+    );
+    notify_pre_compact_hooks(
+        &sess,
+        &turn_context,
+        trigger,
+        &previous_history,
+        Some(&projected_history),
+    );
+
     let mut truncated_count = 0usize;
 
     let max_retries = turn_context.provider.stream_max_retries();
@@ -180,7 +200,6 @@ async fn run_compact_task_inner(
     let summary_text = format!("{SUMMARY_PREFIX}\n{summary_suffix}");
     let user_messages = collect_user_messages(history_items);
 
-    let initial_context = sess.build_initial_context(turn_context.as_ref()).await;
     let mut new_history = build_compacted_history(initial_context, &user_messages, &summary_text);
     let ghost_snapshots: Vec<ResponseItem> = history_items
         .iter()
@@ -206,6 +225,49 @@ async fn run_compact_task_inner(
     Ok(())
 }
 
+/// Reports an upcoming compaction of `previous_history` to `PreCompact` hooks with its estimated
+/// token count. When the history compaction will keep is known ahead of the summarization
+/// request, `projected_history` also yields the estimated tokens left and messages dropped.
+pub(crate) fn notify_pre_compact_hooks(
+    sess: &Session,
+    turn_context: &TurnContext,
+    trigger: &str,
+    previous_history: &ContextManager,
+    projected_history: Option<&[ResponseItem]>,
+) {
+    let estimate_tokens = |history: &ContextManager| {
+        history
+            .estimate_token_count(turn_context)
+            .and_then(|tokens| u64::try_from(tokens).ok())
+            .unwrap_or_default()
+    };
+    let count_messages = |items: &[ResponseItem]| {
+        items
+            .iter()
+            .filter(|item| matches!(item, ResponseItem::Message { .. }))
+            .count()
+    };
+    let tokens_after = projected_history.map(|items| {
+        let mut compacted_history = ContextManager::new();
+        compacted_history.replace(items.to_vec());
+        estimate_tokens(&compacted_history)
+    });
+    let messages_dropped = projected_history.map(|items| {
+        let dropped =
+            count_messages(previous_history.raw_items()).saturating_sub(count_messages(items));
+        u64::try_from(dropped).unwrap_or(u64::MAX)
+    });
+
+    sess.user_hooks().pre_compact(
+        sess.conversation_id.to_string(),
+        turn_context.cwd.display().to_string(),
+        trigger.to_string(),
+        estimate_tokens(previous_history),
+        tokens_after,
+        messages_dropped,
+    );
+}
+
 pub fn content_items_to_text(content: &[ContentItem]) -> Option<String> {
     let mut pieces = Vec::new();
     for item in content {
@@ -651,6 +651,31 @@ fn normalize_skill_config_path(path: &Path) -> String {
         .to_string()
 }
 
+/// The `config.toml` contents before and after a batch of edits, exactly as
+/// [`apply_blocking`] would write them.
+pub struct ConfigEditsPreview {
+    pub original: String,
+    pub updated: String,
+    /// Whether any edit changed the document; unchanged documents are not written.
+    pub mutated: bool,
+    write_path: PathBuf,
+}
+
+impl ConfigEditsPreview {
+    /// Persist `updated` to the resolved config path (following symlinks).
+    pub fn write(&self) -> anyhow::Result<()> {
+        if !self.mutated {
+            return Ok(());
+        }
+        write_atomically(&self.write_path, &self.updated).with_context(|| {
+            format!(
+                "failed to persist config.toml at {}",
+                self.write_path.display()
+            )
+        })
+    }
+}
+
 /// Persist edits using a blocking strategy.
 pub fn apply_blocking(
     codex_home: &Path,
@@ -661,6 +686,15 @@ pub fn apply_blocking(
         return Ok(());
     }
 
+    preview_blocking(codex_home, profile, edits)?.write()
+}
+
+/// Apply edits to an in-memory copy of `config.toml` without writing it.
+pub fn preview_blocking(
+    codex_home: &Path,
+    profile: Option<&str>,
+    edits: &[ConfigEdit],
+) -> anyhow::Result<ConfigEditsPreview> {
     let config_path = codex_home.join(CONFIG_TOML_FILE);
     let write_paths = resolve_symlink_write_paths(&config_path)?;
     let serialized = match write_paths.read_path {
@@ -691,18 +725,12 @@ pub fn apply_blocking(
         mutated |= document.apply(edit)?;
     }
 
-    if !mutated {
-        return Ok(());
-    }
-
-    write_atomically(&write_paths.write_path, &document.doc.to_string()).with_context(|| {
-        format!(
-            "failed to persist config.toml at {}",
-            write_paths.write_path.display()
-        )
-    })?;
-
-    Ok(())
+    Ok(ConfigEditsPreview {
+        updated: document.doc.to_string(),
+        original: serialized,
+        mutated,
+        write_path: write_paths.write_path,
+    })
 }
 
 /// Persist edits asynchronously by offloading the blocking writer.
@@ -868,6 +896,11 @@ impl ConfigEditsBuilder {
         self
     }
 
+    /// Compute the edited document without writing it; see [`ConfigEditsPreview::write`].
+    pub fn preview_blocking(&self) -> anyhow::Result<ConfigEditsPreview> {
+        preview_blocking(&self.codex_home, self.profile.as_deref(), &self.edits)
+    }
+
     /// Apply edits on a blocking thread.
     pub fn apply_blocking(self) -> anyhow::Result<()> {
         apply_blocking(&self.codex_home, self.profile.as_deref(), &self.edits)
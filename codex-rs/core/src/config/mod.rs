@@ -77,6 +77,7 @@ use similar::DiffableStr;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::io::ErrorKind;
+use std::num::NonZeroUsize;
 use std::path::Path;
 use std::path::PathBuf;
 #[cfg(test)]
@@ -103,6 +104,8 @@ pub use service::ConfigServiceError;
 
 pub use codex_git::GhostSnapshotConfig;
 
+pub use crate::xcodex::config::XCODEX_DEFAULT_CONFIG_TOML_STUB;
+
 /// Maximum number of bytes of the documentation that will be embedded. Larger
 /// files are *silently truncated* to this size so we do not take up too much of
 /// the context window.
@@ -436,6 +439,14 @@ pub struct Config {
     /// When true, session is not persisted on disk. Default to `false`
     pub ephemeral: bool,
 
+    /// Maximum number of model requests a single turn may make before it is stopped. `None`
+    /// (the default) means no limit; `codex exec --max-turns` sets it.
+    pub max_model_requests_per_turn: Option<NonZeroUsize>,
+
+    /// Cumulative token usage above which a turn stops instead of sending another model
+    /// request. `None` (the default) means no budget; `codex exec --budget-tokens` sets it.
+    pub token_budget: Option<u64>,
+
     /// Optional URI-based file opener. If set, citations to files in the model
     /// output will be hyperlinked using the specified URI scheme.
     pub file_opener: UriBasedFileOpener,
@@ -1599,6 +1610,8 @@ pub struct ConfigOverrides {
     pub show_raw_agent_reasoning: Option<bool>,
     pub tools_web_search_request: Option<bool>,
     pub ephemeral: Option<bool>,
+    pub max_model_requests_per_turn: Option<NonZeroUsize>,
+    pub token_budget: Option<u64>,
     /// Additional directories that should be treated as writable roots for this session.
     pub additional_writable_roots: Vec<PathBuf>,
 }
@@ -1700,6 +1713,12 @@ pub struct HooksConfig {
     #[serde(default)]
     pub host: HookHostConfig,
 
+    /// Built-in webhook provider that `POST`s each hook payload as JSON to a URL.
+    ///
+    /// Disabled while `url` is unset or empty. Payloads are sanitized like every other provider's.
+    #[serde(default)]
+    pub webhook: HookWebhookConfig,
+
     /// Maximum payload size (in bytes) to send directly via stdin.
     ///
     /// When the serialized payload exceeds this threshold, Codex writes it to a
@@ -1708,9 +1727,75 @@ pub struct HooksConfig {
     #[serde(default = "HooksConfig::default_max_stdin_payload_bytes")]
     pub max_stdin_payload_bytes: usize,
 
+    /// How to deliver payloads larger than `max_stdin_payload_bytes`.
+    ///
+    /// Defaults to `spill`, which writes the payload to a file and sends a `payload_path`
+    /// envelope on stdin.
+    #[serde(default)]
+    pub oversized_payload_strategy: HookOversizedPayloadStrategy,
+
+    /// Include at most this many bytes of tool output as `output_preview` in
+    /// `tool-call-finished` payloads.
+    ///
+    /// The preview is redacted by the hook payload sanitizer. Defaults to 0 (disabled).
+    #[serde(default)]
+    pub output_preview_bytes: usize,
+
     /// Keep only the most recent N payload/log files (global) under CODEX_HOME.
     #[serde(default = "HooksConfig::default_keep_last_n_payloads")]
     pub keep_last_n_payloads: usize,
+
+    /// Event keys whose hooks are temporarily disabled.
+    ///
+    /// Accepts the same event names as `hooks.command` (canonical keys or aliases). Listed events
+    /// are not dispatched to any hook provider, but their configured commands are kept.
+    #[serde(default)]
+    pub disabled_events: Vec<String>,
+
+    /// Maximum number of external hook commands that may run concurrently.
+    ///
+    /// Additional hook invocations wait for a slot. Must be at least 1.
+    #[serde(default = "HooksConfig::default_max_concurrent_commands")]
+    pub max_concurrent_commands: usize,
+
+    /// Drop an event when it is identical to the previous event of the same type emitted
+    /// within this many milliseconds.
+    ///
+    /// Useful when forwarding bursty tool-call events to rate-limited endpoints. 0 (the default)
+    /// disables deduplication.
+    #[serde(default)]
+    pub dedupe_window_ms: u64,
+
+    /// Wait up to this many milliseconds for bounded `session_start` command hooks to finish
+    /// before the session proceeds.
+    ///
+    /// Hooks still running when the budget elapses keep running in the background. 0 (the
+    /// default) dispatches `session_start` hooks without waiting.
+    #[serde(default)]
+    pub session_start_blocking_ms: u64,
+
+    /// Show the captured stdout/stderr of bounded command hooks in the UI when they exit.
+    ///
+    /// Output is capped in size and still written to the hook log files. Detached hooks are not
+    /// echoed.
+    #[serde(default)]
+    pub echo_to_ui: bool,
+
+    /// Order in which hook providers receive each event: any of `inproc`, `host`, `webhook`,
+    /// `external`.
+    ///
+    /// Providers left out keep their default relative order (`inproc`, `host`, `webhook`,
+    /// `external`) after the listed ones. Unknown names are rejected when the config is loaded.
+    #[serde(default)]
+    pub provider_order: Vec<String>,
+
+    /// Log the external commands and in-process hooks each event would run instead of running
+    /// them.
+    ///
+    /// External command payloads are still written under `CODEX_HOME/tmp/hooks/payloads/` so
+    /// they can be inspected. The hook host is unaffected.
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
@@ -1782,6 +1867,21 @@ pub struct HookHostConfig {
     #[serde(default)]
     pub timeout_sec: Option<u64>,
 
+    /// Optional readiness timeout for the host process (seconds).
+    ///
+    /// When set, xcodex sends `{"type":"ping"}` after spawning the host and waits up to this long
+    /// for a `{"type":"pong"}` line on the host stdout before delivering queued events. If the
+    /// host does not answer in time, the hook host circuit breaker opens.
+    #[serde(default)]
+    pub ready_timeout_sec: Option<u64>,
+
+    /// Optional working directory for the host process.
+    ///
+    /// Relative paths are resolved against `CODEX_HOME`. When unset, the host runs in
+    /// `CODEX_HOME`. The host sandbox policy uses the same directory as its cwd.
+    #[serde(default)]
+    pub working_dir: Option<PathBuf>,
+
     /// Optional matcher filters to restrict which events are delivered to the hook host.
     ///
     /// When unset/empty, all events are delivered.
@@ -1789,6 +1889,39 @@ pub struct HookHostConfig {
     pub filters: HookEventFiltersConfig,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct HookWebhookConfig {
+    /// URL that receives each hook payload as the JSON body of a `POST` request.
+    ///
+    /// Example:
+    ///
+    /// ```toml
+    /// [hooks.webhook]
+    /// url = "https://example.com/xcodex-events"
+    /// headers = { Authorization = "Bearer TOKEN" }
+    /// events = ["agent_turn_complete", "approval_requested"]
+    /// ```
+    #[serde(default)]
+    pub url: Option<String>,
+
+    /// Extra HTTP headers sent with every webhook request.
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+
+    /// Event keys to forward (same names as `hooks.command`).
+    ///
+    /// When empty, every event is forwarded. Unknown names are a config error.
+    #[serde(default)]
+    pub events: Vec<String>,
+
+    /// Optional timeout for a single webhook request (seconds).
+    ///
+    /// When unset, requests time out after 5 seconds.
+    #[serde(default)]
+    pub timeout_sec: Option<u64>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
 #[schemars(deny_unknown_fields)]
 pub struct HooksCommandConfig {
@@ -1796,6 +1929,23 @@ pub struct HooksCommandConfig {
     #[serde(default = "HooksCommandConfig::default_timeout_sec")]
     pub default_timeout_sec: u64,
 
+    /// Number of times to retry a command hook that exits non-zero, fails to spawn, or times out.
+    ///
+    /// Detached hooks are never retried. Defaults to 0 (no retries).
+    #[serde(default)]
+    pub retries: u32,
+
+    /// Delay before the first retry (milliseconds); doubles after each further retry, up to 30s.
+    #[serde(default = "HooksCommandConfig::default_retry_backoff_ms")]
+    pub retry_backoff_ms: u64,
+
+    /// Shell argv used to run `command` string hooks, e.g. `["sh", "-c"]` or
+    /// `["pwsh", "-Command"]`. The hook command string is appended as the final argument.
+    ///
+    /// When empty, uses `bash -lc` (or `cmd.exe /C` on Windows).
+    #[serde(default)]
+    pub shell: Vec<String>,
+
     /// Per-event matcher entries.
     ///
     /// Keys are event names (canonical xcodex event keys or aliases like `PostToolUse`).
@@ -1808,12 +1958,19 @@ impl HooksCommandConfig {
     fn default_timeout_sec() -> u64 {
         30
     }
+
+    fn default_retry_backoff_ms() -> u64 {
+        500
+    }
 }
 
 impl Default for HooksCommandConfig {
     fn default() -> Self {
         Self {
             default_timeout_sec: Self::default_timeout_sec(),
+            retries: 0,
+            retry_backoff_ms: Self::default_retry_backoff_ms(),
+            shell: Vec::new(),
             events: HashMap::new(),
         }
     }
@@ -1844,13 +2001,36 @@ pub struct HooksCommandHookConfig {
     #[serde(default)]
     pub argv: Option<Vec<String>>,
 
-    /// Execute a command string via a shell wrapper (`bash -lc ...` / `cmd.exe /C ...`).
+    /// Execute a command string via a shell wrapper (`hooks.command.shell`, defaulting to
+    /// `bash -lc ...` / `cmd.exe /C ...`).
     #[serde(default)]
     pub command: Option<String>,
 
     /// Optional timeout for this hook (seconds). When unset, uses `hooks.command.default_timeout_sec`.
     #[serde(default)]
     pub timeout_sec: Option<u64>,
+
+    /// Run this hook fully detached so it can outlive the xcodex process.
+    ///
+    /// Detached hooks are not bounded by `timeout_sec` or `hooks.max_concurrent_commands`
+    /// and do not report hook process events. When unset, `session_end` hooks run
+    /// detached and all other events run bounded.
+    #[serde(default)]
+    pub detached: Option<bool>,
+}
+
+/// Delivery strategy for hook payloads that exceed `hooks.max_stdin_payload_bytes`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HookOversizedPayloadStrategy {
+    /// Shorten the longest string values until the payload fits and mark it with
+    /// `"truncated": true`.
+    Truncate,
+    /// Write the payload to a file under CODEX_HOME and send a `payload_path` envelope.
+    #[default]
+    Spill,
+    /// Skip hook commands for the oversized event.
+    Drop,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
@@ -1889,6 +2069,10 @@ impl HooksConfig {
     fn default_keep_last_n_payloads() -> usize {
         50
     }
+
+    fn default_max_concurrent_commands() -> usize {
+        8
+    }
 }
 
 impl Default for HooksConfig {
@@ -1912,8 +2096,18 @@ impl Default for HooksConfig {
             enable_unsafe_inproc: false,
             pyo3: HooksPyo3Config::default(),
             host: HookHostConfig::default(),
+            webhook: HookWebhookConfig::default(),
             max_stdin_payload_bytes: Self::default_max_stdin_payload_bytes(),
+            oversized_payload_strategy: HookOversizedPayloadStrategy::default(),
+            output_preview_bytes: 0,
             keep_last_n_payloads: Self::default_keep_last_n_payloads(),
+            disabled_events: Vec::new(),
+            max_concurrent_commands: Self::default_max_concurrent_commands(),
+            dedupe_window_ms: 0,
+            session_start_blocking_ms: 0,
+            echo_to_ui: false,
+            provider_order: Vec::new(),
+            dry_run: false,
         }
     }
 }
@@ -2046,6 +2240,8 @@ impl Config {
             show_raw_agent_reasoning,
             tools_web_search_request: override_tools_web_search_request,
             ephemeral,
+            max_model_requests_per_turn,
+            token_budget,
             additional_writable_roots,
         } = overrides;
 
@@ -2204,6 +2400,14 @@ impl Config {
                 "agents.max_threads must be at least 1",
             ));
         }
+        if cfg.hooks.max_concurrent_commands == 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "hooks.max_concurrent_commands must be at least 1",
+            ));
+        }
+        crate::xcodex::config::validate_hooks_config(&cfg.hooks)?;
+        let hooks = crate::xcodex::config::expand_hooks_env_vars(cfg.hooks, &codex_home)?;
 
         let ghost_snapshot = {
             let mut config = GhostSnapshotConfig::default();
@@ -2418,6 +2622,8 @@ impl Config {
             config_layer_stack,
             history,
             ephemeral: ephemeral.unwrap_or_default(),
+            max_model_requests_per_turn,
+            token_budget,
             file_opener: cfg.file_opener.unwrap_or(UriBasedFileOpener::VsCode),
             codex_linux_sandbox_exe,
             js_repl_node_path,
@@ -2482,7 +2688,7 @@ impl Config {
             show_tooltips: cfg.tui.as_ref().map(|t| t.show_tooltips).unwrap_or(true),
             xcodex: crate::xcodex::config::XcodexRuntimeConfig::from_toml(
                 cfg.notify,
-                cfg.hooks,
+                hooks,
                 cfg.plan.as_ref(),
                 cfg.tui.as_ref(),
                 cfg.themes,
@@ -2684,6 +2890,15 @@ pub fn xcodex_first_run_wizard_marker_path(codex_home: &Path) -> PathBuf {
 pub fn should_run_xcodex_first_run_wizard(codex_home: &Path) -> std::io::Result<bool> {
     crate::xcodex::config::should_run_xcodex_first_run_wizard(codex_home)
 }
+
+pub fn mark_xcodex_first_run_complete(codex_home: &Path) -> std::io::Result<()> {
+    crate::xcodex::config::mark_xcodex_first_run_complete(codex_home)
+}
+
+pub fn initialize_xcodex_home(codex_home: &Path) -> std::io::Result<()> {
+    crate::xcodex::config::initialize_xcodex_home(codex_home)
+}
+
 pub(crate) fn uses_deprecated_instructions_file(config_layer_stack: &ConfigLayerStack) -> bool {
     config_layer_stack
         .layers_high_to_low()
@@ -4940,6 +5155,8 @@ model_verbosity = "high"
                 startup_warnings: Vec::new(),
                 history: History::default(),
                 ephemeral: false,
+                max_model_requests_per_turn: None,
+                token_budget: None,
                 file_opener: UriBasedFileOpener::VsCode,
                 codex_linux_sandbox_exe: None,
                 hide_agent_reasoning: true,
@@ -5073,6 +5290,8 @@ model_verbosity = "high"
             startup_warnings: Vec::new(),
             history: History::default(),
             ephemeral: false,
+            max_model_requests_per_turn: None,
+            token_budget: None,
             file_opener: UriBasedFileOpener::VsCode,
             codex_linux_sandbox_exe: None,
             hide_agent_reasoning: true,
@@ -5204,6 +5423,8 @@ model_verbosity = "high"
             startup_warnings: Vec::new(),
             history: History::default(),
             ephemeral: false,
+            max_model_requests_per_turn: None,
+            token_budget: None,
             file_opener: UriBasedFileOpener::VsCode,
             codex_linux_sandbox_exe: None,
             hide_agent_reasoning: true,
@@ -5321,6 +5542,8 @@ model_verbosity = "high"
             startup_warnings: Vec::new(),
             history: History::default(),
             ephemeral: false,
+            max_model_requests_per_turn: None,
+            token_budget: None,
             file_opener: UriBasedFileOpener::VsCode,
             codex_linux_sandbox_exe: None,
             hide_agent_reasoning: true,
@@ -5647,6 +5870,104 @@ trust_level = "trusted"
         Ok(())
     }
 
+    #[test]
+    fn test_load_config_rejects_zero_hook_concurrency() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        let cfg = ConfigToml {
+            hooks: HooksConfig {
+                max_concurrent_commands: 0,
+                ..HooksConfig::default()
+            },
+            ..Default::default()
+        };
+
+        let error = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )
+        .expect_err("zero hook concurrency should be rejected");
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(
+            error.to_string(),
+            "hooks.max_concurrent_commands must be at least 1"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_config_rejects_unknown_hook_provider_order() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        let cfg = ConfigToml {
+            hooks: HooksConfig {
+                provider_order: vec!["external".to_string(), "plugin".to_string()],
+                ..HooksConfig::default()
+            },
+            ..Default::default()
+        };
+
+        let error = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )
+        .expect_err("unknown hook providers should be rejected");
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(
+            error.to_string(),
+            "unknown provider `plugin` in hooks.provider_order (expected one of: inproc, host, webhook, external)"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_config_rejects_invalid_hook_webhook() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        let load_error = |webhook: HookWebhookConfig| {
+            let cfg = ConfigToml {
+                hooks: HooksConfig {
+                    webhook,
+                    ..HooksConfig::default()
+                },
+                ..Default::default()
+            };
+            Config::load_from_base_config_with_overrides(
+                cfg,
+                ConfigOverrides::default(),
+                codex_home.path().to_path_buf(),
+            )
+            .expect_err("invalid webhook settings should be rejected")
+            .to_string()
+        };
+
+        assert_eq!(
+            load_error(HookWebhookConfig {
+                url: Some("https://example.com/hooks".to_string()),
+                events: vec!["session_start".to_string(), "turn_done".to_string()],
+                ..HookWebhookConfig::default()
+            }),
+            "unknown event `turn_done` in hooks.webhook.events"
+        );
+        assert_eq!(
+            load_error(HookWebhookConfig {
+                url: Some("example.com/hooks".to_string()),
+                ..HookWebhookConfig::default()
+            }),
+            "invalid hooks.webhook.url `example.com/hooks`: relative URL without a base (expected an http(s) URL)"
+        );
+        assert_eq!(
+            load_error(HookWebhookConfig {
+                url: Some("ftp://example.com/hooks".to_string()),
+                ..HookWebhookConfig::default()
+            }),
+            "invalid hooks.webhook.url `ftp://example.com/hooks`: unsupported scheme `ftp` (expected an http(s) URL)"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_untrusted_project_gets_workspace_write_sandbox() -> anyhow::Result<()> {
         let config_with_untrusted = r#"
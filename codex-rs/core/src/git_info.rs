@@ -17,11 +17,14 @@ pub use crate::xcodex::git_info::GitHeadState;
 pub use crate::xcodex::git_info::GitWorktreeEntry;
 pub use crate::xcodex::git_info::SharedDirLinkMode;
 pub use crate::xcodex::git_info::SharedDirLinkOutcome;
+pub use crate::xcodex::git_info::head_sha;
 pub use crate::xcodex::git_info::init_git_worktree;
 pub use crate::xcodex::git_info::init_git_worktree_with_mode;
+pub use crate::xcodex::git_info::is_dirty;
 pub use crate::xcodex::git_info::link_worktree_shared_dir;
 pub use crate::xcodex::git_info::link_worktree_shared_dirs;
 pub use crate::xcodex::git_info::maybe_add_shared_dirs_to_git_info_exclude;
+pub use crate::xcodex::git_info::primary_remote_url;
 pub use crate::xcodex::git_info::read_git_head_state;
 pub use crate::xcodex::git_info::resolve_git_worktree_head;
 pub(crate) use crate::xcodex::git_info::resolve_worktree_pinned_path;
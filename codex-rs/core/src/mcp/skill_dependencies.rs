@@ -93,9 +93,11 @@ async fn should_install_mcp_dependencies(
                     .to_string(),
             },
         ]),
+        ..Default::default()
     };
     let args = RequestUserInputArgs {
         questions: vec![question],
+        timeout_sec: None,
     };
     let sub_id = &turn_context.sub_id;
     let call_id = format!("mcp-deps-{sub_id}");
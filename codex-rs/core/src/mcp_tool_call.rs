@@ -281,6 +281,7 @@ async fn maybe_request_mcp_tool_approval(
     );
     let args = RequestUserInputArgs {
         questions: vec![question],
+        timeout_sec: None,
     };
     let response = sess
         .request_user_input(turn_context, call_id.to_string(), args)
@@ -385,6 +386,7 @@ fn build_mcp_tool_approval_question(
         is_other: false,
         is_secret: false,
         options: Some(options),
+        ..Default::default()
     }
 }
 
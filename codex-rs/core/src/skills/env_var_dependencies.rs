@@ -118,6 +118,7 @@ pub(crate) async fn request_skill_dependencies(
                 is_other: false,
                 is_secret: true,
                 options: None,
+                ..Default::default()
             }
         })
         .collect::<Vec<_>>();
@@ -126,7 +127,10 @@ pub(crate) async fn request_skill_dependencies(
         return;
     }
 
-    let args = RequestUserInputArgs { questions };
+    let args = RequestUserInputArgs {
+        questions,
+        timeout_sec: None,
+    };
     let call_id = format!("skill-deps-{}", turn_context.sub_id);
     let response = sess
         .request_user_input(turn_context, call_id, args)
@@ -13,6 +13,7 @@ use crate::protocol::TokenUsage;
 use crate::protocol::TokenUsageInfo;
 use crate::tasks::RegularTask;
 use crate::truncate::TruncationPolicy;
+use crate::xcodex::hooks::HookSessionStats;
 
 /// Persistent, session-scoped state previously stored directly on `Session`.
 pub(crate) struct SessionState {
@@ -37,6 +38,7 @@ pub(crate) struct SessionState {
     pub(crate) startup_regular_task: Option<RegularTask>,
     pub(crate) active_mcp_tool_selection: Option<Vec<String>>,
     pub(crate) active_connector_selection: HashSet<String>,
+    turns_completed: u64,
 }
 
 impl SessionState {
@@ -59,6 +61,7 @@ impl SessionState {
             startup_regular_task: None,
             active_mcp_tool_selection: None,
             active_connector_selection: HashSet::new(),
+            turns_completed: 0,
         }
     }
 
@@ -132,6 +135,18 @@ impl SessionState {
             .set_token_usage_full(context_window, full_model_context_window);
     }
 
+    /// Counts a finished turn and returns the session totals reported to `agent-turn-complete` hooks.
+    pub(crate) fn record_turn_completed(&mut self) -> HookSessionStats {
+        self.turns_completed += 1;
+        HookSessionStats {
+            turns_completed: self.turns_completed,
+            total_tokens: self
+                .token_info()
+                .map(|info| info.total_token_usage.total_tokens)
+                .unwrap_or_default(),
+        }
+    }
+
     pub(crate) fn auto_compact_enabled(&self) -> bool {
         self.auto_compact_enabled
     }
@@ -270,6 +285,28 @@ mod tests {
     use crate::protocol::RateLimitWindow;
     use pretty_assertions::assert_eq;
 
+    #[tokio::test]
+    async fn record_turn_completed_reports_session_totals() {
+        let session_configuration = make_session_configuration_for_tests().await;
+        let mut state = SessionState::new(session_configuration);
+        let usage = TokenUsage {
+            total_tokens: 120,
+            ..TokenUsage::default()
+        };
+
+        state.update_token_info_from_usage(&usage, None, None);
+        state.record_turn_completed();
+        state.update_token_info_from_usage(&usage, None, None);
+
+        assert_eq!(
+            state.record_turn_completed(),
+            HookSessionStats {
+                turns_completed: 2,
+                total_tokens: 240,
+            }
+        );
+    }
+
     #[tokio::test]
     async fn merge_mcp_tool_selection_deduplicates_and_preserves_order() {
         let session_configuration = make_session_configuration_for_tests().await;
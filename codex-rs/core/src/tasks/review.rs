@@ -13,6 +13,7 @@ use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::ExitedReviewModeEvent;
 use codex_protocol::protocol::ItemCompletedEvent;
 use codex_protocol::protocol::ReviewOutputEvent;
+use codex_protocol::protocol::SubAgentSource;
 use tokio_util::sync::CancellationToken;
 
 use crate::codex::Session;
@@ -113,6 +114,7 @@ async fn start_review_conversation(
         ctx.clone(),
         cancellation_token,
         None,
+        SubAgentSource::Review,
     )
     .await)
         .ok()
@@ -170,9 +170,11 @@ async fn maybe_prompt_for_access(
                 description: "Keep exclusions blocking this path.".to_string(),
             },
         ]),
+        ..Default::default()
     };
     let args = RequestUserInputArgs {
         questions: vec![question],
+        timeout_sec: None,
     };
     let response = context
         .session
@@ -21,6 +21,7 @@ use crate::tools::context::ToolPayload;
 use crate::tools::router::ToolCall;
 use crate::tools::router::ToolRouter;
 use crate::xcodex::hooks::ToolCallStatus;
+use codex_apply_patch::Hunk;
 use codex_protocol::mcp::CallToolResult;
 use codex_protocol::models::FunctionCallOutputBody;
 use codex_protocol::models::FunctionCallOutputPayload;
@@ -71,6 +72,8 @@ impl ToolCallRuntime {
         let call_id = call.call_id.clone();
         let call_for_task = call.clone();
         let tool_input = tool_input_value(&call.payload);
+        let command = tool_call_command(&tool_name, tool_input.as_ref());
+        let paths = apply_patch_paths(&tool_name, tool_input.as_ref());
 
         let router = Arc::clone(&self.router);
         let session = Arc::clone(&self.session);
@@ -83,6 +86,7 @@ impl ToolCallRuntime {
         let thread_id = self.thread_id.clone();
         let model_request_id = self.model_request_id;
         let attempt = self.attempt;
+        let output_preview_bytes = hook_session.user_hooks().output_preview_bytes();
 
         hook_session.user_hooks().tool_call_started(
             thread_id.clone(),
@@ -93,6 +97,8 @@ impl ToolCallRuntime {
             tool_name.clone(),
             call_id.clone(),
             tool_input.clone(),
+            command,
+            paths,
         );
 
         let dispatch_span = trace_span!(
@@ -146,7 +152,7 @@ impl ToolCallRuntime {
             match &result {
                 Ok((status, response)) => {
                     let (mut success, output_bytes, output_preview) =
-                        summarize_tool_output(response, TOOL_OUTPUT_PREVIEW_BYTES);
+                        summarize_tool_output(response, output_preview_bytes);
                     if matches!(status, ToolCallStatus::Aborted) {
                         success = false;
                     }
@@ -178,7 +184,7 @@ impl ToolCallRuntime {
                 }
                 Err(message) => {
                     let message = message.to_string();
-                    let preview = truncate_preview(&message, TOOL_OUTPUT_PREVIEW_BYTES);
+                    let preview = bounded_preview(&message, output_preview_bytes);
                     let tool_response = Some(serde_json::json!({
                         "status": "completed",
                         "success": false,
@@ -197,7 +203,7 @@ impl ToolCallRuntime {
                         duration_ms,
                         false,
                         message.len(),
-                        Some(preview),
+                        preview,
                         tool_input,
                         tool_response,
                     );
@@ -213,8 +219,6 @@ impl ToolCallRuntime {
     }
 }
 
-const TOOL_OUTPUT_PREVIEW_BYTES: usize = 512;
-
 fn tool_input_value(payload: &ToolPayload) -> Option<Value> {
     match payload {
         ToolPayload::Function { arguments } => serde_json::from_str(arguments)
@@ -245,6 +249,64 @@ fn tool_input_value(payload: &ToolPayload) -> Option<Value> {
     }
 }
 
+/// Best-effort argv for exec-style tools. Tools that take a single script string report it as a
+/// one-element argv.
+fn tool_call_command(tool_name: &str, tool_input: Option<&Value>) -> Option<Vec<String>> {
+    if !matches!(
+        tool_name,
+        "shell" | "container.exec" | "local_shell" | "shell_command" | "exec_command"
+    ) {
+        return None;
+    }
+
+    let input = tool_input?.as_object()?;
+    match input.get("command").or_else(|| input.get("cmd"))? {
+        Value::Array(items) => items
+            .iter()
+            .map(|item| item.as_str().map(str::to_string))
+            .collect(),
+        Value::String(script) => Some(vec![script.clone()]),
+        _ => None,
+    }
+}
+
+/// Target paths of an `apply_patch` call, including move destinations.
+fn apply_patch_paths(tool_name: &str, tool_input: Option<&Value>) -> Option<Vec<String>> {
+    if tool_name != "apply_patch" {
+        return None;
+    }
+
+    let patch = match tool_input? {
+        Value::String(patch) => patch.as_str(),
+        Value::Object(input) => input.get("input").and_then(Value::as_str)?,
+        _ => return None,
+    };
+    let args = codex_apply_patch::parse_patch(patch).ok()?;
+    let mut paths = Vec::new();
+    for hunk in args.hunks {
+        match hunk {
+            Hunk::AddFile { path, .. } | Hunk::DeleteFile { path } => paths.push(path),
+            Hunk::UpdateFile {
+                path, move_path, ..
+            } => {
+                paths.push(path);
+                paths.extend(move_path);
+            }
+        }
+    }
+    Some(
+        paths
+            .into_iter()
+            .map(|path| path.display().to_string())
+            .collect(),
+    )
+}
+
+/// Returns `None` when previews are disabled (`max_bytes == 0`).
+fn bounded_preview(text: &str, max_bytes: usize) -> Option<String> {
+    (max_bytes > 0).then(|| truncate_preview(text, max_bytes))
+}
+
 fn truncate_preview(text: &str, max_bytes: usize) -> String {
     if text.len() <= max_bytes {
         return text.to_string();
@@ -270,8 +332,8 @@ fn summarize_mcp_tool_output(
     let Some(content) = payload.body.to_text() else {
         return (success, 0, None);
     };
-    let preview = truncate_preview(&content, max_preview_bytes);
-    (success, content.len(), Some(preview))
+    let preview = bounded_preview(&content, max_preview_bytes);
+    (success, content.len(), preview)
 }
 
 fn summarize_tool_output(
@@ -281,18 +343,18 @@ fn summarize_tool_output(
     match response {
         ResponseInputItem::FunctionCallOutput { output, .. } => {
             let content = output.body.to_text().unwrap_or_default();
-            let preview = truncate_preview(&content, max_preview_bytes);
-            (output.success.unwrap_or(true), content.len(), Some(preview))
+            let preview = bounded_preview(&content, max_preview_bytes);
+            (output.success.unwrap_or(true), content.len(), preview)
         }
         ResponseInputItem::CustomToolCallOutput { output, .. } => {
-            let preview = truncate_preview(output, max_preview_bytes);
-            (true, output.len(), Some(preview))
+            let preview = bounded_preview(output, max_preview_bytes);
+            (true, output.len(), preview)
         }
         ResponseInputItem::McpToolCallOutput { result, .. } => match result {
             Ok(call_result) => summarize_mcp_tool_output(call_result, max_preview_bytes),
             Err(message) => {
-                let preview = truncate_preview(message, max_preview_bytes);
-                (false, message.len(), Some(preview))
+                let preview = bounded_preview(message, max_preview_bytes);
+                (false, message.len(), preview)
             }
         },
         ResponseInputItem::Message { .. } => (true, 0, None),
@@ -335,6 +397,57 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn tool_call_command_reads_exec_argv() {
+        let input = serde_json::json!({ "command": ["bash", "-lc", "ls"], "workdir": "/tmp" });
+
+        assert_eq!(
+            tool_call_command("shell", Some(&input)),
+            Some(vec![
+                "bash".to_string(),
+                "-lc".to_string(),
+                "ls".to_string()
+            ])
+        );
+        assert_eq!(
+            tool_call_command("exec_command", Some(&serde_json::json!({ "cmd": "ls" }))),
+            Some(vec!["ls".to_string()])
+        );
+        assert_eq!(tool_call_command("read_file", Some(&input)), None);
+    }
+
+    #[test]
+    fn apply_patch_paths_lists_patch_targets() {
+        let patch = "*** Begin Patch\n*** Add File: new.txt\n+hello\n*** Update File: src/lib.rs\n*** Move to: src/main.rs\n@@\n-a\n+b\n*** Delete File: old.txt\n*** End Patch";
+
+        assert_eq!(
+            apply_patch_paths("apply_patch", Some(&Value::String(patch.to_string()))),
+            Some(vec![
+                "new.txt".to_string(),
+                "src/lib.rs".to_string(),
+                "src/main.rs".to_string(),
+                "old.txt".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn summarize_tool_output_bounds_preview_to_configured_bytes() {
+        let response = ResponseInputItem::FunctionCallOutput {
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload {
+                body: FunctionCallOutputBody::Text("y".repeat(4096)),
+                ..Default::default()
+            },
+        };
+
+        assert_eq!(
+            summarize_tool_output(&response, 64),
+            (true, 4096, Some("y".repeat(64)))
+        );
+        assert_eq!(summarize_tool_output(&response, 0), (true, 4096, None));
+    }
+
     #[test]
     fn summarize_mcp_tool_output_matches_combined_string_behavior() {
         let result = CallToolResult {
@@ -341,9 +341,11 @@ async fn maybe_prompt_for_send(
                 description: "Keep exclusions blocking this output.".to_string(),
             },
         ]),
+        ..Default::default()
     };
     let args = RequestUserInputArgs {
         questions: vec![question],
+        timeout_sec: None,
     };
     let response = session
         .request_user_input(turn, call_id.to_string(), args)
@@ -533,9 +535,11 @@ async fn prompt_for_redaction_match_selection(
         is_other: false,
         is_secret: false,
         options: Some(options),
+        ..Default::default()
     };
     let args = RequestUserInputArgs {
         questions: vec![question],
+        timeout_sec: None,
     };
     let response = session
         .request_user_input(turn, call_id.to_string(), args)
@@ -643,9 +647,11 @@ async fn maybe_prompt_for_redaction(
             is_other: false,
             is_secret: false,
             options: Some(options),
+            ..Default::default()
         };
         let args = RequestUserInputArgs {
             questions: vec![question],
+            timeout_sec: None,
         };
         let response = session
             .request_user_input(turn, call_id.to_string(), args)
@@ -711,6 +711,71 @@ fn create_request_user_input_tool() -> ToolSpec {
     );
     question_props.insert("options".to_string(), options_schema);
 
+    let mut condition_props = BTreeMap::new();
+    condition_props.insert(
+        "questionId".to_string(),
+        JsonSchema::String {
+            description: Some("Id of an earlier question in this request.".to_string()),
+        },
+    );
+    condition_props.insert(
+        "equals".to_string(),
+        JsonSchema::String {
+            description: Some("Option label that the earlier answer must match.".to_string()),
+        },
+    );
+    question_props.insert(
+        "showIf".to_string(),
+        JsonSchema::Object {
+            properties: condition_props,
+            required: Some(vec!["questionId".to_string(), "equals".to_string()]),
+            additional_properties: Some(false.into()),
+        },
+    );
+
+    question_props.insert(
+        "defaultOptionIndex".to_string(),
+        JsonSchema::Number {
+            description: Some(
+                "Zero-based index of the option that starts out selected, if any.".to_string(),
+            ),
+        },
+    );
+
+    let mut validation_props = BTreeMap::new();
+    validation_props.insert(
+        "pattern".to_string(),
+        JsonSchema::String {
+            description: Some("Regular expression a non-empty answer must match.".to_string()),
+        },
+    );
+    validation_props.insert(
+        "minLen".to_string(),
+        JsonSchema::Number {
+            description: Some("Minimum answer length in characters.".to_string()),
+        },
+    );
+    validation_props.insert(
+        "maxLen".to_string(),
+        JsonSchema::Number {
+            description: Some("Maximum answer length in characters.".to_string()),
+        },
+    );
+    validation_props.insert(
+        "errorMessage".to_string(),
+        JsonSchema::String {
+            description: Some("Message shown when the answer fails validation.".to_string()),
+        },
+    );
+    question_props.insert(
+        "validation".to_string(),
+        JsonSchema::Object {
+            properties: validation_props,
+            required: None,
+            additional_properties: Some(false.into()),
+        },
+    );
+
     let questions_schema = JsonSchema::Array {
         description: Some("Questions to show the user. Prefer 1 and do not exceed 3".to_string()),
         items: Box::new(JsonSchema::Object {
@@ -727,6 +792,15 @@ fn create_request_user_input_tool() -> ToolSpec {
 
     let mut properties = BTreeMap::new();
     properties.insert("questions".to_string(), questions_schema);
+    properties.insert(
+        "timeout_sec".to_string(),
+        JsonSchema::Number {
+            description: Some(
+                "Seconds to wait before the current answers (including default options) are submitted automatically. Omit to wait indefinitely."
+                    .to_string(),
+            ),
+        },
+    );
 
     ToolSpec::Function(ResponsesApiTool {
         name: "request_user_input".to_string(),
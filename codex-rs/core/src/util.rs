@@ -1,3 +1,5 @@
+use std::ffi::OsStr;
+use std::ffi::OsString;
 use std::path::Path;
 use std::path::PathBuf;
 use std::time::Duration;
@@ -85,6 +87,19 @@ pub fn normalize_thread_name(name: &str) -> Option<String> {
     }
 }
 
+/// Name to use for the command in exit resume hints, taken from how the binary was invoked
+/// (`argv[0]`) so renamed installs see a command they can run.
+pub fn resume_bin_name(arg0: Option<OsString>) -> String {
+    arg0.as_deref()
+        .map(Path::new)
+        .and_then(Path::file_name)
+        .and_then(OsStr::to_str)
+        .map(|name| name.strip_suffix(".exe").unwrap_or(name))
+        .filter(|name| !name.is_empty())
+        .unwrap_or("xcodex")
+        .to_string()
+}
+
 pub fn resume_command(thread_name: Option<&str>, thread_id: Option<ThreadId>) -> Option<String> {
     let resume_target = thread_name
         .filter(|name| !name.is_empty())
@@ -15,6 +15,9 @@ use serde::Serialize;
 const XCODEX_DEFAULT_HOME_DIRNAME: &str = ".xcodex";
 const XCODEX_EXE_STEM: &str = "xcodex";
 
+/// Contents written to `config.toml` when xcodex initializes a fresh home.
+pub const XCODEX_DEFAULT_CONFIG_TOML_STUB: &str = "# Generated by xcodex first-run setup wizard.\n# Set CODEX_HOME to explicitly choose a shared home.\n";
+
 /// How TUI2 should interpret mouse scroll events.
 ///
 /// Terminals generally encode both mouse wheels and trackpads as the same "scroll up/down" mouse
@@ -257,6 +260,214 @@ pub fn is_xcodex_invocation() -> bool {
     false
 }
 
+/// Rejects hook settings that would otherwise be silently ignored at run time.
+pub(crate) fn validate_hooks_config(hooks: &HooksConfig) -> std::io::Result<()> {
+    let known = crate::xcodex::hooks::HOOK_PROVIDER_ORDER_NAMES;
+    if let Some(name) = hooks
+        .provider_order
+        .iter()
+        .find(|name| !known.contains(&name.trim()))
+    {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "unknown provider `{name}` in hooks.provider_order (expected one of: {})",
+                known.join(", ")
+            ),
+        ));
+    }
+    if let Some(name) = hooks
+        .webhook
+        .events
+        .iter()
+        .find(|name| crate::xcodex::hooks::canonical_event_key(name).is_none())
+    {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("unknown event `{name}` in hooks.webhook.events"),
+        ));
+    }
+    if let Some(url) = hooks.webhook.url.as_deref().filter(|url| !url.is_empty()) {
+        let reason = match reqwest::Url::parse(url) {
+            Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => None,
+            Ok(parsed) => Some(format!("unsupported scheme `{}`", parsed.scheme())),
+            Err(err) => Some(err.to_string()),
+        };
+        if let Some(reason) = reason {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("invalid hooks.webhook.url `{url}`: {reason} (expected an http(s) URL)"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Script flags of shells whose scripts xcodex leaves for the shell itself to expand. Flags
+/// of the Windows shells are matched case-insensitively.
+const SHELL_SCRIPT_FLAGS: &[(&str, &[&str])] = &[
+    ("sh", &["-c", "-lc", "-ec"]),
+    ("bash", &["-c", "-lc", "-ic", "-ec"]),
+    ("zsh", &["-c", "-lc", "-ic", "-ec"]),
+    ("dash", &["-c", "-ec"]),
+    ("ksh", &["-c", "-lc", "-ec"]),
+    ("fish", &["-c", "--command"]),
+    ("pwsh", &["-c", "-command"]),
+    ("powershell", &["-c", "-command"]),
+    ("cmd", &["/c", "/k"]),
+];
+
+/// Expands `$VAR`, `${VAR}` and `${VAR:-default}` references in the argv-style hook fields:
+/// `hooks.<event>` legacy argv lists, `hooks.command.shell`, `hooks.command.<event>` hook
+/// `argv`, `hooks.host.command`, and `hooks.host.working_dir`.
+///
+/// When an argv runs a shell listed in [`SHELL_SCRIPT_FLAGS`], the entries after its script
+/// flag (`sh -c`, `bash -lc`, `pwsh -Command`) are left untouched so the shell expands them at
+/// run time; `command` string hooks are never expanded for the same reason. An undefined
+/// variable without a default is a config error naming the variable and the hook.
+/// `CODEX_HOME` falls back to the resolved home directory when it is not set in the
+/// environment.
+pub(crate) fn expand_hooks_env_vars(
+    mut hooks: HooksConfig,
+    codex_home: &Path,
+) -> std::io::Result<HooksConfig> {
+    let lookup = |name: &str| {
+        std::env::var(name)
+            .ok()
+            .or_else(|| (name == "CODEX_HOME").then(|| codex_home.display().to_string()))
+    };
+    let expand = |hook: &str, value: &mut String| {
+        *value = expand_env_vars(value, &lookup).map_err(|err| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("{hook}: {err}"))
+        })?;
+        Ok::<_, std::io::Error>(())
+    };
+    let expand_argv = |field: &str, argv: &mut Vec<String>| {
+        let Some((program, args)) = argv.split_first_mut() else {
+            return Ok(());
+        };
+        let hook = format!("{field} hook `{program}`");
+        expand(&hook, program)?;
+        let shell = Path::new(program.as_str())
+            .file_stem()
+            .and_then(OsStr::to_str)
+            .map(str::to_ascii_lowercase);
+        let script_flags = SHELL_SCRIPT_FLAGS
+            .iter()
+            .find(|(name, _)| shell.as_deref() == Some(*name))
+            .map(|(name, flags)| (*name, *flags));
+        for arg in args {
+            expand(&hook, arg)?;
+            let is_script_flag = script_flags.is_some_and(|(name, flags)| {
+                flags.iter().any(|flag| match name {
+                    "pwsh" | "powershell" | "cmd" => arg.eq_ignore_ascii_case(flag),
+                    _ => arg == flag,
+                })
+            });
+            if is_script_flag {
+                break;
+            }
+        }
+        Ok::<_, std::io::Error>(())
+    };
+
+    for (event, argvs) in [
+        ("agent_turn_complete", &mut hooks.agent_turn_complete),
+        ("approval_requested", &mut hooks.approval_requested),
+        ("session_start", &mut hooks.session_start),
+        ("session_end", &mut hooks.session_end),
+        ("user_prompt_submit", &mut hooks.user_prompt_submit),
+        ("pre_compact", &mut hooks.pre_compact),
+        ("notification", &mut hooks.notification),
+        ("subagent_stop", &mut hooks.subagent_stop),
+        ("model_request_started", &mut hooks.model_request_started),
+        (
+            "model_response_completed",
+            &mut hooks.model_response_completed,
+        ),
+        ("tool_call_started", &mut hooks.tool_call_started),
+        ("tool_call_finished", &mut hooks.tool_call_finished),
+    ] {
+        for argv in argvs {
+            expand_argv(&format!("hooks.{event}"), argv)?;
+        }
+    }
+    expand_argv("hooks.command.shell", &mut hooks.command.shell)?;
+    for (event, matchers) in &mut hooks.command.events {
+        for argv in matchers
+            .iter_mut()
+            .flat_map(|matcher| matcher.hooks.iter_mut())
+            .filter_map(|hook| hook.argv.as_mut())
+        {
+            expand_argv(&format!("hooks.command.{event}.argv"), argv)?;
+        }
+    }
+    expand_argv("hooks.host.command", &mut hooks.host.command)?;
+    if let Some(working_dir) = hooks.host.working_dir.as_mut()
+        && let Some(raw) = working_dir.to_str()
+    {
+        let mut expanded = raw.to_string();
+        expand("hooks.host.working_dir", &mut expanded)?;
+        *working_dir = PathBuf::from(expanded);
+    }
+    Ok(hooks)
+}
+
+/// Expands `$VAR`, `${VAR}` and `${VAR:-default}` in `value`. The default applies when the
+/// variable is unset or empty; an unset variable without a default is an error. `$$` yields a
+/// literal `$`, and a `$` not followed by a variable name (such as `$1`) is kept as-is.
+fn expand_env_vars(value: &str, lookup: &dyn Fn(&str) -> Option<String>) -> Result<String, String> {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let unset = |name: &str| {
+        format!(
+            "environment variable `{name}` is not set (use `${{{name}:-default}}` to provide a fallback)"
+        )
+    };
+
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(idx) = rest.find('$') {
+        out.push_str(&rest[..idx]);
+        rest = &rest[idx + 1..];
+
+        if let Some(after) = rest.strip_prefix('$') {
+            out.push('$');
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('{') {
+            let end = after
+                .find('}')
+                .ok_or_else(|| format!("unterminated `${{` in `{value}`"))?;
+            let (name, default) = match after[..end].split_once(":-") {
+                Some((name, default)) => (name, Some(default)),
+                None => (&after[..end], None),
+            };
+            if name.is_empty()
+                || name.starts_with(|c: char| c.is_ascii_digit())
+                || !name.chars().all(is_name_char)
+            {
+                return Err(format!("invalid variable name `{name}` in `{value}`"));
+            }
+            let resolved = match (lookup(name), default) {
+                (Some(resolved), Some(default)) if resolved.is_empty() => default.to_string(),
+                (Some(resolved), _) => resolved,
+                (None, Some(default)) => default.to_string(),
+                (None, None) => return Err(unset(name)),
+            };
+            out.push_str(&resolved);
+            rest = &after[end + 1..];
+        } else if rest.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            let end = rest.find(|c: char| !is_name_char(c)).unwrap_or(rest.len());
+            let name = &rest[..end];
+            out.push_str(&lookup(name).ok_or_else(|| unset(name))?);
+            rest = &rest[end..];
+        } else {
+            out.push('$');
+        }
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
 pub fn xcodex_first_run_wizard_marker_path(codex_home: &Path) -> PathBuf {
     codex_home.join(".xcodex-first-run-wizard.complete")
 }
@@ -281,6 +492,28 @@ pub fn should_run_xcodex_first_run_wizard(codex_home: &Path) -> std::io::Result<
     should_run_xcodex_first_run_wizard_impl(codex_home, is_xcodex_invocation())
 }
 
+/// Create `codex_home` if needed and write the first-run marker so later runs skip the wizard.
+pub fn mark_xcodex_first_run_complete(codex_home: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(codex_home)?;
+    let marker = xcodex_first_run_wizard_marker_path(codex_home);
+    if !marker.exists() {
+        std::fs::write(marker, "")?;
+    }
+    Ok(())
+}
+
+/// Initialize `codex_home` the way the wizard's "start from scratch" option does: create the
+/// directory, write a stub `config.toml` when none exists, and record the first-run marker.
+/// Existing files are left untouched, so running this again is a no-op.
+pub fn initialize_xcodex_home(codex_home: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(codex_home)?;
+    let config_toml = codex_home.join(CONFIG_TOML_FILE);
+    if !config_toml.exists() {
+        std::fs::write(config_toml, XCODEX_DEFAULT_CONFIG_TOML_STUB)?;
+    }
+    mark_xcodex_first_run_complete(codex_home)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -332,6 +565,157 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn mark_first_run_complete_creates_home_and_marker() -> std::io::Result<()> {
+        let dir = tempdir()?;
+        let codex_home = dir.path().join("nested").join(".xcodex");
+
+        mark_xcodex_first_run_complete(&codex_home)?;
+
+        assert_eq!(
+            true,
+            xcodex_first_run_wizard_marker_path(&codex_home).exists()
+        );
+        assert_eq!(
+            false,
+            should_run_xcodex_first_run_wizard_impl(&codex_home, true)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn initialize_xcodex_home_completes_first_run_without_overwriting() -> std::io::Result<()> {
+        let dir = tempdir()?;
+        let codex_home = dir.path().join(".xcodex");
+        assert_eq!(
+            true,
+            should_run_xcodex_first_run_wizard_impl(&codex_home, true)?
+        );
+
+        initialize_xcodex_home(&codex_home)?;
+        assert_eq!(
+            false,
+            should_run_xcodex_first_run_wizard_impl(&codex_home, true)?
+        );
+        assert_eq!(
+            XCODEX_DEFAULT_CONFIG_TOML_STUB,
+            std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE))?
+        );
+
+        std::fs::write(codex_home.join(CONFIG_TOML_FILE), "model = \"o3\"\n")?;
+        initialize_xcodex_home(&codex_home)?;
+        assert_eq!(
+            "model = \"o3\"\n",
+            std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE))?
+        );
+        Ok(())
+    }
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "HOOKS_DIR" => Some("/opt/hooks".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn expand_env_vars_substitutes_bare_and_braced_references() {
+        assert_eq!(
+            Ok("/opt/hooks/run.py /opt/hooks/x ${HOOKS_DIR} $HOOKS_DIR $ $1 $".to_string()),
+            expand_env_vars(
+                "${HOOKS_DIR}/run.py $HOOKS_DIR/x $${HOOKS_DIR} $$HOOKS_DIR $$ $1 $",
+                &lookup
+            )
+        );
+    }
+
+    #[test]
+    fn expand_env_vars_uses_default_when_unset_or_empty() {
+        assert_eq!(
+            Ok("/usr/bin/python3 fallback /opt/hooks".to_string()),
+            expand_env_vars(
+                "${PYTHON:-/usr/bin/python3} ${EMPTY:-fallback} ${HOOKS_DIR:-unused}",
+                &lookup
+            )
+        );
+    }
+
+    #[test]
+    fn expand_env_vars_rejects_undefined_variables() {
+        for value in ["${MISSING}/host.py", "$MISSING/host.py"] {
+            let err = expand_env_vars(value, &lookup).expect_err("undefined var");
+            assert!(err.contains("`MISSING` is not set"), "{err}");
+        }
+    }
+
+    #[test]
+    fn expand_hooks_env_vars_resolves_codex_home_fallback() -> std::io::Result<()> {
+        let dir = tempdir()?;
+        let mut hooks = HooksConfig::default();
+        hooks.host.command = vec!["python3".to_string(), "${CODEX_HOME}/host.py".to_string()];
+
+        let hooks = expand_hooks_env_vars(hooks, dir.path())?;
+
+        let codex_home =
+            std::env::var("CODEX_HOME").unwrap_or_else(|_| dir.path().display().to_string());
+        assert_eq!(
+            vec!["python3".to_string(), format!("{codex_home}/host.py")],
+            hooks.host.command
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn expand_hooks_env_vars_rejects_undefined_variables_naming_the_hook() {
+        let mut hooks = HooksConfig::default();
+        hooks.session_start = vec![vec![
+            "notify.sh".to_string(),
+            "$XCODEX_TEST_UNDEFINED_ARG".to_string(),
+        ]];
+
+        let err = expand_hooks_env_vars(hooks, Path::new("/tmp")).expect_err("undefined var");
+
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        let message = err.to_string();
+        assert!(
+            message.starts_with("hooks.session_start hook `notify.sh`: "),
+            "{message}"
+        );
+        assert!(
+            message.contains("`XCODEX_TEST_UNDEFINED_ARG` is not set"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn expand_hooks_env_vars_leaves_shell_scripts_to_the_shell() -> std::io::Result<()> {
+        let script = "echo ${XCODEX_TEST_UNDEFINED_ARG} $FOO".to_string();
+        let mut hooks = HooksConfig::default();
+        hooks.session_start = vec![
+            vec!["sh".to_string(), "-c".to_string(), script.clone()],
+            vec!["/bin/bash".to_string(), "-lc".to_string(), script.clone()],
+            vec![
+                "pwsh.exe".to_string(),
+                "-Command".to_string(),
+                script.clone(),
+            ],
+        ];
+        hooks.session_end = vec![vec![
+            "tool".to_string(),
+            "-fc".to_string(),
+            "$XCODEX_TEST_UNDEFINED_ARG".to_string(),
+        ]];
+
+        let expanded = expand_hooks_env_vars(hooks.clone(), Path::new("/tmp"))?;
+        assert_eq!(hooks.session_start, expanded.session_start);
+
+        // `-fc` is not a script flag of a known shell, so the argument is still expanded.
+        hooks.session_start.clear();
+        assert!(expand_hooks_env_vars(hooks, Path::new("/tmp")).is_err());
+        Ok(())
+    }
+
     #[test]
     fn exclusion_layer_hook_sanitization_segments_target_exclusion_table() {
         assert_eq!(
@@ -2,13 +2,21 @@
 
 use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::ffi::OsString;
 use std::fs::File;
 use std::fs::OpenOptions;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::io;
+use std::io::Read;
 use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::Stdio;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 use std::time::Instant;
 use std::time::SystemTime;
@@ -17,13 +25,18 @@ use std::time::UNIX_EPOCH;
 use async_channel::Sender;
 use chrono::DateTime;
 use chrono::Utc;
+use codex_utils_string::take_bytes_at_char_boundary;
 use regex::Regex;
+use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
+use tokio::io::AsyncBufReadExt;
 use tokio::io::AsyncWriteExt;
 use tokio::sync::Semaphore;
 use tokio::sync::mpsc;
+use tracing::debug;
 use tracing::error;
+use tracing::info;
 use tracing::warn;
 use uuid::Uuid;
 
@@ -44,6 +57,7 @@ use serde_json::Value as JsonValue;
 #[cfg(feature = "pyo3-hooks")]
 use std::num::NonZeroUsize;
 
+use crate::config::HookOversizedPayloadStrategy;
 use crate::config::HooksConfig;
 use crate::config::types::ExclusionConfig;
 use crate::protocol::AskForApproval;
@@ -52,6 +66,8 @@ use crate::protocol::EventMsg;
 use crate::protocol::ExecPolicyAmendment;
 use crate::protocol::HookProcessBeginEvent;
 use crate::protocol::HookProcessEndEvent;
+use crate::protocol::RateLimitSnapshot;
+use crate::protocol::RateLimitWindow;
 use crate::protocol::SandboxPolicy;
 use crate::protocol::TokenUsage;
 use crate::protocol_config_types::SandboxMode;
@@ -59,11 +75,12 @@ use crate::xcodex::hook_payload_sanitizer::HookPayloadSanitizer;
 
 mod claude_compat;
 
-const MAX_CONCURRENT_HOOKS: usize = 8;
 const TOOL_CALL_SUMMARY_LOG_FILENAME: &str = "hooks-tool-calls.log";
 const HOOK_EVENT_LOG_JSONL_FILENAME: &str = "hooks.jsonl";
+const HOOK_COST_SUMMARY_JSONL_FILENAME: &str = "hooks-costs.jsonl";
 const INPROC_TOOL_CALL_SUMMARY_HOOK_NAME: &str = "tool_call_summary";
 const INPROC_EVENT_LOG_JSONL_HOOK_NAME: &str = "event_log_jsonl";
+const INPROC_COST_SUMMARY_HOOK_NAME: &str = "cost_summary";
 const INPROC_PYO3_HOOK_NAME: &str = "pyo3";
 const INPROC_HOOK_QUEUE_CAPACITY: usize = 256;
 const INPROC_HOOK_TIMEOUT: Duration = Duration::from_secs(1);
@@ -72,6 +89,23 @@ const INPROC_HOOK_CIRCUIT_BREAKER_OPEN_DURATION: Duration = Duration::from_secs(
 const HOOK_HOST_QUEUE_CAPACITY: usize = 1024;
 const HOOK_HOST_FAILURE_THRESHOLD: u32 = 3;
 const HOOK_HOST_CIRCUIT_BREAKER_OPEN_DURATION: Duration = Duration::from_secs(30);
+const WEBHOOK_QUEUE_CAPACITY: usize = 1024;
+const WEBHOOK_DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+const WEBHOOK_FAILURE_THRESHOLD: u32 = 3;
+const WEBHOOK_CIRCUIT_BREAKER_OPEN_DURATION: Duration = Duration::from_secs(30);
+
+/// When set to a non-empty value other than `0`, every hook provider is skipped
+/// regardless of config. Intended as an incident-response kill switch.
+const DISABLE_HOOKS_ENV_VAR: &str = "XCODEX_DISABLE_HOOKS";
+/// Maximum bytes of hook stdout/stderr echoed to the UI when `hooks.echo_to_ui` is enabled.
+const HOOK_UI_OUTPUT_MAX_BYTES: usize = 8 * 1024;
+/// Upper bound for the exponential backoff between command hook retries.
+const HOOK_RETRY_MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// Minimum time to wait for the host's `hello` reply; `hooks.host.ready_timeout_sec` extends it.
+const HOOK_HOST_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(2);
+const TEST_HOOK_EVENT_BUDGET: Duration = Duration::from_secs(30);
+const HOOK_PAYLOAD_SCHEMA_VERSION: u32 = 1;
+const HOOK_HOST_SUPPORTED_SCHEMA_VERSIONS: &[u32] = &[HOOK_PAYLOAD_SCHEMA_VERSION];
 
 pub type HookResult = anyhow::Result<()>;
 
@@ -96,19 +130,152 @@ trait HookProvider: Send + Sync {
     fn on_event_detached(&self, event: &HookEvent) {
         self.on_event(event);
     }
+
+    /// Dispatches `event` like `on_event`, returning handles for work whose completion the
+    /// provider can track. Providers that cannot track completion return no handles.
+    fn on_event_tracked(&self, event: &HookEvent) -> Vec<tokio::task::JoinHandle<()>> {
+        self.on_event(event);
+        Vec::new()
+    }
+
+    /// Dispatches `event` like `on_event_tracked`, also returning counters that record only the
+    /// work started for this dispatch. Providers that queue events to shared workers cannot
+    /// attribute work to a single dispatch and return `None`.
+    fn on_event_measured(
+        &self,
+        event: &HookEvent,
+    ) -> (
+        Vec<tokio::task::JoinHandle<()>>,
+        Option<std::sync::Arc<HookProviderCounters>>,
+    ) {
+        (self.on_event_tracked(event), None)
+    }
+
+    /// Whether this provider may act on events of type `key`. Providers that cannot tell
+    /// ahead of time (e.g. because of matcher filters) return true.
+    fn handles_event(&self, _key: HookEventKey) -> bool {
+        true
+    }
+
+    fn metrics(&self) -> HookProviderMetrics;
+}
+
+/// Point-in-time snapshot of hook dispatch counters for a session.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HookMetrics {
+    /// Events dispatched to the configured providers.
+    pub events_emitted: u64,
+    pub providers: Vec<HookProviderMetrics>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HookProviderMetrics {
+    /// One of `inproc`, `host`, `webhook`, or `external_command`.
+    pub provider: String,
+    /// Processes (or in-process handler invocations) started for hook events.
+    pub commands_spawned: u64,
+    pub failures: u64,
+    pub timeouts: u64,
+    pub breaker_trips: u64,
+}
+
+#[derive(Default)]
+struct HookProviderCounters {
+    commands_spawned: AtomicU64,
+    failures: AtomicU64,
+    timeouts: AtomicU64,
+    breaker_trips: AtomicU64,
+    /// Provider-wide counters that every increment is also recorded into, set for counters
+    /// scoped to a single dispatch.
+    parent: Option<std::sync::Arc<HookProviderCounters>>,
+}
+
+impl HookProviderCounters {
+    /// Counters that only see work started for one dispatch, while still feeding `parent`.
+    fn scoped(parent: &std::sync::Arc<Self>) -> std::sync::Arc<Self> {
+        std::sync::Arc::new(Self {
+            parent: Some(std::sync::Arc::clone(parent)),
+            ..Self::default()
+        })
+    }
+
+    fn record_command_spawned(&self) {
+        self.commands_spawned.fetch_add(1, Ordering::Relaxed);
+        if let Some(parent) = &self.parent {
+            parent.record_command_spawned();
+        }
+    }
+
+    fn record_failure(&self) {
+        self.failures.fetch_add(1, Ordering::Relaxed);
+        if let Some(parent) = &self.parent {
+            parent.record_failure();
+        }
+    }
+
+    fn record_timeout(&self) {
+        self.timeouts.fetch_add(1, Ordering::Relaxed);
+        if let Some(parent) = &self.parent {
+            parent.record_timeout();
+        }
+    }
+
+    fn record_breaker_trip(&self) {
+        self.breaker_trips.fetch_add(1, Ordering::Relaxed);
+        if let Some(parent) = &self.parent {
+            parent.record_breaker_trip();
+        }
+    }
+
+    fn snapshot(&self, provider: &str) -> HookProviderMetrics {
+        HookProviderMetrics {
+            provider: provider.to_string(),
+            commands_spawned: self.commands_spawned.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+            timeouts: self.timeouts.load(Ordering::Relaxed),
+            breaker_trips: self.breaker_trips.load(Ordering::Relaxed),
+        }
+    }
 }
 
 #[derive(Clone)]
 struct HookBus {
     providers: Vec<std::sync::Arc<dyn HookProvider>>,
+    disabled_events: HashSet<HookEventKey>,
+    dedupe: Option<std::sync::Arc<HookEventDeduper>>,
+    events_emitted: std::sync::Arc<AtomicU64>,
 }
 
 impl HookBus {
+    fn is_disabled(&self, notification: &HookNotification) -> bool {
+        self.disabled_events
+            .contains(&HookEventKey::from_notification(notification))
+    }
+
+    fn should_skip(&self, notification: &HookNotification) -> bool {
+        self.providers.is_empty()
+            || self.is_disabled(notification)
+            || self
+                .dedupe
+                .as_ref()
+                .is_some_and(|dedupe| dedupe.is_duplicate(notification))
+    }
+
+    /// Whether dispatching an event of type `key` could reach any provider.
+    fn is_active_for(&self, key: HookEventKey) -> bool {
+        !self.disabled_events.contains(&key)
+            && self
+                .providers
+                .iter()
+                .any(|provider| provider.handles_event(key))
+    }
+
     fn emit(&self, notification: HookNotification) {
-        if self.providers.is_empty() {
+        if self.should_skip(&notification) {
             return;
         }
 
+        self.events_emitted.fetch_add(1, Ordering::Relaxed);
         let event = HookEvent::new(notification);
         for provider in &self.providers {
             provider.on_event(&event);
@@ -116,62 +283,176 @@ impl HookBus {
     }
 
     fn emit_detached(&self, notification: HookNotification) {
-        if self.providers.is_empty() {
+        if self.should_skip(&notification) {
             return;
         }
 
+        self.events_emitted.fetch_add(1, Ordering::Relaxed);
         let event = HookEvent::new(notification);
         for provider in &self.providers {
             provider.on_event_detached(&event);
         }
     }
+
+    /// Dispatches `notification` and waits up to `budget` for tracked provider work (bounded
+    /// command hooks) to finish. Work still running after `budget` continues in the background.
+    async fn emit_sync(&self, notification: HookNotification, budget: Duration) {
+        if self.should_skip(&notification) {
+            return;
+        }
+
+        self.events_emitted.fetch_add(1, Ordering::Relaxed);
+        let event = HookEvent::new(notification);
+        let handles: Vec<_> = self
+            .providers
+            .iter()
+            .flat_map(|provider| provider.on_event_tracked(&event))
+            .collect();
+        wait_for_tracked_hooks(&event, handles, budget).await;
+    }
+
+    /// Like `emit_sync`, but returns the metrics recorded for this dispatch alone, leaving out
+    /// providers that cannot attribute work to a single dispatch.
+    async fn emit_measured(&self, notification: HookNotification, budget: Duration) -> HookMetrics {
+        if self.should_skip(&notification) {
+            return HookMetrics::default();
+        }
+
+        self.events_emitted.fetch_add(1, Ordering::Relaxed);
+        let event = HookEvent::new(notification);
+        let mut handles = Vec::new();
+        let mut measured = Vec::new();
+        for provider in &self.providers {
+            let (provider_handles, counters) = provider.on_event_measured(&event);
+            handles.extend(provider_handles);
+            if let Some(counters) = counters {
+                measured.push((provider.metrics().provider, counters));
+            }
+        }
+        wait_for_tracked_hooks(&event, handles, budget).await;
+
+        HookMetrics {
+            events_emitted: 1,
+            providers: measured
+                .into_iter()
+                .map(|(provider, counters)| counters.snapshot(&provider))
+                .collect(),
+        }
+    }
+
+    fn metrics(&self) -> HookMetrics {
+        HookMetrics {
+            events_emitted: self.events_emitted.load(Ordering::Relaxed),
+            providers: self
+                .providers
+                .iter()
+                .map(|provider| provider.metrics())
+                .collect(),
+        }
+    }
 }
 
-#[derive(Clone)]
-pub(crate) struct UserHooks {
-    bus: HookBus,
-    payload_sanitizer: Option<std::sync::Arc<HookPayloadSanitizer>>,
+/// Waits up to `budget` for `handles`; work still running after `budget` continues in the
+/// background.
+async fn wait_for_tracked_hooks(
+    event: &HookEvent,
+    handles: Vec<tokio::task::JoinHandle<()>>,
+    budget: Duration,
+) {
+    if handles.is_empty() {
+        return;
+    }
+
+    if tokio::time::timeout(budget, futures::future::join_all(handles))
+        .await
+        .is_err()
+    {
+        let event_type = event.notification().event_type();
+        let budget_ms = budget.as_millis();
+        warn!("{event_type} hooks did not finish within {budget_ms}ms; continuing");
+    }
 }
 
-#[derive(Clone)]
-struct InprocHookPolicy {
-    queue_capacity: usize,
-    timeout: Duration,
-    failure_threshold: u32,
-    circuit_breaker_open_duration: Duration,
+/// Drops events identical to the last dispatched event of the same type within `window`
+/// (`hooks.dedupe_window_ms`).
+struct HookEventDeduper {
+    window: Duration,
+    last_dispatched: std::sync::Mutex<HashMap<HookEventKey, (u64, Instant)>>,
+    dropped: AtomicU64,
 }
 
-impl Default for InprocHookPolicy {
-    fn default() -> Self {
-        Self {
-            queue_capacity: INPROC_HOOK_QUEUE_CAPACITY,
-            timeout: INPROC_HOOK_TIMEOUT,
-            failure_threshold: INPROC_HOOK_FAILURE_THRESHOLD,
-            circuit_breaker_open_duration: INPROC_HOOK_CIRCUIT_BREAKER_OPEN_DURATION,
+impl HookEventDeduper {
+    fn new(window_ms: u64) -> Option<Self> {
+        (window_ms > 0).then(|| Self {
+            window: Duration::from_millis(window_ms),
+            last_dispatched: std::sync::Mutex::new(HashMap::new()),
+            dropped: AtomicU64::new(0),
+        })
+    }
+
+    fn is_duplicate(&self, notification: &HookNotification) -> bool {
+        let Ok(content) = serde_json::to_vec(notification) else {
+            return false;
+        };
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        let content_hash = hasher.finish();
+
+        let key = HookEventKey::from_notification(notification);
+        let now = Instant::now();
+        let Ok(mut last_dispatched) = self.last_dispatched.lock() else {
+            return false;
+        };
+        let duplicate = last_dispatched
+            .get(&key)
+            .is_some_and(|(last_hash, last_at)| {
+                *last_hash == content_hash && now.duration_since(*last_at) < self.window
+            });
+        if !duplicate {
+            last_dispatched.insert(key, (content_hash, now));
+            return false;
         }
+
+        let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+        let event_type = notification.event_type();
+        warn!("dropped duplicate hook event {event_type} ({dropped} dropped so far)");
+        true
     }
 }
 
 #[derive(Clone)]
-struct InprocHookEntry {
-    name: String,
-    hook: std::sync::Arc<dyn HookHandler>,
-    timeout: Option<Duration>,
+pub(crate) struct UserHooks {
+    bus: HookBus,
+    payload_sanitizer: Option<std::sync::Arc<HookPayloadSanitizer>>,
+    output_preview_bytes: usize,
+    session_start_blocking: Option<Duration>,
 }
 
-#[derive(Clone)]
-struct InprocHookWorker {
-    name: String,
-    tx_payload: mpsc::Sender<std::sync::Arc<HookEvent>>,
+#[derive(Clone, Copy)]
+struct CircuitBreakerPolicy {
+    failure_threshold: u32,
+    open_duration: Duration,
 }
 
-#[derive(Clone, Default)]
-struct InprocHookCircuitBreaker {
+/// Skips a hook provider's work for `open_duration` once `failure_threshold` consecutive
+/// deliveries have failed. Shared by the in-process, hook host, and webhook providers.
+struct HookCircuitBreaker {
+    policy: CircuitBreakerPolicy,
     consecutive_failures: u32,
     open_until: Option<Instant>,
+    counters: std::sync::Arc<HookProviderCounters>,
 }
 
-impl InprocHookCircuitBreaker {
+impl HookCircuitBreaker {
+    fn new(policy: CircuitBreakerPolicy, counters: std::sync::Arc<HookProviderCounters>) -> Self {
+        Self {
+            policy,
+            consecutive_failures: 0,
+            open_until: None,
+            counters,
+        }
+    }
+
     fn is_open(&self) -> bool {
         self.open_until
             .is_some_and(|open_until| Instant::now() < open_until)
@@ -182,26 +463,70 @@ impl InprocHookCircuitBreaker {
         self.open_until = None;
     }
 
-    fn on_failure(&mut self, policy: &InprocHookPolicy) {
+    fn on_failure(&mut self) {
         self.consecutive_failures = self.consecutive_failures.saturating_add(1);
-        if self.consecutive_failures >= policy.failure_threshold {
-            self.open_until = Some(Instant::now() + policy.circuit_breaker_open_duration);
+        if self.consecutive_failures >= self.policy.failure_threshold {
+            self.open();
         }
     }
 
-    fn on_timeout(&mut self, policy: &InprocHookPolicy) {
-        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
-        self.open_until = Some(Instant::now() + policy.circuit_breaker_open_duration);
+    /// Opens the breaker immediately, for failures that retrying cannot fix.
+    fn trip(&mut self) {
+        self.consecutive_failures = self.policy.failure_threshold;
+        self.open();
+    }
+
+    fn open(&mut self) {
+        self.counters.record_breaker_trip();
+        self.open_until = Some(Instant::now() + self.policy.open_duration);
+    }
+}
+
+#[derive(Clone)]
+struct InprocHookPolicy {
+    queue_capacity: usize,
+    timeout: Duration,
+    breaker: CircuitBreakerPolicy,
+}
+
+impl Default for InprocHookPolicy {
+    fn default() -> Self {
+        Self {
+            queue_capacity: INPROC_HOOK_QUEUE_CAPACITY,
+            timeout: INPROC_HOOK_TIMEOUT,
+            breaker: CircuitBreakerPolicy {
+                failure_threshold: INPROC_HOOK_FAILURE_THRESHOLD,
+                open_duration: INPROC_HOOK_CIRCUIT_BREAKER_OPEN_DURATION,
+            },
+        }
     }
 }
 
+#[derive(Clone)]
+struct InprocHookEntry {
+    name: String,
+    hook: std::sync::Arc<dyn HookHandler>,
+    timeout: Option<Duration>,
+}
+
+#[derive(Clone)]
+struct InprocHookWorker {
+    name: String,
+    tx_payload: mpsc::Sender<std::sync::Arc<HookEvent>>,
+}
+
 struct InprocHooksProvider {
     hooks: Vec<InprocHookWorker>,
+    counters: std::sync::Arc<HookProviderCounters>,
+    dry_run: bool,
 }
 
 impl InprocHooksProvider {
-    fn new(codex_home: PathBuf, hooks: Vec<InprocHookEntry>) -> Self {
-        Self::new_with_policy(codex_home, hooks, InprocHookPolicy::default())
+    fn new(codex_home: PathBuf, hooks: Vec<InprocHookEntry>, dry_run: bool) -> Self {
+        Self {
+            dry_run,
+            ..Self::new_with_policy(codex_home, hooks, InprocHookPolicy::default())
+        }
     }
 
     fn new_with_policy(
@@ -210,6 +535,7 @@ impl InprocHooksProvider {
         policy: InprocHookPolicy,
     ) -> Self {
         let ctx = HookContext { codex_home };
+        let counters = std::sync::Arc::new(HookProviderCounters::default());
         let mut workers = Vec::with_capacity(hooks.len());
 
         for hook in hooks {
@@ -219,9 +545,11 @@ impl InprocHooksProvider {
             let ctx = ctx.clone();
             let policy = policy.clone();
             let timeout = hook.timeout.unwrap_or(policy.timeout);
+            let counters = std::sync::Arc::clone(&counters);
 
             tokio::spawn(async move {
-                let mut breaker = InprocHookCircuitBreaker::default();
+                let mut breaker =
+                    HookCircuitBreaker::new(policy.breaker, std::sync::Arc::clone(&counters));
                 while let Some(event) = rx_payload.recv().await {
                     if breaker.is_open() {
                         warn!("skipping in-process hook due to open circuit breaker: {entry_name}");
@@ -234,6 +562,7 @@ impl InprocHooksProvider {
                     let handler = std::sync::Arc::clone(&handler);
                     let started_at = Instant::now();
 
+                    counters.record_command_spawned();
                     let handle = tokio::task::spawn_blocking(move || {
                         std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
                             handler.on_event(&ctx, &event)
@@ -246,20 +575,24 @@ impl InprocHooksProvider {
                         }
                         Ok(Ok(Ok(Err(err)))) => {
                             error!("in-process hook failed: {entry_name}: {err}");
-                            breaker.on_failure(&policy);
+                            counters.record_failure();
+                            breaker.on_failure();
                         }
                         Ok(Ok(Err(_panic))) => {
                             error!("in-process hook panicked: {entry_name}");
-                            breaker.on_failure(&policy);
+                            counters.record_failure();
+                            breaker.on_failure();
                         }
                         Ok(Err(join_err)) => {
                             error!("in-process hook join error: {entry_name}: {join_err}");
-                            breaker.on_failure(&policy);
+                            counters.record_failure();
+                            breaker.on_failure();
                         }
                         Err(_timeout) => {
                             let timeout_ms = timeout.as_millis();
                             error!("in-process hook timed out after {timeout_ms}ms: {entry_name}");
-                            breaker.on_timeout(&policy);
+                            counters.record_timeout();
+                            breaker.trip();
                         }
                     }
 
@@ -280,12 +613,27 @@ impl InprocHooksProvider {
             });
         }
 
-        Self { hooks: workers }
+        Self {
+            hooks: workers,
+            counters,
+            dry_run: false,
+        }
     }
 }
 
 impl HookProvider for InprocHooksProvider {
     fn on_event(&self, event: &HookEvent) {
+        if self.dry_run {
+            let event_type = event.xcodex_event_type();
+            for hook in &self.hooks {
+                info!(
+                    "hooks.dry_run: would run in-process hook {} for {event_type}",
+                    hook.name
+                );
+            }
+            return;
+        }
+
         let event = std::sync::Arc::new(event.clone());
         for hook in &self.hooks {
             if hook
@@ -300,6 +648,10 @@ impl HookProvider for InprocHooksProvider {
             }
         }
     }
+
+    fn metrics(&self) -> HookProviderMetrics {
+        self.counters.snapshot("inproc")
+    }
 }
 
 struct ToolCallSummaryHook;
@@ -339,6 +691,70 @@ impl HookHandler for EventLogJsonlHook {
     }
 }
 
+/// Sums `model-response-completed` token usage per session and appends the running totals to
+/// `hooks-costs.jsonl` at each `agent-turn-complete` and `session-end`.
+#[derive(Default)]
+struct CostSummaryHook {
+    sessions: std::sync::Mutex<HashMap<String, SessionCostTotals>>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct SessionCostTotals {
+    responses: u64,
+    token_usage: TokenUsage,
+}
+
+impl HookHandler for CostSummaryHook {
+    fn on_event(&self, ctx: &HookContext, event: &HookEvent) -> HookResult {
+        let mut sessions = self
+            .sessions
+            .lock()
+            .map_err(|_| anyhow::anyhow!("cost summary hook mutex is poisoned"))?;
+        let (session_id, totals) = match &event.notification {
+            HookNotification::ModelResponseCompleted {
+                thread_id,
+                token_usage: Some(token_usage),
+                ..
+            } => {
+                let totals = sessions.entry(thread_id.clone()).or_default();
+                totals.responses += 1;
+                totals.token_usage.add_assign(token_usage);
+                return Ok(());
+            }
+            HookNotification::AgentTurnComplete { thread_id, .. } => {
+                (thread_id, sessions.get(thread_id).cloned())
+            }
+            HookNotification::SessionEnd { thread_id, .. } => {
+                (thread_id, sessions.remove(thread_id))
+            }
+            _ => return Ok(()),
+        };
+        drop(sessions);
+        let Some(totals) = totals else {
+            return Ok(());
+        };
+
+        let out_path = ctx.codex_home.join(HOOK_COST_SUMMARY_JSONL_FILENAME);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&out_path)?;
+        set_file_permissions(&out_path, &file)?;
+        serde_json::to_writer(
+            &mut file,
+            &serde_json::json!({
+                "timestamp": event.timestamp,
+                "session_id": session_id,
+                "xcodex_event_type": event.xcodex_event_type(),
+                "responses": totals.responses,
+                "token_usage": totals.token_usage,
+            }),
+        )?;
+        file.write_all(b"\n")?;
+        Ok(())
+    }
+}
+
 #[cfg(feature = "pyo3-hooks")]
 struct Pyo3Hook {
     script_path: String,
@@ -541,34 +957,41 @@ struct ExternalCommandHooksProvider {
     codex_home: PathBuf,
     tx_event: Option<Sender<Event>>,
     semaphore: std::sync::Arc<Semaphore>,
+    counters: std::sync::Arc<HookProviderCounters>,
 }
 
 impl ExternalCommandHooksProvider {
     fn new(codex_home: PathBuf, hooks: HooksConfig, tx_event: Option<Sender<Event>>) -> Self {
         let command_hooks = CompiledCommandHooksConfig::compile(&hooks.command);
+        let semaphore = std::sync::Arc::new(Semaphore::new(hooks.max_concurrent_commands.max(1)));
         Self {
             hooks,
             command_hooks,
             codex_home,
             tx_event,
-            semaphore: std::sync::Arc::new(Semaphore::new(MAX_CONCURRENT_HOOKS)),
+            semaphore,
+            counters: std::sync::Arc::new(HookProviderCounters::default()),
         }
     }
 
     fn commands_for_event(&self, event: &HookEvent) -> &[Vec<String>] {
-        match event.notification() {
-            HookNotification::AgentTurnComplete { .. } => &self.hooks.agent_turn_complete,
-            HookNotification::ApprovalRequested { .. } => &self.hooks.approval_requested,
-            HookNotification::SessionStart { .. } => &self.hooks.session_start,
-            HookNotification::SessionEnd { .. } => &self.hooks.session_end,
-            HookNotification::UserPromptSubmit { .. } => &self.hooks.user_prompt_submit,
-            HookNotification::PreCompact { .. } => &self.hooks.pre_compact,
-            HookNotification::Notification { .. } => &self.hooks.notification,
-            HookNotification::SubagentStop { .. } => &self.hooks.subagent_stop,
-            HookNotification::ModelRequestStarted { .. } => &self.hooks.model_request_started,
-            HookNotification::ModelResponseCompleted { .. } => &self.hooks.model_response_completed,
-            HookNotification::ToolCallStarted { .. } => &self.hooks.tool_call_started,
-            HookNotification::ToolCallFinished { .. } => &self.hooks.tool_call_finished,
+        self.commands_for_key(HookEventKey::from_notification(event.notification()))
+    }
+
+    fn commands_for_key(&self, key: HookEventKey) -> &[Vec<String>] {
+        match key {
+            HookEventKey::AgentTurnComplete => &self.hooks.agent_turn_complete,
+            HookEventKey::ApprovalRequested => &self.hooks.approval_requested,
+            HookEventKey::SessionStart => &self.hooks.session_start,
+            HookEventKey::SessionEnd => &self.hooks.session_end,
+            HookEventKey::UserPromptSubmit => &self.hooks.user_prompt_submit,
+            HookEventKey::PreCompact => &self.hooks.pre_compact,
+            HookEventKey::Notification => &self.hooks.notification,
+            HookEventKey::SubagentStop => &self.hooks.subagent_stop,
+            HookEventKey::ModelRequestStarted => &self.hooks.model_request_started,
+            HookEventKey::ModelResponseCompleted => &self.hooks.model_response_completed,
+            HookEventKey::ToolCallStarted => &self.hooks.tool_call_started,
+            HookEventKey::ToolCallFinished => &self.hooks.tool_call_finished,
         }
     }
 
@@ -589,16 +1012,95 @@ impl ExternalCommandHooksProvider {
         hooks
     }
 
-    fn invoke_hook_commands(&self, commands: &[Vec<String>], event: HookEvent) {
+    /// Logs the commands `event` would run under `hooks.dry_run`, writing each payload to the
+    /// payloads directory so it can be inspected. Nothing is spawned.
+    fn log_dry_run(&self, event: &HookEvent) {
+        let mut invocations: Vec<(Vec<String>, String)> = self
+            .command_hooks_for_event(event)
+            .into_iter()
+            .map(|hook| (hook.argv, hook.hook_event_name))
+            .collect();
+        let hook_event_name = default_hook_event_name(event);
+        invocations.extend(
+            self.commands_for_event(event)
+                .iter()
+                .map(|command| (command.clone(), hook_event_name.clone())),
+        );
+
+        let mut payload_paths: HashMap<String, Option<PathBuf>> = HashMap::new();
+        for (command, hook_event_name) in invocations {
+            if command.is_empty() {
+                continue;
+            }
+
+            let payload_path = payload_paths
+                .entry(hook_event_name.clone())
+                .or_insert_with(|| {
+                    let payload = HookPayload::from_event(event, &hook_event_name);
+                    serde_json::to_vec(&payload)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|payload_json| {
+                            write_payload_file(
+                                &self.codex_home,
+                                &payload,
+                                &payload_json,
+                                self.hooks.keep_last_n_payloads,
+                            )
+                        })
+                        .inspect_err(|e| warn!("failed to write dry-run hook payload: {e}"))
+                        .ok()
+                });
+            match payload_path {
+                Some(path) => info!(
+                    "hooks.dry_run: would run {command:?} for {hook_event_name} (payload: {})",
+                    path.display()
+                ),
+                None => info!("hooks.dry_run: would run {command:?} for {hook_event_name}"),
+            }
+        }
+    }
+
+    /// Dispatches `event` to the matching command hooks, recording into `counters`, and returns
+    /// handles for the bounded ones.
+    fn dispatch_tracked(
+        &self,
+        event: &HookEvent,
+        counters: &std::sync::Arc<HookProviderCounters>,
+    ) -> Vec<tokio::task::JoinHandle<()>> {
+        if self.hooks.dry_run {
+            self.log_dry_run(event);
+            return Vec::new();
+        }
+
+        let mut handles = Vec::new();
+        let command_hooks = self.command_hooks_for_event(event);
+        if !command_hooks.is_empty() {
+            handles.extend(self.dispatch_command_hooks(command_hooks, event, false, counters));
+        }
+
+        let commands = self.commands_for_event(event);
+        if !commands.is_empty() {
+            handles.extend(self.invoke_hook_commands(commands, event.clone(), counters));
+        }
+        handles
+    }
+
+    /// Spawns the legacy argv hooks for `event`; the returned handle resolves once they all exit.
+    fn invoke_hook_commands(
+        &self,
+        commands: &[Vec<String>],
+        event: HookEvent,
+        counters: &std::sync::Arc<HookProviderCounters>,
+    ) -> Option<tokio::task::JoinHandle<()>> {
         if commands.is_empty() {
-            return;
+            return None;
         }
 
         let hook_event_name = default_hook_event_name(&event);
         let payload = HookPayload::from_event(&event, &hook_event_name);
         let Ok(payload_json) = serde_json::to_vec(&payload) else {
             error!("failed to serialise hook payload to JSON");
-            return;
+            return None;
         };
 
         let commands: Vec<Vec<String>> = commands
@@ -607,35 +1109,47 @@ impl ExternalCommandHooksProvider {
             .cloned()
             .collect();
         if commands.is_empty() {
-            return;
+            return None;
         }
 
         let ctx = HookCommandContext {
             max_stdin_payload_bytes: self.hooks.max_stdin_payload_bytes,
+            oversized_payload_strategy: self.hooks.oversized_payload_strategy,
             keep_last_n_payloads: self.hooks.keep_last_n_payloads,
             codex_home: self.codex_home.clone(),
             tx_event: self.tx_event.clone(),
             semaphore: self.semaphore.clone(),
+            counters: std::sync::Arc::clone(counters),
+            retries: self.hooks.command.retries,
+            retry_backoff: Duration::from_millis(self.hooks.command.retry_backoff_ms),
+            echo_to_ui: self.hooks.echo_to_ui,
         };
 
-        tokio::spawn(async move {
-            let stdin_payload = prepare_hook_stdin_payload(
+        Some(tokio::spawn(async move {
+            let Some(stdin_payload) = prepare_hook_stdin_payload(
                 &payload,
                 &payload_json,
                 ctx.max_stdin_payload_bytes,
+                ctx.oversized_payload_strategy,
                 ctx.keep_last_n_payloads,
                 &ctx.codex_home,
-            );
+            ) else {
+                return;
+            };
 
-            for command in commands {
-                let ctx = ctx.clone();
-                let payload = payload.clone();
-                let stdin_payload = stdin_payload.clone();
-                tokio::spawn(async move {
-                    run_hook_command(command, payload, stdin_payload, ctx).await;
-                });
-            }
-        });
+            let handles: Vec<_> = commands
+                .into_iter()
+                .map(|command| {
+                    let ctx = ctx.clone();
+                    let payload = payload.clone();
+                    let stdin_payload = stdin_payload.clone();
+                    tokio::spawn(async move {
+                        run_hook_command(command, payload, stdin_payload, ctx, None).await;
+                    })
+                })
+                .collect();
+            futures::future::join_all(handles).await;
+        }))
     }
 
     fn invoke_hook_commands_detached(&self, commands: &[Vec<String>], event: HookEvent) {
@@ -650,13 +1164,16 @@ impl ExternalCommandHooksProvider {
             return;
         };
 
-        let stdin_payload = prepare_hook_stdin_payload(
+        let Some(stdin_payload) = prepare_hook_stdin_payload(
             &payload,
             &payload_json,
             self.hooks.max_stdin_payload_bytes,
+            self.hooks.oversized_payload_strategy,
             self.hooks.keep_last_n_payloads,
             &self.codex_home,
-        );
+        ) else {
+            return;
+        };
 
         for command in commands.iter().cloned() {
             if command.is_empty() {
@@ -668,13 +1185,37 @@ impl ExternalCommandHooksProvider {
                 self.hooks.keep_last_n_payloads,
                 &self.codex_home,
                 &stdin_payload,
+                &self.counters,
             );
         }
     }
 
-    fn invoke_command_hooks(&self, hooks: Vec<CommandHookSpec>, event: HookEvent) {
+    /// Splits `hooks` between the detached and bounded spawn paths. Hooks without an explicit
+    /// `detached` setting follow the event's default dispatch mode. Returns a handle for the
+    /// bounded hooks.
+    fn dispatch_command_hooks(
+        &self,
+        hooks: Vec<CommandHookSpec>,
+        event: &HookEvent,
+        detached_by_default: bool,
+        counters: &std::sync::Arc<HookProviderCounters>,
+    ) -> Option<tokio::task::JoinHandle<()>> {
+        let (detached, bounded): (Vec<_>, Vec<_>) = hooks
+            .into_iter()
+            .partition(|hook| hook.detached.unwrap_or(detached_by_default));
+        self.invoke_command_hooks_detached(detached, event.clone(), counters);
+        self.invoke_command_hooks(bounded, event.clone(), counters)
+    }
+
+    /// Spawns bounded command hooks for `event`; the returned handle resolves once they all exit.
+    fn invoke_command_hooks(
+        &self,
+        hooks: Vec<CommandHookSpec>,
+        event: HookEvent,
+        counters: &std::sync::Arc<HookProviderCounters>,
+    ) -> Option<tokio::task::JoinHandle<()>> {
         if hooks.is_empty() {
-            return;
+            return None;
         }
 
         let hooks: Vec<CommandHookSpec> = hooks
@@ -682,48 +1223,56 @@ impl ExternalCommandHooksProvider {
             .filter(|hook| !hook.argv.is_empty())
             .collect();
         if hooks.is_empty() {
-            return;
+            return None;
         }
 
         let ctx = HookCommandContext {
             max_stdin_payload_bytes: self.hooks.max_stdin_payload_bytes,
+            oversized_payload_strategy: self.hooks.oversized_payload_strategy,
             keep_last_n_payloads: self.hooks.keep_last_n_payloads,
             codex_home: self.codex_home.clone(),
             tx_event: self.tx_event.clone(),
             semaphore: self.semaphore.clone(),
+            counters: std::sync::Arc::clone(counters),
+            retries: self.hooks.command.retries,
+            retry_backoff: Duration::from_millis(self.hooks.command.retry_backoff_ms),
+            echo_to_ui: self.hooks.echo_to_ui,
         };
 
-        tokio::spawn(async move {
+        Some(tokio::spawn(async move {
+            let mut handles = Vec::new();
             for hook in hooks {
                 let ctx = ctx.clone();
                 let payload = HookPayload::from_event(&event, hook.hook_event_name.as_str());
-                let stdin_payload = serde_json::to_vec(&payload)
-                    .ok()
-                    .map(|payload_json| {
+                let Some(stdin_payload) =
+                    serde_json::to_vec(&payload).map_or(Some(Vec::new()), |payload_json| {
                         prepare_hook_stdin_payload(
                             &payload,
                             &payload_json,
                             ctx.max_stdin_payload_bytes,
+                            ctx.oversized_payload_strategy,
                             ctx.keep_last_n_payloads,
                             &ctx.codex_home,
                         )
                     })
-                    .unwrap_or_default();
-                tokio::spawn(async move {
-                    run_hook_command_with_timeout(
-                        hook.argv,
-                        payload,
-                        stdin_payload,
-                        ctx,
-                        hook.timeout,
-                    )
-                    .await;
-                });
+                else {
+                    continue;
+                };
+                handles.push(tokio::spawn(async move {
+                    run_hook_command(hook.argv, payload, stdin_payload, ctx, Some(hook.timeout))
+                        .await;
+                }));
             }
-        });
+            futures::future::join_all(handles).await;
+        }))
     }
 
-    fn invoke_command_hooks_detached(&self, hooks: Vec<CommandHookSpec>, event: HookEvent) {
+    fn invoke_command_hooks_detached(
+        &self,
+        hooks: Vec<CommandHookSpec>,
+        event: HookEvent,
+        counters: &std::sync::Arc<HookProviderCounters>,
+    ) {
         if hooks.is_empty() {
             return;
         }
@@ -738,29 +1287,34 @@ impl ExternalCommandHooksProvider {
 
         tokio::spawn({
             let codex_home = self.codex_home.clone();
+            let counters = std::sync::Arc::clone(counters);
             let max_stdin_payload_bytes = self.hooks.max_stdin_payload_bytes;
+            let oversized_payload_strategy = self.hooks.oversized_payload_strategy;
             let keep_last_n_payloads = self.hooks.keep_last_n_payloads;
             async move {
                 for hook in hooks {
                     let payload = HookPayload::from_event(&event, hook.hook_event_name.as_str());
-                    let stdin_payload = serde_json::to_vec(&payload)
-                        .ok()
-                        .map(|payload_json| {
+                    let Some(stdin_payload) =
+                        serde_json::to_vec(&payload).map_or(Some(Vec::new()), |payload_json| {
                             prepare_hook_stdin_payload(
                                 &payload,
                                 &payload_json,
                                 max_stdin_payload_bytes,
+                                oversized_payload_strategy,
                                 keep_last_n_payloads,
                                 &codex_home,
                             )
                         })
-                        .unwrap_or_default();
-
+                    else {
+                        continue;
+                    };
+
                     spawn_hook_command_detached(
                         hook.argv,
                         keep_last_n_payloads,
                         &codex_home,
                         &stdin_payload,
+                        &counters,
                     );
                 }
             }
@@ -802,15 +1356,57 @@ impl HookEventKey {
         }
     }
 
-    fn is_tool_scoped(self) -> bool {
+    /// Events with matcher candidates: tool names for tool-scoped events and the model slug for
+    /// model request/response events.
+    fn supports_matcher(self) -> bool {
         matches!(
             self,
-            Self::ApprovalRequested | Self::ToolCallStarted | Self::ToolCallFinished
+            Self::ApprovalRequested
+                | Self::ToolCallStarted
+                | Self::ToolCallFinished
+                | Self::ModelRequestStarted
+                | Self::ModelResponseCompleted
         )
     }
 }
 
-fn canonical_event_key(name: &str) -> Option<HookEventKey> {
+/// Hook provider names accepted by `hooks.provider_order`, in default dispatch order.
+pub(crate) const HOOK_PROVIDER_ORDER_NAMES: [&str; 4] = ["inproc", "host", "webhook", "external"];
+
+fn resolve_provider_order(names: &[String]) -> Vec<&'static str> {
+    let mut order = Vec::new();
+    for name in names {
+        match HOOK_PROVIDER_ORDER_NAMES
+            .iter()
+            .find(|known| **known == name.trim())
+        {
+            Some(known) if !order.contains(known) => order.push(*known),
+            // Unknown names are rejected when the config is loaded.
+            Some(_) | None => {}
+        }
+    }
+    for known in HOOK_PROVIDER_ORDER_NAMES {
+        if !order.contains(&known) {
+            order.push(known);
+        }
+    }
+    order
+}
+
+fn compile_disabled_events(names: &[String]) -> HashSet<HookEventKey> {
+    names
+        .iter()
+        .filter_map(|name| {
+            let key = canonical_event_key(name);
+            if key.is_none() {
+                warn!("unknown event in hooks.disabled_events: {name}");
+            }
+            key
+        })
+        .collect()
+}
+
+pub(crate) fn canonical_event_key(name: &str) -> Option<HookEventKey> {
     match name.trim() {
         // Canonical TOML keys (snake_case)
         "agent_turn_complete" => Some(HookEventKey::AgentTurnComplete),
@@ -902,6 +1498,7 @@ struct CommandHookSpec {
     argv: Vec<String>,
     timeout: Duration,
     hook_event_name: String,
+    detached: Option<bool>,
 }
 
 #[derive(Clone, Debug)]
@@ -936,12 +1533,17 @@ impl CompiledCommandHooksConfig {
                     continue;
                 };
 
-                if !event.is_tool_scoped() && !matches!(matcher, CommandMatcher::Any) {
-                    warn!("matcher is ignored for non-tool event {event_name}");
+                if !event.supports_matcher() && !matches!(matcher, CommandMatcher::Any) {
+                    warn!("matcher is ignored for event {event_name}");
                     matcher = CommandMatcher::Any;
                 }
 
-                let hooks = compile_command_hook_specs(event_name, &entry.hooks, default_timeout);
+                let hooks = compile_command_hook_specs(
+                    event_name,
+                    &entry.hooks,
+                    default_timeout,
+                    &cfg.shell,
+                );
                 if hooks.is_empty() {
                     continue;
                 }
@@ -985,8 +1587,8 @@ impl CompiledEventFilters {
                     continue;
                 };
 
-                if !event.is_tool_scoped() && !matches!(matcher, CommandMatcher::Any) {
-                    warn!("matcher is ignored for non-tool filter event {event_name}");
+                if !event.supports_matcher() && !matches!(matcher, CommandMatcher::Any) {
+                    warn!("matcher is ignored for filter event {event_name}");
                     matcher = CommandMatcher::Any;
                 }
 
@@ -1020,6 +1622,7 @@ fn compile_command_hook_specs(
     event_name: &str,
     hooks: &[crate::config::HooksCommandHookConfig],
     default_timeout: Duration,
+    shell: &[String],
 ) -> Vec<CommandHookSpec> {
     let mut compiled = Vec::new();
 
@@ -1040,6 +1643,7 @@ fn compile_command_hook_specs(
                     argv: argv.clone(),
                     timeout,
                     hook_event_name: event_name.to_string(),
+                    detached: hook.detached,
                 });
             }
             (None, Some(command)) => {
@@ -1049,9 +1653,10 @@ fn compile_command_hook_specs(
                 }
 
                 compiled.push(CommandHookSpec {
-                    argv: wrap_shell_command(command),
+                    argv: wrap_shell_command(shell, command),
                     timeout,
                     hook_event_name: event_name.to_string(),
+                    detached: hook.detached,
                 });
             }
             (Some(_), Some(_)) => {
@@ -1068,8 +1673,14 @@ fn compile_command_hook_specs(
     compiled
 }
 
-fn wrap_shell_command(command: &str) -> Vec<String> {
-    if cfg!(windows) {
+/// Wraps a `command` string hook in `shell` (`hooks.command.shell`), or the platform default
+/// shell when `shell` is empty.
+fn wrap_shell_command(shell: &[String], command: &str) -> Vec<String> {
+    if !shell.is_empty() {
+        let mut argv = shell.to_vec();
+        argv.push(command.to_string());
+        argv
+    } else if cfg!(windows) {
         vec!["cmd.exe".to_string(), "/C".to_string(), command.to_string()]
     } else {
         vec!["bash".to_string(), "-lc".to_string(), command.to_string()]
@@ -1111,6 +1722,11 @@ fn build_match_candidates(notification: &HookNotification) -> HookMatchCandidate
             xcodex: Some(tool_name.as_str()),
             claude: claude_compat::map_tool_name(tool_name),
         },
+        HookNotification::ModelRequestStarted { model, .. }
+        | HookNotification::ModelResponseCompleted { model, .. } => HookMatchCandidates {
+            xcodex: Some(model.as_str()),
+            claude: None,
+        },
         HookNotification::ApprovalRequested { kind, .. } => {
             let (xcodex, claude) = match kind {
                 ApprovalKind::Exec => ("exec", "Bash"),
@@ -1129,6 +1745,15 @@ fn build_match_candidates(notification: &HookNotification) -> HookMatchCandidate
     }
 }
 
+/// Environment variables xcodex sets on external command hooks, on top of the environment they
+/// inherit from the xcodex process.
+pub fn command_hook_env(codex_home: &Path) -> Vec<(String, OsString)> {
+    vec![(
+        "CODEX_HOME".to_string(),
+        codex_home.as_os_str().to_os_string(),
+    )]
+}
+
 fn default_hook_event_name(event: &HookEvent) -> String {
     claude_compat::default_hook_event_name(event.notification())
         .unwrap_or_else(|| event.xcodex_event_type())
@@ -1157,21 +1782,33 @@ impl HookHandler for FilteredHookHandler {
 
 impl HookProvider for ExternalCommandHooksProvider {
     fn on_event(&self, event: &HookEvent) {
-        let command_hooks = self.command_hooks_for_event(event);
-        if !command_hooks.is_empty() {
-            self.invoke_command_hooks(command_hooks, event.clone());
-        }
+        self.on_event_tracked(event);
+    }
 
-        let commands = self.commands_for_event(event);
-        if !commands.is_empty() {
-            self.invoke_hook_commands(commands, event.clone());
-        }
+    fn on_event_tracked(&self, event: &HookEvent) -> Vec<tokio::task::JoinHandle<()>> {
+        self.dispatch_tracked(event, &self.counters)
+    }
+
+    fn on_event_measured(
+        &self,
+        event: &HookEvent,
+    ) -> (
+        Vec<tokio::task::JoinHandle<()>>,
+        Option<std::sync::Arc<HookProviderCounters>>,
+    ) {
+        let counters = HookProviderCounters::scoped(&self.counters);
+        (self.dispatch_tracked(event, &counters), Some(counters))
     }
 
     fn on_event_detached(&self, event: &HookEvent) {
+        if self.hooks.dry_run {
+            self.log_dry_run(event);
+            return;
+        }
+
         let command_hooks = self.command_hooks_for_event(event);
         if !command_hooks.is_empty() {
-            self.invoke_command_hooks_detached(command_hooks, event.clone());
+            self.dispatch_command_hooks(command_hooks, event, true, &self.counters);
         }
 
         let commands = self.commands_for_event(event);
@@ -1179,46 +1816,34 @@ impl HookProvider for ExternalCommandHooksProvider {
             self.invoke_hook_commands_detached(commands, event.clone());
         }
     }
+
+    fn handles_event(&self, key: HookEventKey) -> bool {
+        self.command_hooks
+            .by_event
+            .get(&key)
+            .is_some_and(|entries| !entries.is_empty())
+            || !self.commands_for_key(key).is_empty()
+    }
+
+    fn metrics(&self) -> HookProviderMetrics {
+        self.counters.snapshot("external_command")
+    }
 }
 
 #[derive(Clone)]
 struct HookHostPolicy {
     queue_capacity: usize,
-    failure_threshold: u32,
-    circuit_breaker_open_duration: Duration,
+    breaker: CircuitBreakerPolicy,
 }
 
 impl Default for HookHostPolicy {
     fn default() -> Self {
         Self {
             queue_capacity: HOOK_HOST_QUEUE_CAPACITY,
-            failure_threshold: HOOK_HOST_FAILURE_THRESHOLD,
-            circuit_breaker_open_duration: HOOK_HOST_CIRCUIT_BREAKER_OPEN_DURATION,
-        }
-    }
-}
-
-#[derive(Default)]
-struct HookHostCircuitBreaker {
-    consecutive_failures: u32,
-    open_until: Option<Instant>,
-}
-
-impl HookHostCircuitBreaker {
-    fn is_open(&self) -> bool {
-        self.open_until
-            .is_some_and(|open_until| Instant::now() < open_until)
-    }
-
-    fn on_success(&mut self) {
-        self.consecutive_failures = 0;
-        self.open_until = None;
-    }
-
-    fn on_failure(&mut self, policy: &HookHostPolicy) {
-        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
-        if self.consecutive_failures >= policy.failure_threshold {
-            self.open_until = Some(Instant::now() + policy.circuit_breaker_open_duration);
+            breaker: CircuitBreakerPolicy {
+                failure_threshold: HOOK_HOST_FAILURE_THRESHOLD,
+                open_duration: HOOK_HOST_CIRCUIT_BREAKER_OPEN_DURATION,
+            },
         }
     }
 }
@@ -1230,16 +1855,20 @@ enum HookHostMessage {
 struct HookHostProvider {
     tx_line: mpsc::Sender<HookHostMessage>,
     filters: CompiledEventFilters,
+    counters: std::sync::Arc<HookProviderCounters>,
 }
 
 #[derive(Clone)]
 struct HookHostSpawnConfig {
     command: Vec<String>,
     codex_home: PathBuf,
+    working_dir: PathBuf,
     sandbox_policy: SandboxPolicy,
     codex_linux_sandbox_exe: Option<PathBuf>,
     keep_last_n_payloads: usize,
     write_timeout: Option<Duration>,
+    handshake_timeout: Duration,
+    ready_timeout: Option<Duration>,
 }
 
 impl HookHostProvider {
@@ -1263,21 +1892,42 @@ impl HookHostProvider {
 
         let filters = CompiledEventFilters::compile(&hooks.host.filters);
         let write_timeout = hooks.host.timeout_sec.map(Duration::from_secs);
+        let ready_timeout = hooks.host.ready_timeout_sec.map(Duration::from_secs);
+        let working_dir = hooks
+            .host
+            .working_dir
+            .as_ref()
+            .map_or_else(|| codex_home.clone(), |dir| codex_home.join(dir));
 
         let spawn_cfg = HookHostSpawnConfig {
             command: hooks.host.command.clone(),
             codex_home,
+            working_dir,
             sandbox_policy,
             codex_linux_sandbox_exe,
             keep_last_n_payloads: hooks.keep_last_n_payloads,
             write_timeout,
+            handshake_timeout: ready_timeout.map_or(HOOK_HOST_HANDSHAKE_TIMEOUT, |timeout| {
+                timeout.max(HOOK_HOST_HANDSHAKE_TIMEOUT)
+            }),
+            ready_timeout,
         };
 
         let policy = HookHostPolicy::default();
         let (tx_line, rx_line) = mpsc::channel(policy.queue_capacity);
-        tokio::spawn(run_hook_host_manager(rx_line, spawn_cfg, policy));
-
-        Some(Self { tx_line, filters })
+        let counters = std::sync::Arc::new(HookProviderCounters::default());
+        tokio::spawn(run_hook_host_manager(
+            rx_line,
+            spawn_cfg,
+            policy,
+            counters.clone(),
+        ));
+
+        Some(Self {
+            tx_line,
+            filters,
+            counters,
+        })
     }
 }
 
@@ -1300,6 +1950,10 @@ impl HookProvider for HookHostProvider {
             warn!("hook host queue full; dropping hook event");
         }
     }
+
+    fn metrics(&self) -> HookProviderMetrics {
+        self.counters.snapshot("host")
+    }
 }
 
 fn resolve_hook_host_sandbox_policy(
@@ -1318,6 +1972,35 @@ fn resolve_hook_host_sandbox_policy(
     }
 }
 
+#[derive(Serialize)]
+struct HookHostHello {
+    #[serde(rename = "type")]
+    ty: &'static str,
+    supported_schema_versions: &'static [u32],
+}
+
+#[derive(Deserialize)]
+struct HookHostHelloReply {
+    #[serde(rename = "type")]
+    ty: String,
+    schema_version: u32,
+}
+
+#[derive(Debug, thiserror::Error)]
+enum HookHostSpawnError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(
+        "hook host only supports payload schema_version {host}, but xcodex sends {supported:?}; update the hook host"
+    )]
+    IncompatibleSchema {
+        host: u32,
+        supported: &'static [u32],
+    },
+    #[error("hook host did not answer the readiness ping within {}ms", .timeout.as_millis())]
+    NotReady { timeout: Duration },
+}
+
 #[derive(Serialize)]
 struct HookHostLine<'a> {
     schema_version: u32,
@@ -1331,8 +2014,9 @@ async fn run_hook_host_manager(
     mut rx_line: mpsc::Receiver<HookHostMessage>,
     spawn_cfg: HookHostSpawnConfig,
     policy: HookHostPolicy,
+    counters: std::sync::Arc<HookProviderCounters>,
 ) {
-    let mut breaker = HookHostCircuitBreaker::default();
+    let mut breaker = HookCircuitBreaker::new(policy.breaker, counters.clone());
     let mut child: Option<tokio::process::Child> = None;
     let mut stdin: Option<tokio::process::ChildStdin> = None;
     let mut sequence: u64 = 0;
@@ -1346,12 +2030,23 @@ async fn run_hook_host_manager(
         if child.is_none() || stdin.is_none() {
             match spawn_hook_host_process(&spawn_cfg).await {
                 Ok((next_child, next_stdin)) => {
+                    counters.record_command_spawned();
                     child = Some(next_child);
                     stdin = Some(next_stdin);
                 }
-                Err(e) => {
+                Err(
+                    err @ (HookHostSpawnError::IncompatibleSchema { .. }
+                    | HookHostSpawnError::NotReady { .. }),
+                ) => {
+                    error!("{err}");
+                    counters.record_failure();
+                    breaker.trip();
+                    continue;
+                }
+                Err(HookHostSpawnError::Io(e)) => {
                     warn!("failed to spawn hook host: {e}");
-                    breaker.on_failure(&policy);
+                    counters.record_failure();
+                    breaker.on_failure();
                     continue;
                 }
             }
@@ -1373,7 +2068,8 @@ async fn run_hook_host_manager(
 
         let Ok(mut line) = serde_json::to_vec(&line) else {
             error!("failed to serialise hook host payload");
-            breaker.on_failure(&policy);
+            counters.record_failure();
+            breaker.on_failure();
             continue;
         };
 
@@ -1394,11 +2090,12 @@ async fn run_hook_host_manager(
             }
             Err(Some(err)) => {
                 warn!("failed to write hook event to host stdin: {err}");
+                counters.record_failure();
                 stdin = None;
                 if let Some(mut child) = child.take() {
                     let _ = child.start_kill();
                 }
-                breaker.on_failure(&policy);
+                breaker.on_failure();
             }
             Err(None) => {
                 let Some(timeout) = spawn_cfg.write_timeout else {
@@ -1406,11 +2103,12 @@ async fn run_hook_host_manager(
                 };
                 let timeout_ms = timeout.as_millis();
                 warn!("timeout writing hook event to host stdin after {timeout_ms}ms");
+                counters.record_timeout();
                 stdin = None;
                 if let Some(mut child) = child.take() {
                     let _ = child.start_kill();
                 }
-                breaker.on_failure(&policy);
+                breaker.on_failure();
             }
         }
     }
@@ -1496,40 +2194,104 @@ fn downgrade_hook_host_sandbox_if_unavailable(
     }
 }
 
-async fn spawn_hook_host_process(
-    cfg: &HookHostSpawnConfig,
-) -> io::Result<(tokio::process::Child, tokio::process::ChildStdin)> {
-    #[allow(clippy::indexing_slicing)]
-    let program = cfg.command[0].clone();
-    #[allow(clippy::indexing_slicing)]
-    let args: Vec<String> = cfg.command[1..].to_vec();
-
-    let (stdout, stderr) = open_hook_host_log_files(&cfg.codex_home, cfg.keep_last_n_payloads);
-    let command_cwd = cfg.codex_home.clone();
-    let sandbox_policy_cwd = cfg.codex_home.clone();
+/// Reported when a restrictive sandbox is requested for the hook host on Windows.
+pub const HOOK_HOST_WINDOWS_SANDBOX_UNSUPPORTED: &str =
+    "hook host sandboxing is not supported on Windows yet; the host runs without sandboxing";
 
-    let mut sandbox = match &cfg.sandbox_policy {
+/// Platform sandbox the hook host is spawned under for `sandbox_policy`,
+/// together with the warning to surface when the requested sandbox cannot be
+/// applied on this machine.
+fn hook_host_platform_sandbox(
+    sandbox_policy: &SandboxPolicy,
+    codex_linux_sandbox_exe: &Option<PathBuf>,
+) -> (crate::exec::SandboxType, Option<&'static str>) {
+    let sandbox = match sandbox_policy {
         SandboxPolicy::DangerFullAccess | SandboxPolicy::ExternalSandbox { .. } => {
             crate::exec::SandboxType::None
         }
+        // The restricted-token sandbox is not wired up for the hook host, so a
+        // restrictive policy on Windows always ends up unsandboxed.
+        _ if cfg!(windows) => {
+            return (
+                crate::exec::SandboxType::None,
+                Some(HOOK_HOST_WINDOWS_SANDBOX_UNSUPPORTED),
+            );
+        }
         _ => crate::safety::get_platform_sandbox(false).unwrap_or(crate::exec::SandboxType::None),
     };
 
-    let downgraded =
-        downgrade_hook_host_sandbox_if_unavailable(sandbox, &cfg.codex_linux_sandbox_exe);
+    let downgraded = downgrade_hook_host_sandbox_if_unavailable(sandbox, codex_linux_sandbox_exe);
     if sandbox == crate::exec::SandboxType::LinuxSeccomp
         && downgraded == crate::exec::SandboxType::None
     {
-        warn!(
-            "linux sandbox requested for hook host, but codex_linux_sandbox_exe is not configured; spawning unsandboxed"
+        return (
+            downgraded,
+            Some(
+                "linux sandbox requested for hook host, but codex_linux_sandbox_exe is not configured; spawning unsandboxed",
+            ),
         );
     }
-    sandbox = downgraded;
 
-    if sandbox == crate::exec::SandboxType::WindowsRestrictedToken {
-        warn!("hook host sandboxing is not supported on Windows yet; spawning unsandboxed");
+    (downgraded, None)
+}
+
+/// Effective sandbox for the hook host, as reported by
+/// `xcodex hooks doctor python-host`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HookHostSandboxPreview {
+    pub sandbox_policy: SandboxPolicy,
+    pub warning: Option<&'static str>,
+}
+
+/// Resolve the sandbox the hook host would run under without spawning it.
+pub fn preview_hook_host_sandbox(
+    codex_home: &Path,
+    session_sandbox_policy: &SandboxPolicy,
+    hooks: &HooksConfig,
+    codex_linux_sandbox_exe: &Option<PathBuf>,
+) -> HookHostSandboxPreview {
+    let sandbox_policy =
+        resolve_hook_host_sandbox_policy(codex_home, session_sandbox_policy, hooks);
+    let (_, warning) = hook_host_platform_sandbox(&sandbox_policy, codex_linux_sandbox_exe);
+    HookHostSandboxPreview {
+        sandbox_policy,
+        warning,
+    }
+}
+
+/// Spawns the hook host and performs the startup handshake.
+///
+/// Every spawn negotiates the schema version again, so a host upgraded mid-session is checked
+/// against the versions xcodex supports before it receives events.
+async fn spawn_hook_host_process(
+    cfg: &HookHostSpawnConfig,
+) -> Result<(tokio::process::Child, tokio::process::ChildStdin), HookHostSpawnError> {
+    #[allow(clippy::indexing_slicing)]
+    let program = cfg.command[0].clone();
+    #[allow(clippy::indexing_slicing)]
+    let args: Vec<String> = cfg.command[1..].to_vec();
+
+    let log_file = open_hook_host_log_file(&cfg.codex_home, cfg.keep_last_n_payloads);
+    let stderr = match log_file.as_ref().map(File::try_clone) {
+        Some(Ok(file)) => Stdio::from(file),
+        Some(Err(e)) => {
+            warn!("failed to clone hook host log file handle: {e}");
+            Stdio::null()
+        }
+        None => Stdio::null(),
+    };
+    let command_cwd = cfg.working_dir.clone();
+    let sandbox_policy_cwd = cfg.working_dir.clone();
+
+    let (sandbox, sandbox_warning) =
+        hook_host_platform_sandbox(&cfg.sandbox_policy, &cfg.codex_linux_sandbox_exe);
+    if let Some(sandbox_warning) = sandbox_warning {
+        warn!("{sandbox_warning}");
     }
 
+    #[cfg(windows)]
+    let (program, args) = resolve_windows_hook_host_command(program, args, &command_cwd);
+
     let invocation = build_hook_host_spawn_invocation(
         program,
         args,
@@ -1555,22 +2317,170 @@ async fn spawn_hook_host_process(
     std_cmd.current_dir(command_cwd);
     std_cmd.env("CODEX_HOME", cfg.codex_home.as_os_str());
     std_cmd.stdin(Stdio::piped());
-    std_cmd.stdout(stdout);
+    std_cmd.stdout(Stdio::piped());
     std_cmd.stderr(stderr);
 
     let mut cmd = tokio::process::Command::from(std_cmd);
     cmd.kill_on_drop(true);
 
     let mut child = cmd.spawn()?;
-    let stdin = child
+    let mut stdin = child
         .stdin
         .take()
         .ok_or_else(|| io::Error::other("hook host stdin pipe not available"))?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| io::Error::other("hook host stdout pipe not available"))?;
+    let mut stdout_lines = tokio::io::BufReader::new(stdout).lines();
+
+    let mut log_file = log_file;
+    negotiate_hook_host_schema(
+        &mut stdin,
+        &mut stdout_lines,
+        &mut log_file,
+        cfg.handshake_timeout,
+    )
+    .await?;
+    if let Some(timeout) = cfg.ready_timeout {
+        wait_for_hook_host_ready(&mut stdin, &mut stdout_lines, &mut log_file, timeout).await?;
+    }
+    forward_hook_host_stdout(stdout_lines, log_file);
 
     Ok((child, stdin))
 }
 
-fn open_hook_host_log_files(codex_home: &Path, keep_last_n: usize) -> (Stdio, Stdio) {
+/// Resolves the host program on Windows, respecting PATH + PATHEXT (e.g. `host` -> `host.cmd`).
+///
+/// Programs that cannot be resolved run through `cmd /C`, mirroring how updates are run, so
+/// `.cmd`/`.bat` hosts relative to CODEX_HOME still start.
+#[cfg(windows)]
+fn resolve_windows_hook_host_command(
+    program: String,
+    args: Vec<String>,
+    cwd: &Path,
+) -> (String, Vec<String>) {
+    match which::which_in(&program, std::env::var_os("PATH"), cwd) {
+        Ok(path) => (path.to_string_lossy().to_string(), args),
+        Err(_) => (
+            "cmd".to_string(),
+            ["/C".to_string(), program]
+                .into_iter()
+                .chain(args)
+                .collect(),
+        ),
+    }
+}
+
+async fn send_hook_host_hello(stdin: &mut tokio::process::ChildStdin) -> io::Result<()> {
+    let hello = HookHostHello {
+        ty: "hello",
+        supported_schema_versions: HOOK_HOST_SUPPORTED_SCHEMA_VERSIONS,
+    };
+    let mut line = serde_json::to_vec(&hello).map_err(io::Error::other)?;
+    line.push(b'\n');
+    stdin.write_all(&line).await
+}
+
+/// Sends the `hello` handshake and checks the payload schema version the host declares.
+///
+/// Stdout lines that are not a hello reply are copied to the host log and skipped. Hosts that
+/// predate the handshake never reply; they are sent the current schema so existing hosts keep
+/// working.
+async fn negotiate_hook_host_schema(
+    stdin: &mut tokio::process::ChildStdin,
+    stdout_lines: &mut tokio::io::Lines<tokio::io::BufReader<tokio::process::ChildStdout>>,
+    log_file: &mut Option<File>,
+    timeout: Duration,
+) -> Result<(), HookHostSpawnError> {
+    send_hook_host_hello(stdin).await?;
+
+    let wait_for_hello = async {
+        while let Some(line) = stdout_lines.next_line().await? {
+            match serde_json::from_str::<HookHostHelloReply>(line.trim()) {
+                Ok(reply) if reply.ty == "hello" => return Ok(Some(reply.schema_version)),
+                _ => {
+                    if let Some(file) = log_file.as_mut() {
+                        let _ = writeln!(file, "{line}");
+                    }
+                }
+            }
+        }
+        Ok::<_, io::Error>(None)
+    };
+
+    let schema_version = match tokio::time::timeout(timeout, wait_for_hello).await {
+        Ok(Ok(Some(schema_version))) => schema_version,
+        Ok(Ok(None)) | Err(_) => {
+            warn!(
+                "hook host did not reply to the hello handshake; assuming schema_version {HOOK_PAYLOAD_SCHEMA_VERSION}"
+            );
+            return Ok(());
+        }
+        Ok(Err(e)) => return Err(e.into()),
+    };
+    if !HOOK_HOST_SUPPORTED_SCHEMA_VERSIONS.contains(&schema_version) {
+        return Err(HookHostSpawnError::IncompatibleSchema {
+            host: schema_version,
+            supported: HOOK_HOST_SUPPORTED_SCHEMA_VERSIONS,
+        });
+    }
+
+    Ok(())
+}
+
+/// Sends `{"type":"ping"}` and waits for the host to answer `{"type":"pong"}`.
+///
+/// Other stdout lines printed while the host starts up are copied to the host log.
+async fn wait_for_hook_host_ready(
+    stdin: &mut tokio::process::ChildStdin,
+    stdout_lines: &mut tokio::io::Lines<tokio::io::BufReader<tokio::process::ChildStdout>>,
+    log_file: &mut Option<File>,
+    timeout: Duration,
+) -> Result<(), HookHostSpawnError> {
+    stdin.write_all(b"{\"type\":\"ping\"}\n").await?;
+
+    let wait_for_pong = async {
+        while let Some(line) = stdout_lines.next_line().await? {
+            let is_pong = serde_json::from_str::<Value>(line.trim())
+                .is_ok_and(|value| value.get("type").and_then(Value::as_str) == Some("pong"));
+            if is_pong {
+                return Ok(());
+            }
+            if let Some(file) = log_file.as_mut() {
+                let _ = writeln!(file, "{line}");
+            }
+        }
+        Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "hook host exited before answering the readiness ping",
+        ))
+    };
+
+    match tokio::time::timeout(timeout, wait_for_pong).await {
+        Ok(result) => result.map_err(HookHostSpawnError::from),
+        Err(_) => Err(HookHostSpawnError::NotReady { timeout }),
+    }
+}
+
+/// Drains host stdout into the host log so a chatty host never blocks on a full pipe.
+fn forward_hook_host_stdout(
+    mut stdout_lines: tokio::io::Lines<tokio::io::BufReader<tokio::process::ChildStdout>>,
+    mut log_file: Option<File>,
+) {
+    tokio::spawn(async move {
+        while let Ok(Some(line)) = stdout_lines.next_line().await {
+            if let Some(file) = log_file.as_mut()
+                && let Err(e) = writeln!(file, "{line}")
+            {
+                warn!("failed to write hook host stdout to log: {e}");
+                log_file = None;
+            }
+        }
+    });
+}
+
+fn open_hook_host_log_file(codex_home: &Path, keep_last_n: usize) -> Option<File> {
     let logs_dir = codex_home
         .join("tmp")
         .join("hooks")
@@ -1578,7 +2488,7 @@ fn open_hook_host_log_files(codex_home: &Path, keep_last_n: usize) -> (Stdio, St
         .join("logs");
     if let Err(e) = ensure_dir(&logs_dir) {
         warn!("failed to create hook host log dir: {e}");
-        return (Stdio::null(), Stdio::null());
+        return None;
     }
 
     let timestamp_ms = SystemTime::now()
@@ -1596,7 +2506,7 @@ fn open_hook_host_log_files(codex_home: &Path, keep_last_n: usize) -> (Stdio, St
         Ok(file) => file,
         Err(e) => {
             warn!("failed to open hook host log file: {e}");
-            return (Stdio::null(), Stdio::null());
+            return None;
         }
     };
 
@@ -1608,15 +2518,169 @@ fn open_hook_host_log_files(codex_home: &Path, keep_last_n: usize) -> (Stdio, St
         warn!("failed to prune hook host log files: {e}");
     }
 
-    let stderr = match file.try_clone() {
-        Ok(clone) => clone,
-        Err(e) => {
-            warn!("failed to clone hook host log file handle: {e}");
-            return (Stdio::from(file), Stdio::null());
+    Some(file)
+}
+
+#[derive(Clone)]
+struct WebhookPolicy {
+    queue_capacity: usize,
+    breaker: CircuitBreakerPolicy,
+}
+
+impl Default for WebhookPolicy {
+    fn default() -> Self {
+        Self {
+            queue_capacity: WEBHOOK_QUEUE_CAPACITY,
+            breaker: CircuitBreakerPolicy {
+                failure_threshold: WEBHOOK_FAILURE_THRESHOLD,
+                open_duration: WEBHOOK_CIRCUIT_BREAKER_OPEN_DURATION,
+            },
         }
-    };
+    }
+}
+
+/// Forwards hook payloads to `hooks.webhook.url` as JSON `POST` requests, one at a time and in
+/// emission order, from a background task.
+struct WebhookHookProvider {
+    tx_payload: mpsc::Sender<std::sync::Arc<HookPayload>>,
+    events: HashSet<HookEventKey>,
+    counters: std::sync::Arc<HookProviderCounters>,
+}
+
+#[derive(Clone)]
+struct WebhookRequestConfig {
+    url: reqwest::Url,
+    headers: reqwest::header::HeaderMap,
+    timeout: Duration,
+}
+
+impl WebhookHookProvider {
+    fn new(hooks: &HooksConfig) -> Option<Self> {
+        let url = hooks.webhook.url.as_deref().filter(|url| !url.is_empty())?;
+        let url = match reqwest::Url::parse(url) {
+            Ok(url) => url,
+            Err(err) => {
+                warn!("invalid hooks.webhook.url {url:?}: {err}; webhook is disabled");
+                return None;
+            }
+        };
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        for (name, value) in &hooks.webhook.headers {
+            match (
+                reqwest::header::HeaderName::from_bytes(name.as_bytes()),
+                reqwest::header::HeaderValue::from_str(value),
+            ) {
+                (Ok(name), Ok(mut value)) => {
+                    value.set_sensitive(true);
+                    headers.insert(name, value);
+                }
+                _ => warn!("ignoring invalid hooks.webhook.headers entry: {name}"),
+            }
+        }
+
+        let events = hooks
+            .webhook
+            .events
+            .iter()
+            .filter_map(|name| {
+                let key = canonical_event_key(name);
+                if key.is_none() {
+                    warn!("unknown event in hooks.webhook.events: {name}");
+                }
+                key
+            })
+            .collect();
+
+        let request_cfg = WebhookRequestConfig {
+            url,
+            headers,
+            timeout: hooks
+                .webhook
+                .timeout_sec
+                .map_or(WEBHOOK_DEFAULT_TIMEOUT, Duration::from_secs),
+        };
+
+        let policy = WebhookPolicy::default();
+        let (tx_payload, rx_payload) = mpsc::channel(policy.queue_capacity);
+        let counters = std::sync::Arc::new(HookProviderCounters::default());
+        tokio::spawn(run_webhook_sender(
+            rx_payload,
+            request_cfg,
+            policy,
+            counters.clone(),
+        ));
+
+        Some(Self {
+            tx_payload,
+            events,
+            counters,
+        })
+    }
+}
+
+impl HookProvider for WebhookHookProvider {
+    fn on_event(&self, event: &HookEvent) {
+        let key = HookEventKey::from_notification(event.notification());
+        if !self.events.is_empty() && !self.events.contains(&key) {
+            return;
+        }
+
+        let hook_event_name = default_hook_event_name(event);
+        let payload = std::sync::Arc::new(HookPayload::from_event(event, &hook_event_name));
+        if self.tx_payload.try_send(payload).is_err() {
+            warn!("hook webhook queue full; dropping hook event");
+        }
+    }
+
+    fn handles_event(&self, key: HookEventKey) -> bool {
+        self.events.is_empty() || self.events.contains(&key)
+    }
+
+    fn metrics(&self) -> HookProviderMetrics {
+        self.counters.snapshot("webhook")
+    }
+}
 
-    (Stdio::from(file), Stdio::from(stderr))
+async fn run_webhook_sender(
+    mut rx_payload: mpsc::Receiver<std::sync::Arc<HookPayload>>,
+    request_cfg: WebhookRequestConfig,
+    policy: WebhookPolicy,
+    counters: std::sync::Arc<HookProviderCounters>,
+) {
+    let client = crate::default_client::build_reqwest_client();
+    let mut breaker = HookCircuitBreaker::new(policy.breaker, counters.clone());
+
+    while let Some(payload) = rx_payload.recv().await {
+        if breaker.is_open() {
+            warn!("skipping hook webhook due to open circuit breaker");
+            continue;
+        }
+
+        counters.record_command_spawned();
+        let result = client
+            .post(request_cfg.url.clone())
+            .headers(request_cfg.headers.clone())
+            .timeout(request_cfg.timeout)
+            .json(payload.as_ref())
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status);
+        match result {
+            Ok(_) => breaker.on_success(),
+            Err(err) if err.is_timeout() => {
+                let timeout_ms = request_cfg.timeout.as_millis();
+                warn!("hook webhook request timed out after {timeout_ms}ms");
+                counters.record_timeout();
+                breaker.on_failure();
+            }
+            Err(err) => {
+                warn!("hook webhook request failed: {err}");
+                counters.record_failure();
+                breaker.on_failure();
+            }
+        }
+    }
 }
 
 fn resolve_inproc_hooks(hooks: &HooksConfig) -> Vec<InprocHookEntry> {
@@ -1647,6 +2711,13 @@ fn resolve_inproc_hooks(hooks: &HooksConfig) -> Vec<InprocHookEntry> {
                     timeout: None,
                 });
             }
+            INPROC_COST_SUMMARY_HOOK_NAME => {
+                resolved.push(InprocHookEntry {
+                    name: hook_name,
+                    hook: std::sync::Arc::new(CostSummaryHook::default()),
+                    timeout: None,
+                });
+            }
             INPROC_PYO3_HOOK_NAME => {
                 #[cfg(feature = "pyo3-hooks")]
                 {
@@ -1718,6 +2789,14 @@ fn resolve_inproc_hooks(hooks: &HooksConfig) -> Vec<InprocHookEntry> {
     resolved
 }
 
+fn kill_switch_enabled(value: Option<OsString>) -> bool {
+    value.is_some_and(|value| {
+        let value = value.to_string_lossy();
+        let value = value.trim();
+        !value.is_empty() && value != "0"
+    })
+}
+
 impl UserHooks {
     pub(crate) fn new(
         codex_home: PathBuf,
@@ -1728,14 +2807,59 @@ impl UserHooks {
         exclusion: ExclusionConfig,
         cwd: PathBuf,
     ) -> Self {
-        let mut providers: Vec<std::sync::Arc<dyn HookProvider>> = Vec::new();
+        Self::new_with_kill_switch(
+            kill_switch_enabled(std::env::var_os(DISABLE_HOOKS_ENV_VAR)),
+            codex_home,
+            hooks,
+            tx_event,
+            session_sandbox_policy,
+            codex_linux_sandbox_exe,
+            exclusion,
+            cwd,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn new_with_kill_switch(
+        disabled_by_env: bool,
+        codex_home: PathBuf,
+        hooks: HooksConfig,
+        tx_event: Option<Sender<Event>>,
+        session_sandbox_policy: SandboxPolicy,
+        codex_linux_sandbox_exe: Option<PathBuf>,
+        exclusion: ExclusionConfig,
+        cwd: PathBuf,
+    ) -> Self {
+        if disabled_by_env {
+            static LOGGED: std::sync::Once = std::sync::Once::new();
+            LOGGED.call_once(|| {
+                warn!("hooks are disabled by {DISABLE_HOOKS_ENV_VAR}; no hook providers will run");
+            });
+            return Self {
+                bus: HookBus {
+                    providers: Vec::new(),
+                    disabled_events: HashSet::new(),
+                    dedupe: None,
+                    events_emitted: std::sync::Arc::default(),
+                },
+                payload_sanitizer: None,
+                output_preview_bytes: hooks.output_preview_bytes,
+                session_start_blocking: None,
+            };
+        }
+
+        let mut providers: Vec<(&str, std::sync::Arc<dyn HookProvider>)> = Vec::new();
 
         let inproc_hooks = resolve_inproc_hooks(&hooks);
         if !inproc_hooks.is_empty() {
-            providers.push(std::sync::Arc::new(InprocHooksProvider::new(
-                codex_home.clone(),
-                inproc_hooks,
-            )));
+            providers.push((
+                "inproc",
+                std::sync::Arc::new(InprocHooksProvider::new(
+                    codex_home.clone(),
+                    inproc_hooks,
+                    hooks.dry_run,
+                )),
+            ));
         }
 
         if let Some(host_provider) = HookHostProvider::new(
@@ -1744,7 +2868,11 @@ impl UserHooks {
             session_sandbox_policy,
             codex_linux_sandbox_exe,
         ) {
-            providers.push(std::sync::Arc::new(host_provider));
+            providers.push(("host", std::sync::Arc::new(host_provider)));
+        }
+
+        if let Some(webhook_provider) = WebhookHookProvider::new(&hooks) {
+            providers.push(("webhook", std::sync::Arc::new(webhook_provider)));
         }
 
         let payload_sanitizer = if exclusion.layer_hook_sanitization_enabled() {
@@ -1753,16 +2881,46 @@ impl UserHooks {
             None
         };
 
-        providers.push(std::sync::Arc::new(ExternalCommandHooksProvider::new(
-            codex_home, hooks, tx_event,
-        )));
+        let disabled_events = compile_disabled_events(&hooks.disabled_events);
+        let dedupe = HookEventDeduper::new(hooks.dedupe_window_ms).map(std::sync::Arc::new);
+        let output_preview_bytes = hooks.output_preview_bytes;
+        let session_start_blocking = (hooks.session_start_blocking_ms > 0)
+            .then(|| Duration::from_millis(hooks.session_start_blocking_ms));
+        let provider_order = resolve_provider_order(&hooks.provider_order);
+        providers.push((
+            "external",
+            std::sync::Arc::new(ExternalCommandHooksProvider::new(
+                codex_home, hooks, tx_event,
+            )),
+        ));
+        providers.sort_by_key(|(name, _)| provider_order.iter().position(|known| known == name));
 
         Self {
-            bus: HookBus { providers },
+            bus: HookBus {
+                providers: providers
+                    .into_iter()
+                    .map(|(_, provider)| provider)
+                    .collect(),
+                disabled_events,
+                dedupe,
+                events_emitted: std::sync::Arc::default(),
+            },
             payload_sanitizer,
+            output_preview_bytes,
+            session_start_blocking,
         }
     }
 
+    pub(crate) fn metrics(&self) -> HookMetrics {
+        self.bus.metrics()
+    }
+
+    /// Maximum number of tool output bytes to include as `output_preview` in
+    /// `tool-call-finished` payloads; 0 disables the preview.
+    pub(crate) fn output_preview_bytes(&self) -> usize {
+        self.output_preview_bytes
+    }
+
     fn sanitize_text(&self, text: String) -> String {
         self.payload_sanitizer
             .as_ref()
@@ -1797,6 +2955,7 @@ impl UserHooks {
         cwd: String,
         input_messages: Vec<String>,
         last_assistant_message: Option<String>,
+        session_stats: HookSessionStats,
     ) {
         self.bus.emit(HookNotification::AgentTurnComplete {
             thread_id,
@@ -1804,6 +2963,7 @@ impl UserHooks {
             cwd,
             input_messages: self.sanitize_vec_text(input_messages),
             last_assistant_message: self.sanitize_opt_text(last_assistant_message),
+            session_stats,
         });
     }
 
@@ -1846,6 +3006,8 @@ impl UserHooks {
             thread_id,
             cwd,
             "permission_prompt".to_string(),
+            NotificationCategory::ApprovalNeeded,
+            Some(NotificationPriority::High),
             Some(notification_message),
             Some("Permission requested".to_string()),
         );
@@ -1891,6 +3053,8 @@ impl UserHooks {
             thread_id,
             cwd,
             "permission_prompt".to_string(),
+            NotificationCategory::ApprovalNeeded,
+            Some(NotificationPriority::High),
             Some(notification_message),
             Some("Permission requested".to_string()),
         );
@@ -1929,17 +3093,53 @@ impl UserHooks {
             thread_id,
             cwd,
             "elicitation_dialog".to_string(),
+            NotificationCategory::ApprovalNeeded,
+            Some(NotificationPriority::High),
             Some(notification_message),
             Some("Elicitation".to_string()),
         );
     }
 
-    pub(crate) fn session_start(&self, thread_id: String, cwd: String, session_source: String) {
-        self.bus.emit(HookNotification::SessionStart {
+    /// Whether a `session_start` event would reach any provider, so callers can skip gathering
+    /// payload fields (e.g. git metadata) nobody will see.
+    pub(crate) fn wants_session_start(&self) -> bool {
+        self.bus.is_active_for(HookEventKey::SessionStart)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    /// Emits `session-start`, waiting up to `hooks.session_start_blocking_ms` for bounded command
+    /// hooks to finish when configured.
+    pub(crate) async fn session_start(
+        &self,
+        thread_id: String,
+        cwd: String,
+        session_source: String,
+        git_branch: Option<String>,
+        git_dirty: Option<bool>,
+        git_commit: Option<String>,
+        git_remote: Option<String>,
+    ) {
+        let notification = HookNotification::SessionStart {
             thread_id,
             cwd,
             session_source,
-        });
+            git_branch,
+            git_dirty,
+            git_commit,
+            git_remote,
+        };
+        match self.session_start_blocking {
+            Some(budget) => self.bus.emit_sync(notification, budget).await,
+            None => self.bus.emit(notification),
+        }
+    }
+
+    /// Dispatches a synthetic event (from the app-server `hook/test/emit` request) and waits
+    /// for bounded command hooks to finish. Returns the metrics recorded for this dispatch only.
+    pub(crate) async fn emit_test_event(&self, notification: HookNotification) -> HookMetrics {
+        self.bus
+            .emit_measured(notification, TEST_HOOK_EVENT_BUDGET)
+            .await
     }
 
     pub(crate) fn session_end(&self, thread_id: String, cwd: String, session_source: String) {
@@ -1958,19 +3158,33 @@ impl UserHooks {
         });
     }
 
-    pub(crate) fn pre_compact(&self, thread_id: String, cwd: String, trigger: String) {
+    pub(crate) fn pre_compact(
+        &self,
+        thread_id: String,
+        cwd: String,
+        trigger: String,
+        tokens_before: u64,
+        tokens_after: Option<u64>,
+        messages_dropped: Option<u64>,
+    ) {
         self.bus.emit(HookNotification::PreCompact {
             thread_id,
             cwd,
             trigger: self.sanitize_text(trigger),
+            tokens_before,
+            tokens_after,
+            messages_dropped,
         });
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn notification(
         &self,
         thread_id: String,
         cwd: String,
         notification_type: String,
+        category: NotificationCategory,
+        priority: Option<NotificationPriority>,
         message: Option<String>,
         title: Option<String>,
     ) {
@@ -1978,23 +3192,32 @@ impl UserHooks {
             thread_id,
             cwd,
             notification_type,
+            category,
+            priority,
             message: self.sanitize_opt_text(message),
             title: self.sanitize_opt_text(title),
         });
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn subagent_stop(
         &self,
         thread_id: String,
         cwd: String,
         subagent: String,
+        subagent_id: String,
+        subagent_name: String,
         status: String,
+        result_summary: Option<String>,
     ) {
         self.bus.emit(HookNotification::SubagentStop {
             thread_id,
             cwd,
             subagent: self.sanitize_text(subagent),
+            subagent_id,
+            subagent_name: self.sanitize_text(subagent_name),
             status: self.sanitize_text(status),
+            result_summary: self.sanitize_opt_text(result_summary),
         });
     }
 
@@ -2012,6 +3235,7 @@ impl UserHooks {
         tool_count: usize,
         parallel_tool_calls: bool,
         has_output_schema: bool,
+        rate_limits: Option<&RateLimitSnapshot>,
     ) {
         self.bus.emit(HookNotification::ModelRequestStarted {
             thread_id,
@@ -2025,6 +3249,7 @@ impl UserHooks {
             tool_count,
             parallel_tool_calls,
             has_output_schema,
+            rate_limits: rate_limits.map(HookRateLimitSnapshot::from),
         });
     }
 
@@ -2036,6 +3261,7 @@ impl UserHooks {
         cwd: String,
         model_request_id: Uuid,
         attempt: u32,
+        model: String,
         response_id: String,
         token_usage: Option<TokenUsage>,
         needs_follow_up: bool,
@@ -2046,6 +3272,7 @@ impl UserHooks {
             cwd,
             model_request_id,
             attempt,
+            model,
             response_id,
             token_usage,
             needs_follow_up,
@@ -2063,6 +3290,8 @@ impl UserHooks {
         tool_name: String,
         call_id: String,
         tool_input: Option<Value>,
+        command: Option<Vec<String>>,
+        paths: Option<Vec<String>>,
     ) {
         self.bus.emit(HookNotification::ToolCallStarted {
             thread_id,
@@ -2073,6 +3302,8 @@ impl UserHooks {
             tool_name,
             call_id,
             tool_input: self.sanitize_value(tool_input),
+            command: command.map(|command| self.sanitize_vec_text(command)),
+            paths: paths.map(|paths| self.sanitize_vec_text(paths)),
         });
     }
 
@@ -2116,188 +3347,226 @@ impl UserHooks {
 #[derive(Clone)]
 struct HookCommandContext {
     max_stdin_payload_bytes: usize,
+    oversized_payload_strategy: HookOversizedPayloadStrategy,
     keep_last_n_payloads: usize,
     codex_home: PathBuf,
     tx_event: Option<Sender<Event>>,
     semaphore: std::sync::Arc<Semaphore>,
+    counters: std::sync::Arc<HookProviderCounters>,
+    retries: u32,
+    retry_backoff: Duration,
+    echo_to_ui: bool,
 }
 
+/// Runs a bounded command hook, retrying per `hooks.command.retries` until it exits 0. A single
+/// begin/end hook process event pair is reported for all attempts, carrying the final exit code.
 async fn run_hook_command(
     command: Vec<String>,
     payload: HookPayload,
     stdin_payload: Vec<u8>,
     ctx: HookCommandContext,
+    timeout: Option<Duration>,
 ) {
     let HookCommandContext {
         keep_last_n_payloads,
         codex_home,
         tx_event,
         semaphore,
+        counters,
+        retries,
+        retry_backoff,
+        echo_to_ui,
         ..
     } = ctx;
 
-    let _permit = semaphore.acquire().await;
     let hook_id = Uuid::new_v4();
     let event_type = payload.xcodex_event_type().to_string();
 
-    let (stdout, stderr) = open_hook_log_files(&codex_home, hook_id, keep_last_n_payloads);
+    let mut began = false;
+    let mut attempt = 0;
+    let mut log_path = None;
+    let exit_code = loop {
+        let permit = semaphore.acquire().await;
+        let exit_code = match spawn_hook_process(
+            &command,
+            &codex_home,
+            hook_id,
+            keep_last_n_payloads,
+            &counters,
+        ) {
+            Some((mut child, attempt_log_path)) => {
+                log_path = attempt_log_path;
+                if !began && let Some(tx_event) = &tx_event {
+                    let _ = tx_event
+                        .send(Event {
+                            id: "hook_process".to_string(),
+                            msg: EventMsg::HookProcessBegin(HookProcessBeginEvent {
+                                hook_id,
+                                payload_event_id: payload.event_id(),
+                                event_type: event_type.clone(),
+                                command: command.clone(),
+                                timeout_ms: timeout.map(|timeout| {
+                                    u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX)
+                                }),
+                            }),
+                        })
+                        .await;
+                }
+                began = true;
 
-    let child = {
-        let mut cmd = tokio::process::Command::new(&command[0]);
-        if command.len() > 1 {
-            cmd.args(&command[1..]);
-        }
-        cmd.stdin(Stdio::piped());
-        cmd.stdout(stdout);
-        cmd.stderr(stderr);
-        cmd.spawn()
-    };
+                if let Some(mut stdin) = child.stdin.take()
+                    && let Err(e) = stdin.write_all(&stdin_payload).await
+                {
+                    warn!("failed to write hook payload to stdin: {e}");
+                }
 
-    let mut child = match child {
-        Ok(child) => child,
-        Err(e) => {
-            #[allow(clippy::indexing_slicing)]
-            let program = &command[0];
-            warn!("failed to spawn hook '{program}': {e}");
-            return;
+                wait_for_hook_process(&mut child, timeout, &event_type, &counters).await
+            }
+            None => None,
+        };
+        // Release the concurrency slot while backing off so other hooks can run.
+        drop(permit);
+
+        if exit_code == Some(0) || attempt >= retries {
+            break exit_code;
         }
+        let backoff = hook_retry_backoff(retry_backoff, attempt);
+        attempt += 1;
+        let backoff_ms = backoff.as_millis();
+        warn!("retrying hook in {backoff_ms}ms (retry {attempt} of {retries}): {event_type}");
+        tokio::time::sleep(backoff).await;
     };
 
-    if let Some(tx_event) = &tx_event {
+    if began && let Some(tx_event) = &tx_event {
+        let output = if echo_to_ui {
+            log_path.as_deref().and_then(read_hook_output_for_ui)
+        } else {
+            None
+        };
         let _ = tx_event
             .send(Event {
                 id: "hook_process".to_string(),
-                msg: EventMsg::HookProcessBegin(HookProcessBeginEvent {
+                msg: EventMsg::HookProcessEnd(HookProcessEndEvent {
                     hook_id,
-                    payload_event_id: payload.event_id(),
-                    event_type: event_type.clone(),
-                    command: command.clone(),
+                    exit_code,
+                    output,
                 }),
             })
             .await;
     }
+}
 
-    if let Some(mut stdin) = child.stdin.take()
-        && let Err(e) = stdin.write_all(&stdin_payload).await
-    {
-        warn!("failed to write hook payload to stdin: {e}");
-    }
+/// Delay before retry `attempt + 1`: `base` doubled per earlier retry, capped at
+/// `HOOK_RETRY_MAX_BACKOFF`.
+fn hook_retry_backoff(base: Duration, attempt: u32) -> Duration {
+    base.saturating_mul(1 << attempt.min(16))
+        .min(HOOK_RETRY_MAX_BACKOFF)
+}
 
-    let exit_code = match child.wait().await {
-        Ok(status) => status.code(),
+/// Reads at most `HOOK_UI_OUTPUT_MAX_BYTES` of a hook's captured stdout/stderr for
+/// `hooks.echo_to_ui`. Returns `None` when the hook wrote nothing.
+fn read_hook_output_for_ui(log_path: &Path) -> Option<String> {
+    let file = match File::open(log_path) {
+        Ok(file) => file,
         Err(e) => {
-            warn!("failed waiting for hook process to exit: {e}");
-            None
+            warn!("failed to open hook log file for echo: {e}");
+            return None;
         }
     };
-    if let Some(code) = exit_code
-        && code != 0
+    let mut bytes = Vec::new();
+    if let Err(e) = file
+        .take(HOOK_UI_OUTPUT_MAX_BYTES as u64 + 1)
+        .read_to_end(&mut bytes)
     {
-        warn!("hook exited with non-zero status {code}: {event_type}");
+        warn!("failed to read hook log file for echo: {e}");
+        return None;
+    }
+    if bytes.is_empty() {
+        return None;
     }
 
-    if let Some(tx_event) = &tx_event {
-        let _ = tx_event
-            .send(Event {
-                id: "hook_process".to_string(),
-                msg: EventMsg::HookProcessEnd(HookProcessEndEvent { hook_id, exit_code }),
-            })
-            .await;
+    let text = String::from_utf8_lossy(&bytes);
+    let mut output = take_bytes_at_char_boundary(&text, HOOK_UI_OUTPUT_MAX_BYTES).to_string();
+    if bytes.len() > HOOK_UI_OUTPUT_MAX_BYTES {
+        output.push_str("\n[output truncated]");
     }
+    Some(output)
 }
 
-async fn run_hook_command_with_timeout(
-    command: Vec<String>,
-    payload: HookPayload,
-    stdin_payload: Vec<u8>,
-    ctx: HookCommandContext,
-    timeout: Duration,
-) {
-    let HookCommandContext {
-        keep_last_n_payloads,
-        codex_home,
-        tx_event,
-        semaphore,
-        ..
-    } = ctx;
-
-    let _permit = semaphore.acquire().await;
-    let hook_id = Uuid::new_v4();
-    let event_type = payload.xcodex_event_type().to_string();
-
-    let (stdout, stderr) = open_hook_log_files(&codex_home, hook_id, keep_last_n_payloads);
+fn spawn_hook_process(
+    command: &[String],
+    codex_home: &Path,
+    hook_id: Uuid,
+    keep_last_n_payloads: usize,
+    counters: &HookProviderCounters,
+) -> Option<(tokio::process::Child, Option<PathBuf>)> {
+    let (stdout, stderr, log_path) = open_hook_log_files(codex_home, hook_id, keep_last_n_payloads);
 
     let child = {
         let mut cmd = tokio::process::Command::new(&command[0]);
         if command.len() > 1 {
             cmd.args(&command[1..]);
         }
+        cmd.envs(command_hook_env(codex_home));
         cmd.stdin(Stdio::piped());
         cmd.stdout(stdout);
         cmd.stderr(stderr);
         cmd.spawn()
     };
 
-    let mut child = match child {
-        Ok(child) => child,
+    match child {
+        Ok(child) => {
+            counters.record_command_spawned();
+            Some((child, log_path))
+        }
         Err(e) => {
             #[allow(clippy::indexing_slicing)]
             let program = &command[0];
             warn!("failed to spawn hook '{program}': {e}");
-            return;
+            counters.record_failure();
+            None
         }
-    };
-
-    if let Some(tx_event) = &tx_event {
-        let _ = tx_event
-            .send(Event {
-                id: "hook_process".to_string(),
-                msg: EventMsg::HookProcessBegin(HookProcessBeginEvent {
-                    hook_id,
-                    payload_event_id: payload.event_id(),
-                    event_type: event_type.clone(),
-                    command: command.clone(),
-                }),
-            })
-            .await;
     }
+}
 
-    if let Some(mut stdin) = child.stdin.take()
-        && let Err(e) = stdin.write_all(&stdin_payload).await
-    {
-        warn!("failed to write hook payload to stdin: {e}");
-    }
+/// Waits for a hook process to exit, killing it once `timeout` elapses. Failures and timeouts
+/// are recorded in `counters`.
+async fn wait_for_hook_process(
+    child: &mut tokio::process::Child,
+    timeout: Option<Duration>,
+    event_type: &str,
+    counters: &HookProviderCounters,
+) -> Option<i32> {
+    let status = match timeout {
+        Some(timeout) => match tokio::time::timeout(timeout, child.wait()).await {
+            Ok(status) => status,
+            Err(_timeout) => {
+                let timeout_sec = timeout.as_secs();
+                warn!("hook timed out after {timeout_sec}s: {event_type}");
+                counters.record_timeout();
+                let _ = child.start_kill();
+                let _ = tokio::time::timeout(Duration::from_secs(1), child.wait()).await;
+                return None;
+            }
+        },
+        None => child.wait().await,
+    };
 
-    let mut exit_code = None;
-    match tokio::time::timeout(timeout, child.wait()).await {
-        Ok(Ok(status)) => {
-            exit_code = status.code();
-        }
-        Ok(Err(e)) => {
+    let exit_code = match status {
+        Ok(status) => status.code(),
+        Err(e) => {
             warn!("failed waiting for hook process to exit: {e}");
+            counters.record_failure();
+            None
         }
-        Err(_timeout) => {
-            let timeout_sec = timeout.as_secs();
-            warn!("hook timed out after {timeout_sec}s: {event_type}");
-            let _ = child.start_kill();
-            let _ = tokio::time::timeout(Duration::from_secs(1), child.wait()).await;
-        }
-    }
-
+    };
     if let Some(code) = exit_code
         && code != 0
     {
         warn!("hook exited with non-zero status {code}: {event_type}");
+        counters.record_failure();
     }
-
-    if let Some(tx_event) = &tx_event {
-        let _ = tx_event
-            .send(Event {
-                id: "hook_process".to_string(),
-                msg: EventMsg::HookProcessEnd(HookProcessEndEvent { hook_id, exit_code }),
-            })
-            .await;
-    }
+    exit_code
 }
 
 fn spawn_hook_command_detached(
@@ -2305,14 +3574,17 @@ fn spawn_hook_command_detached(
     keep_last_n_payloads: usize,
     codex_home: &Path,
     stdin_payload: &[u8],
+    counters: &HookProviderCounters,
 ) {
-    let (stdout, stderr) = open_hook_log_files(codex_home, Uuid::new_v4(), keep_last_n_payloads);
+    let (stdout, stderr, _log_path) =
+        open_hook_log_files(codex_home, Uuid::new_v4(), keep_last_n_payloads);
 
     let child = {
         let mut cmd = std::process::Command::new(&command[0]);
         if command.len() > 1 {
             cmd.args(&command[1..]);
         }
+        cmd.envs(command_hook_env(codex_home));
         cmd.stdin(Stdio::piped());
         cmd.stdout(stdout);
         cmd.stderr(stderr);
@@ -2325,9 +3597,11 @@ fn spawn_hook_command_detached(
             #[allow(clippy::indexing_slicing)]
             let program = &command[0];
             warn!("failed to spawn hook '{program}': {e}");
+            counters.record_failure();
             return;
         }
     };
+    counters.record_command_spawned();
 
     if let Some(mut stdin) = child.stdin.take()
         && let Err(e) = stdin.write_all(stdin_payload)
@@ -2336,11 +3610,17 @@ fn spawn_hook_command_detached(
     }
 }
 
-fn open_hook_log_files(codex_home: &Path, hook_id: Uuid, keep_last_n: usize) -> (Stdio, Stdio) {
+/// Opens the combined stdout/stderr log file for a hook process. The returned path is `None`
+/// when output is discarded.
+fn open_hook_log_files(
+    codex_home: &Path,
+    hook_id: Uuid,
+    keep_last_n: usize,
+) -> (Stdio, Stdio, Option<PathBuf>) {
     let logs_dir = codex_home.join("tmp").join("hooks").join("logs");
     if let Err(e) = ensure_dir(&logs_dir) {
         warn!("failed to create hooks log dir: {e}");
-        return (Stdio::null(), Stdio::null());
+        return (Stdio::null(), Stdio::null(), None);
     }
 
     let timestamp_ms = SystemTime::now()
@@ -2357,7 +3637,7 @@ fn open_hook_log_files(codex_home: &Path, hook_id: Uuid, keep_last_n: usize) ->
         Ok(file) => file,
         Err(e) => {
             warn!("failed to open hook log file: {e}");
-            return (Stdio::null(), Stdio::null());
+            return (Stdio::null(), Stdio::null(), None);
         }
     };
 
@@ -2373,24 +3653,110 @@ fn open_hook_log_files(codex_home: &Path, hook_id: Uuid, keep_last_n: usize) ->
         Ok(clone) => clone,
         Err(e) => {
             warn!("failed to clone hook log file handle: {e}");
-            return (Stdio::from(file), Stdio::null());
+            return (Stdio::from(file), Stdio::null(), Some(log_path));
         }
     };
 
-    (Stdio::from(file), Stdio::from(stderr))
+    (Stdio::from(file), Stdio::from(stderr), Some(log_path))
 }
 
+/// Returns the bytes to write to a hook's stdin, applying `oversized_payload_strategy` when the
+/// payload exceeds `max_stdin_payload_bytes`. `None` means the hook should not be invoked.
 fn prepare_hook_stdin_payload(
     payload: &HookPayload,
     payload_json: &[u8],
     max_stdin_payload_bytes: usize,
+    oversized_payload_strategy: HookOversizedPayloadStrategy,
     keep_last_n_payloads: usize,
     codex_home: &Path,
-) -> Vec<u8> {
+) -> Option<Vec<u8>> {
     if payload_json.len() <= max_stdin_payload_bytes {
-        return payload_json.to_vec();
+        return Some(payload_json.to_vec());
+    }
+
+    match oversized_payload_strategy {
+        HookOversizedPayloadStrategy::Truncate => Some(truncate_hook_stdin_payload(
+            payload_json,
+            max_stdin_payload_bytes,
+        )),
+        HookOversizedPayloadStrategy::Spill => Some(spill_hook_stdin_payload(
+            payload,
+            payload_json,
+            keep_last_n_payloads,
+            codex_home,
+        )),
+        HookOversizedPayloadStrategy::Drop => {
+            debug!(
+                "dropping hook payload for event {}: {} bytes exceeds max_stdin_payload_bytes={max_stdin_payload_bytes}",
+                payload.event_id,
+                payload_json.len()
+            );
+            None
+        }
+    }
+}
+
+/// Shortens the longest string values in the payload until it fits in `max_bytes` and marks it
+/// with `"truncated": true`. Falls back to the original bytes if the payload is not JSON.
+fn truncate_hook_stdin_payload(payload_json: &[u8], max_bytes: usize) -> Vec<u8> {
+    let mut value: Value = match serde_json::from_slice(payload_json) {
+        Ok(value) => value,
+        Err(e) => {
+            warn!("failed to parse hook payload for truncation: {e}");
+            return payload_json.to_vec();
+        }
+    };
+    if let Value::Object(map) = &mut value {
+        map.insert("truncated".to_string(), Value::Bool(true));
+    }
+
+    loop {
+        let encoded = match serde_json::to_vec(&value) {
+            Ok(encoded) => encoded,
+            Err(e) => {
+                warn!("failed to serialise truncated hook payload: {e}");
+                return payload_json.to_vec();
+            }
+        };
+        if encoded.len() <= max_bytes {
+            return encoded;
+        }
+
+        // Escaped characters never encode shorter than their raw bytes, so removing the overflow
+        // from the longest string is enough unless that string is shorter than the overflow.
+        let overflow = encoded.len() - max_bytes;
+        match longest_string_mut(&mut value) {
+            Some(longest) if !longest.is_empty() => {
+                let keep = longest.len().saturating_sub(overflow);
+                let keep = take_bytes_at_char_boundary(longest, keep).len();
+                longest.truncate(keep);
+            }
+            _ => return encoded,
+        }
+    }
+}
+
+fn longest_string_mut(value: &mut Value) -> Option<&mut String> {
+    match value {
+        Value::String(text) => Some(text),
+        Value::Array(items) => items
+            .iter_mut()
+            .filter_map(longest_string_mut)
+            .max_by_key(|text| text.len()),
+        Value::Object(map) => map
+            .values_mut()
+            .filter_map(longest_string_mut)
+            .max_by_key(|text| text.len()),
+        Value::Null | Value::Bool(_) | Value::Number(_) => None,
     }
+}
 
+fn spill_hook_stdin_payload(
+    payload: &HookPayload,
+    payload_json: &[u8],
+    keep_last_n_payloads: usize,
+    codex_home: &Path,
+) -> Vec<u8> {
     let payload_path =
         match write_payload_file(codex_home, payload, payload_json, keep_last_n_payloads) {
             Ok(path) => path,
@@ -2500,7 +3866,7 @@ struct HookEvent {
 impl HookEvent {
     pub fn new(notification: HookNotification) -> Self {
         Self {
-            schema_version: 1,
+            schema_version: HOOK_PAYLOAD_SCHEMA_VERSION,
             event_id: Uuid::new_v4(),
             timestamp: Utc::now(),
             notification,
@@ -2559,6 +3925,10 @@ pub struct HookPayload {
     #[serde(skip_serializing_if = "Option::is_none")]
     notification_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    category: Option<NotificationCategory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<NotificationPriority>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     title: Option<String>,
@@ -2568,14 +3938,36 @@ pub struct HookPayload {
     #[serde(skip_serializing_if = "Option::is_none")]
     last_assistant_message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    session_stats: Option<HookSessionStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     prompt: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     trigger: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    tokens_before: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tokens_after: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    messages_dropped: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     session_source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    git_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    git_dirty: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    git_commit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    git_remote: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     subagent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subagent_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subagent_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result_summary: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     kind: Option<String>,
@@ -2617,6 +4009,8 @@ pub struct HookPayload {
     #[serde(skip_serializing_if = "Option::is_none")]
     has_output_schema: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    rate_limits: Option<HookRateLimitSnapshot>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     response_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     token_usage: Option<TokenUsage>,
@@ -2705,14 +4099,27 @@ impl HookPayload {
             output_bytes: None,
             output_preview: None,
             notification_type: None,
+            category: None,
+            priority: None,
             message: None,
             title: None,
             input_messages: None,
             last_assistant_message: None,
+            session_stats: None,
             prompt: None,
             trigger: None,
+            tokens_before: None,
+            tokens_after: None,
+            messages_dropped: None,
             session_source: None,
+            git_branch: None,
+            git_dirty: None,
+            git_commit: None,
+            git_remote: None,
             subagent: None,
+            subagent_id: None,
+            subagent_name: None,
+            result_summary: None,
             kind: None,
             call_id: None,
             reason: None,
@@ -2732,6 +4139,7 @@ impl HookPayload {
             tool_count: None,
             parallel_tool_calls: None,
             has_output_schema: None,
+            rate_limits: None,
             response_id: None,
             token_usage: None,
             needs_follow_up: None,
@@ -2741,10 +4149,12 @@ impl HookPayload {
             HookNotification::AgentTurnComplete {
                 input_messages,
                 last_assistant_message,
+                session_stats,
                 ..
             } => {
                 out.input_messages = Some(input_messages.clone());
                 out.last_assistant_message = last_assistant_message.clone();
+                out.session_stats = Some(session_stats.clone());
             }
             HookNotification::ApprovalRequested {
                 kind,
@@ -2792,32 +4202,66 @@ impl HookPayload {
                 };
                 out.tool_response = Some(Value::Null);
             }
-            HookNotification::SessionStart { session_source, .. }
-            | HookNotification::SessionEnd { session_source, .. } => {
+            HookNotification::SessionStart {
+                session_source,
+                git_branch,
+                git_dirty,
+                git_commit,
+                git_remote,
+                ..
+            } => {
+                out.session_source = Some(session_source.clone());
+                out.git_branch = git_branch.clone();
+                out.git_dirty = *git_dirty;
+                out.git_commit = git_commit.clone();
+                out.git_remote = git_remote.clone();
+            }
+            HookNotification::SessionEnd { session_source, .. } => {
                 out.session_source = Some(session_source.clone());
             }
             HookNotification::UserPromptSubmit { prompt, .. } => {
                 out.prompt = Some(prompt.clone());
             }
-            HookNotification::PreCompact { trigger, .. } => {
+            HookNotification::PreCompact {
+                trigger,
+                tokens_before,
+                tokens_after,
+                messages_dropped,
+                ..
+            } => {
                 out.trigger = Some(trigger.clone());
+                out.tokens_before = Some(*tokens_before);
+                out.tokens_after = *tokens_after;
+                out.messages_dropped = *messages_dropped;
             }
             HookNotification::Notification {
                 notification_type,
+                category,
+                priority,
                 message,
                 title,
                 ..
             } => {
                 out.notification_type = Some(notification_type.clone());
+                out.category = Some(*category);
+                out.priority = *priority;
                 out.message = message.clone();
                 out.title = title.clone();
             }
             HookNotification::SubagentStop {
-                subagent, status, ..
+                subagent,
+                subagent_id,
+                subagent_name,
+                status,
+                result_summary,
+                ..
             } => {
                 out.tool_name = Some("Task".to_string());
                 out.subagent = Some(subagent.clone());
+                out.subagent_id = Some(subagent_id.clone());
+                out.subagent_name = Some(subagent_name.clone());
                 out.status = Some(status.clone());
+                out.result_summary = result_summary.clone();
             }
             HookNotification::ModelRequestStarted {
                 model_request_id,
@@ -2828,6 +4272,7 @@ impl HookPayload {
                 tool_count,
                 parallel_tool_calls,
                 has_output_schema,
+                rate_limits,
                 ..
             } => {
                 out.model_request_id = Some(model_request_id.to_string());
@@ -2838,10 +4283,12 @@ impl HookPayload {
                 out.tool_count = Some(*tool_count);
                 out.parallel_tool_calls = Some(*parallel_tool_calls);
                 out.has_output_schema = Some(*has_output_schema);
+                out.rate_limits = rate_limits.clone();
             }
             HookNotification::ModelResponseCompleted {
                 model_request_id,
                 attempt,
+                model,
                 response_id,
                 token_usage,
                 needs_follow_up,
@@ -2849,6 +4296,7 @@ impl HookPayload {
             } => {
                 out.model_request_id = Some(model_request_id.to_string());
                 out.attempt = Some(*attempt);
+                out.model = Some(model.clone());
                 out.response_id = Some(response_id.clone());
                 out.token_usage = token_usage.clone();
                 out.needs_follow_up = Some(*needs_follow_up);
@@ -2859,6 +4307,8 @@ impl HookPayload {
                 tool_name,
                 call_id,
                 tool_input,
+                command,
+                paths,
                 ..
             } => {
                 let tool_name =
@@ -2874,6 +4324,8 @@ impl HookPayload {
                 out.tool_response = Some(Value::Null);
                 out.model_request_id = Some(model_request_id.to_string());
                 out.attempt = Some(*attempt);
+                out.command = command.clone();
+                out.paths = paths.clone();
             }
             HookNotification::ToolCallFinished {
                 model_request_id,
@@ -2934,6 +4386,173 @@ impl HookPayload {
     }
 }
 
+/// Event-specific `HookPayload` fields set for each `xcodex_event_type` (see
+/// `HookPayload::from_event`). Fields shared by every event are the schema's required fields.
+#[cfg(feature = "hooks-schema")]
+const HOOK_PAYLOAD_EVENT_FIELDS: &[(&str, &[&str])] = &[
+    (
+        "agent-turn-complete",
+        &["input_messages", "last_assistant_message", "session_stats"],
+    ),
+    (
+        "approval-requested",
+        &[
+            "kind",
+            "call_id",
+            "reason",
+            "approval_policy",
+            "sandbox_policy",
+            "proposed_execpolicy_amendment",
+            "command",
+            "paths",
+            "grant_root",
+            "server_name",
+            "request_id",
+            "message",
+            "tool_name",
+            "tool_use_id",
+            "tool_input",
+            "tool_response",
+        ],
+    ),
+    (
+        "session-start",
+        &[
+            "session_source",
+            "git_branch",
+            "git_dirty",
+            "git_commit",
+            "git_remote",
+        ],
+    ),
+    ("session-end", &["session_source"]),
+    ("user-prompt-submit", &["prompt"]),
+    (
+        "pre-compact",
+        &[
+            "trigger",
+            "tokens_before",
+            "tokens_after",
+            "messages_dropped",
+        ],
+    ),
+    (
+        "notification",
+        &[
+            "notification_type",
+            "category",
+            "priority",
+            "message",
+            "title",
+        ],
+    ),
+    (
+        "subagent-stop",
+        &[
+            "tool_name",
+            "subagent",
+            "subagent_id",
+            "subagent_name",
+            "status",
+            "result_summary",
+        ],
+    ),
+    (
+        "model-request-started",
+        &[
+            "model_request_id",
+            "attempt",
+            "model",
+            "provider",
+            "input_item_count",
+            "tool_count",
+            "parallel_tool_calls",
+            "has_output_schema",
+            "rate_limits",
+        ],
+    ),
+    (
+        "model-response-completed",
+        &[
+            "model_request_id",
+            "attempt",
+            "model",
+            "response_id",
+            "token_usage",
+            "needs_follow_up",
+        ],
+    ),
+    (
+        "tool-call-started",
+        &[
+            "tool_name",
+            "tool_use_id",
+            "tool_input",
+            "tool_response",
+            "model_request_id",
+            "attempt",
+            "command",
+            "paths",
+        ],
+    ),
+    (
+        "tool-call-finished",
+        &[
+            "tool_name",
+            "tool_use_id",
+            "tool_input",
+            "tool_response",
+            "status",
+            "duration_ms",
+            "success",
+            "output_bytes",
+            "output_preview",
+            "model_request_id",
+            "attempt",
+        ],
+    ),
+];
+
+/// JSON Schema for the payload external hooks receive for each event type, keyed by
+/// `xcodex_event_type` (e.g. `tool-call-finished`). Each schema is titled after the event, e.g.
+/// `ToolCallFinishedPayload`.
+#[cfg(feature = "hooks-schema")]
+pub fn hook_payload_schemas() -> Result<Vec<(&'static str, Value)>, serde_json::Error> {
+    let base = serde_json::to_value(schemars::schema_for!(HookPayload))?;
+    let common_fields: Vec<&str> = base["required"]
+        .as_array()
+        .map(|required| required.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    Ok(HOOK_PAYLOAD_EVENT_FIELDS
+        .iter()
+        .map(|(event_type, event_fields)| {
+            let mut schema = base.clone();
+            let title = event_type
+                .split('-')
+                .map(|word| {
+                    let mut chars = word.chars();
+                    chars.next().map_or_else(String::new, |first| {
+                        first.to_ascii_uppercase().to_string() + chars.as_str()
+                    })
+                })
+                .collect::<String>()
+                + "Payload";
+            schema["title"] = Value::String(title);
+            if let Some(properties) = schema["properties"].as_object_mut() {
+                properties.retain(|name, _| {
+                    common_fields.contains(&name.as_str()) || event_fields.contains(&name.as_str())
+                });
+                properties.insert(
+                    "xcodex_event_type".to_string(),
+                    serde_json::json!({ "const": event_type, "type": "string" }),
+                );
+            }
+            (*event_type, schema)
+        })
+        .collect())
+}
+
 #[cfg_attr(feature = "hooks-schema", derive(schemars::JsonSchema))]
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -2962,7 +4581,7 @@ impl HookStdinEnvelope {
 }
 
 #[cfg_attr(feature = "hooks-schema", derive(schemars::JsonSchema))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum ApprovalKind {
     Exec,
@@ -2970,8 +4589,78 @@ pub enum ApprovalKind {
     Elicitation,
 }
 
+/// Session totals at the end of a turn, carried by `agent-turn-complete` events.
+#[cfg_attr(feature = "hooks-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookSessionStats {
+    /// Turns completed in this session, including the one that just finished.
+    pub turns_completed: u64,
+    /// Cumulative tokens reported by the model provider for this session.
+    pub total_tokens: i64,
+}
+
+/// Latest rate-limit state for `model-request-started` events. Account details (plan type and
+/// credit balance) are left out.
+#[cfg_attr(feature = "hooks-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HookRateLimitSnapshot {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<HookRateLimitWindow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary: Option<HookRateLimitWindow>,
+}
+
+#[cfg_attr(feature = "hooks-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HookRateLimitWindow {
+    /// Percentage (0-100) of the window still available.
+    pub remaining_percent: f64,
+    /// Rolling window duration, in minutes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_minutes: Option<i64>,
+    /// Unix timestamp (seconds since epoch) when the window resets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resets_at: Option<i64>,
+}
+
+impl From<&RateLimitSnapshot> for HookRateLimitSnapshot {
+    fn from(snapshot: &RateLimitSnapshot) -> Self {
+        let window = |window: &RateLimitWindow| HookRateLimitWindow {
+            remaining_percent: (100.0 - window.used_percent).clamp(0.0, 100.0),
+            window_minutes: window.window_minutes,
+            resets_at: window.resets_at,
+        };
+        Self {
+            limit_id: snapshot.limit_id.clone(),
+            primary: snapshot.primary.as_ref().map(window),
+            secondary: snapshot.secondary.as_ref().map(window),
+        }
+    }
+}
+
+/// Routing category for `notification` events, so hooks can branch without parsing text.
+#[cfg_attr(feature = "hooks-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NotificationCategory {
+    #[default]
+    Info,
+    ApprovalNeeded,
+}
+
+#[cfg_attr(feature = "hooks-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NotificationPriority {
+    Low,
+    Normal,
+    High,
+}
+
 #[cfg_attr(feature = "hooks-schema", derive(schemars::JsonSchema))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum ToolCallStatus {
     Completed,
@@ -3010,7 +4699,7 @@ fn append_hook_payload_jsonl_line(path: &Path, event: &HookEvent) -> HookResult
 }
 
 #[cfg_attr(feature = "hooks-schema", derive(schemars::JsonSchema))]
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "kebab-case")]
 pub enum HookNotification {
     #[serde(rename_all = "kebab-case")]
@@ -3021,6 +4710,7 @@ pub enum HookNotification {
 
         input_messages: Vec<String>,
         last_assistant_message: Option<String>,
+        session_stats: HookSessionStats,
     },
 
     #[serde(rename_all = "kebab-case")]
@@ -3064,6 +4754,14 @@ pub enum HookNotification {
         thread_id: String,
         cwd: String,
         session_source: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        git_branch: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        git_dirty: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        git_commit: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        git_remote: Option<String>,
     },
 
     #[serde(rename_all = "kebab-case")]
@@ -3085,6 +4783,11 @@ pub enum HookNotification {
         thread_id: String,
         cwd: String,
         trigger: String,
+        tokens_before: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tokens_after: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        messages_dropped: Option<u64>,
     },
 
     #[serde(rename_all = "kebab-case")]
@@ -3092,6 +4795,10 @@ pub enum HookNotification {
         thread_id: String,
         cwd: String,
         notification_type: String,
+        #[serde(default)]
+        category: NotificationCategory,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        priority: Option<NotificationPriority>,
         #[serde(skip_serializing_if = "Option::is_none")]
         message: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -3103,7 +4810,11 @@ pub enum HookNotification {
         thread_id: String,
         cwd: String,
         subagent: String,
+        subagent_id: String,
+        subagent_name: String,
         status: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        result_summary: Option<String>,
     },
 
     #[serde(rename_all = "kebab-case")]
@@ -3121,6 +4832,8 @@ pub enum HookNotification {
         tool_count: usize,
         parallel_tool_calls: bool,
         has_output_schema: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        rate_limits: Option<HookRateLimitSnapshot>,
     },
 
     #[serde(rename_all = "kebab-case")]
@@ -3131,6 +4844,7 @@ pub enum HookNotification {
         #[cfg_attr(feature = "hooks-schema", schemars(with = "String"))]
         model_request_id: Uuid,
         attempt: u32,
+        model: String,
         response_id: String,
         #[serde(skip_serializing_if = "Option::is_none")]
         token_usage: Option<TokenUsage>,
@@ -3149,6 +4863,12 @@ pub enum HookNotification {
         call_id: String,
         #[serde(skip_serializing_if = "Option::is_none")]
         tool_input: Option<Value>,
+        /// Argv the tool is about to run, for exec-style tools.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        command: Option<Vec<String>>,
+        /// Files the tool is about to touch, for `apply_patch`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        paths: Option<Vec<String>>,
     },
 
     #[serde(rename_all = "kebab-case")]
@@ -3175,13 +4895,50 @@ pub enum HookNotification {
 }
 
 impl HookNotification {
-    pub fn event_type(&self) -> &'static str {
-        match self {
-            Self::AgentTurnComplete { .. } => "agent-turn-complete",
-            Self::ApprovalRequested { .. } => "approval-requested",
-            Self::SessionStart { .. } => "session-start",
-            Self::SessionEnd { .. } => "session-end",
-            Self::UserPromptSubmit { .. } => "user-prompt-submit",
+    /// Builds a notification from an event type (e.g. `tool-call-finished`) and a JSON object
+    /// holding the event's kebab-case fields.
+    pub fn from_event_payload(event_type: &str, payload: Value) -> Result<Self, String> {
+        let Value::Object(mut fields) = payload else {
+            return Err("hook event payload must be a JSON object".to_string());
+        };
+        fields.insert("type".to_string(), Value::String(event_type.to_string()));
+        serde_json::from_value(Value::Object(fields))
+            .map_err(|err| format!("invalid {event_type} payload: {err}"))
+    }
+
+    /// Builds a notification from a camelCase event type (e.g. `toolCallFinished`) and a JSON
+    /// object holding the event's camelCase fields (e.g. `toolName`), as sent by app-server
+    /// clients. Only top-level keys are renamed; nested values are passed through unchanged.
+    pub fn from_camel_case_payload(event_type: &str, payload: Value) -> Result<Self, String> {
+        let Value::Object(fields) = payload else {
+            return Err("hook event payload must be a JSON object".to_string());
+        };
+        let to_kebab_case = |name: &str| {
+            name.chars()
+                .fold(String::with_capacity(name.len()), |mut out, c| {
+                    if c.is_ascii_uppercase() {
+                        out.push('-');
+                        out.push(c.to_ascii_lowercase());
+                    } else {
+                        out.push(c);
+                    }
+                    out
+                })
+        };
+        let fields = fields
+            .into_iter()
+            .map(|(key, value)| (to_kebab_case(&key), value))
+            .collect();
+        Self::from_event_payload(&to_kebab_case(event_type), Value::Object(fields))
+    }
+
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Self::AgentTurnComplete { .. } => "agent-turn-complete",
+            Self::ApprovalRequested { .. } => "approval-requested",
+            Self::SessionStart { .. } => "session-start",
+            Self::SessionEnd { .. } => "session-end",
+            Self::UserPromptSubmit { .. } => "user-prompt-submit",
             Self::PreCompact { .. } => "pre-compact",
             Self::Notification { .. } => "notification",
             Self::SubagentStop { .. } => "subagent-stop",
@@ -3193,6 +4950,51 @@ impl HookNotification {
     }
 }
 
+/// Rebuilds the notification a hook payload was emitted for, e.g. to replay logged payloads.
+///
+/// Payloads carry Claude-compatible tool names and inputs (`Bash`, `Read`, ...), which are kept
+/// as-is rather than mapped back to xcodex tool names.
+impl TryFrom<codex_hooks_sdk::HookPayload> for HookNotification {
+    type Error = codex_hooks_sdk::UnknownHookEvent;
+
+    fn try_from(payload: codex_hooks_sdk::HookPayload) -> Result<Self, Self::Error> {
+        let event_type = payload.xcodex_event_type.clone();
+        let raw = serde_json::to_value(&payload).unwrap_or(Value::Null);
+        if !codex_hooks_sdk::KNOWN_EVENT_TYPES.contains(&event_type.as_str()) {
+            return Err(codex_hooks_sdk::UnknownHookEvent {
+                event_type: Some(event_type),
+                raw,
+                parse_error: None,
+            });
+        }
+
+        let is_tool_call = matches!(
+            event_type.as_str(),
+            "tool-call-started" | "tool-call-finished"
+        );
+        let mut fields = serde_json::Map::new();
+        if let Value::Object(object) = &raw {
+            for (key, value) in object.iter().filter(|(_, value)| !value.is_null()) {
+                let key = match key.as_str() {
+                    "session_id" => "thread-id".to_string(),
+                    "input_item_count" => "prompt-input-item-count".to_string(),
+                    "tool_use_id" if is_tool_call => "call-id".to_string(),
+                    key => key.replace('_', "-"),
+                };
+                fields.insert(key, value.clone());
+            }
+        }
+
+        Self::from_event_payload(&event_type, Value::Object(fields)).map_err(|parse_error| {
+            codex_hooks_sdk::UnknownHookEvent {
+                event_type: Some(event_type),
+                raw,
+                parse_error: Some(parse_error),
+            }
+        })
+    }
+}
+
 pub(crate) mod hooks_test {
     use super::*;
     use std::time::Duration;
@@ -3269,13 +5071,16 @@ pub(crate) mod hooks_test {
             for command in commands {
                 let payload = HookPayload::from_event(&event, &command.hook_event_name);
                 let payload_json = serde_json::to_vec(&payload)?;
-                let stdin_payload = prepare_hook_stdin_payload(
+                let Some(stdin_payload) = prepare_hook_stdin_payload(
                     &payload,
                     &payload_json,
                     hooks.max_stdin_payload_bytes,
+                    hooks.oversized_payload_strategy,
                     hooks.keep_last_n_payloads,
                     &codex_home,
-                );
+                ) else {
+                    continue;
+                };
 
                 let exit_code = tokio::time::timeout(
                     timeout,
@@ -3494,6 +5299,10 @@ pub(crate) mod hooks_test {
                 cwd,
                 input_messages: vec!["hooks test".to_string()],
                 last_assistant_message: Some("hooks test".to_string()),
+                session_stats: HookSessionStats {
+                    turns_completed: 1,
+                    total_tokens: 0,
+                },
             },
             HooksTestEvent::ApprovalRequestedExec => HookNotification::ApprovalRequested {
                 thread_id,
@@ -3550,6 +5359,10 @@ pub(crate) mod hooks_test {
                 thread_id,
                 cwd,
                 session_source: "hooks-test".to_string(),
+                git_branch: None,
+                git_dirty: None,
+                git_commit: None,
+                git_remote: None,
             },
             HooksTestEvent::SessionEnd => HookNotification::SessionEnd {
                 thread_id,
@@ -3565,11 +5378,16 @@ pub(crate) mod hooks_test {
                 thread_id,
                 cwd,
                 trigger: "hooks-test".to_string(),
+                tokens_before: 0,
+                tokens_after: Some(0),
+                messages_dropped: Some(0),
             },
             HooksTestEvent::Notification => HookNotification::Notification {
                 thread_id,
                 cwd,
                 notification_type: "hooks-test".to_string(),
+                category: NotificationCategory::Info,
+                priority: None,
                 message: Some("hooks test".to_string()),
                 title: Some("hooks-test".to_string()),
             },
@@ -3577,7 +5395,10 @@ pub(crate) mod hooks_test {
                 thread_id,
                 cwd,
                 subagent: "hooks-test".to_string(),
+                subagent_id: "hooks-test-subagent".to_string(),
+                subagent_name: "hooks-test".to_string(),
                 status: "completed".to_string(),
+                result_summary: Some("hooks test".to_string()),
             },
             HooksTestEvent::ModelRequestStarted => HookNotification::ModelRequestStarted {
                 thread_id,
@@ -3591,6 +5412,7 @@ pub(crate) mod hooks_test {
                 tool_count: 0,
                 parallel_tool_calls: false,
                 has_output_schema: false,
+                rate_limits: None,
             },
             HooksTestEvent::ModelResponseCompleted => HookNotification::ModelResponseCompleted {
                 thread_id,
@@ -3598,6 +5420,7 @@ pub(crate) mod hooks_test {
                 cwd,
                 model_request_id: Uuid::new_v4(),
                 attempt: 1,
+                model: "hooks-test".to_string(),
                 response_id: "hooks-test".to_string(),
                 token_usage: None,
                 needs_follow_up: false,
@@ -3611,6 +5434,8 @@ pub(crate) mod hooks_test {
                 tool_name: "hooks-test".to_string(),
                 call_id: format!("call-{}", Uuid::new_v4()),
                 tool_input: None,
+                command: None,
+                paths: None,
             },
             HooksTestEvent::ToolCallFinished => HookNotification::ToolCallFinished {
                 thread_id,
@@ -3641,13 +5466,14 @@ pub(crate) mod hooks_test {
             return Ok(None);
         }
 
-        let (stdout, stderr) =
+        let (stdout, stderr, _log_path) =
             open_hook_log_files(codex_home, Uuid::new_v4(), keep_last_n_payloads);
 
         let mut cmd = tokio::process::Command::new(&command[0]);
         if command.len() > 1 {
             cmd.args(&command[1..]);
         }
+        cmd.envs(command_hook_env(codex_home));
         cmd.stdin(Stdio::piped());
         cmd.stdout(stdout);
         cmd.stderr(stderr);
@@ -3664,6 +5490,7 @@ pub(crate) mod hooks_test {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::protocol::CreditsSnapshot;
     use anyhow::Result;
     use pretty_assertions::assert_eq;
     use serde_json::Value;
@@ -3757,196 +5584,1706 @@ mod tests {
         Ok(())
     }
 
-    #[cfg(not(windows))]
-    #[tokio::test]
-    async fn hooks_command_runs_for_alias_event_and_claude_tool_name() -> Result<()> {
-        use std::collections::HashMap;
-
+    #[cfg(unix)]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn external_hook_commands_respect_concurrency_cap() -> Result<()> {
         let codex_home = TempDir::new()?;
-        let marker_path = codex_home.path().join("hooks.command.marker");
+        let state_dir = codex_home.path().join("state");
+        std::fs::create_dir_all(&state_dir)?;
+        let state_dir = state_dir
+            .to_str()
+            .ok_or_else(|| anyhow::anyhow!("state dir is not valid utf-8"))?;
+        let script = format!(
+            r#"touch "{state_dir}/running.$$"
+ls "{state_dir}"/running.* | wc -l >> "{state_dir}/counts"
+sleep 0.3
+rm "{state_dir}/running.$$"
+echo done >> "{state_dir}/done""#
+        );
+
+        let command_count = 5;
+        let hooks = HooksConfig {
+            session_start: vec![vec!["sh".to_string(), "-c".to_string(), script]; command_count],
+            max_concurrent_commands: 2,
+            ..HooksConfig::default()
+        };
+        let provider =
+            ExternalCommandHooksProvider::new(codex_home.path().to_path_buf(), hooks, None);
+        provider.on_event(&HookEvent::new(HookNotification::SessionStart {
+            thread_id: "thread-1".to_string(),
+            cwd: "/tmp".to_string(),
+            session_source: "exec".to_string(),
+            git_branch: None,
+            git_dirty: None,
+            git_commit: None,
+            git_remote: None,
+        }));
+
+        let done_path = codex_home.path().join("state").join("done");
+        tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                if let Ok(contents) = std::fs::read_to_string(&done_path)
+                    && contents.lines().count() >= command_count
+                {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        })
+        .await?;
+
+        let counts = std::fs::read_to_string(codex_home.path().join("state").join("counts"))?;
+        let max_running = counts
+            .lines()
+            .map(|line| line.trim().parse::<usize>())
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .into_iter()
+            .max();
+        assert!(
+            max_running.is_some_and(|max| max <= 2),
+            "expected at most 2 concurrent hooks; saw counts: {counts:?}"
+        );
+
+        Ok(())
+    }
 
+    #[cfg(unix)]
+    fn session_end_command_hooks(
+        marker_path: &Path,
+        detached: Option<bool>,
+    ) -> Result<HooksConfig> {
+        let marker_path = marker_path
+            .to_str()
+            .ok_or_else(|| anyhow::anyhow!("marker path is not valid utf-8"))?;
         let mut events = HashMap::new();
         events.insert(
-            "PostToolUse".to_string(),
+            "session_end".to_string(),
             vec![crate::config::HooksCommandMatcherConfig {
-                matcher: Some("Write".to_string()),
+                matcher: None,
                 hooks: vec![crate::config::HooksCommandHookConfig {
                     payload: crate::config::HookPayloadFormat::Xcodex,
                     argv: Some(vec![
-                        "python3".to_string(),
+                        "sh".to_string(),
                         "-c".to_string(),
-                        format!(
-                            r#"import json, pathlib, sys
-payload = json.load(sys.stdin)
-assert payload.get("hook_event_name") == "PostToolUse", payload
-assert payload.get("xcodex_event_type") == "tool-call-finished", payload
-assert payload.get("tool_name") == "Write", payload
-pathlib.Path({path:?}).write_text("ok", encoding="utf-8")
-"#,
-                            path = marker_path.to_string_lossy()
-                        ),
+                        format!("cat > /dev/null; echo ok > \"{marker_path}\""),
                     ]),
                     command: None,
                     timeout_sec: Some(5),
+                    detached,
                 }],
             }],
         );
-
-        let hooks = HooksConfig {
+        Ok(HooksConfig {
             command: crate::config::HooksCommandConfig {
                 default_timeout_sec: 30,
                 events,
+                ..crate::config::HooksCommandConfig::default()
             },
             ..HooksConfig::default()
-        };
+        })
+    }
 
-        let user_hooks = UserHooks::new(
-            codex_home.path().to_path_buf(),
-            hooks,
-            None,
-            SandboxPolicy::DangerFullAccess,
-            None,
-            ExclusionConfig::default(),
-            codex_home.path().to_path_buf(),
-        );
+    #[cfg(unix)]
+    fn session_end_event() -> HookEvent {
+        HookEvent::new(HookNotification::SessionEnd {
+            thread_id: "thread-1".to_string(),
+            cwd: "/tmp".to_string(),
+            session_source: "exec".to_string(),
+        })
+    }
 
-        user_hooks.tool_call_finished(
-            "thread-1".to_string(),
-            "turn-1".to_string(),
-            "/tmp".to_string(),
-            Uuid::new_v4(),
-            0,
-            "write_file".to_string(),
-            "call-1".to_string(),
-            ToolCallStatus::Completed,
-            1,
-            true,
-            0,
-            None,
-            None,
-            None,
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn session_end_command_hook_with_detached_true_uses_detached_path() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let marker_path = codex_home.path().join("session-end.marker");
+        let (tx_event, rx_event) = async_channel::unbounded();
+        let provider = ExternalCommandHooksProvider::new(
+            codex_home.path().to_path_buf(),
+            session_end_command_hooks(&marker_path, Some(true))?,
+            Some(tx_event),
         );
 
-        let contents = read_to_string_eventually(&marker_path).await?;
-        assert_eq!(contents.trim(), "ok");
-        Ok(())
-    }
+        // Dispatch through the bounded entry point: the per-hook setting must win.
+        provider.on_event(&session_end_event());
 
-    #[test]
-    fn test_hook_stdin_envelope_has_payload_path() -> Result<()> {
-        let event = HookEvent::new(HookNotification::AgentTurnComplete {
-            thread_id: "t".to_string(),
-            turn_id: "turn".to_string(),
-            cwd: "/tmp".to_string(),
-            input_messages: Vec::new(),
-            last_assistant_message: None,
-        });
-        let payload = HookPayload::from_event(&event, "Stop");
-        let envelope =
-            HookStdinEnvelope::from_payload(&payload, PathBuf::from("/tmp/payload.json"));
-        let serialized = serde_json::to_string(&envelope)?;
+        assert_eq!(read_to_string_eventually(&marker_path).await?, "ok\n");
         assert!(
-            serialized.contains(r#""payload_path":"/tmp/payload.json""#),
-            "envelope must include payload_path: {serialized}"
+            rx_event.try_recv().is_err(),
+            "detached hooks must not report hook process events"
         );
+
         Ok(())
     }
 
-    #[test]
-    fn tool_call_summary_log_matches_gallery_script() -> Result<()> {
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn session_end_command_hook_with_detached_false_uses_bounded_path() -> Result<()> {
         let codex_home = TempDir::new()?;
-        let out_path = codex_home.path().join(TOOL_CALL_SUMMARY_LOG_FILENAME);
-        let line = format!(
-            "type=tool-call-finished tool=exec status={} success=true duration_ms=12 output_bytes=34 cwd=/tmp\n",
-            tool_call_status_string(ToolCallStatus::Completed)
+        let marker_path = codex_home.path().join("session-end.marker");
+        let (tx_event, rx_event) = async_channel::unbounded();
+        let provider = ExternalCommandHooksProvider::new(
+            codex_home.path().to_path_buf(),
+            session_end_command_hooks(&marker_path, Some(false))?,
+            Some(tx_event),
         );
 
-        append_tool_call_summary_line(&out_path, &line)?;
-        let contents = std::fs::read_to_string(&out_path)?;
-        assert_eq!(contents, line);
+        provider.on_event_detached(&session_end_event());
+
+        let event = tokio::time::timeout(Duration::from_secs(2), rx_event.recv()).await??;
+        assert!(
+            matches!(event.msg, EventMsg::HookProcessBegin(_)),
+            "expected a hook process begin event; got {event:?}"
+        );
+        assert_eq!(read_to_string_eventually(&marker_path).await?, "ok\n");
+
         Ok(())
     }
 
     #[test]
-    fn large_payload_uses_payload_path_envelope() -> Result<()> {
-        let codex_home = TempDir::new()?;
-        let event = HookEvent::new(HookNotification::AgentTurnComplete {
-            thread_id: "t".to_string(),
-            turn_id: "turn".to_string(),
-            cwd: "/tmp".to_string(),
-            input_messages: vec!["x".repeat(20_000)],
-            last_assistant_message: None,
-        });
-        let payload = HookPayload::from_event(&event, "Stop");
-        let payload_json = serde_json::to_vec(&payload)?;
-
-        let stdin_payload =
-            prepare_hook_stdin_payload(&payload, &payload_json, 16, 50, codex_home.path());
-
-        let envelope: Value = serde_json::from_slice(&stdin_payload)?;
-        let payload_path = envelope
-            .get("payload_path")
-            .and_then(Value::as_str)
-            .ok_or_else(|| anyhow::anyhow!("expected payload_path envelope"))?;
-
-        let read_back = std::fs::read(payload_path)?;
-        assert_eq!(read_back, payload_json);
-        Ok(())
+    fn hook_retry_backoff_doubles_and_is_capped() {
+        let base = Duration::from_millis(500);
+        assert_eq!(hook_retry_backoff(base, 0), Duration::from_millis(500));
+        assert_eq!(hook_retry_backoff(base, 2), Duration::from_secs(2));
+        assert_eq!(hook_retry_backoff(base, 10), HOOK_RETRY_MAX_BACKOFF);
+        assert_eq!(hook_retry_backoff(base, u32::MAX), HOOK_RETRY_MAX_BACKOFF);
     }
 
+    #[cfg(unix)]
     #[tokio::test]
-    async fn tool_call_summary_log_emits_from_user_hooks() -> Result<()> {
+    async fn command_hook_retry_reports_success_after_transient_failure() -> Result<()> {
         let codex_home = TempDir::new()?;
+        let marker_path = codex_home.path().join("failed-once.marker");
+        let marker_path = marker_path
+            .to_str()
+            .ok_or_else(|| anyhow::anyhow!("marker path is not valid utf-8"))?;
+        let mut events = HashMap::new();
+        events.insert(
+            "session_end".to_string(),
+            vec![crate::config::HooksCommandMatcherConfig {
+                matcher: None,
+                hooks: vec![crate::config::HooksCommandHookConfig {
+                    payload: crate::config::HookPayloadFormat::Xcodex,
+                    argv: Some(vec![
+                        "sh".to_string(),
+                        "-c".to_string(),
+                        format!(
+                            "cat > /dev/null; [ -f \"{marker_path}\" ] && exit 0; touch \"{marker_path}\"; exit 1"
+                        ),
+                    ]),
+                    command: None,
+                    timeout_sec: Some(5),
+                    detached: Some(false),
+                }],
+            }],
+        );
         let hooks = HooksConfig {
-            inproc_tool_call_summary: true,
+            command: crate::config::HooksCommandConfig {
+                retries: 1,
+                retry_backoff_ms: 10,
+                events,
+                ..crate::config::HooksCommandConfig::default()
+            },
             ..HooksConfig::default()
         };
-        let user_hooks = UserHooks::new(
+        let (tx_event, rx_event) = async_channel::unbounded();
+        let provider = ExternalCommandHooksProvider::new(
             codex_home.path().to_path_buf(),
             hooks,
-            None,
-            SandboxPolicy::DangerFullAccess,
-            None,
-            ExclusionConfig::default(),
-            codex_home.path().to_path_buf(),
+            Some(tx_event),
         );
 
-        user_hooks.tool_call_finished(
-            "thread-1".to_string(),
-            "turn-1".to_string(),
-            "/tmp".to_string(),
-            Uuid::new_v4(),
-            1,
-            "exec".to_string(),
-            "call-1".to_string(),
-            ToolCallStatus::Completed,
-            12,
-            true,
-            34,
-            None,
-            None,
-            None,
-        );
+        provider.on_event(&session_end_event());
 
-        let out_path = codex_home.path().join(TOOL_CALL_SUMMARY_LOG_FILENAME);
-        let contents = read_to_string_eventually(&out_path).await?;
+        let begin = tokio::time::timeout(Duration::from_secs(5), rx_event.recv()).await??;
         assert!(
-            contents.starts_with("type=tool-call-finished tool=exec status=completed"),
-            "summary line missing expected prefix: {contents:?}"
+            matches!(begin.msg, EventMsg::HookProcessBegin(_)),
+            "expected a hook process begin event; got {begin:?}"
+        );
+        let end = tokio::time::timeout(Duration::from_secs(5), rx_event.recv()).await??;
+        let EventMsg::HookProcessEnd(end) = end.msg else {
+            anyhow::bail!("expected a hook process end event; got {end:?}");
+        };
+        assert_eq!(end.exit_code, Some(0));
+        assert_eq!(
+            provider.metrics(),
+            HookProviderMetrics {
+                provider: "external_command".to_string(),
+                commands_spawned: 2,
+                failures: 1,
+                timeouts: 0,
+                breaker_trips: 0,
+            }
         );
 
         Ok(())
     }
 
+    #[cfg(unix)]
     #[tokio::test]
-    async fn tool_call_summary_inproc_list_dedupes() -> Result<()> {
+    async fn command_hook_retry_covers_timeouts() -> Result<()> {
         let codex_home = TempDir::new()?;
+        let marker_path = codex_home.path().join("timed-out-once.marker");
+        let marker_path = marker_path
+            .to_str()
+            .ok_or_else(|| anyhow::anyhow!("marker path is not valid utf-8"))?;
+        let mut events = HashMap::new();
+        events.insert(
+            "session_end".to_string(),
+            vec![crate::config::HooksCommandMatcherConfig {
+                matcher: None,
+                hooks: vec![crate::config::HooksCommandHookConfig {
+                    payload: crate::config::HookPayloadFormat::Xcodex,
+                    argv: Some(vec![
+                        "sh".to_string(),
+                        "-c".to_string(),
+                        format!(
+                            "cat > /dev/null; [ -f \"{marker_path}\" ] && exit 0; touch \"{marker_path}\"; sleep 10"
+                        ),
+                    ]),
+                    command: None,
+                    timeout_sec: Some(1),
+                    detached: Some(false),
+                }],
+            }],
+        );
         let hooks = HooksConfig {
-            inproc_tool_call_summary: true,
-            inproc: vec![INPROC_TOOL_CALL_SUMMARY_HOOK_NAME.to_string()],
+            command: crate::config::HooksCommandConfig {
+                retries: 1,
+                retry_backoff_ms: 10,
+                events,
+                ..crate::config::HooksCommandConfig::default()
+            },
             ..HooksConfig::default()
         };
-        let user_hooks = UserHooks::new(
+        let (tx_event, rx_event) = async_channel::unbounded();
+        let provider = ExternalCommandHooksProvider::new(
+            codex_home.path().to_path_buf(),
+            hooks,
+            Some(tx_event),
+        );
+
+        provider.on_event(&session_end_event());
+
+        let begin = tokio::time::timeout(Duration::from_secs(5), rx_event.recv()).await??;
+        assert!(
+            matches!(begin.msg, EventMsg::HookProcessBegin(_)),
+            "expected a hook process begin event; got {begin:?}"
+        );
+        let end = tokio::time::timeout(Duration::from_secs(10), rx_event.recv()).await??;
+        let EventMsg::HookProcessEnd(end) = end.msg else {
+            anyhow::bail!("expected a hook process end event; got {end:?}");
+        };
+        assert_eq!(end.exit_code, Some(0));
+        assert_eq!(
+            provider.metrics(),
+            HookProviderMetrics {
+                provider: "external_command".to_string(),
+                commands_spawned: 2,
+                failures: 0,
+                timeouts: 1,
+                breaker_trips: 0,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn command_hooks_receive_codex_home_env() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let marker_path = codex_home.path().join("codex-home.marker");
+        let hooks = HooksConfig {
+            session_start: vec![vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                format!(
+                    "cat > /dev/null; printf '%s' \"$CODEX_HOME\" > \"{}\"",
+                    marker_path.display()
+                ),
+            ]],
+            ..HooksConfig::default()
+        };
+        let provider =
+            ExternalCommandHooksProvider::new(codex_home.path().to_path_buf(), hooks, None);
+
+        provider.on_event(&HookEvent::new(HookNotification::SessionStart {
+            thread_id: "thread-1".to_string(),
+            cwd: "/tmp".to_string(),
+            session_source: "exec".to_string(),
+            git_branch: None,
+            git_dirty: None,
+            git_commit: None,
+            git_remote: None,
+        }));
+
+        assert_eq!(
+            read_to_string_eventually(&marker_path).await?,
+            codex_home.path().display().to_string()
+        );
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn echo_to_ui_attaches_hook_output_to_end_event() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let hooks = HooksConfig {
+            session_start: vec![vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                "cat > /dev/null; echo hello from hook; echo oops >&2".to_string(),
+            ]],
+            echo_to_ui: true,
+            ..HooksConfig::default()
+        };
+        let (tx_event, rx_event) = async_channel::unbounded();
+        let provider = ExternalCommandHooksProvider::new(
+            codex_home.path().to_path_buf(),
+            hooks,
+            Some(tx_event),
+        );
+
+        provider.on_event(&HookEvent::new(HookNotification::SessionStart {
+            thread_id: "thread-1".to_string(),
+            cwd: "/tmp".to_string(),
+            session_source: "exec".to_string(),
+            git_branch: None,
+            git_dirty: None,
+            git_commit: None,
+            git_remote: None,
+        }));
+
+        let begin = tokio::time::timeout(Duration::from_secs(5), rx_event.recv()).await??;
+        assert!(
+            matches!(begin.msg, EventMsg::HookProcessBegin(_)),
+            "expected a hook process begin event; got {begin:?}"
+        );
+        let end = tokio::time::timeout(Duration::from_secs(5), rx_event.recv()).await??;
+        let EventMsg::HookProcessEnd(end) = end.msg else {
+            anyhow::bail!("expected a hook process end event; got {end:?}");
+        };
+        assert_eq!(end.exit_code, Some(0));
+        assert_eq!(end.output.as_deref(), Some("hello from hook\noops\n"));
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn session_start_blocking_waits_for_command_hooks() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let marker_path = codex_home.path().join("session-start.marker");
+        let hooks = HooksConfig {
+            session_start: vec![vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                format!(
+                    "cat > /dev/null; sleep 0.2; echo ok > \"{}\"",
+                    marker_path.display()
+                ),
+            ]],
+            session_start_blocking_ms: 5_000,
+            ..HooksConfig::default()
+        };
+        let user_hooks = UserHooks::new(
+            codex_home.path().to_path_buf(),
+            hooks,
+            None,
+            SandboxPolicy::DangerFullAccess,
+            None,
+            ExclusionConfig::default(),
+            codex_home.path().to_path_buf(),
+        );
+
+        user_hooks
+            .session_start(
+                "thread-1".to_string(),
+                "/tmp".to_string(),
+                "exec".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+        assert_eq!(std::fs::read_to_string(&marker_path)?, "ok\n");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wants_session_start_only_when_a_provider_handles_it() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let user_hooks_for = |hooks: HooksConfig| {
+            UserHooks::new(
+                codex_home.path().to_path_buf(),
+                hooks,
+                None,
+                SandboxPolicy::DangerFullAccess,
+                None,
+                ExclusionConfig::default(),
+                codex_home.path().to_path_buf(),
+            )
+        };
+        let session_start_hook = vec![vec!["true".to_string()]];
+
+        assert!(!user_hooks_for(HooksConfig::default()).wants_session_start());
+        assert!(
+            !user_hooks_for(HooksConfig {
+                tool_call_started: session_start_hook.clone(),
+                ..HooksConfig::default()
+            })
+            .wants_session_start()
+        );
+        assert!(
+            user_hooks_for(HooksConfig {
+                session_start: session_start_hook.clone(),
+                ..HooksConfig::default()
+            })
+            .wants_session_start()
+        );
+        assert!(
+            !user_hooks_for(HooksConfig {
+                session_start: session_start_hook,
+                disabled_events: vec!["session_start".to_string()],
+                ..HooksConfig::default()
+            })
+            .wants_session_start()
+        );
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn disabled_events_suppress_dispatch_but_keep_other_events() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let started_path = codex_home.path().join("started.marker");
+        let finished_path = codex_home.path().join("finished.marker");
+        let touch = |path: &Path| {
+            vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                format!("cat > /dev/null; echo ok > \"{}\"", path.display()),
+            ]
+        };
+
+        let hooks = HooksConfig {
+            tool_call_started: vec![touch(&started_path)],
+            tool_call_finished: vec![touch(&finished_path)],
+            disabled_events: vec!["tool_call_started".to_string()],
+            ..HooksConfig::default()
+        };
+        let user_hooks = UserHooks::new(
+            codex_home.path().to_path_buf(),
+            hooks,
+            None,
+            SandboxPolicy::DangerFullAccess,
+            None,
+            ExclusionConfig::default(),
+            codex_home.path().to_path_buf(),
+        );
+
+        user_hooks.tool_call_started(
+            "thread-1".to_string(),
+            "turn-1".to_string(),
+            "/tmp".to_string(),
+            Uuid::new_v4(),
+            1,
+            "shell".to_string(),
+            "call-1".to_string(),
+            None,
+            None,
+            None,
+        );
+        user_hooks.tool_call_finished(
+            "thread-1".to_string(),
+            "turn-1".to_string(),
+            "/tmp".to_string(),
+            Uuid::new_v4(),
+            1,
+            "shell".to_string(),
+            "call-1".to_string(),
+            ToolCallStatus::Completed,
+            12,
+            true,
+            34,
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(read_to_string_eventually(&finished_path).await?, "ok\n");
+        assert!(!started_path.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn kill_switch_ignores_empty_and_zero_values() {
+        assert!(!kill_switch_enabled(None));
+        assert!(!kill_switch_enabled(Some(OsString::new())));
+        assert!(!kill_switch_enabled(Some(OsString::from("0"))));
+        assert!(kill_switch_enabled(Some(OsString::from("1"))));
+        assert!(kill_switch_enabled(Some(OsString::from("true"))));
+    }
+
+    #[cfg(not(windows))]
+    #[tokio::test]
+    async fn kill_switch_builds_bus_without_providers() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let marker_path = codex_home.path().join("session_end.marker");
+
+        let hooks = HooksConfig {
+            session_end: vec![vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                format!("cat > /dev/null; echo ok > \"{}\"", marker_path.display()),
+            ]],
+            inproc_tool_call_summary: true,
+            host: crate::config::HookHostConfig {
+                enabled: true,
+                command: vec!["python3".to_string(), "host.py".to_string()],
+                ..crate::config::HookHostConfig::default()
+            },
+            ..HooksConfig::default()
+        };
+        let user_hooks = UserHooks::new_with_kill_switch(
+            true,
+            codex_home.path().to_path_buf(),
+            hooks,
+            None,
+            SandboxPolicy::DangerFullAccess,
+            None,
+            ExclusionConfig::default(),
+            codex_home.path().to_path_buf(),
+        );
+        assert!(user_hooks.bus.providers.is_empty());
+
+        user_hooks.session_end(
+            "thread-1".to_string(),
+            "/tmp".to_string(),
+            "exec".to_string(),
+        );
+
+        assert!(!marker_path.exists());
+
+        Ok(())
+    }
+
+    #[cfg(not(windows))]
+    #[tokio::test]
+    async fn hook_metrics_count_emitted_events_and_command_outcomes() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let hooks = HooksConfig {
+            tool_call_started: vec![vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                "cat > /dev/null".to_string(),
+            ]],
+            tool_call_finished: vec![vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                "cat > /dev/null; exit 3".to_string(),
+            ]],
+            disabled_events: vec!["session_start".to_string()],
+            ..HooksConfig::default()
+        };
+        let user_hooks = UserHooks::new_with_kill_switch(
+            false,
+            codex_home.path().to_path_buf(),
+            hooks,
+            None,
+            SandboxPolicy::DangerFullAccess,
+            None,
+            ExclusionConfig::default(),
+            codex_home.path().to_path_buf(),
+        );
+
+        for call_id in ["call-1", "call-2"] {
+            user_hooks.tool_call_started(
+                "thread-1".to_string(),
+                "turn-1".to_string(),
+                "/tmp".to_string(),
+                Uuid::new_v4(),
+                1,
+                "shell".to_string(),
+                call_id.to_string(),
+                None,
+                None,
+                None,
+            );
+        }
+        user_hooks.tool_call_finished(
+            "thread-1".to_string(),
+            "turn-1".to_string(),
+            "/tmp".to_string(),
+            Uuid::new_v4(),
+            1,
+            "shell".to_string(),
+            "call-1".to_string(),
+            ToolCallStatus::Completed,
+            12,
+            true,
+            34,
+            None,
+            None,
+            None,
+        );
+        user_hooks
+            .session_start(
+                "thread-1".to_string(),
+                "/tmp".to_string(),
+                "exec".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+        let expected = HookMetrics {
+            events_emitted: 3,
+            providers: vec![HookProviderMetrics {
+                provider: "external_command".to_string(),
+                commands_spawned: 3,
+                failures: 1,
+                timeouts: 0,
+                breaker_trips: 0,
+            }],
+        };
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+        while user_hooks.metrics() != expected && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(user_hooks.metrics(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_provider_order_skips_unknown_and_appends_missing() {
+        let order = resolve_provider_order(&[
+            "external".to_string(),
+            "not_a_provider".to_string(),
+            "external".to_string(),
+            "inproc".to_string(),
+        ]);
+        assert_eq!(order, vec!["external", "inproc", "host", "webhook"]);
+    }
+
+    #[test]
+    fn circuit_breaker_opens_at_threshold_and_trips_immediately() {
+        let counters = std::sync::Arc::new(HookProviderCounters::default());
+        let mut breaker = HookCircuitBreaker::new(
+            CircuitBreakerPolicy {
+                failure_threshold: 2,
+                open_duration: Duration::from_secs(60),
+            },
+            std::sync::Arc::clone(&counters),
+        );
+
+        breaker.on_failure();
+        assert!(!breaker.is_open());
+        breaker.on_success();
+        breaker.on_failure();
+        assert!(!breaker.is_open());
+        breaker.on_failure();
+        assert!(breaker.is_open());
+
+        breaker.on_success();
+        assert!(!breaker.is_open());
+        breaker.trip();
+        assert!(breaker.is_open());
+        assert_eq!(counters.snapshot("test").breaker_trips, 2);
+    }
+
+    #[tokio::test]
+    async fn webhook_posts_payload_for_configured_events() -> Result<()> {
+        use wiremock::Mock;
+        use wiremock::MockServer;
+        use wiremock::ResponseTemplate;
+        use wiremock::matchers::header;
+        use wiremock::matchers::method;
+        use wiremock::matchers::path;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/xcodex-events"))
+            .and(header("x-hook-token", "token-1"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let codex_home = TempDir::new()?;
+        let hooks = HooksConfig {
+            webhook: crate::config::HookWebhookConfig {
+                url: Some(format!("{}/xcodex-events", server.uri())),
+                headers: HashMap::from([("X-Hook-Token".to_string(), "token-1".to_string())]),
+                events: vec!["user_prompt_submit".to_string()],
+                timeout_sec: None,
+            },
+            ..HooksConfig::default()
+        };
+        let user_hooks = UserHooks::new(
+            codex_home.path().to_path_buf(),
+            hooks,
+            None,
+            SandboxPolicy::DangerFullAccess,
+            None,
+            ExclusionConfig::default(),
+            codex_home.path().to_path_buf(),
+        );
+
+        user_hooks.tool_call_started(
+            "thread-1".to_string(),
+            "turn-1".to_string(),
+            "/tmp".to_string(),
+            Uuid::new_v4(),
+            1,
+            "shell".to_string(),
+            "call-1".to_string(),
+            None,
+            None,
+            None,
+        );
+        user_hooks.user_prompt_submit(
+            "thread-1".to_string(),
+            "/tmp".to_string(),
+            "hello webhook".to_string(),
+        );
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+        let requests = loop {
+            let requests = server.received_requests().await.unwrap_or_default();
+            if !requests.is_empty() || tokio::time::Instant::now() >= deadline {
+                break requests;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        };
+        assert_eq!(requests.len(), 1);
+
+        let body: Value = serde_json::from_slice(&requests[0].body)?;
+        let event = HookEvent {
+            schema_version: HOOK_PAYLOAD_SCHEMA_VERSION,
+            event_id: serde_json::from_value(body["event_id"].clone())?,
+            timestamp: serde_json::from_value(body["timestamp"].clone())?,
+            notification: HookNotification::UserPromptSubmit {
+                thread_id: "thread-1".to_string(),
+                cwd: "/tmp".to_string(),
+                prompt: "hello webhook".to_string(),
+            },
+        };
+        let expected = HookPayload::from_event(&event, &default_hook_event_name(&event));
+        assert_eq!(body, serde_json::to_value(expected)?);
+
+        let metrics = user_hooks.metrics();
+        let webhook = metrics
+            .providers
+            .iter()
+            .find(|provider| provider.provider == "webhook")
+            .expect("webhook provider metrics");
+        assert_eq!(webhook.commands_spawned, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn provider_order_reorders_hook_bus_providers() {
+        let codex_home = TempDir::new().expect("tempdir");
+        let hooks = HooksConfig {
+            inproc: vec![INPROC_EVENT_LOG_JSONL_HOOK_NAME.to_string()],
+            host: crate::config::HookHostConfig {
+                enabled: true,
+                command: vec!["python3".to_string(), "host.py".to_string()],
+                ..crate::config::HookHostConfig::default()
+            },
+            provider_order: vec!["external".to_string(), "inproc".to_string()],
+            ..HooksConfig::default()
+        };
+
+        let user_hooks = UserHooks::new(
+            codex_home.path().to_path_buf(),
+            hooks,
+            None,
+            SandboxPolicy::DangerFullAccess,
+            None,
+            ExclusionConfig::default(),
+            codex_home.path().to_path_buf(),
+        );
+
+        let providers = user_hooks
+            .bus
+            .providers
+            .iter()
+            .map(|provider| provider.metrics().provider)
+            .collect::<Vec<_>>();
+        assert_eq!(providers, vec!["external_command", "inproc", "host"]);
+    }
+
+    #[cfg(not(windows))]
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn dry_run_logs_commands_without_spawning() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let marker_path = codex_home.path().join("dry-run.marker");
+        let hooks = HooksConfig {
+            user_prompt_submit: vec![vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                format!("cat > /dev/null; echo ran > \"{}\"", marker_path.display()),
+            ]],
+            dry_run: true,
+            ..HooksConfig::default()
+        };
+        let provider =
+            ExternalCommandHooksProvider::new(codex_home.path().to_path_buf(), hooks, None);
+
+        let event = HookEvent::new(HookNotification::UserPromptSubmit {
+            thread_id: "thread-1".to_string(),
+            cwd: "/tmp".to_string(),
+            prompt: "hello".to_string(),
+        });
+        let handles = provider.on_event_tracked(&event);
+
+        assert!(handles.is_empty());
+        assert_eq!(provider.metrics().commands_spawned, 0);
+        assert!(!marker_path.exists());
+        assert!(logs_contain("hooks.dry_run: would run [\"sh\", \"-c\""));
+        Ok(())
+    }
+
+    #[cfg(not(windows))]
+    #[tokio::test]
+    async fn emit_test_event_runs_configured_tool_call_finished_hooks() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let payload_dir = codex_home.path().join("payloads");
+        std::fs::create_dir(&payload_dir)?;
+        let hooks = HooksConfig {
+            tool_call_finished: vec![vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                format!("cat > \"{}/$$.json\"", payload_dir.display()),
+            ]],
+            ..HooksConfig::default()
+        };
+        let user_hooks = UserHooks::new(
+            codex_home.path().to_path_buf(),
+            hooks,
+            None,
+            SandboxPolicy::DangerFullAccess,
+            None,
+            ExclusionConfig::default(),
+            codex_home.path().to_path_buf(),
+        );
+
+        let notification = |call_id: &str| {
+            HookNotification::from_camel_case_payload(
+                "toolCallFinished",
+                serde_json::json!({
+                    "threadId": "thread-1",
+                    "turnId": "turn-1",
+                    "cwd": "/tmp",
+                    "modelRequestId": Uuid::nil().to_string(),
+                    "attempt": 1,
+                    "toolName": "shell",
+                    "callId": call_id,
+                    "status": "completed",
+                    "durationMs": 12,
+                    "success": true,
+                    "outputBytes": 3,
+                }),
+            )
+            .map_err(anyhow::Error::msg)
+        };
+        // A real event dispatched alongside the test one must not show up in its metrics.
+        user_hooks.bus.emit(notification("call-0")?);
+        let notification = notification("call-1")?;
+        let metrics = user_hooks.emit_test_event(notification).await;
+
+        assert_eq!(
+            metrics,
+            HookMetrics {
+                events_emitted: 1,
+                providers: vec![HookProviderMetrics {
+                    provider: "external_command".to_string(),
+                    commands_spawned: 1,
+                    ..HookProviderMetrics::default()
+                }],
+            }
+        );
+        let payload = std::fs::read_dir(&payload_dir)?
+            .filter_map(|entry| std::fs::read_to_string(entry.ok()?.path()).ok())
+            .filter_map(|contents| serde_json::from_str::<Value>(&contents).ok())
+            .find(|payload| payload["tool_use_id"] == "call-1")
+            .expect("payload for the test event");
+        assert_eq!(payload["xcodex_event_type"], "tool-call-finished");
+        assert_eq!(payload["tool_name"], "shell");
+        Ok(())
+    }
+
+    #[test]
+    fn tool_call_finished_round_trips_through_sdk_payload() -> Result<()> {
+        let notification = HookNotification::ToolCallFinished {
+            thread_id: "thread-1".to_string(),
+            turn_id: "turn-1".to_string(),
+            cwd: "/tmp".to_string(),
+            model_request_id: Uuid::nil(),
+            attempt: 2,
+            tool_name: "custom_tool".to_string(),
+            call_id: "call-1".to_string(),
+            status: ToolCallStatus::Completed,
+            duration_ms: 12,
+            success: true,
+            output_bytes: 3,
+            output_preview: Some("ok".to_string()),
+            tool_input: None,
+            tool_response: None,
+        };
+        let payload = HookPayload::new(notification.clone(), "PostToolUse");
+        let sdk_payload: codex_hooks_sdk::HookPayload =
+            serde_json::from_value(serde_json::to_value(&payload)?)?;
+
+        assert_eq!(HookNotification::try_from(sdk_payload)?, notification);
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_sdk_payload_rejects_unknown_event_type() -> Result<()> {
+        let notification = HookNotification::SessionEnd {
+            thread_id: "thread-1".to_string(),
+            cwd: "/tmp".to_string(),
+            session_source: "cli".to_string(),
+        };
+        let mut raw = serde_json::to_value(HookPayload::new(notification, "SessionEnd"))?;
+        raw["xcodex_event_type"] = Value::String("mystery-event".to_string());
+        let sdk_payload: codex_hooks_sdk::HookPayload = serde_json::from_value(raw)?;
+
+        let err = HookNotification::try_from(sdk_payload).expect_err("unknown event type");
+        assert_eq!(err.to_string(), "unknown hook event type: mystery-event");
+        Ok(())
+    }
+
+    #[test]
+    fn from_event_payload_rejects_non_object_payload() {
+        assert_eq!(
+            HookNotification::from_event_payload("session-end", Value::Null),
+            Err("hook event payload must be a JSON object".to_string())
+        );
+    }
+
+    #[test]
+    fn configured_shell_wraps_command_hooks() {
+        let hooks = [crate::config::HooksCommandHookConfig {
+            payload: crate::config::HookPayloadFormat::Xcodex,
+            argv: None,
+            command: Some("echo hi".to_string()),
+            timeout_sec: None,
+            detached: None,
+        }];
+        let shell = ["pwsh".to_string(), "-Command".to_string()];
+
+        let specs = compile_command_hook_specs(
+            "tool_call_finished",
+            &hooks,
+            Duration::from_secs(1),
+            &shell,
+        );
+
+        assert_eq!(
+            specs.into_iter().map(|spec| spec.argv).collect::<Vec<_>>(),
+            vec![vec![
+                "pwsh".to_string(),
+                "-Command".to_string(),
+                "echo hi".to_string()
+            ]]
+        );
+    }
+
+    #[test]
+    fn empty_shell_wraps_command_with_platform_default() {
+        let expected = if cfg!(windows) {
+            vec![
+                "cmd.exe".to_string(),
+                "/C".to_string(),
+                "echo hi".to_string(),
+            ]
+        } else {
+            vec!["bash".to_string(), "-lc".to_string(), "echo hi".to_string()]
+        };
+        assert_eq!(wrap_shell_command(&[], "echo hi"), expected);
+    }
+
+    #[test]
+    fn compile_disabled_events_accepts_aliases_and_skips_unknown() {
+        let disabled = compile_disabled_events(&[
+            "tool_call_started".to_string(),
+            "SessionEnd".to_string(),
+            "not_an_event".to_string(),
+        ]);
+        assert_eq!(
+            disabled,
+            HashSet::from([HookEventKey::ToolCallStarted, HookEventKey::SessionEnd])
+        );
+    }
+
+    #[cfg(not(windows))]
+    #[tokio::test]
+    async fn hooks_command_runs_for_alias_event_and_claude_tool_name() -> Result<()> {
+        use std::collections::HashMap;
+
+        let codex_home = TempDir::new()?;
+        let marker_path = codex_home.path().join("hooks.command.marker");
+
+        let mut events = HashMap::new();
+        events.insert(
+            "PostToolUse".to_string(),
+            vec![crate::config::HooksCommandMatcherConfig {
+                matcher: Some("Write".to_string()),
+                hooks: vec![crate::config::HooksCommandHookConfig {
+                    payload: crate::config::HookPayloadFormat::Xcodex,
+                    argv: Some(vec![
+                        "python3".to_string(),
+                        "-c".to_string(),
+                        format!(
+                            r#"import json, pathlib, sys
+payload = json.load(sys.stdin)
+assert payload.get("hook_event_name") == "PostToolUse", payload
+assert payload.get("xcodex_event_type") == "tool-call-finished", payload
+assert payload.get("tool_name") == "Write", payload
+pathlib.Path({path:?}).write_text("ok", encoding="utf-8")
+"#,
+                            path = marker_path.to_string_lossy()
+                        ),
+                    ]),
+                    command: None,
+                    timeout_sec: Some(5),
+                    detached: None,
+                }],
+            }],
+        );
+
+        let hooks = HooksConfig {
+            command: crate::config::HooksCommandConfig {
+                default_timeout_sec: 30,
+                events,
+                ..crate::config::HooksCommandConfig::default()
+            },
+            ..HooksConfig::default()
+        };
+
+        let user_hooks = UserHooks::new(
+            codex_home.path().to_path_buf(),
+            hooks,
+            None,
+            SandboxPolicy::DangerFullAccess,
+            None,
+            ExclusionConfig::default(),
+            codex_home.path().to_path_buf(),
+        );
+
+        user_hooks.tool_call_finished(
+            "thread-1".to_string(),
+            "turn-1".to_string(),
+            "/tmp".to_string(),
+            Uuid::new_v4(),
+            0,
+            "write_file".to_string(),
+            "call-1".to_string(),
+            ToolCallStatus::Completed,
+            1,
+            true,
+            0,
+            None,
+            None,
+            None,
+        );
+
+        let contents = read_to_string_eventually(&marker_path).await?;
+        assert_eq!(contents.trim(), "ok");
+        Ok(())
+    }
+
+    #[test]
+    fn test_hook_stdin_envelope_has_payload_path() -> Result<()> {
+        let event = HookEvent::new(HookNotification::AgentTurnComplete {
+            thread_id: "t".to_string(),
+            turn_id: "turn".to_string(),
+            cwd: "/tmp".to_string(),
+            input_messages: Vec::new(),
+            last_assistant_message: None,
+            session_stats: HookSessionStats::default(),
+        });
+        let payload = HookPayload::from_event(&event, "Stop");
+        let envelope =
+            HookStdinEnvelope::from_payload(&payload, PathBuf::from("/tmp/payload.json"));
+        let serialized = serde_json::to_string(&envelope)?;
+        assert!(
+            serialized.contains(r#""payload_path":"/tmp/payload.json""#),
+            "envelope must include payload_path: {serialized}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn tool_call_summary_log_matches_gallery_script() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let out_path = codex_home.path().join(TOOL_CALL_SUMMARY_LOG_FILENAME);
+        let line = format!(
+            "type=tool-call-finished tool=exec status={} success=true duration_ms=12 output_bytes=34 cwd=/tmp\n",
+            tool_call_status_string(ToolCallStatus::Completed)
+        );
+
+        append_tool_call_summary_line(&out_path, &line)?;
+        let contents = std::fs::read_to_string(&out_path)?;
+        assert_eq!(contents, line);
+        Ok(())
+    }
+
+    #[test]
+    fn model_filter_matches_model_slug() {
+        let filters = CompiledEventFilters::compile(&crate::config::HookEventFiltersConfig {
+            events: HashMap::from([(
+                "model_request_started".to_string(),
+                vec![crate::config::HookEventMatcherConfig {
+                    matcher: Some("^gpt-5".to_string()),
+                }],
+            )]),
+        });
+        let request_started = |model: &str| HookNotification::ModelRequestStarted {
+            thread_id: "thread-1".to_string(),
+            turn_id: "turn-1".to_string(),
+            cwd: "/tmp".to_string(),
+            model_request_id: Uuid::new_v4(),
+            attempt: 1,
+            model: model.to_string(),
+            provider: "openai".to_string(),
+            input_item_count: 1,
+            tool_count: 0,
+            parallel_tool_calls: false,
+            has_output_schema: false,
+            rate_limits: None,
+        };
+
+        let allows = |notification: &HookNotification| {
+            filters.allows(
+                HookEventKey::from_notification(notification),
+                &build_match_candidates(notification),
+            )
+        };
+        assert_eq!(
+            (
+                allows(&request_started("gpt-5-codex")),
+                allows(&request_started("o3")),
+            ),
+            (true, false)
+        );
+    }
+
+    #[test]
+    fn model_response_completed_payload_carries_token_usage() -> Result<()> {
+        let token_usage = TokenUsage {
+            input_tokens: 120,
+            cached_input_tokens: 20,
+            output_tokens: 30,
+            reasoning_output_tokens: 5,
+            total_tokens: 150,
+        };
+        let payload = HookPayload::new(
+            HookNotification::ModelResponseCompleted {
+                thread_id: "thread-1".to_string(),
+                turn_id: "turn-1".to_string(),
+                cwd: "/tmp".to_string(),
+                model_request_id: Uuid::new_v4(),
+                attempt: 1,
+                model: "gpt-5".to_string(),
+                response_id: "resp-1".to_string(),
+                token_usage: Some(token_usage),
+                needs_follow_up: false,
+            },
+            "model_response_completed",
+        );
+
+        let payload_json = serde_json::to_value(&payload)?;
+        assert_eq!(
+            payload_json.get("token_usage"),
+            Some(&serde_json::json!({
+                "input_tokens": 120,
+                "cached_input_tokens": 20,
+                "output_tokens": 30,
+                "reasoning_output_tokens": 5,
+                "total_tokens": 150,
+            }))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn model_request_started_payload_carries_redacted_rate_limits() -> Result<()> {
+        let snapshot = RateLimitSnapshot {
+            limit_id: Some("codex".to_string()),
+            limit_name: None,
+            primary: Some(RateLimitWindow {
+                used_percent: 75.0,
+                window_minutes: Some(300),
+                resets_at: Some(1_700_000_000),
+            }),
+            secondary: None,
+            credits: Some(CreditsSnapshot {
+                has_credits: true,
+                unlimited: false,
+                balance: Some("42.00".to_string()),
+            }),
+            plan_type: None,
+        };
+        let request_started = |rate_limits: Option<&RateLimitSnapshot>| {
+            let payload = HookPayload::new(
+                HookNotification::ModelRequestStarted {
+                    thread_id: "thread-1".to_string(),
+                    turn_id: "turn-1".to_string(),
+                    cwd: "/tmp".to_string(),
+                    model_request_id: Uuid::new_v4(),
+                    attempt: 1,
+                    model: "gpt-5".to_string(),
+                    provider: "openai".to_string(),
+                    input_item_count: 1,
+                    tool_count: 0,
+                    parallel_tool_calls: false,
+                    has_output_schema: false,
+                    rate_limits: rate_limits.map(HookRateLimitSnapshot::from),
+                },
+                "model_request_started",
+            );
+            serde_json::to_value(&payload)
+        };
+
+        assert_eq!(
+            request_started(Some(&snapshot))?.get("rate_limits"),
+            Some(&serde_json::json!({
+                "limit_id": "codex",
+                "primary": {
+                    "remaining_percent": 25.0,
+                    "window_minutes": 300,
+                    "resets_at": 1_700_000_000,
+                },
+            }))
+        );
+        assert_eq!(request_started(None)?.get("rate_limits"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn dedupe_window_drops_identical_back_to_back_events() {
+        #[derive(Default)]
+        struct CountingProvider {
+            events: AtomicU64,
+        }
+
+        impl HookProvider for CountingProvider {
+            fn on_event(&self, _event: &HookEvent) {
+                self.events.fetch_add(1, Ordering::Relaxed);
+            }
+
+            fn metrics(&self) -> HookProviderMetrics {
+                HookProviderMetrics::default()
+            }
+        }
+
+        let tool_call_started = |call_id: &str| HookNotification::ToolCallStarted {
+            thread_id: "thread-1".to_string(),
+            turn_id: "turn-1".to_string(),
+            cwd: "/tmp".to_string(),
+            model_request_id: Uuid::nil(),
+            attempt: 1,
+            tool_name: "shell".to_string(),
+            call_id: call_id.to_string(),
+            tool_input: None,
+            command: None,
+            paths: None,
+        };
+        let provider = std::sync::Arc::new(CountingProvider::default());
+        let bus = HookBus {
+            providers: vec![provider.clone()],
+            disabled_events: HashSet::new(),
+            dedupe: HookEventDeduper::new(60_000).map(std::sync::Arc::new),
+            events_emitted: std::sync::Arc::default(),
+        };
+
+        bus.emit(tool_call_started("call-1"));
+        bus.emit(tool_call_started("call-1"));
+        bus.emit(tool_call_started("call-2"));
+
+        assert_eq!(provider.events.load(Ordering::Relaxed), 2);
+        assert_eq!(bus.metrics().events_emitted, 2);
+    }
+
+    #[test]
+    fn tool_call_started_payload_carries_exec_argv() -> Result<()> {
+        let command = vec!["bash".to_string(), "-lc".to_string(), "ls -la".to_string()];
+        let payload = HookPayload::new(
+            HookNotification::ToolCallStarted {
+                thread_id: "thread-1".to_string(),
+                turn_id: "turn-1".to_string(),
+                cwd: "/tmp".to_string(),
+                model_request_id: Uuid::new_v4(),
+                attempt: 1,
+                tool_name: "shell".to_string(),
+                call_id: "call-1".to_string(),
+                tool_input: Some(serde_json::json!({ "command": command })),
+                command: Some(command.clone()),
+                paths: None,
+            },
+            "PreToolUse",
+        );
+
+        let payload_json = serde_json::to_value(&payload)?;
+        assert_eq!(
+            payload_json.get("command"),
+            Some(&serde_json::json!(command))
+        );
+        assert_eq!(payload_json.get("paths"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn approval_notification_is_categorized_as_approval_needed() -> Result<()> {
+        #[derive(Default)]
+        struct RecordingProvider {
+            events: std::sync::Mutex<Vec<HookEvent>>,
+        }
+
+        impl HookProvider for RecordingProvider {
+            fn on_event(&self, event: &HookEvent) {
+                if let Ok(mut events) = self.events.lock() {
+                    events.push(event.clone());
+                }
+            }
+
+            fn metrics(&self) -> HookProviderMetrics {
+                HookProviderMetrics::default()
+            }
+        }
+
+        let provider = std::sync::Arc::new(RecordingProvider::default());
+        let hooks = UserHooks {
+            bus: HookBus {
+                providers: vec![provider.clone()],
+                disabled_events: HashSet::new(),
+                dedupe: None,
+                events_emitted: std::sync::Arc::default(),
+            },
+            payload_sanitizer: None,
+            output_preview_bytes: 0,
+            session_start_blocking: None,
+        };
+
+        hooks.approval_requested_elicitation(
+            "thread-1".to_string(),
+            "/tmp".to_string(),
+            "server".to_string(),
+            "request-1".to_string(),
+            "Allow access?".to_string(),
+        );
+
+        let events = provider
+            .events
+            .lock()
+            .map_err(|_| anyhow::anyhow!("recorded events lock poisoned"))?;
+        let notification = events
+            .iter()
+            .find(|event| matches!(event.notification(), HookNotification::Notification { .. }))
+            .ok_or_else(|| anyhow::anyhow!("expected a notification event"))?;
+        let payload = serde_json::to_value(HookPayload::from_event(notification, "Notification"))?;
+        assert_eq!(payload["category"], "approval-needed");
+        assert_eq!(payload["priority"], "high");
+        Ok(())
+    }
+
+    #[test]
+    fn subagent_stop_payload_identifies_subagent() -> Result<()> {
+        let event = HookEvent::new(HookNotification::SubagentStop {
+            thread_id: "t".to_string(),
+            cwd: "/tmp".to_string(),
+            subagent: "review".to_string(),
+            subagent_id: "child-thread".to_string(),
+            subagent_name: "review".to_string(),
+            status: "completed".to_string(),
+            result_summary: Some("no issues found".to_string()),
+        });
+        let payload = serde_json::to_value(HookPayload::from_event(&event, "SubagentStop"))?;
+
+        assert_eq!(payload["xcodex_event_type"], "subagent-stop");
+        assert_eq!(payload["subagent_id"], "child-thread");
+        assert_eq!(payload["subagent_name"], "review");
+        assert_eq!(payload["result_summary"], "no issues found");
+        Ok(())
+    }
+
+    #[test]
+    fn large_payload_uses_payload_path_envelope() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let event = HookEvent::new(HookNotification::AgentTurnComplete {
+            thread_id: "t".to_string(),
+            turn_id: "turn".to_string(),
+            cwd: "/tmp".to_string(),
+            input_messages: vec!["x".repeat(20_000)],
+            last_assistant_message: None,
+            session_stats: HookSessionStats::default(),
+        });
+        let payload = HookPayload::from_event(&event, "Stop");
+        let payload_json = serde_json::to_vec(&payload)?;
+
+        let stdin_payload = prepare_hook_stdin_payload(
+            &payload,
+            &payload_json,
+            16,
+            HookOversizedPayloadStrategy::Spill,
+            50,
+            codex_home.path(),
+        )
+        .ok_or_else(|| anyhow::anyhow!("spill strategy should deliver the payload"))?;
+
+        let envelope: Value = serde_json::from_slice(&stdin_payload)?;
+        let payload_path = envelope
+            .get("payload_path")
+            .and_then(Value::as_str)
+            .ok_or_else(|| anyhow::anyhow!("expected payload_path envelope"))?;
+
+        let read_back = std::fs::read(payload_path)?;
+        assert_eq!(read_back, payload_json);
+        Ok(())
+    }
+
+    #[test]
+    fn large_payload_truncate_strategy_marks_truncated_payload() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let event = HookEvent::new(HookNotification::AgentTurnComplete {
+            thread_id: "t".to_string(),
+            turn_id: "turn".to_string(),
+            cwd: "/tmp".to_string(),
+            input_messages: vec!["x".repeat(20_000)],
+            last_assistant_message: None,
+            session_stats: HookSessionStats::default(),
+        });
+        let payload = HookPayload::from_event(&event, "Stop");
+        let payload_json = serde_json::to_vec(&payload)?;
+
+        let stdin_payload = prepare_hook_stdin_payload(
+            &payload,
+            &payload_json,
+            4096,
+            HookOversizedPayloadStrategy::Truncate,
+            50,
+            codex_home.path(),
+        )
+        .ok_or_else(|| anyhow::anyhow!("truncate strategy should deliver the payload"))?;
+
+        assert!(stdin_payload.len() <= 4096);
+        let truncated: Value = serde_json::from_slice(&stdin_payload)?;
+        assert_eq!(truncated.get("truncated"), Some(&Value::Bool(true)));
+        assert_eq!(truncated.get("payload_path"), None);
+        assert_eq!(
+            truncated.get("event_id").and_then(Value::as_str),
+            Some(payload.event_id.to_string().as_str())
+        );
+        assert!(!codex_home.path().join("tmp/hooks/payloads").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn large_payload_drop_strategy_skips_delivery() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let event = HookEvent::new(HookNotification::AgentTurnComplete {
+            thread_id: "t".to_string(),
+            turn_id: "turn".to_string(),
+            cwd: "/tmp".to_string(),
+            input_messages: vec!["x".repeat(20_000)],
+            last_assistant_message: None,
+            session_stats: HookSessionStats::default(),
+        });
+        let payload = HookPayload::from_event(&event, "Stop");
+        let payload_json = serde_json::to_vec(&payload)?;
+
+        let stdin_payload = prepare_hook_stdin_payload(
+            &payload,
+            &payload_json,
+            16,
+            HookOversizedPayloadStrategy::Drop,
+            50,
+            codex_home.path(),
+        );
+
+        assert_eq!(stdin_payload, None);
+        assert!(!codex_home.path().join("tmp/hooks/payloads").exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tool_call_summary_log_emits_from_user_hooks() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let hooks = HooksConfig {
+            inproc_tool_call_summary: true,
+            ..HooksConfig::default()
+        };
+        let user_hooks = UserHooks::new(
+            codex_home.path().to_path_buf(),
+            hooks,
+            None,
+            SandboxPolicy::DangerFullAccess,
+            None,
+            ExclusionConfig::default(),
+            codex_home.path().to_path_buf(),
+        );
+
+        user_hooks.tool_call_finished(
+            "thread-1".to_string(),
+            "turn-1".to_string(),
+            "/tmp".to_string(),
+            Uuid::new_v4(),
+            1,
+            "exec".to_string(),
+            "call-1".to_string(),
+            ToolCallStatus::Completed,
+            12,
+            true,
+            34,
+            None,
+            None,
+            None,
+        );
+
+        let out_path = codex_home.path().join(TOOL_CALL_SUMMARY_LOG_FILENAME);
+        let contents = read_to_string_eventually(&out_path).await?;
+        assert!(
+            contents.starts_with("type=tool-call-finished tool=exec status=completed"),
+            "summary line missing expected prefix: {contents:?}"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cost_summary_sums_token_usage_per_session() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let hooks = HooksConfig {
+            inproc: vec![INPROC_COST_SUMMARY_HOOK_NAME.to_string()],
+            ..HooksConfig::default()
+        };
+        let user_hooks = UserHooks::new(
+            codex_home.path().to_path_buf(),
+            hooks,
+            None,
+            SandboxPolicy::DangerFullAccess,
+            None,
+            ExclusionConfig::default(),
+            codex_home.path().to_path_buf(),
+        );
+
+        for (response_id, input_tokens, output_tokens) in [("resp-1", 100, 20), ("resp-2", 50, 10)]
+        {
+            user_hooks.model_response_completed(
+                "thread-1".to_string(),
+                "turn-1".to_string(),
+                "/tmp".to_string(),
+                Uuid::new_v4(),
+                1,
+                "gpt-5".to_string(),
+                response_id.to_string(),
+                Some(TokenUsage {
+                    input_tokens,
+                    cached_input_tokens: 0,
+                    output_tokens,
+                    reasoning_output_tokens: 0,
+                    total_tokens: input_tokens + output_tokens,
+                }),
+                false,
+            );
+        }
+        user_hooks.agent_turn_complete(
+            "thread-1".to_string(),
+            "turn-1".to_string(),
+            "/tmp".to_string(),
+            Vec::new(),
+            None,
+        );
+
+        let out_path = codex_home.path().join(HOOK_COST_SUMMARY_JSONL_FILENAME);
+        let contents = read_to_string_eventually(&out_path).await?;
+        let mut summary: Value = serde_json::from_str(contents.trim_end())?;
+        summary
+            .as_object_mut()
+            .and_then(|summary| summary.remove("timestamp"))
+            .ok_or_else(|| anyhow::anyhow!("cost summary is missing timestamp"))?;
+        assert_eq!(
+            summary,
+            serde_json::json!({
+                "session_id": "thread-1",
+                "xcodex_event_type": "agent-turn-complete",
+                "responses": 2,
+                "token_usage": {
+                    "input_tokens": 150,
+                    "cached_input_tokens": 0,
+                    "output_tokens": 30,
+                    "reasoning_output_tokens": 0,
+                    "total_tokens": 180,
+                },
+            })
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tool_call_summary_inproc_list_dedupes() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let hooks = HooksConfig {
+            inproc_tool_call_summary: true,
+            inproc: vec![INPROC_TOOL_CALL_SUMMARY_HOOK_NAME.to_string()],
+            ..HooksConfig::default()
+        };
+        let user_hooks = UserHooks::new(
+            codex_home.path().to_path_buf(),
+            hooks,
+            None,
+            SandboxPolicy::DangerFullAccess,
+            None,
+            ExclusionConfig::default(),
+            codex_home.path().to_path_buf(),
+        );
+
+        user_hooks.tool_call_finished(
+            "thread-1".to_string(),
+            "turn-1".to_string(),
+            "/tmp".to_string(),
+            Uuid::new_v4(),
+            1,
+            "exec".to_string(),
+            "call-1".to_string(),
+            ToolCallStatus::Completed,
+            12,
+            true,
+            34,
+            None,
+            None,
+            None,
+        );
+
+        let out_path = codex_home.path().join(TOOL_CALL_SUMMARY_LOG_FILENAME);
+        let contents = read_to_string_eventually(&out_path).await?;
+        assert_eq!(contents.lines().count(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn event_log_jsonl_emits_all_payloads() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let hooks = HooksConfig {
+            inproc: vec![INPROC_EVENT_LOG_JSONL_HOOK_NAME.to_string()],
+            ..HooksConfig::default()
+        };
+        let user_hooks = UserHooks::new(
             codex_home.path().to_path_buf(),
             hooks,
             None,
@@ -3956,6 +7293,17 @@ pathlib.Path({path:?}).write_text("ok", encoding="utf-8")
             codex_home.path().to_path_buf(),
         );
 
+        user_hooks
+            .session_start(
+                "thread-1".to_string(),
+                "/tmp".to_string(),
+                "exec".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
         user_hooks.tool_call_finished(
             "thread-1".to_string(),
             "turn-1".to_string(),
@@ -3973,84 +7321,431 @@ pathlib.Path({path:?}).write_text("ok", encoding="utf-8")
             None,
         );
 
-        let out_path = codex_home.path().join(TOOL_CALL_SUMMARY_LOG_FILENAME);
-        let contents = read_to_string_eventually(&out_path).await?;
-        assert_eq!(contents.lines().count(), 1);
+        let out_path = codex_home.path().join(HOOK_EVENT_LOG_JSONL_FILENAME);
+        let contents = tokio::time::timeout(Duration::from_secs(2), async {
+            loop {
+                if let Ok(contents) = std::fs::read_to_string(&out_path)
+                    && contents.lines().count() >= 2
+                {
+                    break contents;
+                }
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await?;
+
+        let types = contents
+            .lines()
+            .map(serde_json::from_str::<Value>)
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter_map(|value| {
+                value
+                    .get("xcodex_event_type")
+                    .and_then(|v| v.as_str())
+                    .map(ToString::to_string)
+            })
+            .collect::<Vec<_>>();
+
+        assert!(
+            types.iter().any(|ty| ty == "session-start"),
+            "expected session-start event; saw: {types:?}"
+        );
+        assert!(
+            types.iter().any(|ty| ty == "tool-call-finished"),
+            "expected tool-call-finished event; saw: {types:?}"
+        );
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn hook_host_receives_events() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let codex_home = TempDir::new()?;
+        let out_path = codex_home.path().join("hook-host.out.jsonl");
+        let script_path = codex_home.path().join("host.sh");
+
+        std::fs::write(
+            &script_path,
+            r#"#!/bin/sh
+set -eu
+out="$1"
+mkdir -p "$(dirname "$out")"
+while IFS= read -r line; do
+  case "$line" in
+    *'"type":"hello"'*) printf '{"type":"hello","schema_version":1}\n' ;;
+    *) printf '%s\n' "$line" >> "$out" ;;
+  esac
+done
+"#,
+        )?;
+        std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755))?;
+
+        let hooks = HooksConfig {
+            host: crate::config::HookHostConfig {
+                enabled: true,
+                command: vec![
+                    script_path
+                        .to_str()
+                        .ok_or_else(|| anyhow::anyhow!("script path is not valid utf-8"))?
+                        .to_string(),
+                    out_path
+                        .to_str()
+                        .ok_or_else(|| anyhow::anyhow!("output path is not valid utf-8"))?
+                        .to_string(),
+                ],
+                sandbox_mode: None,
+                timeout_sec: None,
+                ready_timeout_sec: None,
+                working_dir: None,
+                filters: crate::config::HookEventFiltersConfig::default(),
+            },
+            ..HooksConfig::default()
+        };
+
+        let user_hooks = UserHooks::new(
+            codex_home.path().to_path_buf(),
+            hooks,
+            None,
+            SandboxPolicy::DangerFullAccess,
+            None,
+            ExclusionConfig::default(),
+            codex_home.path().to_path_buf(),
+        );
+
+        user_hooks
+            .session_start(
+                "thread-1".to_string(),
+                "/tmp".to_string(),
+                "exec".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+        let contents = read_to_string_eventually(&out_path).await?;
+        let first: Value = serde_json::from_str(
+            contents
+                .lines()
+                .next()
+                .ok_or_else(|| anyhow::anyhow!("expected at least one host line"))?,
+        )?;
+
+        assert_eq!(first["type"], "hook-event");
+        assert_eq!(first["event"]["xcodex_event_type"], "session-start");
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn hook_host_runs_in_configured_working_dir() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let codex_home = TempDir::new()?;
+        let project_dir = codex_home.path().join("project");
+        std::fs::create_dir(&project_dir)?;
+        let cwd_path = codex_home.path().join("hook-host.cwd");
+        let script_path = codex_home.path().join("host.sh");
+
+        std::fs::write(
+            &script_path,
+            r#"#!/bin/sh
+set -eu
+pwd -P > "$1"
+while IFS= read -r line; do
+  case "$line" in
+    *'"type":"hello"'*) printf '{"type":"hello","schema_version":1}\n' ;;
+  esac
+done
+"#,
+        )?;
+        std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755))?;
+
+        let hooks = HooksConfig {
+            host: crate::config::HookHostConfig {
+                enabled: true,
+                command: vec![
+                    script_path.to_string_lossy().to_string(),
+                    cwd_path.to_string_lossy().to_string(),
+                ],
+                working_dir: Some(PathBuf::from("project")),
+                ..crate::config::HookHostConfig::default()
+            },
+            ..HooksConfig::default()
+        };
+
+        let user_hooks = UserHooks::new(
+            codex_home.path().to_path_buf(),
+            hooks,
+            None,
+            SandboxPolicy::DangerFullAccess,
+            None,
+            ExclusionConfig::default(),
+            codex_home.path().to_path_buf(),
+        );
+
+        user_hooks
+            .session_start(
+                "thread-1".to_string(),
+                "/tmp".to_string(),
+                "exec".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+        let contents = read_to_string_eventually(&cwd_path).await?;
+        assert_eq!(PathBuf::from(contents.trim()), project_dir.canonicalize()?);
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn hook_host_with_incompatible_schema_opens_breaker() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let codex_home = TempDir::new()?;
+        let spawns_path = codex_home.path().join("hook-host.spawns");
+        let out_path = codex_home.path().join("hook-host.out.jsonl");
+        let script_path = codex_home.path().join("host.sh");
+
+        std::fs::write(
+            &script_path,
+            r#"#!/bin/sh
+set -eu
+echo spawned >> "$1"
+while IFS= read -r line; do
+  case "$line" in
+    *'"type":"hello"'*)
+      echo "starting up"
+      printf '{"type":"log","message":"booting"}\n'
+      printf '{"type":"hello","schema_version":99}\n'
+      ;;
+    *) printf '%s\n' "$line" >> "$2" ;;
+  esac
+done
+"#,
+        )?;
+        std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755))?;
+
+        let spawn_cfg = HookHostSpawnConfig {
+            command: vec![
+                script_path.to_string_lossy().to_string(),
+                spawns_path.to_string_lossy().to_string(),
+                out_path.to_string_lossy().to_string(),
+            ],
+            codex_home: codex_home.path().to_path_buf(),
+            working_dir: codex_home.path().to_path_buf(),
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            codex_linux_sandbox_exe: None,
+            keep_last_n_payloads: 50,
+            write_timeout: None,
+            handshake_timeout: Duration::from_secs(5),
+            ready_timeout: None,
+        };
+        let policy = HookHostPolicy::default();
+        let (tx_line, rx_line) = mpsc::channel(policy.queue_capacity);
+        let manager = tokio::spawn(run_hook_host_manager(
+            rx_line,
+            spawn_cfg,
+            policy,
+            std::sync::Arc::default(),
+        ));
+
+        for _ in 0..2 {
+            let event = HookEvent::new(HookNotification::UserPromptSubmit {
+                thread_id: "thread-1".to_string(),
+                cwd: "/tmp".to_string(),
+                prompt: "hello".to_string(),
+            });
+            let payload = HookPayload::from_event(&event, "UserPromptSubmit");
+            tx_line
+                .send(HookHostMessage::Payload(std::sync::Arc::new(payload)))
+                .await
+                .map_err(|_| anyhow::anyhow!("hook host queue closed"))?;
+        }
+        drop(tx_line);
+        tokio::time::timeout(Duration::from_secs(5), manager).await??;
+
+        // The host was spawned once, rejected, and never respawned or sent events.
+        assert_eq!(std::fs::read_to_string(&spawns_path)?, "spawned\n");
+        assert!(!out_path.exists());
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn hook_host_without_hello_reply_keeps_receiving_events() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let codex_home = TempDir::new()?;
+        let spawns_path = codex_home.path().join("hook-host.spawns");
+        let out_path = codex_home.path().join("hook-host.out.jsonl");
+        let script_path = codex_home.path().join("host.sh");
+
+        // A host that predates the handshake: it prints startup noise and never replies.
+        std::fs::write(
+            &script_path,
+            r#"#!/bin/sh
+set -eu
+echo spawned >> "$1"
+echo "legacy host ready"
+printf '{"type":"log","message":"booting"}\n'
+while IFS= read -r line; do
+  case "$line" in
+    *'"type":"hello"'*) ;;
+    *) printf '%s\n' "$line" >> "$2" ;;
+  esac
+done
+"#,
+        )?;
+        std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755))?;
+
+        let spawn_cfg = HookHostSpawnConfig {
+            command: vec![
+                script_path.to_string_lossy().to_string(),
+                spawns_path.to_string_lossy().to_string(),
+                out_path.to_string_lossy().to_string(),
+            ],
+            codex_home: codex_home.path().to_path_buf(),
+            working_dir: codex_home.path().to_path_buf(),
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            codex_linux_sandbox_exe: None,
+            keep_last_n_payloads: 50,
+            write_timeout: None,
+            handshake_timeout: Duration::from_millis(200),
+            ready_timeout: None,
+        };
+        let policy = HookHostPolicy::default();
+        let (tx_line, rx_line) = mpsc::channel(policy.queue_capacity);
+        let manager = tokio::spawn(run_hook_host_manager(
+            rx_line,
+            spawn_cfg,
+            policy,
+            std::sync::Arc::default(),
+        ));
+
+        for _ in 0..2 {
+            let event = HookEvent::new(HookNotification::UserPromptSubmit {
+                thread_id: "thread-1".to_string(),
+                cwd: "/tmp".to_string(),
+                prompt: "hello".to_string(),
+            });
+            let payload = HookPayload::from_event(&event, "UserPromptSubmit");
+            tx_line
+                .send(HookHostMessage::Payload(std::sync::Arc::new(payload)))
+                .await
+                .map_err(|_| anyhow::anyhow!("hook host queue closed"))?;
+        }
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+        while std::fs::read_to_string(&out_path)
+            .map(|contents| contents.lines().count())
+            .unwrap_or_default()
+            < 2
+            && tokio::time::Instant::now() < deadline
+        {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        drop(tx_line);
+        tokio::time::timeout(Duration::from_secs(5), manager).await??;
+
+        // Startup noise is not a spawn error, so the host is neither rejected nor respawned.
+        assert_eq!(std::fs::read_to_string(&spawns_path)?, "spawned\n");
+        assert_eq!(std::fs::read_to_string(&out_path)?.lines().count(), 2);
+
         Ok(())
     }
 
+    #[cfg(unix)]
     #[tokio::test]
-    async fn event_log_jsonl_emits_all_payloads() -> Result<()> {
+    async fn respawned_hook_host_renegotiates_schema_version() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
         let codex_home = TempDir::new()?;
-        let hooks = HooksConfig {
-            inproc: vec![INPROC_EVENT_LOG_JSONL_HOOK_NAME.to_string()],
-            ..HooksConfig::default()
+        let spawns_path = codex_home.path().join("hook-host.spawns");
+        let script_path = codex_home.path().join("host.sh");
+
+        // The first spawn speaks schema 1; the "upgraded" second spawn only speaks 99.
+        std::fs::write(
+            &script_path,
+            r#"#!/bin/sh
+set -eu
+echo spawned >> "$1"
+version=1
+if [ "$(wc -l < "$1")" -gt 1 ]; then version=99; fi
+while IFS= read -r line; do
+  case "$line" in
+    *'"type":"hello"'*) printf '{"type":"hello","schema_version":%s}\n' "$version" ;;
+  esac
+done
+"#,
+        )?;
+        std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755))?;
+
+        let spawn_cfg = HookHostSpawnConfig {
+            command: vec![
+                script_path.to_string_lossy().to_string(),
+                spawns_path.to_string_lossy().to_string(),
+            ],
+            codex_home: codex_home.path().to_path_buf(),
+            working_dir: codex_home.path().to_path_buf(),
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            codex_linux_sandbox_exe: None,
+            keep_last_n_payloads: 50,
+            write_timeout: None,
+            handshake_timeout: Duration::from_secs(5),
+            ready_timeout: None,
         };
-        let user_hooks = UserHooks::new(
-            codex_home.path().to_path_buf(),
-            hooks,
-            None,
-            SandboxPolicy::DangerFullAccess,
-            None,
-            ExclusionConfig::default(),
-            codex_home.path().to_path_buf(),
-        );
 
-        user_hooks.session_start(
-            "thread-1".to_string(),
-            "/tmp".to_string(),
-            "exec".to_string(),
-        );
-        user_hooks.tool_call_finished(
-            "thread-1".to_string(),
-            "turn-1".to_string(),
-            "/tmp".to_string(),
-            Uuid::new_v4(),
-            1,
-            "exec".to_string(),
-            "call-1".to_string(),
-            ToolCallStatus::Completed,
-            12,
-            true,
-            34,
-            None,
-            None,
-            None,
-        );
+        let (_child, _stdin) = spawn_hook_host_process(&spawn_cfg).await?;
+        let respawned = spawn_hook_host_process(&spawn_cfg).await;
 
-        let out_path = codex_home.path().join(HOOK_EVENT_LOG_JSONL_FILENAME);
-        let contents = tokio::time::timeout(Duration::from_secs(2), async {
-            loop {
-                if let Ok(contents) = std::fs::read_to_string(&out_path)
-                    && contents.lines().count() >= 2
-                {
-                    break contents;
-                }
-                tokio::time::sleep(Duration::from_millis(5)).await;
-            }
-        })
-        .await?;
+        assert!(matches!(
+            respawned,
+            Err(HookHostSpawnError::IncompatibleSchema { host: 99, .. })
+        ));
 
-        let types = contents
-            .lines()
-            .map(serde_json::from_str::<Value>)
-            .collect::<std::result::Result<Vec<_>, _>>()?
-            .into_iter()
-            .filter_map(|value| {
-                value
-                    .get("xcodex_event_type")
-                    .and_then(|v| v.as_str())
-                    .map(ToString::to_string)
-            })
-            .collect::<Vec<_>>();
+        Ok(())
+    }
 
-        assert!(
-            types.iter().any(|ty| ty == "session-start"),
-            "expected session-start event; saw: {types:?}"
-        );
-        assert!(
-            types.iter().any(|ty| ty == "tool-call-finished"),
-            "expected tool-call-finished event; saw: {types:?}"
+    #[cfg(windows)]
+    #[tokio::test]
+    async fn hook_host_cmd_script_is_spawnable() -> Result<()> {
+        let codex_home = TempDir::new()?;
+        let marker_path = codex_home.path().join("spawned.txt");
+        std::fs::write(
+            codex_home.path().join("host.cmd"),
+            "@echo off\r\necho spawned> \"%~dp0spawned.txt\"\r\nmore > nul\r\n",
+        )?;
+
+        // No extension: resolution must find `host.cmd` via PATHEXT.
+        let spawn_cfg = HookHostSpawnConfig {
+            command: vec![codex_home.path().join("host").to_string_lossy().to_string()],
+            codex_home: codex_home.path().to_path_buf(),
+            working_dir: codex_home.path().to_path_buf(),
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            codex_linux_sandbox_exe: None,
+            keep_last_n_payloads: 50,
+            write_timeout: None,
+            handshake_timeout: Duration::from_millis(100),
+            ready_timeout: None,
+        };
+        let (_child, _stdin) = spawn_hook_host_process(&spawn_cfg).await?;
+
+        assert_eq!(
+            read_to_string_eventually(&marker_path).await?.trim(),
+            "spawned"
         );
 
         Ok(())
@@ -4058,7 +7753,7 @@ pathlib.Path({path:?}).write_text("ok", encoding="utf-8")
 
     #[cfg(unix)]
     #[tokio::test]
-    async fn hook_host_receives_events() -> Result<()> {
+    async fn hook_host_waits_for_delayed_pong_before_sending_events() -> Result<()> {
         use std::os::unix::fs::PermissionsExt;
 
         let codex_home = TempDir::new()?;
@@ -4069,61 +7764,80 @@ pathlib.Path({path:?}).write_text("ok", encoding="utf-8")
             &script_path,
             r#"#!/bin/sh
 set -eu
-out="$1"
-mkdir -p "$(dirname "$out")"
 while IFS= read -r line; do
-  printf '%s\n' "$line" >> "$out"
+  case "$line" in
+    *'"type":"hello"'*) printf '{"type":"hello","schema_version":1}\n' ;;
+    *'"type":"ping"'*)
+      sleep 0.5
+      echo ready >> "$1"
+      printf '{"type":"pong"}\n'
+      ;;
+    *) printf '%s\n' "$line" >> "$1" ;;
+  esac
 done
 "#,
         )?;
         std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755))?;
 
-        let hooks = HooksConfig {
-            host: crate::config::HookHostConfig {
-                enabled: true,
-                command: vec![
-                    script_path
-                        .to_str()
-                        .ok_or_else(|| anyhow::anyhow!("script path is not valid utf-8"))?
-                        .to_string(),
-                    out_path
-                        .to_str()
-                        .ok_or_else(|| anyhow::anyhow!("output path is not valid utf-8"))?
-                        .to_string(),
-                ],
-                sandbox_mode: None,
-                timeout_sec: None,
-                filters: crate::config::HookEventFiltersConfig::default(),
-            },
-            ..HooksConfig::default()
+        let spawn_cfg = HookHostSpawnConfig {
+            command: vec![
+                script_path.to_string_lossy().to_string(),
+                out_path.to_string_lossy().to_string(),
+            ],
+            codex_home: codex_home.path().to_path_buf(),
+            working_dir: codex_home.path().to_path_buf(),
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            codex_linux_sandbox_exe: None,
+            keep_last_n_payloads: 50,
+            write_timeout: None,
+            handshake_timeout: Duration::from_secs(5),
+            ready_timeout: Some(Duration::from_secs(5)),
         };
+        let policy = HookHostPolicy::default();
+        let (tx_line, rx_line) = mpsc::channel(policy.queue_capacity);
+        tokio::spawn(run_hook_host_manager(
+            rx_line,
+            spawn_cfg,
+            policy,
+            std::sync::Arc::default(),
+        ));
+
+        for prompt in ["first", "second"] {
+            let event = HookEvent::new(HookNotification::UserPromptSubmit {
+                thread_id: "thread-1".to_string(),
+                cwd: "/tmp".to_string(),
+                prompt: prompt.to_string(),
+            });
+            let payload = HookPayload::from_event(&event, "UserPromptSubmit");
+            tx_line
+                .send(HookHostMessage::Payload(std::sync::Arc::new(payload)))
+                .await
+                .map_err(|_| anyhow::anyhow!("hook host queue closed"))?;
+        }
 
-        let user_hooks = UserHooks::new(
-            codex_home.path().to_path_buf(),
-            hooks,
-            None,
-            SandboxPolicy::DangerFullAccess,
-            None,
-            ExclusionConfig::default(),
-            codex_home.path().to_path_buf(),
-        );
-
-        user_hooks.session_start(
-            "thread-1".to_string(),
-            "/tmp".to_string(),
-            "exec".to_string(),
-        );
-
-        let contents = read_to_string_eventually(&out_path).await?;
-        let first: Value = serde_json::from_str(
-            contents
-                .lines()
-                .next()
-                .ok_or_else(|| anyhow::anyhow!("expected at least one host line"))?,
-        )?;
+        let contents = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Ok(contents) = std::fs::read_to_string(&out_path)
+                    && contents.lines().count() >= 3
+                {
+                    break contents;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await?;
 
-        assert_eq!(first["type"], "hook-event");
-        assert_eq!(first["event"]["xcodex_event_type"], "session-start");
+        // Both queued events arrive, and only after the host reported ready.
+        let lines = contents.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "ready");
+        let prompts = lines[1..]
+            .iter()
+            .map(|line| serde_json::from_str::<Value>(line))
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .into_iter()
+            .map(|value| value["event"]["prompt"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(prompts, vec![Value::from("first"), Value::from("second")]);
 
         Ok(())
     }
@@ -4144,7 +7858,10 @@ set -eu
 out="$1"
 mkdir -p "$(dirname "$out")"
 while IFS= read -r line; do
-  printf '%s\n' "$line" >> "$out"
+  case "$line" in
+    *'"type":"hello"'*) printf '{"type":"hello","schema_version":1}\n' ;;
+    *) printf '%s\n' "$line" >> "$out" ;;
+  esac
 done
 "#,
         )?;
@@ -4165,6 +7882,8 @@ done
                 ],
                 sandbox_mode: None,
                 timeout_sec: None,
+                ready_timeout_sec: None,
+                working_dir: None,
                 filters: crate::config::HookEventFiltersConfig::default(),
             },
             ..HooksConfig::default()
@@ -4180,11 +7899,17 @@ done
             codex_home.path().to_path_buf(),
         );
 
-        user_hooks.session_start(
-            "thread-1".to_string(),
-            "/tmp".to_string(),
-            "exec".to_string(),
-        );
+        user_hooks
+            .session_start(
+                "thread-1".to_string(),
+                "/tmp".to_string(),
+                "exec".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
         user_hooks.user_prompt_submit(
             "thread-1".to_string(),
             "/tmp".to_string(),
@@ -4316,6 +8041,7 @@ for key in required_keys:
                     argv: Some(argv),
                     command: None,
                     timeout_sec: Some(5),
+                    detached: None,
                 }],
             }
         };
@@ -4630,6 +8356,7 @@ for key in required_keys:
             command: crate::config::HooksCommandConfig {
                 default_timeout_sec: 30,
                 events,
+                ..crate::config::HooksCommandConfig::default()
             },
             ..HooksConfig::default()
         };
@@ -4689,6 +8416,7 @@ assert payload.get("hook_event_name") == expected_hook_event_name, payload
                 argv: Some(argv),
                 command: None,
                 timeout_sec: Some(5),
+                detached: None,
             }],
         };
 
@@ -4734,6 +8462,7 @@ assert payload.get("hook_event_name") == expected_hook_event_name, payload
             command: crate::config::HooksCommandConfig {
                 default_timeout_sec: 30,
                 events,
+                ..crate::config::HooksCommandConfig::default()
             },
             ..HooksConfig::default()
         };
@@ -4779,7 +8508,10 @@ set -eu
 out="$1"
 mkdir -p "$(dirname "$out")"
 while IFS= read -r line; do
-  printf '%s\n' "$line" >> "$out"
+  case "$line" in
+    *'"type":"hello"'*) printf '{"type":"hello","schema_version":1}\n' ;;
+    *) printf '%s\n' "$line" >> "$out" ;;
+  esac
 done
 "#,
         )?;
@@ -4800,6 +8532,8 @@ done
                 ],
                 sandbox_mode: None,
                 timeout_sec: None,
+                ready_timeout_sec: None,
+                working_dir: None,
                 filters: crate::config::HookEventFiltersConfig::default(),
             },
             ..HooksConfig::default()
@@ -4984,6 +8718,8 @@ def on_event(event):
                     command: vec!["python3".to_string()],
                     sandbox_mode: Some(mode),
                     timeout_sec: None,
+                    ready_timeout_sec: None,
+                    working_dir: None,
                     filters: crate::config::HookEventFiltersConfig::default(),
                 },
                 ..HooksConfig::default()
@@ -4994,6 +8730,81 @@ def on_event(event):
         }
     }
 
+    #[test]
+    fn hook_host_sandbox_preview_applies_read_only_override() {
+        let session = SandboxPolicy::DangerFullAccess;
+        let hooks = HooksConfig {
+            host: crate::config::HookHostConfig {
+                enabled: true,
+                command: vec!["python3".to_string()],
+                sandbox_mode: Some(SandboxMode::ReadOnly),
+                timeout_sec: None,
+                ready_timeout_sec: None,
+                working_dir: None,
+                filters: crate::config::HookEventFiltersConfig::default(),
+            },
+            ..HooksConfig::default()
+        };
+        let exe = Some(PathBuf::from("/opt/codex-linux-sandbox"));
+
+        let preview = preview_hook_host_sandbox(Path::new("/tmp"), &session, &hooks, &exe);
+
+        assert_eq!(
+            preview,
+            HookHostSandboxPreview {
+                sandbox_policy: SandboxPolicy::new_read_only_policy(),
+                warning: None,
+            }
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn hook_host_sandbox_preview_reports_windows_unsupported() {
+        let session = SandboxPolicy::DangerFullAccess;
+        let hooks = HooksConfig {
+            host: crate::config::HookHostConfig {
+                enabled: true,
+                command: vec!["python".to_string()],
+                sandbox_mode: Some(SandboxMode::WorkspaceWrite),
+                timeout_sec: None,
+                ready_timeout_sec: None,
+                working_dir: None,
+                filters: crate::config::HookEventFiltersConfig::default(),
+            },
+            ..HooksConfig::default()
+        };
+
+        let preview = preview_hook_host_sandbox(Path::new("C:\\codex"), &session, &hooks, &None);
+
+        assert_eq!(
+            preview,
+            HookHostSandboxPreview {
+                sandbox_policy: SandboxPolicy::new_workspace_write_policy(),
+                warning: Some(HOOK_HOST_WINDOWS_SANDBOX_UNSUPPORTED),
+            }
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn hook_host_sandbox_preview_warns_without_linux_sandbox_helper() {
+        let session = SandboxPolicy::new_read_only_policy();
+
+        let preview =
+            preview_hook_host_sandbox(Path::new("/tmp"), &session, &HooksConfig::default(), &None);
+
+        assert_eq!(
+            preview,
+            HookHostSandboxPreview {
+                sandbox_policy: SandboxPolicy::new_read_only_policy(),
+                warning: Some(
+                    "linux sandbox requested for hook host, but codex_linux_sandbox_exe is not configured; spawning unsandboxed",
+                ),
+            }
+        );
+    }
+
     #[test]
     fn hook_host_spawn_invocation_linux_seccomp_wraps_command() -> Result<()> {
         let tmp = TempDir::new()?;
@@ -5088,8 +8899,10 @@ def on_event(event):
             InprocHookPolicy {
                 queue_capacity: 8,
                 timeout: Duration::from_millis(10),
-                failure_threshold: 1,
-                circuit_breaker_open_duration: Duration::from_millis(200),
+                breaker: CircuitBreakerPolicy {
+                    failure_threshold: 1,
+                    open_duration: Duration::from_millis(200),
+                },
             },
         );
 
@@ -5097,6 +8910,10 @@ def on_event(event):
             thread_id: "t".to_string(),
             cwd: "/tmp".to_string(),
             session_source: "exec".to_string(),
+            git_branch: None,
+            git_dirty: None,
+            git_commit: None,
+            git_remote: None,
         });
 
         provider.on_event(&payload);
@@ -5130,8 +8947,10 @@ def on_event(event):
             InprocHookPolicy {
                 queue_capacity: 8,
                 timeout: Duration::from_millis(50),
-                failure_threshold: 1,
-                circuit_breaker_open_duration: Duration::from_millis(200),
+                breaker: CircuitBreakerPolicy {
+                    failure_threshold: 1,
+                    open_duration: Duration::from_millis(200),
+                },
             },
         );
 
@@ -5139,6 +8958,10 @@ def on_event(event):
             thread_id: "t".to_string(),
             cwd: "/tmp".to_string(),
             session_source: "exec".to_string(),
+            git_branch: None,
+            git_dirty: None,
+            git_commit: None,
+            git_remote: None,
         });
 
         provider.on_event(&payload);
@@ -5217,11 +9040,17 @@ def on_event(event):
             codex_home.path().to_path_buf(),
         );
 
-        user_hooks.session_start(
-            "thread-1".to_string(),
-            "/tmp".to_string(),
-            "exec".to_string(),
-        );
+        user_hooks
+            .session_start(
+                "thread-1".to_string(),
+                "/tmp".to_string(),
+                "exec".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
 
         let contents = read_to_string_eventually(&marker_path).await?;
         assert_eq!(contents, "ok");
@@ -5275,11 +9104,17 @@ def on_event(event):
             codex_home.path().to_path_buf(),
         );
 
-        user_hooks.session_start(
-            "thread-1".to_string(),
-            "/tmp".to_string(),
-            "exec".to_string(),
-        );
+        user_hooks
+            .session_start(
+                "thread-1".to_string(),
+                "/tmp".to_string(),
+                "exec".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
         user_hooks.tool_call_finished(
             "thread-1".to_string(),
             "turn-1".to_string(),
@@ -5373,16 +9208,28 @@ def on_events(events):
             codex_home.path().to_path_buf(),
         );
 
-        user_hooks.session_start(
-            "thread-1".to_string(),
-            "/tmp".to_string(),
-            "exec".to_string(),
-        );
-        user_hooks.session_start(
-            "thread-1".to_string(),
-            "/tmp".to_string(),
-            "exec".to_string(),
-        );
+        user_hooks
+            .session_start(
+                "thread-1".to_string(),
+                "/tmp".to_string(),
+                "exec".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+        user_hooks
+            .session_start(
+                "thread-1".to_string(),
+                "/tmp".to_string(),
+                "exec".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
 
         let contents = read_to_string_eventually(&marker_path).await?;
         assert_eq!(contents, "2");
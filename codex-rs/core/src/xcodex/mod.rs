@@ -4,9 +4,7 @@ use async_channel::Sender;
 
 use crate::config::Config;
 use crate::mcp_connection_manager::McpHookContext;
-use crate::protocol::DeprecationNoticeEvent;
 use crate::protocol::Event;
-use crate::protocol::EventMsg;
 use crate::user_notification::UserNotifier;
 use crate::xcodex::hooks::UserHooks;
 
@@ -14,26 +12,10 @@ pub mod config;
 pub mod git_info;
 mod hook_payload_sanitizer;
 pub mod hooks;
+mod notices;
 pub mod themes;
 
-pub(crate) fn maybe_push_notify_deprecation(
-    submit_id: &str,
-    config: &Config,
-    events: &mut Vec<Event>,
-) {
-    if matches!(config.xcodex.notify.as_ref(), Some(notify) if !notify.is_empty()) {
-        events.push(Event {
-            id: submit_id.to_owned(),
-            msg: EventMsg::DeprecationNotice(DeprecationNoticeEvent {
-                summary: "`notify` is deprecated. Use `[hooks].agent_turn_complete` instead."
-                    .to_string(),
-                details: Some(
-                    "See docs/xcodex/hooks.md for the hooks contract and examples.".to_string(),
-                ),
-            }),
-        });
-    }
-}
+pub(crate) use notices::maybe_push_notify_deprecation;
 
 pub(crate) fn build_user_hooks(config: &Config, tx_event: Sender<Event>) -> UserHooks {
     UserHooks::new(
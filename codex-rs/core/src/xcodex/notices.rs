@@ -0,0 +1,185 @@
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use toml::Value as TomlValue;
+use tracing::warn;
+
+use crate::protocol::DeprecationNoticeEvent;
+use crate::protocol::Event;
+use crate::protocol::EventMsg;
+
+const DISMISSED_NOTICES_FILENAME: &str = ".xcodex-dismissed-notices";
+const NOTIFY_DEPRECATION_NOTICE_ID: &str = "notify-deprecated";
+
+/// When set to a non-empty value other than `0`, deprecation notices are shown
+/// every session even if they were acknowledged before.
+const ALWAYS_SHOW_NOTICES_ENV_VAR: &str = "XCODEX_ALWAYS_SHOW_NOTICES";
+
+fn dismissed_notices_path(codex_home: &Path) -> PathBuf {
+    codex_home.join(DISMISSED_NOTICES_FILENAME)
+}
+
+fn always_show_notices() -> bool {
+    std::env::var_os(ALWAYS_SHOW_NOTICES_ENV_VAR)
+        .is_some_and(|value| !value.is_empty() && value != "0")
+}
+
+fn is_notice_dismissed(codex_home: &Path, notice_id: &str) -> bool {
+    std::fs::read_to_string(dismissed_notices_path(codex_home))
+        .map(|contents| contents.lines().any(|line| line.trim() == notice_id))
+        .unwrap_or(false)
+}
+
+fn record_notice_dismissed(codex_home: &Path, notice_id: &str) -> std::io::Result<()> {
+    if is_notice_dismissed(codex_home, notice_id) {
+        return Ok(());
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dismissed_notices_path(codex_home))?;
+    writeln!(file, "{notice_id}")
+}
+
+pub(crate) fn maybe_push_notify_deprecation(
+    submit_id: &str,
+    codex_home: &Path,
+    notify: Option<&[String]>,
+    events: &mut Vec<Event>,
+) {
+    maybe_push_notify_deprecation_impl(
+        submit_id,
+        codex_home,
+        notify,
+        always_show_notices(),
+        events,
+    );
+}
+
+fn maybe_push_notify_deprecation_impl(
+    submit_id: &str,
+    codex_home: &Path,
+    notify: Option<&[String]>,
+    always_show: bool,
+    events: &mut Vec<Event>,
+) {
+    let Some(notify) = notify.filter(|notify| !notify.is_empty()) else {
+        return;
+    };
+    if !always_show && is_notice_dismissed(codex_home, NOTIFY_DEPRECATION_NOTICE_ID) {
+        return;
+    }
+
+    // Render the user's `notify` command as a ready-to-paste `[hooks]` snippet.
+    let command = TomlValue::Array(notify.iter().cloned().map(TomlValue::String).collect());
+    let commands = TomlValue::Array(vec![command]);
+    events.push(Event {
+        id: submit_id.to_owned(),
+        msg: EventMsg::DeprecationNotice(DeprecationNoticeEvent {
+            summary: "`notify` is deprecated. Use `[hooks].agent_turn_complete` instead."
+                .to_string(),
+            details: Some(format!(
+                "Replace `notify` in config.toml with:\n\n[hooks]\nagent_turn_complete = {commands}\n\nSee docs/xcodex/hooks.md for the hooks contract and examples."
+            )),
+        }),
+    });
+
+    if let Err(err) = record_notice_dismissed(codex_home, NOTIFY_DEPRECATION_NOTICE_ID) {
+        warn!("failed to record acknowledgment for `notify` deprecation notice: {err}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+
+    fn notify_command() -> Vec<String> {
+        vec!["notify-send".to_string(), "codex".to_string()]
+    }
+
+    #[test]
+    fn notify_deprecation_is_shown_once_then_acknowledged() -> std::io::Result<()> {
+        let dir = tempdir()?;
+        let notify = notify_command();
+
+        let mut first = Vec::new();
+        maybe_push_notify_deprecation_impl("0", dir.path(), Some(&notify), false, &mut first);
+        assert_eq!(1, first.len());
+        assert_eq!(
+            format!("{NOTIFY_DEPRECATION_NOTICE_ID}\n"),
+            std::fs::read_to_string(dismissed_notices_path(dir.path()))?
+        );
+
+        let mut second = Vec::new();
+        maybe_push_notify_deprecation_impl("0", dir.path(), Some(&notify), false, &mut second);
+        assert_eq!(0, second.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn notify_deprecation_always_show_ignores_existing_ack() -> std::io::Result<()> {
+        let dir = tempdir()?;
+        std::fs::write(
+            dismissed_notices_path(dir.path()),
+            format!("{NOTIFY_DEPRECATION_NOTICE_ID}\n"),
+        )?;
+        let notify = notify_command();
+
+        let mut events = Vec::new();
+        maybe_push_notify_deprecation_impl("0", dir.path(), Some(&notify), true, &mut events);
+        assert_eq!(1, events.len());
+        assert_eq!(
+            format!("{NOTIFY_DEPRECATION_NOTICE_ID}\n"),
+            std::fs::read_to_string(dismissed_notices_path(dir.path()))?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn notify_deprecation_details_include_hooks_snippet() -> std::io::Result<()> {
+        let dir = tempdir()?;
+        let notify = vec![
+            "python3".to_string(),
+            "/home/user/.xcodex/notify.py".to_string(),
+        ];
+
+        let mut events = Vec::new();
+        maybe_push_notify_deprecation_impl("0", dir.path(), Some(&notify), false, &mut events);
+        let details = match events.as_slice() {
+            [
+                Event {
+                    msg: EventMsg::DeprecationNotice(DeprecationNoticeEvent { details, .. }),
+                    ..
+                },
+            ] => details.clone(),
+            other => panic!("expected a single deprecation notice, got {other:?}"),
+        };
+        assert_eq!(
+            Some(
+                "Replace `notify` in config.toml with:\n\n[hooks]\nagent_turn_complete = [[\"python3\", \"/home/user/.xcodex/notify.py\"]]\n\nSee docs/xcodex/hooks.md for the hooks contract and examples."
+                    .to_string()
+            ),
+            details
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn notify_deprecation_skipped_without_notify() -> std::io::Result<()> {
+        let dir = tempdir()?;
+
+        let mut events = Vec::new();
+        maybe_push_notify_deprecation_impl("0", dir.path(), None, false, &mut events);
+        maybe_push_notify_deprecation_impl("0", dir.path(), Some(&[]), false, &mut events);
+        assert_eq!(0, events.len());
+        assert_eq!(false, dismissed_notices_path(dir.path()).exists());
+
+        Ok(())
+    }
+}
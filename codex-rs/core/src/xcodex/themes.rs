@@ -1143,33 +1143,28 @@ impl ThemeCatalog {
                     continue;
                 }
 
-                let src = match fs::read_to_string(&path) {
-                    Ok(src) => src,
-                    Err(source) => {
-                        load_warnings.push(ThemeLoadWarning {
-                            path: path.clone(),
-                            message: format!("Failed to read: {source}"),
-                        });
-                        continue;
-                    }
-                };
-                let theme: ThemeDefinition = match serde_yaml::from_str(&src) {
+                let theme = match load_theme_file(&path) {
                     Ok(theme) => theme,
-                    Err(source) => {
+                    Err(err) => {
+                        let message = match err {
+                            ThemeError::Read { source, .. } => format!("Failed to read: {source}"),
+                            ThemeError::InvalidYaml { source, .. } => {
+                                format!("Invalid YAML: {source}")
+                            }
+                            err @ (ThemeError::MissingField { .. }
+                            | ThemeError::InvalidColor { .. }
+                            | ThemeError::MissingPaletteSlot { .. }
+                            | ThemeError::SerializeYaml { .. }) => {
+                                format!("Invalid theme: {err}")
+                            }
+                        };
                         load_warnings.push(ThemeLoadWarning {
                             path: path.clone(),
-                            message: format!("Invalid YAML: {source}"),
+                            message,
                         });
                         continue;
                     }
                 };
-                if let Err(err) = theme.validate() {
-                    load_warnings.push(ThemeLoadWarning {
-                        path: path.clone(),
-                        message: format!("Invalid theme: {err}"),
-                    });
-                    continue;
-                }
                 let name = theme.name.clone();
                 by_name.insert(name.clone(), theme);
                 user_theme_paths.insert(name, path);
@@ -1211,19 +1206,9 @@ impl ThemeCatalog {
         &'a self,
         cfg: &Themes,
         auto_variant: Option<ThemeVariant>,
-        terminal_background_is_light: bool,
+        terminal_background_is_light: Option<bool>,
     ) -> &'a ThemeDefinition {
-        let variant = match cfg.theme_mode {
-            ThemeMode::Light => ThemeVariant::Light,
-            ThemeMode::Dark => ThemeVariant::Dark,
-            ThemeMode::Auto => auto_variant.unwrap_or({
-                if terminal_background_is_light {
-                    ThemeVariant::Light
-                } else {
-                    ThemeVariant::Dark
-                }
-            }),
-        };
+        let variant = resolve_theme_variant(cfg, auto_variant, terminal_background_is_light);
         let selected = match variant {
             ThemeVariant::Light => cfg.light.as_deref(),
             ThemeVariant::Dark => cfg.dark.as_deref(),
@@ -1234,6 +1219,47 @@ impl ThemeCatalog {
     }
 }
 
+/// Picks the light/dark variant for `cfg`.
+///
+/// In `auto` mode the OS appearance (`auto_variant`) wins, then the detected terminal background.
+/// When neither is available, the variant whose theme is explicitly configured is used (dark when
+/// both or neither are set).
+pub fn resolve_theme_variant(
+    cfg: &Themes,
+    auto_variant: Option<ThemeVariant>,
+    terminal_background_is_light: Option<bool>,
+) -> ThemeVariant {
+    match cfg.theme_mode {
+        ThemeMode::Light => ThemeVariant::Light,
+        ThemeMode::Dark => ThemeVariant::Dark,
+        ThemeMode::Auto => match (auto_variant, terminal_background_is_light) {
+            (Some(variant), _) => variant,
+            (None, Some(true)) => ThemeVariant::Light,
+            (None, Some(false)) => ThemeVariant::Dark,
+            (None, None) if cfg.dark.is_none() && cfg.light.is_some() => ThemeVariant::Light,
+            (None, None) => ThemeVariant::Dark,
+        },
+    }
+}
+
+/// Reads, parses, and validates a single theme YAML file.
+///
+/// Parse errors carry the serde location (`at line N column M`), including for missing
+/// required `palette.*` / `roles.*` keys.
+pub fn load_theme_file(path: &Path) -> Result<ThemeDefinition, ThemeError> {
+    let src = fs::read_to_string(path).map_err(|source| ThemeError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let theme: ThemeDefinition =
+        serde_yaml::from_str(&src).map_err(|source| ThemeError::InvalidYaml {
+            path: path.to_path_buf(),
+            source,
+        })?;
+    theme.validate()?;
+    Ok(theme)
+}
+
 pub fn themes_dir(codex_home: &Path, cfg: &Themes) -> PathBuf {
     match cfg.dir.as_ref() {
         Some(dir) => dir.as_path().to_path_buf(),
@@ -1438,6 +1464,111 @@ selection_text: "#555555"
         assert!(themes.iter().all(|theme| theme.validate().is_ok()));
     }
 
+    #[test]
+    fn resolve_theme_variant_follows_detected_background_in_auto_mode() {
+        let cfg = Themes {
+            theme_mode: ThemeMode::Auto,
+            light: Some("light-theme".to_string()),
+            dark: Some("dark-theme".to_string()),
+            ..Themes::default()
+        };
+        assert_eq!(
+            resolve_theme_variant(&cfg, None, Some(true)),
+            ThemeVariant::Light
+        );
+        assert_eq!(
+            resolve_theme_variant(&cfg, None, Some(false)),
+            ThemeVariant::Dark
+        );
+        assert_eq!(
+            resolve_theme_variant(&cfg, Some(ThemeVariant::Dark), Some(true)),
+            ThemeVariant::Dark
+        );
+
+        let explicit = Themes {
+            theme_mode: ThemeMode::Light,
+            ..cfg.clone()
+        };
+        assert_eq!(
+            resolve_theme_variant(&explicit, None, Some(false)),
+            ThemeVariant::Light
+        );
+    }
+
+    #[test]
+    fn resolve_theme_variant_falls_back_to_configured_theme_when_detection_fails() {
+        let light_only = Themes {
+            theme_mode: ThemeMode::Auto,
+            light: Some("light-theme".to_string()),
+            ..Themes::default()
+        };
+        assert_eq!(
+            resolve_theme_variant(&light_only, None, None),
+            ThemeVariant::Light
+        );
+
+        let both = Themes {
+            dark: Some("dark-theme".to_string()),
+            ..light_only
+        };
+        assert_eq!(resolve_theme_variant(&both, None, None), ThemeVariant::Dark);
+    }
+
+    #[test]
+    fn theme_file_missing_required_role_reports_file_and_line() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let dir = codex_home.path().join("themes");
+        fs::create_dir_all(&dir).expect("create themes dir");
+        let path = dir.join("broken.yaml");
+        fs::write(
+            &path,
+            r##"name: broken
+variant: dark
+palette:
+  black: "#000000"
+  red: "#800000"
+  green: "#008000"
+  yellow: "#808000"
+  blue: "#000080"
+  magenta: "#800080"
+  cyan: "#008080"
+  white: "#c0c0c0"
+  bright_black: "#808080"
+  bright_red: "#ff0000"
+  bright_green: "#00ff00"
+  bright_yellow: "#ffff00"
+  bright_blue: "#0000ff"
+  bright_magenta: "#ff00ff"
+  bright_cyan: "#00ffff"
+  bright_white: "#ffffff"
+roles:
+  bg: "#000000"
+"##,
+        )
+        .expect("write theme");
+
+        let err = load_theme_file(&path).expect_err("theme without roles.fg should fail");
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "invalid theme YAML in {}: roles: missing field `fg` at line 21 column 3",
+                path.display()
+            )
+        );
+
+        let mut config = crate::config::test_config();
+        config.codex_home = codex_home.path().to_path_buf();
+        let catalog = ThemeCatalog::load(&config).expect("catalog loads");
+        assert_eq!(
+            catalog.load_warnings(),
+            &[ThemeLoadWarning {
+                path: path.clone(),
+                message: "Invalid YAML: roles: missing field `fg` at line 21 column 3".to_string(),
+            }]
+        );
+        assert_eq!(catalog.get("broken"), None);
+    }
+
     #[test]
     fn convert_upstream_yaml_maps_core_fields_and_palette() {
         let src = r##"
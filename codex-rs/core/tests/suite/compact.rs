@@ -2588,6 +2588,69 @@ async fn auto_compact_triggers_after_function_call_over_95_percent_usage() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn auto_compact_does_not_exceed_max_model_requests_per_turn() {
+    skip_if_no_network!();
+
+    let server = start_mock_server().await;
+
+    let context_window = 100;
+    let first_turn = sse(vec![
+        ev_function_call(DUMMY_CALL_ID, DUMMY_FUNCTION_NAME, "{}"),
+        ev_completed_with_tokens("r1", context_window),
+    ]);
+    let auto_compact_turn = sse(vec![
+        ev_assistant_message("m2", &auto_summary(AUTO_SUMMARY_TEXT)),
+        ev_completed_with_tokens("r2", 10),
+    ]);
+    let first_turn_mock = mount_sse_once(&server, first_turn).await;
+    let auto_compact_mock = mount_sse_once(&server, auto_compact_turn).await;
+
+    let model_provider = non_openai_model_provider(&server);
+
+    let mut builder = test_codex().with_config(move |config| {
+        config.model_provider = model_provider;
+        set_test_compact_prompt(config);
+        config.model_context_window = Some(context_window);
+        config.model_auto_compact_token_limit = Some(context_window / 2);
+        config.max_model_requests_per_turn = std::num::NonZeroUsize::new(1);
+    });
+    let codex = builder.build(&server).await.unwrap().codex;
+
+    codex
+        .submit(Op::SetAutoCompact { enabled: true })
+        .await
+        .unwrap();
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: FUNCTION_CALL_LIMIT_MSG.into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+        })
+        .await
+        .unwrap();
+
+    let message = wait_for_event_match(&codex, |msg| match msg {
+        EventMsg::Error(err) => Some(err.message.clone()),
+        _ => None,
+    })
+    .await;
+    assert_eq!(
+        message,
+        "Turn limit reached (1); stopping before the agent finished."
+    );
+    wait_for_event(&codex, |msg| matches!(msg, EventMsg::TurnComplete(_))).await;
+
+    assert_eq!(first_turn_mock.requests().len(), 1);
+    assert!(
+        auto_compact_mock.requests().is_empty(),
+        "auto compact should not send a request past the per-turn cap"
+    );
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn auto_compact_clamps_config_limit_to_context_window() {
     skip_if_no_network!();
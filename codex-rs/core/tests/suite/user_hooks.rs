@@ -5,6 +5,7 @@ use std::time::Duration;
 
 use anyhow::Result;
 use anyhow::anyhow;
+use codex_core::built_in_model_providers;
 use codex_core::config::Constrained;
 use codex_core::protocol::AskForApproval;
 use codex_core::protocol::EventMsg;
@@ -106,6 +107,65 @@ async fn hooks_agent_turn_complete_invoked() -> Result<()> {
     assert_eq!(payload["xcodex_event_type"], json!("agent-turn-complete"));
     assert_eq!(payload["input_messages"], json!(["hello world"]));
     assert_eq!(payload["last_assistant_message"], json!("Done"));
+    assert_eq!(payload["session_stats"]["turns_completed"], json!(1));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn hooks_agent_turn_complete_counts_session_turns() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_assistant_message("m1", "First"),
+                ev_completed("r1"),
+            ]),
+            sse(vec![
+                ev_assistant_message("m2", "Second"),
+                ev_completed("r2"),
+            ]),
+        ],
+    )
+    .await;
+
+    let hook_dir = TempDir::new()?;
+    let hook_script = write_hook_script(&hook_dir, "hook.sh", "turn.json")?;
+    let hook_file = hook_dir.path().join("turn.json");
+
+    let TestCodex { codex, .. } = test_codex()
+        .with_config(move |cfg| {
+            cfg.xcodex.hooks.agent_turn_complete = vec![vec![hook_script]];
+        })
+        .build(&server)
+        .await?;
+
+    for text in ["first", "second"] {
+        codex
+            .submit(Op::UserInput {
+                items: vec![UserInput::Text {
+                    text: text.into(),
+                    text_elements: Vec::new(),
+                }],
+                final_output_json_schema: None,
+            })
+            .await?;
+        wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+        fs_wait::wait_for_path_exists(&hook_file, Duration::from_secs(5)).await?;
+        if text == "first" {
+            std::fs::remove_file(&hook_file)?;
+        }
+    }
+
+    let hook_payload_raw = tokio::fs::read_to_string(&hook_file).await?;
+    let payload: Value = serde_json::from_str(&hook_payload_raw)?;
+
+    assert_eq!(payload["last_assistant_message"], json!("Second"));
+    assert_eq!(payload["session_stats"]["turns_completed"], json!(2));
 
     Ok(())
 }
@@ -352,6 +412,7 @@ async fn hooks_tool_call_started_and_finished_invoked() -> Result<()> {
         .with_config(move |cfg| {
             cfg.xcodex.hooks.tool_call_started = vec![vec![started_hook_script]];
             cfg.xcodex.hooks.tool_call_finished = vec![vec![finished_hook_script]];
+            cfg.xcodex.hooks.output_preview_bytes = 512;
             cfg.permissions.approval_policy = Constrained::allow_any(AskForApproval::Never);
         })
         .build(&server)
@@ -432,6 +493,79 @@ async fn hooks_session_end_invoked() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn hooks_pre_compact_reports_token_counts() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let long_reply = "lorem ipsum ".repeat(2_000);
+    mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_assistant_message("m1", &long_reply),
+                ev_completed("r1"),
+            ]),
+            sse(vec![
+                ev_assistant_message("m2", "short summary"),
+                ev_completed("r2"),
+            ]),
+        ],
+    )
+    .await;
+
+    let hook_dir = TempDir::new()?;
+    let hook_script = write_hook_script(&hook_dir, "hook.sh", "pre_compact.json")?;
+    let hook_file = hook_dir.path().join("pre_compact.json");
+
+    // Use a non-OpenAI provider so compaction runs locally against the mock server.
+    let mut model_provider = built_in_model_providers()["openai"].clone();
+    model_provider.name = "OpenAI (test)".into();
+    model_provider.base_url = Some(format!("{}/v1", server.uri()));
+    let TestCodex { codex, .. } = test_codex()
+        .with_config(move |cfg| {
+            cfg.model_provider = model_provider;
+            cfg.xcodex.hooks.pre_compact = vec![vec![hook_script]];
+        })
+        .build(&server)
+        .await?;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "hello world".into(),
+                text_elements: Vec::new(),
+            }],
+            final_output_json_schema: None,
+        })
+        .await?;
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    codex.submit(Op::Compact).await?;
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    fs_wait::wait_for_path_exists(&hook_file, Duration::from_secs(5)).await?;
+    let hook_payload_raw = tokio::fs::read_to_string(&hook_file).await?;
+    let payload: Value = serde_json::from_str(&hook_payload_raw)?;
+
+    assert_eq!(payload["hook_event_name"], json!("PreCompact"));
+    assert_eq!(payload["xcodex_event_type"], json!("pre-compact"));
+    assert_eq!(payload["trigger"], json!("manual"));
+    let tokens_before = payload["tokens_before"]
+        .as_u64()
+        .ok_or_else(|| anyhow!("tokens_before missing"))?;
+    let tokens_after = payload["tokens_after"]
+        .as_u64()
+        .ok_or_else(|| anyhow!("tokens_after missing"))?;
+    assert!(
+        tokens_after < tokens_before,
+        "expected compaction to shrink history: before={tokens_before} after={tokens_after}"
+    );
+    assert!(payload["messages_dropped"].as_u64().is_some());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn hooks_tool_call_finished_emits_hook_process_events() -> Result<()> {
     skip_if_no_network!(Ok(()));
@@ -496,6 +630,7 @@ async fn hooks_tool_call_finished_emits_hook_process_events() -> Result<()> {
     let EventMsg::HookProcessEnd(HookProcessEndEvent {
         hook_id: finished_id,
         exit_code,
+        ..
     }) = wait_for_event(&codex, |ev| matches!(ev, EventMsg::HookProcessEnd(_))).await
     else {
         unreachable!("wait_for_event filters for HookProcessEnd")
@@ -3,6 +3,7 @@ use clap::FromArgMatches;
 use clap::Parser;
 use clap::ValueEnum;
 use codex_utils_cli::CliConfigOverrides;
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
@@ -75,6 +76,16 @@ pub struct Cli {
     #[arg(long = "ephemeral", global = true, default_value_t = false)]
     pub ephemeral: bool,
 
+    /// Stop the run with an error once the agent has made this many model requests (turns)
+    /// without finishing. Unlimited by default.
+    #[arg(long = "max-turns", value_name = "N")]
+    pub max_turns: Option<NonZeroUsize>,
+
+    /// Stop the run with an error once cumulative token usage exceeds this many tokens.
+    /// Unlimited by default.
+    #[arg(long = "budget-tokens", value_name = "N")]
+    pub budget_tokens: Option<u64>,
+
     /// Path to a JSON Schema file describing the model's final response shape.
     #[arg(long = "output-schema", value_name = "FILE")]
     pub output_schema: Option<PathBuf>,
@@ -99,6 +110,11 @@ pub struct Cli {
     #[arg(long = "output-last-message", short = 'o', value_name = "FILE")]
     pub last_message_file: Option<PathBuf>,
 
+    /// Write a JSON record of the run (turns, tool calls, token usage, final
+    /// message) to the specified file on completion.
+    #[arg(long = "output-json", value_name = "FILE")]
+    pub output_json: Option<PathBuf>,
+
     /// Read the initial prompt from a file (useful for large prompts).
     #[arg(long = "file", value_name = "FILE", value_hint = clap::ValueHint::FilePath, conflicts_with = "prompt")]
     pub prompt_file: Option<PathBuf>,
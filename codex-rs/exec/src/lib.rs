@@ -9,6 +9,7 @@ mod event_processor;
 mod event_processor_with_human_output;
 pub mod event_processor_with_jsonl_output;
 pub mod exec_events;
+mod run_record;
 mod xcodex_non_interactive;
 
 pub use cli::Cli;
@@ -34,10 +35,12 @@ use codex_core::models_manager::manager::RefreshStrategy;
 use codex_core::protocol::AskForApproval;
 use codex_core::protocol::Event;
 use codex_core::protocol::EventMsg;
+use codex_core::protocol::FinalOutput;
 use codex_core::protocol::Op;
 use codex_core::protocol::ReviewRequest;
 use codex_core::protocol::ReviewTarget;
 use codex_core::protocol::SessionSource;
+use codex_core::protocol::TokenCountEvent;
 use codex_protocol::config_types::SandboxMode;
 use codex_protocol::user_input::UserInput;
 use codex_utils_absolute_path::AbsolutePathBuf;
@@ -45,6 +48,7 @@ use codex_utils_oss::ensure_oss_provider_ready;
 use codex_utils_oss::get_default_model_for_oss_provider;
 use event_processor_with_human_output::EventProcessorWithHumanOutput;
 use event_processor_with_jsonl_output::EventProcessorWithJsonOutput;
+use run_record::RunRecord;
 use serde_json::Value;
 use std::collections::HashSet;
 use std::io::IsTerminal;
@@ -104,8 +108,11 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         skip_git_repo_check,
         add_dir,
         ephemeral,
+        max_turns,
+        budget_tokens,
         color,
         last_message_file,
+        output_json,
         json: json_mode,
         sandbox_mode: sandbox_mode_cli_arg,
         prompt_file,
@@ -275,6 +282,8 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         show_raw_agent_reasoning: oss.then_some(true),
         tools_web_search_request: None,
         ephemeral: ephemeral.then_some(true),
+        max_model_requests_per_turn: max_turns,
+        token_budget: budget_tokens,
         additional_writable_roots: add_dir,
     };
 
@@ -543,6 +552,9 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
     // exit with a non-zero status for automation-friendly signaling.
     let mut error_seen = false;
     let mut shutdown_requested = false;
+    let mut run_record = output_json
+        .is_some()
+        .then(|| RunRecord::new(primary_thread_id));
     while let Some(envelope) = rx.recv().await {
         let ThreadEventEnvelope {
             thread_id,
@@ -558,6 +570,20 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         {
             continue;
         }
+        if let Some(budget) = budget_tokens
+            && thread_id == primary_thread_id
+            && !shutdown_requested
+            && let EventMsg::TokenCount(TokenCountEvent {
+                info: Some(info), ..
+            }) = &event.msg
+            && u64::try_from(info.total_token_usage.total_tokens).unwrap_or_default() > budget
+        {
+            error_seen = true;
+            eprintln!("Token budget of {budget} exceeded; stopping the run.");
+            eprintln!("{}", FinalOutput::from(info.total_token_usage.clone()));
+            thread.submit(Op::Shutdown).await?;
+            shutdown_requested = true;
+        }
         if let EventMsg::McpStartupUpdate(update) = &event.msg
             && required_mcp_servers.contains(&update.server)
             && let codex_core::protocol::McpStartupStatus::Failed { error } = &update.status
@@ -575,6 +601,11 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         if thread_id != primary_thread_id && matches!(&event.msg, EventMsg::TurnComplete(_)) {
             continue;
         }
+        if thread_id == primary_thread_id
+            && let Some(record) = run_record.as_mut()
+        {
+            record.observe(&event.msg);
+        }
         let shutdown = event_processor.process_event(event);
         if thread_id != primary_thread_id && matches!(shutdown, CodexStatus::InitiateShutdown) {
             continue;
@@ -592,6 +623,9 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         }
     }
     event_processor.print_final_output();
+    if let (Some(record), Some(path)) = (&run_record, output_json.as_deref()) {
+        record.write(path);
+    }
     if error_seen {
         std::process::exit(1);
     }
@@ -816,7 +850,7 @@ fn resolve_prompt_inner(
             if buffer.trim().is_empty() {
                 anyhow::bail!("No prompt provided via stdin.");
             }
-            return Ok(buffer);
+            return Ok(buffer.replace("\r\n", "\n").replace('\r', "\n"));
         }
 
         let buffer = std::fs::read_to_string(&path).map_err(|err| {
@@ -954,6 +988,19 @@ mod tests {
         assert_eq!(prompt, payload);
     }
 
+    #[test]
+    fn resolves_prompt_file_dash_from_piped_stdin_with_normalized_newlines() {
+        let prompt = resolve_prompt_inner(
+            None,
+            Some(PathBuf::from("-")),
+            || false,
+            || Ok(b"first\r\nsecond\rthird\n".to_vec()),
+        )
+        .expect("resolve");
+
+        assert_eq!(prompt, "first\nsecond\nthird\n");
+    }
+
     #[test]
     fn errors_when_no_prompt_and_tty_stdin() {
         let err =
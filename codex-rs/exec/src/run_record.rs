@@ -0,0 +1,129 @@
+use std::path::Path;
+
+use codex_core::protocol::EventMsg;
+use codex_core::protocol::FinalOutput;
+use codex_core::protocol::TokenUsage;
+use codex_protocol::ThreadId;
+use serde::Serialize;
+
+/// Machine-readable summary of an exec run, written by `--output-json`.
+#[derive(Debug, Serialize)]
+pub(crate) struct RunRecord {
+    thread_id: String,
+    turns: Vec<TurnRecord>,
+    #[serde(flatten)]
+    final_output: FinalOutput,
+    final_message: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct TurnRecord {
+    turn_id: String,
+    user_message: Option<String>,
+    tool_calls: Vec<ToolCallRecord>,
+    final_message: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ToolCallRecord {
+    Exec {
+        call_id: String,
+        command: Vec<String>,
+        exit_code: i32,
+    },
+    Mcp {
+        call_id: String,
+        server: String,
+        tool: String,
+        success: bool,
+    },
+    Patch {
+        call_id: String,
+        success: bool,
+    },
+    WebSearch {
+        call_id: String,
+        query: String,
+    },
+}
+
+impl RunRecord {
+    pub(crate) fn new(thread_id: ThreadId) -> Self {
+        Self {
+            thread_id: thread_id.to_string(),
+            turns: Vec::new(),
+            final_output: FinalOutput::from(TokenUsage::default()),
+            final_message: None,
+        }
+    }
+
+    /// Record a single event emitted by the primary thread.
+    pub(crate) fn observe(&mut self, msg: &EventMsg) {
+        match msg {
+            EventMsg::TurnStarted(ev) => self.turns.push(TurnRecord {
+                turn_id: ev.turn_id.clone(),
+                user_message: None,
+                tool_calls: Vec::new(),
+                final_message: None,
+            }),
+            EventMsg::TokenCount(ev) => {
+                if let Some(info) = &ev.info {
+                    self.final_output = FinalOutput::from(info.total_token_usage.clone());
+                }
+            }
+            EventMsg::UserMessage(ev) => {
+                if let Some(turn) = self.turns.last_mut() {
+                    turn.user_message = Some(ev.message.clone());
+                }
+            }
+            EventMsg::ExecCommandEnd(ev) => self.push_tool_call(ToolCallRecord::Exec {
+                call_id: ev.call_id.clone(),
+                command: ev.command.clone(),
+                exit_code: ev.exit_code,
+            }),
+            EventMsg::McpToolCallEnd(ev) => self.push_tool_call(ToolCallRecord::Mcp {
+                call_id: ev.call_id.clone(),
+                server: ev.invocation.server.clone(),
+                tool: ev.invocation.tool.clone(),
+                success: ev.is_success(),
+            }),
+            EventMsg::PatchApplyEnd(ev) => self.push_tool_call(ToolCallRecord::Patch {
+                call_id: ev.call_id.clone(),
+                success: ev.success,
+            }),
+            EventMsg::WebSearchEnd(ev) => self.push_tool_call(ToolCallRecord::WebSearch {
+                call_id: ev.call_id.clone(),
+                query: ev.query.clone(),
+            }),
+            EventMsg::TurnComplete(ev) => {
+                if let Some(turn) = self.turns.last_mut() {
+                    turn.final_message = ev.last_agent_message.clone();
+                }
+                if ev.last_agent_message.is_some() {
+                    self.final_message = ev.last_agent_message.clone();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn push_tool_call(&mut self, call: ToolCallRecord) {
+        if let Some(turn) = self.turns.last_mut() {
+            turn.tool_calls.push(call);
+        }
+    }
+
+    pub(crate) fn write(&self, path: &Path) {
+        let contents = match serde_json::to_string_pretty(self) {
+            Ok(contents) => contents,
+            Err(e) => {
+                eprintln!("Failed to serialize run record: {e}");
+                return;
+            }
+        };
+        if let Err(e) = std::fs::write(path, contents) {
+            eprintln!("Failed to write run record {path:?}: {e}");
+        }
+    }
+}
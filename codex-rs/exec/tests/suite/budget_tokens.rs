@@ -0,0 +1,51 @@
+#![cfg(not(target_os = "windows"))]
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use core_test_support::responses;
+use core_test_support::test_codex_exec::test_codex_exec;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+/// Once reported usage crosses `--budget-tokens`, the run stops, prints the usage so far, and
+/// exits non-zero instead of continuing the agent loop.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn budget_tokens_stops_the_run_once_exceeded() -> anyhow::Result<()> {
+    let test = test_codex_exec();
+
+    let server = responses::start_mock_server().await;
+    let plan_args = json!({
+        "plan": [{"step": "inspect the repo", "status": "in_progress"}],
+    })
+    .to_string();
+    let mock = responses::mount_sse_sequence(
+        &server,
+        vec![
+            responses::sse(vec![
+                responses::ev_response_created("resp1"),
+                responses::ev_function_call("call-1", "update_plan", &plan_args),
+                responses::ev_completed_with_tokens("resp1", 500),
+            ]),
+            responses::sse(vec![
+                responses::ev_response_created("resp2"),
+                responses::ev_assistant_message("m2", "done"),
+                responses::ev_completed_with_tokens("resp2", 500),
+            ]),
+        ],
+    )
+    .await;
+
+    test.cmd_with_server(&server)
+        .arg("--skip-git-repo-check")
+        .arg("--budget-tokens")
+        .arg("100")
+        .arg("make a plan")
+        .assert()
+        .code(1)
+        .stderr(contains("Token budget of 100 exceeded").and(contains("total=500")));
+
+    assert_eq!(mock.requests().len(), 1);
+
+    Ok(())
+}
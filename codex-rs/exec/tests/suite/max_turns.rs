@@ -0,0 +1,49 @@
+#![cfg(not(target_os = "windows"))]
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use core_test_support::responses;
+use core_test_support::test_codex_exec::test_codex_exec;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+/// A run capped with `--max-turns 1` stops after the first model response instead of sending
+/// the tool output back for another turn, and exits non-zero so CI notices.
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn max_turns_stops_after_the_limit() -> anyhow::Result<()> {
+    let test = test_codex_exec();
+
+    let server = responses::start_mock_server().await;
+    let plan_args = json!({
+        "plan": [{"step": "inspect the repo", "status": "in_progress"}],
+    })
+    .to_string();
+    let mock = responses::mount_sse_sequence(
+        &server,
+        vec![
+            responses::sse(vec![
+                responses::ev_response_created("resp1"),
+                responses::ev_function_call("call-1", "update_plan", &plan_args),
+                responses::ev_completed("resp1"),
+            ]),
+            responses::sse(vec![
+                responses::ev_response_created("resp2"),
+                responses::ev_assistant_message("m2", "done"),
+                responses::ev_completed("resp2"),
+            ]),
+        ],
+    )
+    .await;
+
+    test.cmd_with_server(&server)
+        .arg("--skip-git-repo-check")
+        .arg("--max-turns")
+        .arg("1")
+        .arg("make a plan")
+        .assert()
+        .code(1)
+        .stderr(predicates::str::contains("Turn limit reached (1)"));
+
+    assert_eq!(mock.requests().len(), 1);
+
+    Ok(())
+}
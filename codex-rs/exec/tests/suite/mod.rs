@@ -2,9 +2,12 @@
 mod add_dir;
 mod apply_patch;
 mod auth_env;
+mod budget_tokens;
 mod ephemeral;
+mod max_turns;
 mod mcp_required_exit;
 mod originator;
+mod output_json;
 mod output_schema;
 mod resume;
 mod sandbox;
@@ -0,0 +1,48 @@
+#![cfg(not(target_os = "windows"))]
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use core_test_support::responses;
+use core_test_support::test_codex_exec::test_codex_exec;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use serde_json::json;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn exec_writes_run_record_with_token_totals() -> anyhow::Result<()> {
+    let test = test_codex_exec();
+    let record_path = test.cwd_path().join("run.json");
+
+    let server = responses::start_mock_server().await;
+    let body = responses::sse(vec![
+        responses::ev_response_created("resp1"),
+        responses::ev_assistant_message("m1", "fixture hello"),
+        responses::ev_completed_with_tokens("resp1", 42),
+    ]);
+    responses::mount_sse_once(&server, body).await;
+
+    test.cmd_with_server(&server)
+        .arg("--skip-git-repo-check")
+        .arg("-C")
+        .arg(test.cwd_path())
+        .arg("--output-json")
+        .arg(&record_path)
+        .arg("tell me a joke")
+        .assert()
+        .success();
+
+    let record: Value = serde_json::from_str(&std::fs::read_to_string(&record_path)?)?;
+    assert_eq!(
+        record["token_usage"],
+        json!({
+            "input_tokens": 42,
+            "cached_input_tokens": 0,
+            "output_tokens": 0,
+            "reasoning_output_tokens": 0,
+            "total_tokens": 42,
+        })
+    );
+    assert_eq!(record["final_message"], json!("fixture hello"));
+    assert_eq!(record["turns"][0]["user_message"], json!("tell me a joke"));
+
+    Ok(())
+}
@@ -22,10 +22,19 @@ pub struct ExecPolicyCheckCommand {
     #[arg(long)]
     pub pretty: bool,
 
+    /// Print a single JSON verdict object (`decision`, `matchedRule`, `reason`) instead of the
+    /// default JSON list of every matched rule.
+    #[arg(long, visible_alias = "verdict", conflicts_with = "validate_only")]
+    pub json: bool,
+
+    /// Only parse the rule files and report syntax errors; no command is checked.
+    #[arg(long = "validate-only")]
+    pub validate_only: bool,
+
     /// Command tokens to check against the policy.
     #[arg(
         value_name = "COMMAND",
-        required = true,
+        required_unless_present = "validate_only",
         trailing_var_arg = true,
         allow_hyphen_values = true
     )]
@@ -35,10 +44,26 @@ pub struct ExecPolicyCheckCommand {
 impl ExecPolicyCheckCommand {
     /// Load the policies for this command, evaluate the command, and render JSON output.
     pub fn run(&self) -> Result<()> {
+        if self.validate_only {
+            let errors = validate_policies(&self.rules);
+            for error in &errors {
+                eprintln!("{error}");
+            }
+            if !errors.is_empty() {
+                anyhow::bail!("{} policy file(s) failed validation", errors.len());
+            }
+            println!("{} policy file(s) are valid", self.rules.len());
+            return Ok(());
+        }
+
         let policy = load_policies(&self.rules)?;
         let matched_rules = policy.matches_for_command(&self.command, None);
 
-        let json = format_matches_json(&matched_rules, self.pretty)?;
+        let json = if self.json {
+            format_verdict_json(&matched_rules, self.pretty)?
+        } else {
+            format_matches_json(&matched_rules, self.pretty)?
+        };
         println!("{json}");
 
         Ok(())
@@ -51,10 +76,37 @@ pub fn format_matches_json(matched_rules: &[RuleMatch], pretty: bool) -> Result<
         decision: matched_rules.iter().map(RuleMatch::decision).max(),
     };
 
+    to_json(&output, pretty)
+}
+
+/// Renders the strictest matching rule as a single verdict. Commands that match no rule are
+/// reported as `ask`, since Codex falls back to its approval policy for them.
+pub fn format_verdict_json(matched_rules: &[RuleMatch], pretty: bool) -> Result<String> {
+    let matched_rule = matched_rules.iter().max_by_key(|rule| rule.decision());
+    let decision = match matched_rule.map(RuleMatch::decision) {
+        Some(Decision::Allow) => "allow",
+        Some(Decision::Forbidden) => "deny",
+        Some(Decision::Prompt) | None => "ask",
+    };
+    let reason = match matched_rule {
+        Some(RuleMatch::PrefixRuleMatch { justification, .. }) => justification.clone(),
+        Some(RuleMatch::HeuristicsRuleMatch { .. }) => None,
+        None => Some("no policy rule matched the command".to_string()),
+    };
+    let output = ExecPolicyVerdict {
+        decision,
+        matched_rule,
+        reason,
+    };
+
+    to_json(&output, pretty)
+}
+
+fn to_json<T: Serialize>(value: &T, pretty: bool) -> Result<String> {
     if pretty {
-        serde_json::to_string_pretty(&output).map_err(Into::into)
+        serde_json::to_string_pretty(value).map_err(Into::into)
     } else {
-        serde_json::to_string(&output).map_err(Into::into)
+        serde_json::to_string(value).map_err(Into::into)
     }
 }
 
@@ -73,6 +125,36 @@ pub fn load_policies(policy_paths: &[PathBuf]) -> Result<Policy> {
     Ok(parser.build())
 }
 
+/// Parses each policy file independently and returns one `path:line:column: message`
+/// diagnostic per file that fails to read or parse.
+pub fn validate_policies(policy_paths: &[PathBuf]) -> Vec<String> {
+    policy_paths
+        .iter()
+        .filter_map(|policy_path| {
+            let policy_file_contents = match fs::read_to_string(policy_path) {
+                Ok(contents) => contents,
+                Err(err) => {
+                    return Some(format!(
+                        "{}: failed to read policy: {err}",
+                        policy_path.display()
+                    ));
+                }
+            };
+            let policy_identifier = policy_path.to_string_lossy().to_string();
+            let err = PolicyParser::new()
+                .parse(&policy_identifier, &policy_file_contents)
+                .err()?;
+            Some(match err.location() {
+                Some(location) => format!(
+                    "{}:{}:{}: {err}",
+                    location.path, location.range.start.line, location.range.start.column
+                ),
+                None => format!("{policy_identifier}: {err}"),
+            })
+        })
+        .collect()
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ExecPolicyCheckOutput<'a> {
@@ -81,3 +163,11 @@ struct ExecPolicyCheckOutput<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     decision: Option<Decision>,
 }
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExecPolicyVerdict<'a> {
+    decision: &'static str,
+    matched_rule: Option<&'a RuleMatch>,
+    reason: Option<String>,
+}
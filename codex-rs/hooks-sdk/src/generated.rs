@@ -21,6 +21,8 @@ pub struct HookPayload {
     pub attempt: Option<u64>,
     #[serde(rename = "call_id")]
     pub call_id: Option<String>,
+    #[serde(rename = "category")]
+    pub category: Option<NotificationCategory>,
     #[serde(rename = "command")]
     pub command: Option<Vec<String>>,
     #[serde(rename = "cwd")]
@@ -29,6 +31,14 @@ pub struct HookPayload {
     pub duration_ms: Option<u64>,
     #[serde(rename = "event_id")]
     pub event_id: String,
+    #[serde(rename = "git_branch")]
+    pub git_branch: Option<String>,
+    #[serde(rename = "git_commit")]
+    pub git_commit: Option<String>,
+    #[serde(rename = "git_dirty")]
+    pub git_dirty: Option<bool>,
+    #[serde(rename = "git_remote")]
+    pub git_remote: Option<String>,
     #[serde(rename = "grant_root")]
     pub grant_root: Option<String>,
     #[serde(rename = "has_output_schema")]
@@ -45,6 +55,8 @@ pub struct HookPayload {
     pub last_assistant_message: Option<String>,
     #[serde(rename = "message")]
     pub message: Option<String>,
+    #[serde(rename = "messages_dropped")]
+    pub messages_dropped: Option<u64>,
     #[serde(rename = "model")]
     pub model: Option<String>,
     #[serde(rename = "model_request_id")]
@@ -63,18 +75,24 @@ pub struct HookPayload {
     pub paths: Option<Vec<String>>,
     #[serde(rename = "permission_mode")]
     pub permission_mode: String,
+    #[serde(rename = "priority")]
+    pub priority: Option<NotificationPriority>,
     #[serde(rename = "prompt")]
     pub prompt: Option<String>,
     #[serde(rename = "proposed_execpolicy_amendment")]
     pub proposed_execpolicy_amendment: Option<Vec<String>>,
     #[serde(rename = "provider")]
     pub provider: Option<String>,
+    #[serde(rename = "rate_limits")]
+    pub rate_limits: Option<Value>,
     #[serde(rename = "reason")]
     pub reason: Option<String>,
     #[serde(rename = "request_id")]
     pub request_id: Option<String>,
     #[serde(rename = "response_id")]
     pub response_id: Option<String>,
+    #[serde(rename = "result_summary")]
+    pub result_summary: Option<String>,
     #[serde(rename = "sandbox_policy")]
     pub sandbox_policy: Option<Value>,
     #[serde(rename = "schema_version")]
@@ -85,10 +103,16 @@ pub struct HookPayload {
     pub session_id: String,
     #[serde(rename = "session_source")]
     pub session_source: Option<String>,
+    #[serde(rename = "session_stats")]
+    pub session_stats: Option<Value>,
     #[serde(rename = "status")]
     pub status: Option<String>,
     #[serde(rename = "subagent")]
     pub subagent: Option<String>,
+    #[serde(rename = "subagent_id")]
+    pub subagent_id: Option<String>,
+    #[serde(rename = "subagent_name")]
+    pub subagent_name: Option<String>,
     #[serde(rename = "success")]
     pub success: Option<bool>,
     #[serde(rename = "timestamp")]
@@ -97,6 +121,10 @@ pub struct HookPayload {
     pub title: Option<String>,
     #[serde(rename = "token_usage")]
     pub token_usage: Option<Value>,
+    #[serde(rename = "tokens_after")]
+    pub tokens_after: Option<u64>,
+    #[serde(rename = "tokens_before")]
+    pub tokens_before: Option<u64>,
     #[serde(rename = "tool_count")]
     pub tool_count: Option<u64>,
     #[serde(rename = "tool_input")]
@@ -118,3 +146,437 @@ pub struct HookPayload {
     #[serde(flatten)]
     pub extra: ExtraFields,
 }
+
+/// Routing category for `notification` events, so hooks can branch without parsing text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NotificationCategory {
+    #[serde(rename = "info")]
+    Info,
+    #[serde(rename = "approval-needed")]
+    ApprovalNeeded,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NotificationPriority {
+    #[serde(rename = "low")]
+    Low,
+    #[serde(rename = "normal")]
+    Normal,
+    #[serde(rename = "high")]
+    High,
+}
+
+#[cfg(any(test, feature = "test-support"))]
+#[derive(Debug, Clone)]
+pub struct HookPayloadBuilder {
+    payload: HookPayload,
+}
+
+#[cfg(any(test, feature = "test-support"))]
+impl HookPayloadBuilder {
+    /// Starts a payload with placeholder envelope fields (`event_id`, `timestamp`,
+    /// `schema_version`, ...) for the given event.
+    pub fn new(xcodex_event_type: impl Into<String>, hook_event_name: impl Into<String>) -> Self {
+        Self {
+            payload: HookPayload {
+                approval_policy: None,
+                attempt: None,
+                call_id: None,
+                category: None,
+                command: None,
+                cwd: Default::default(),
+                duration_ms: None,
+                event_id: "00000000-0000-0000-0000-000000000000".to_string(),
+                git_branch: None,
+                git_commit: None,
+                git_dirty: None,
+                git_remote: None,
+                grant_root: None,
+                has_output_schema: None,
+                hook_event_name: hook_event_name.into(),
+                input_item_count: None,
+                input_messages: None,
+                kind: None,
+                last_assistant_message: None,
+                message: None,
+                messages_dropped: None,
+                model: None,
+                model_request_id: None,
+                needs_follow_up: None,
+                notification_type: None,
+                output_bytes: None,
+                output_preview: None,
+                parallel_tool_calls: None,
+                paths: None,
+                permission_mode: "default".to_string(),
+                priority: None,
+                prompt: None,
+                proposed_execpolicy_amendment: None,
+                provider: None,
+                rate_limits: None,
+                reason: None,
+                request_id: None,
+                response_id: None,
+                result_summary: None,
+                sandbox_policy: None,
+                schema_version: 1,
+                server_name: None,
+                session_id: Default::default(),
+                session_source: None,
+                session_stats: None,
+                status: None,
+                subagent: None,
+                subagent_id: None,
+                subagent_name: None,
+                success: None,
+                timestamp: "1970-01-01T00:00:00Z".to_string(),
+                title: None,
+                token_usage: None,
+                tokens_after: None,
+                tokens_before: None,
+                tool_count: None,
+                tool_input: None,
+                tool_name: None,
+                tool_response: None,
+                tool_use_id: None,
+                transcript_path: Default::default(),
+                trigger: None,
+                turn_id: None,
+                xcodex_event_type: xcodex_event_type.into(),
+                extra: ExtraFields::new(),
+            },
+        }
+    }
+
+    pub fn approval_policy(mut self, approval_policy: Value) -> Self {
+        self.payload.approval_policy = Some(approval_policy);
+        self
+    }
+
+    pub fn attempt(mut self, attempt: u64) -> Self {
+        self.payload.attempt = Some(attempt);
+        self
+    }
+
+    pub fn call_id(mut self, call_id: impl Into<String>) -> Self {
+        self.payload.call_id = Some(call_id.into());
+        self
+    }
+
+    pub fn category(mut self, category: NotificationCategory) -> Self {
+        self.payload.category = Some(category);
+        self
+    }
+
+    pub fn command(mut self, command: Vec<String>) -> Self {
+        self.payload.command = Some(command);
+        self
+    }
+
+    pub fn cwd(mut self, cwd: impl Into<String>) -> Self {
+        self.payload.cwd = cwd.into();
+        self
+    }
+
+    pub fn duration_ms(mut self, duration_ms: u64) -> Self {
+        self.payload.duration_ms = Some(duration_ms);
+        self
+    }
+
+    pub fn event_id(mut self, event_id: impl Into<String>) -> Self {
+        self.payload.event_id = event_id.into();
+        self
+    }
+
+    pub fn git_branch(mut self, git_branch: impl Into<String>) -> Self {
+        self.payload.git_branch = Some(git_branch.into());
+        self
+    }
+
+    pub fn git_commit(mut self, git_commit: impl Into<String>) -> Self {
+        self.payload.git_commit = Some(git_commit.into());
+        self
+    }
+
+    pub fn git_dirty(mut self, git_dirty: bool) -> Self {
+        self.payload.git_dirty = Some(git_dirty);
+        self
+    }
+
+    pub fn git_remote(mut self, git_remote: impl Into<String>) -> Self {
+        self.payload.git_remote = Some(git_remote.into());
+        self
+    }
+
+    pub fn grant_root(mut self, grant_root: impl Into<String>) -> Self {
+        self.payload.grant_root = Some(grant_root.into());
+        self
+    }
+
+    pub fn has_output_schema(mut self, has_output_schema: bool) -> Self {
+        self.payload.has_output_schema = Some(has_output_schema);
+        self
+    }
+
+    pub fn hook_event_name(mut self, hook_event_name: impl Into<String>) -> Self {
+        self.payload.hook_event_name = hook_event_name.into();
+        self
+    }
+
+    pub fn input_item_count(mut self, input_item_count: u64) -> Self {
+        self.payload.input_item_count = Some(input_item_count);
+        self
+    }
+
+    pub fn input_messages(mut self, input_messages: Vec<String>) -> Self {
+        self.payload.input_messages = Some(input_messages);
+        self
+    }
+
+    pub fn kind(mut self, kind: impl Into<String>) -> Self {
+        self.payload.kind = Some(kind.into());
+        self
+    }
+
+    pub fn last_assistant_message(mut self, last_assistant_message: impl Into<String>) -> Self {
+        self.payload.last_assistant_message = Some(last_assistant_message.into());
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.payload.message = Some(message.into());
+        self
+    }
+
+    pub fn messages_dropped(mut self, messages_dropped: u64) -> Self {
+        self.payload.messages_dropped = Some(messages_dropped);
+        self
+    }
+
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.payload.model = Some(model.into());
+        self
+    }
+
+    pub fn model_request_id(mut self, model_request_id: impl Into<String>) -> Self {
+        self.payload.model_request_id = Some(model_request_id.into());
+        self
+    }
+
+    pub fn needs_follow_up(mut self, needs_follow_up: bool) -> Self {
+        self.payload.needs_follow_up = Some(needs_follow_up);
+        self
+    }
+
+    pub fn notification_type(mut self, notification_type: impl Into<String>) -> Self {
+        self.payload.notification_type = Some(notification_type.into());
+        self
+    }
+
+    pub fn output_bytes(mut self, output_bytes: u64) -> Self {
+        self.payload.output_bytes = Some(output_bytes);
+        self
+    }
+
+    pub fn output_preview(mut self, output_preview: impl Into<String>) -> Self {
+        self.payload.output_preview = Some(output_preview.into());
+        self
+    }
+
+    pub fn parallel_tool_calls(mut self, parallel_tool_calls: bool) -> Self {
+        self.payload.parallel_tool_calls = Some(parallel_tool_calls);
+        self
+    }
+
+    pub fn paths(mut self, paths: Vec<String>) -> Self {
+        self.payload.paths = Some(paths);
+        self
+    }
+
+    pub fn permission_mode(mut self, permission_mode: impl Into<String>) -> Self {
+        self.payload.permission_mode = permission_mode.into();
+        self
+    }
+
+    pub fn priority(mut self, priority: NotificationPriority) -> Self {
+        self.payload.priority = Some(priority);
+        self
+    }
+
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.payload.prompt = Some(prompt.into());
+        self
+    }
+
+    pub fn proposed_execpolicy_amendment(
+        mut self,
+        proposed_execpolicy_amendment: Vec<String>,
+    ) -> Self {
+        self.payload.proposed_execpolicy_amendment = Some(proposed_execpolicy_amendment);
+        self
+    }
+
+    pub fn provider(mut self, provider: impl Into<String>) -> Self {
+        self.payload.provider = Some(provider.into());
+        self
+    }
+
+    pub fn rate_limits(mut self, rate_limits: Value) -> Self {
+        self.payload.rate_limits = Some(rate_limits);
+        self
+    }
+
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.payload.reason = Some(reason.into());
+        self
+    }
+
+    pub fn request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.payload.request_id = Some(request_id.into());
+        self
+    }
+
+    pub fn response_id(mut self, response_id: impl Into<String>) -> Self {
+        self.payload.response_id = Some(response_id.into());
+        self
+    }
+
+    pub fn result_summary(mut self, result_summary: impl Into<String>) -> Self {
+        self.payload.result_summary = Some(result_summary.into());
+        self
+    }
+
+    pub fn sandbox_policy(mut self, sandbox_policy: Value) -> Self {
+        self.payload.sandbox_policy = Some(sandbox_policy);
+        self
+    }
+
+    pub fn schema_version(mut self, schema_version: u64) -> Self {
+        self.payload.schema_version = schema_version;
+        self
+    }
+
+    pub fn server_name(mut self, server_name: impl Into<String>) -> Self {
+        self.payload.server_name = Some(server_name.into());
+        self
+    }
+
+    pub fn session_id(mut self, session_id: impl Into<String>) -> Self {
+        self.payload.session_id = session_id.into();
+        self
+    }
+
+    pub fn session_source(mut self, session_source: impl Into<String>) -> Self {
+        self.payload.session_source = Some(session_source.into());
+        self
+    }
+
+    pub fn session_stats(mut self, session_stats: Value) -> Self {
+        self.payload.session_stats = Some(session_stats);
+        self
+    }
+
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.payload.status = Some(status.into());
+        self
+    }
+
+    pub fn subagent(mut self, subagent: impl Into<String>) -> Self {
+        self.payload.subagent = Some(subagent.into());
+        self
+    }
+
+    pub fn subagent_id(mut self, subagent_id: impl Into<String>) -> Self {
+        self.payload.subagent_id = Some(subagent_id.into());
+        self
+    }
+
+    pub fn subagent_name(mut self, subagent_name: impl Into<String>) -> Self {
+        self.payload.subagent_name = Some(subagent_name.into());
+        self
+    }
+
+    pub fn success(mut self, success: bool) -> Self {
+        self.payload.success = Some(success);
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: impl Into<String>) -> Self {
+        self.payload.timestamp = timestamp.into();
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.payload.title = Some(title.into());
+        self
+    }
+
+    pub fn token_usage(mut self, token_usage: Value) -> Self {
+        self.payload.token_usage = Some(token_usage);
+        self
+    }
+
+    pub fn tokens_after(mut self, tokens_after: u64) -> Self {
+        self.payload.tokens_after = Some(tokens_after);
+        self
+    }
+
+    pub fn tokens_before(mut self, tokens_before: u64) -> Self {
+        self.payload.tokens_before = Some(tokens_before);
+        self
+    }
+
+    pub fn tool_count(mut self, tool_count: u64) -> Self {
+        self.payload.tool_count = Some(tool_count);
+        self
+    }
+
+    pub fn tool_input(mut self, tool_input: Value) -> Self {
+        self.payload.tool_input = Some(tool_input);
+        self
+    }
+
+    pub fn tool_name(mut self, tool_name: impl Into<String>) -> Self {
+        self.payload.tool_name = Some(tool_name.into());
+        self
+    }
+
+    pub fn tool_response(mut self, tool_response: Value) -> Self {
+        self.payload.tool_response = Some(tool_response);
+        self
+    }
+
+    pub fn tool_use_id(mut self, tool_use_id: impl Into<String>) -> Self {
+        self.payload.tool_use_id = Some(tool_use_id.into());
+        self
+    }
+
+    pub fn transcript_path(mut self, transcript_path: impl Into<String>) -> Self {
+        self.payload.transcript_path = transcript_path.into();
+        self
+    }
+
+    pub fn trigger(mut self, trigger: impl Into<String>) -> Self {
+        self.payload.trigger = Some(trigger.into());
+        self
+    }
+
+    pub fn turn_id(mut self, turn_id: impl Into<String>) -> Self {
+        self.payload.turn_id = Some(turn_id.into());
+        self
+    }
+
+    pub fn xcodex_event_type(mut self, xcodex_event_type: impl Into<String>) -> Self {
+        self.payload.xcodex_event_type = xcodex_event_type.into();
+        self
+    }
+
+    pub fn extra(mut self, key: impl Into<String>, value: Value) -> Self {
+        self.payload.extra.insert(key.into(), value);
+        self
+    }
+
+    pub fn build(self) -> HookPayload {
+        self.payload
+    }
+}
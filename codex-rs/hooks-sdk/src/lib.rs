@@ -6,8 +6,105 @@ use std::io;
 use std::io::Read;
 use std::path::Path;
 
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
 use serde_json::Value;
 
+/// Replacement text for matches removed by [`HookPayload::redacted`].
+pub const REDACTED: &str = "[REDACTED]";
+
+#[cfg(any(test, feature = "test-support"))]
+impl HookPayloadBuilder {
+    pub fn agent_turn_complete() -> Self {
+        Self::new("agent-turn-complete", "Stop")
+    }
+
+    pub fn approval_requested() -> Self {
+        Self::new("approval-requested", "PermissionRequest")
+    }
+
+    pub fn session_start() -> Self {
+        Self::new("session-start", "SessionStart")
+    }
+
+    pub fn session_end() -> Self {
+        Self::new("session-end", "SessionEnd")
+    }
+
+    pub fn user_prompt_submit() -> Self {
+        Self::new("user-prompt-submit", "UserPromptSubmit")
+    }
+
+    pub fn pre_compact() -> Self {
+        Self::new("pre-compact", "PreCompact")
+    }
+
+    pub fn notification() -> Self {
+        Self::new("notification", "Notification")
+    }
+
+    pub fn subagent_stop() -> Self {
+        Self::new("subagent-stop", "SubagentStop")
+    }
+
+    pub fn model_request_started() -> Self {
+        Self::new("model-request-started", "model-request-started")
+    }
+
+    pub fn model_response_completed() -> Self {
+        Self::new("model-response-completed", "model-response-completed")
+    }
+
+    pub fn tool_call_started() -> Self {
+        Self::new("tool-call-started", "PreToolUse")
+    }
+
+    pub fn tool_call_finished() -> Self {
+        Self::new("tool-call-finished", "PostToolUse")
+    }
+}
+
+/// Typed accessors for the unknown fields preserved in [`HookPayload::extra`].
+///
+/// `ExtraFields` is a `BTreeMap`, so iterating it (`for (key, value) in &payload.extra`)
+/// always yields fields sorted by key.
+pub trait ExtraFieldsExt {
+    fn get_str(&self, key: &str) -> Option<&str>;
+    fn get_i64(&self, key: &str) -> Option<i64>;
+    fn get_u64(&self, key: &str) -> Option<u64>;
+    fn get_bool(&self, key: &str) -> Option<bool>;
+}
+
+impl ExtraFieldsExt for ExtraFields {
+    fn get_str(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(Value::as_str)
+    }
+
+    fn get_i64(&self, key: &str) -> Option<i64> {
+        self.get(key).and_then(Value::as_i64)
+    }
+
+    fn get_u64(&self, key: &str) -> Option<u64> {
+        self.get(key).and_then(Value::as_u64)
+    }
+
+    fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key).and_then(Value::as_bool)
+    }
+}
+
+/// Token counts carried by `model-response-completed` payloads in `token_usage`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TokenUsage {
+    pub input_tokens: i64,
+    pub cached_input_tokens: i64,
+    pub output_tokens: i64,
+    pub reasoning_output_tokens: i64,
+    pub total_tokens: i64,
+}
+
 #[derive(Debug)]
 pub enum HookReadError {
     Io(io::Error),
@@ -44,6 +141,71 @@ impl From<serde_json::Error> for HookReadError {
     }
 }
 
+/// Values of `xcodex_event_type` this SDK version knows how to handle.
+pub const KNOWN_EVENT_TYPES: &[&str] = &[
+    "agent-turn-complete",
+    "approval-requested",
+    "session-start",
+    "session-end",
+    "user-prompt-submit",
+    "pre-compact",
+    "notification",
+    "subagent-stop",
+    "model-request-started",
+    "model-response-completed",
+    "tool-call-started",
+    "tool-call-finished",
+];
+
+/// A payload rejected by [`parse_event_strict`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownHookEvent {
+    /// The payload's `xcodex_event_type`, if it had one.
+    pub event_type: Option<String>,
+    pub raw: Value,
+    /// Why a payload with a known event type failed to parse.
+    pub parse_error: Option<String>,
+}
+
+impl std::fmt::Display for UnknownHookEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.event_type, &self.parse_error) {
+            (Some(event_type), Some(err)) => {
+                write!(f, "invalid {event_type} hook payload: {err}")
+            }
+            (Some(event_type), None) => write!(f, "unknown hook event type: {event_type}"),
+            (None, _) => write!(f, "hook payload is missing xcodex_event_type"),
+        }
+    }
+}
+
+impl std::error::Error for UnknownHookEvent {}
+
+/// Parses `value` into a [`HookPayload`], rejecting payloads whose event type is missing or not
+/// in [`KNOWN_EVENT_TYPES`], or that fail to deserialize.
+pub fn parse_event_strict(value: Value) -> Result<HookPayload, UnknownHookEvent> {
+    let event_type = value
+        .get("xcodex_event_type")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    if !event_type
+        .as_deref()
+        .is_some_and(|event_type| KNOWN_EVENT_TYPES.contains(&event_type))
+    {
+        return Err(UnknownHookEvent {
+            event_type,
+            raw: value,
+            parse_error: None,
+        });
+    }
+
+    serde_json::from_value(value.clone()).map_err(|err| UnknownHookEvent {
+        event_type,
+        raw: value,
+        parse_error: Some(err.to_string()),
+    })
+}
+
 pub fn read_payload_from_stdin() -> Result<HookPayload, HookReadError> {
     read_payload_from_reader(io::stdin())
 }
@@ -70,6 +232,30 @@ pub fn read_payload_json_from_reader<R: Read>(mut reader: R) -> Result<Value, Ho
     resolve_payload_path_envelope(payload)
 }
 
+/// Reads a batch of payloads: either a single JSON array or a stream of concatenated
+/// (e.g. newline-delimited) JSON values. Each element may be a `payload_path` envelope.
+pub fn read_payloads_from_reader<R: Read>(
+    mut reader: R,
+) -> Result<Vec<HookPayload>, HookReadError> {
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf)?;
+
+    let mut values = serde_json::Deserializer::from_slice(&buf)
+        .into_iter::<Value>()
+        .collect::<Result<Vec<_>, _>>()?;
+    if let [Value::Array(items)] = values.as_mut_slice() {
+        values = std::mem::take(items);
+    }
+
+    values
+        .into_iter()
+        .map(|value| {
+            let value = resolve_payload_path_envelope(value)?;
+            Ok(serde_json::from_value(value)?)
+        })
+        .collect()
+}
+
 fn resolve_payload_path_envelope(payload: Value) -> Result<Value, HookReadError> {
     let payload_path = payload
         .as_object()
@@ -83,3 +269,44 @@ fn resolve_payload_path_envelope(payload: Value) -> Result<Value, HookReadError>
         Ok(payload)
     }
 }
+
+impl HookPayload {
+    /// Returns a copy of this payload with every match of `patterns` in string values replaced
+    /// by [`REDACTED`]. Object keys, numbers, and the payload structure are left intact.
+    pub fn redacted(&self, patterns: &[Regex]) -> Result<HookPayload, serde_json::Error> {
+        let value = serde_json::to_value(self)?;
+        serde_json::from_value(redact_json_value(&value, patterns))
+    }
+
+    /// Parses `token_usage` into [`TokenUsage`]. Returns `None` when the field is absent or
+    /// malformed.
+    pub fn typed_token_usage(&self) -> Option<TokenUsage> {
+        self.token_usage
+            .as_ref()
+            .and_then(|value| TokenUsage::deserialize(value).ok())
+    }
+}
+
+/// Like [`HookPayload::redacted`], for raw JSON payloads (e.g. from
+/// [`read_payload_json_from_stdin`]).
+pub fn redact_json_value(value: &Value, patterns: &[Regex]) -> Value {
+    match value {
+        Value::String(text) => {
+            Value::String(patterns.iter().fold(text.clone(), |text, pattern| {
+                pattern.replace_all(&text, REDACTED).into_owned()
+            }))
+        }
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| redact_json_value(item, patterns))
+                .collect(),
+        ),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), redact_json_value(value, patterns)))
+                .collect(),
+        ),
+        Value::Null | Value::Bool(_) | Value::Number(_) => value.clone(),
+    }
+}
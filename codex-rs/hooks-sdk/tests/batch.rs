@@ -0,0 +1,60 @@
+use codex_hooks_sdk::read_payloads_from_reader;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+fn payload(event_type: &str, event_id: &str) -> String {
+    format!(
+        "{{\"schema_version\":1,\"event_id\":\"{event_id}\",\"timestamp\":\"t\",\"session_id\":\"th\",\"transcript_path\":\"\",\"permission_mode\":\"default\",\"hook_event_name\":\"x\",\"xcodex_event_type\":\"{event_type}\",\"cwd\":\"/tmp\"}}"
+    )
+}
+
+fn event_ids(input: &str) -> Vec<(String, String)> {
+    read_payloads_from_reader(input.as_bytes())
+        .expect("read")
+        .into_iter()
+        .map(|payload| (payload.xcodex_event_type, payload.event_id))
+        .collect()
+}
+
+#[test]
+fn reads_json_array_batch() {
+    let input = format!(
+        "[{}, {}]",
+        payload("session-start", "a"),
+        payload("session-end", "b")
+    );
+
+    assert_eq!(
+        event_ids(&input),
+        vec![
+            ("session-start".to_string(), "a".to_string()),
+            ("session-end".to_string(), "b".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn reads_ndjson_batch_and_resolves_envelopes() {
+    let dir = TempDir::new().expect("tmp");
+    let payload_path = dir.path().join("payload.json");
+    std::fs::write(&payload_path, payload("tool-call-finished", "c")).expect("write");
+
+    let input = format!(
+        "{}\n{{\"payload_path\":\"{}\"}}\n\n",
+        payload("session-start", "a"),
+        payload_path.display()
+    );
+
+    assert_eq!(
+        event_ids(&input),
+        vec![
+            ("session-start".to_string(), "a".to_string()),
+            ("tool-call-finished".to_string(), "c".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn empty_input_yields_no_payloads() {
+    assert_eq!(event_ids(" \n"), Vec::new());
+}
@@ -0,0 +1,29 @@
+use codex_hooks_sdk::HookPayloadBuilder;
+use codex_hooks_sdk::read_payload_from_reader;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn built_payload_round_trips_through_reader() {
+    let payload = HookPayloadBuilder::tool_call_finished()
+        .tool_name("Bash")
+        .tool_use_id("call-1")
+        .success(true)
+        .duration_ms(12)
+        .tool_input(json!({"command": "ls"}))
+        .extra("__marker__", json!("ok"))
+        .build();
+
+    let json = serde_json::to_vec(&payload).expect("serialize");
+    let parsed = read_payload_from_reader(json.as_slice()).expect("read");
+
+    assert_eq!(parsed, payload);
+    assert_eq!(
+        (
+            parsed.xcodex_event_type.as_str(),
+            parsed.hook_event_name.as_str(),
+            parsed.schema_version,
+        ),
+        ("tool-call-finished", "PostToolUse", 1)
+    );
+}
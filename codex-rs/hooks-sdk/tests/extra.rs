@@ -0,0 +1,62 @@
+use codex_hooks_sdk::ExtraFieldsExt;
+use codex_hooks_sdk::read_payload_from_reader;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use serde_json::json;
+
+const PAYLOAD: &str = r#"{
+  "schema_version": 1,
+  "event_id": "e",
+  "timestamp": "t",
+  "session_id": "th",
+  "transcript_path": "",
+  "permission_mode": "default",
+  "hook_event_name": "FutureEvent",
+  "xcodex_event_type": "future-event",
+  "cwd": "/tmp",
+  "zeta": "last",
+  "count": -3,
+  "bytes": 42,
+  "enabled": true,
+  "alpha": {"nested": 1}
+}"#;
+
+#[test]
+fn typed_getters_extract_unknown_fields() {
+    let payload = read_payload_from_reader(PAYLOAD.as_bytes()).expect("read");
+
+    assert_eq!(
+        (
+            payload.extra.get_str("zeta"),
+            payload.extra.get_i64("count"),
+            payload.extra.get_u64("bytes"),
+            payload.extra.get_bool("enabled"),
+        ),
+        (Some("last"), Some(-3), Some(42), Some(true))
+    );
+    assert_eq!(
+        (
+            payload.extra.get_str("count"),
+            payload.extra.get_u64("count"),
+            payload.extra.get_i64("missing"),
+        ),
+        (None, None, None)
+    );
+}
+
+#[test]
+fn iterating_extra_fields_is_sorted_by_key() {
+    let payload = read_payload_from_reader(PAYLOAD.as_bytes()).expect("read");
+
+    let fields: Vec<(String, Value)> = payload.extra.into_iter().collect();
+    assert_eq!(
+        fields,
+        vec![
+            ("alpha".to_string(), json!({"nested": 1})),
+            ("bytes".to_string(), json!(42)),
+            ("count".to_string(), json!(-3)),
+            ("enabled".to_string(), json!(true)),
+            ("zeta".to_string(), json!("last")),
+        ]
+    );
+}
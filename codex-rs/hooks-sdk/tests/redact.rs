@@ -0,0 +1,55 @@
+use codex_hooks_sdk::read_payload_from_reader;
+use codex_hooks_sdk::redact_json_value;
+use pretty_assertions::assert_eq;
+use regex::Regex;
+use serde_json::json;
+
+#[test]
+fn redacts_tokens_and_preserves_structure() {
+    let token = Regex::new(r"sk-[A-Za-z0-9]+").expect("regex");
+    let payload = r#"{
+  "schema_version": 1,
+  "event_id": "e",
+  "timestamp": "t",
+  "session_id": "th",
+  "transcript_path": "",
+  "permission_mode": "default",
+  "hook_event_name": "PreToolUse",
+  "xcodex_event_type": "tool-call-started",
+  "cwd": "/tmp",
+  "prompt": "use sk-abc123 for auth",
+  "tool_input": {"headers": {"authorization": "Bearer sk-abc123"}, "retries": 2, "args": ["sk-def456", "ok"]},
+  "__extra__": "sk-xyz"
+}"#;
+    let payload = read_payload_from_reader(payload.as_bytes()).expect("read");
+
+    let redacted = payload
+        .redacted(std::slice::from_ref(&token))
+        .expect("redact");
+
+    let mut expected = payload.clone();
+    expected.prompt = Some("use [REDACTED] for auth".to_string());
+    expected.tool_input = Some(json!({
+        "headers": {"authorization": "Bearer [REDACTED]"},
+        "retries": 2,
+        "args": ["[REDACTED]", "ok"],
+    }));
+    expected
+        .extra
+        .insert("__extra__".to_string(), json!("[REDACTED]"));
+    assert_eq!(redacted, expected);
+}
+
+#[test]
+fn redact_json_value_applies_every_pattern() {
+    let patterns = [
+        Regex::new(r"sk-[A-Za-z0-9]+").expect("regex"),
+        Regex::new(r"ghp_[A-Za-z0-9]+").expect("regex"),
+    ];
+    let value = json!({"a": "sk-1 and ghp_2", "b": [true, null, 3]});
+
+    assert_eq!(
+        redact_json_value(&value, &patterns),
+        json!({"a": "[REDACTED] and [REDACTED]", "b": [true, null, 3]})
+    );
+}
@@ -0,0 +1,50 @@
+use codex_hooks_sdk::UnknownHookEvent;
+use codex_hooks_sdk::parse_event_strict;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use serde_json::json;
+
+fn envelope(event_type: &str) -> Value {
+    json!({
+        "schema_version": 1,
+        "event_id": "e",
+        "timestamp": "t",
+        "session_id": "th",
+        "transcript_path": "",
+        "permission_mode": "default",
+        "hook_event_name": "SessionStart",
+        "xcodex_event_type": event_type,
+        "cwd": "/tmp",
+    })
+}
+
+#[test]
+fn known_event_parses() {
+    let payload = parse_event_strict(envelope("session-start")).expect("known event");
+    assert_eq!(payload.xcodex_event_type, "session-start");
+}
+
+#[test]
+fn unknown_event_type_is_rejected() {
+    let raw = envelope("future-event");
+    assert_eq!(
+        parse_event_strict(raw.clone()),
+        Err(UnknownHookEvent {
+            event_type: Some("future-event".to_string()),
+            raw,
+            parse_error: None,
+        })
+    );
+}
+
+#[test]
+fn known_event_that_fails_to_parse_carries_error() {
+    let mut raw = envelope("session-start");
+    raw["cwd"] = json!(42);
+
+    let err = parse_event_strict(raw).expect_err("malformed payload");
+    assert_eq!(
+        (err.event_type.as_deref(), err.parse_error.is_some()),
+        (Some("session-start"), true)
+    );
+}
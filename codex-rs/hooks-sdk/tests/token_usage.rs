@@ -0,0 +1,49 @@
+use codex_hooks_sdk::HookPayloadBuilder;
+use codex_hooks_sdk::TokenUsage;
+use codex_hooks_sdk::read_payload_from_reader;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn model_response_completed_exposes_typed_token_usage() {
+    let payload = read_payload_from_reader(
+        r#"{
+          "schema_version": 1,
+          "event_id": "e",
+          "timestamp": "t",
+          "session_id": "th",
+          "transcript_path": "",
+          "permission_mode": "default",
+          "hook_event_name": "model_response_completed",
+          "xcodex_event_type": "model-response-completed",
+          "cwd": "/tmp",
+          "response_id": "resp-1",
+          "token_usage": {
+            "input_tokens": 120,
+            "cached_input_tokens": 20,
+            "output_tokens": 30,
+            "reasoning_output_tokens": 5,
+            "total_tokens": 150
+          }
+        }"#
+        .as_bytes(),
+    )
+    .expect("read");
+
+    assert_eq!(
+        payload.typed_token_usage(),
+        Some(TokenUsage {
+            input_tokens: 120,
+            cached_input_tokens: 20,
+            output_tokens: 30,
+            reasoning_output_tokens: 5,
+            total_tokens: 150,
+        })
+    );
+}
+
+#[test]
+fn missing_token_usage_is_none() {
+    let payload = HookPayloadBuilder::model_response_completed().build();
+
+    assert_eq!(payload.typed_token_usage(), None);
+}
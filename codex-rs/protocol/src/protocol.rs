@@ -2280,6 +2280,10 @@ pub struct HookProcessBeginEvent {
     pub event_type: String,
     /// The hook command that was spawned.
     pub command: Vec<String>,
+    /// Timeout applied to this hook process in milliseconds, when bounded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub timeout_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
@@ -2291,6 +2295,10 @@ pub struct HookProcessEndEvent {
     /// Exit code if available.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exit_code: Option<i32>,
+    /// Captured stdout/stderr (bounded), when `hooks.echo_to_ui` is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub output: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
@@ -11,7 +11,45 @@ pub struct RequestUserInputQuestionOption {
     pub description: String,
 }
 
+/// Shows a question only when another question's answer matches `equals`.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+pub struct RequestUserInputCondition {
+    #[serde(rename = "questionId")]
+    #[schemars(rename = "questionId")]
+    #[ts(rename = "questionId")]
+    pub question_id: String,
+    pub equals: String,
+}
+
+/// Constraints a freeform answer must satisfy before the user can move on.
+/// `pattern` and `maxLen` only apply to non-empty answers; use `minLen` to
+/// require one.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+pub struct RequestUserInputValidation {
+    /// Regular expression the answer must match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub pattern: Option<String>,
+    #[serde(rename = "minLen", default, skip_serializing_if = "Option::is_none")]
+    #[schemars(rename = "minLen")]
+    #[ts(rename = "minLen", optional)]
+    pub min_len: Option<usize>,
+    #[serde(rename = "maxLen", default, skip_serializing_if = "Option::is_none")]
+    #[schemars(rename = "maxLen")]
+    #[ts(rename = "maxLen", optional)]
+    pub max_len: Option<usize>,
+    /// Shown instead of the generated message when validation fails.
+    #[serde(
+        rename = "errorMessage",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(rename = "errorMessage")]
+    #[ts(rename = "errorMessage", optional)]
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
 pub struct RequestUserInputQuestion {
     pub id: String,
     pub header: String,
@@ -26,11 +64,36 @@ pub struct RequestUserInputQuestion {
     pub is_secret: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<Vec<RequestUserInputQuestionOption>>,
+    /// When set, the question is only asked if the referenced question was
+    /// answered with `equals`.
+    #[serde(rename = "showIf", default, skip_serializing_if = "Option::is_none")]
+    #[schemars(rename = "showIf")]
+    #[ts(rename = "showIf", optional)]
+    pub show_if: Option<RequestUserInputCondition>,
+    /// Index of the option that starts out selected. Without it the first
+    /// option is highlighted but the question stays unanswered.
+    #[serde(
+        rename = "defaultOptionIndex",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schemars(rename = "defaultOptionIndex")]
+    #[ts(rename = "defaultOptionIndex", optional)]
+    pub default_option_index: Option<usize>,
+    /// Checks applied to freeform answers (questions without options).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub validation: Option<RequestUserInputValidation>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
 pub struct RequestUserInputArgs {
     pub questions: Vec<RequestUserInputQuestion>,
+    /// Seconds to wait before the current answers are submitted
+    /// automatically. `None` waits indefinitely.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub timeout_sec: Option<u32>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
@@ -52,4 +115,9 @@ pub struct RequestUserInputEvent {
     #[serde(default)]
     pub turn_id: String,
     pub questions: Vec<RequestUserInputQuestion>,
+    /// Seconds to wait before the current answers (including defaults) are
+    /// submitted automatically. `None` waits indefinitely.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub timeout_sec: Option<u32>,
 }
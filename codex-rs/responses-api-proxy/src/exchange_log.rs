@@ -0,0 +1,205 @@
+use std::fs::File;
+use std::fs::{self};
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use anyhow::Context;
+use anyhow::Result;
+
+/// Headers that carry credentials and are never written to exchange logs.
+const REDACTED_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "api-key",
+    "x-api-key",
+];
+
+/// Writes one file per proxied request/response pair under `dir`, named
+/// `<unix-millis>-<sequence>.log` so files sort in the order requests arrived.
+pub(crate) struct ExchangeLog {
+    dir: PathBuf,
+    max_body_bytes: Option<usize>,
+    sequence: AtomicU64,
+}
+
+impl ExchangeLog {
+    pub(crate) fn new(dir: PathBuf, max_body_bytes: Option<usize>) -> Result<Self> {
+        // Logged bodies can hold user data, so keep the directory and files private to the
+        // current user.
+        let mut builder = fs::DirBuilder::new();
+        builder.recursive(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::DirBuilderExt;
+            builder.mode(0o700);
+        }
+        builder
+            .create(&dir)
+            .with_context(|| format!("creating log directory {}", dir.display()))?;
+        Ok(Self {
+            dir,
+            max_body_bytes,
+            sequence: AtomicU64::new(0),
+        })
+    }
+
+    /// Create the log file for a new exchange and record the incoming request.
+    pub(crate) fn start<'a>(
+        &self,
+        method: &str,
+        path: &str,
+        headers: impl IntoIterator<Item = (&'a str, &'a [u8])>,
+        body: &[u8],
+    ) -> Result<ExchangeLogFile> {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis())
+            .unwrap_or_default();
+        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed);
+        let path_on_disk = self.dir.join(format!("{millis}-{sequence:06}.log"));
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let file = options
+            .open(&path_on_disk)
+            .with_context(|| format!("creating log file {}", path_on_disk.display()))?;
+        let mut log = ExchangeLogFile {
+            file,
+            max_body_bytes: self.max_body_bytes,
+        };
+        writeln!(log.file, "> {method} {path}")?;
+        log.write_headers('>', headers)?;
+        log.write_body(body)?;
+        Ok(log)
+    }
+}
+
+pub(crate) struct ExchangeLogFile {
+    file: File,
+    max_body_bytes: Option<usize>,
+}
+
+impl ExchangeLogFile {
+    /// Record the upstream status line and headers; the body is captured by
+    /// [`ExchangeLogReader`] as it streams to the client.
+    pub(crate) fn write_response_head<'a>(
+        &mut self,
+        status: u16,
+        headers: impl IntoIterator<Item = (&'a str, &'a [u8])>,
+    ) -> Result<()> {
+        writeln!(self.file)?;
+        writeln!(self.file, "< {status}")?;
+        self.write_headers('<', headers)?;
+        writeln!(self.file)?;
+        Ok(())
+    }
+
+    pub(crate) fn write_note(&mut self, note: &str) -> Result<()> {
+        writeln!(self.file)?;
+        writeln!(self.file, "! {note}")?;
+        Ok(())
+    }
+
+    fn write_headers<'a>(
+        &mut self,
+        direction: char,
+        headers: impl IntoIterator<Item = (&'a str, &'a [u8])>,
+    ) -> Result<()> {
+        for (name, value) in headers {
+            if REDACTED_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+                continue;
+            }
+            writeln!(
+                self.file,
+                "{direction} {name}: {}",
+                String::from_utf8_lossy(value)
+            )?;
+        }
+        Ok(())
+    }
+
+    fn write_body(&mut self, body: &[u8]) -> Result<()> {
+        writeln!(self.file)?;
+        let limit = self.max_body_bytes.unwrap_or(body.len()).min(body.len());
+        self.file.write_all(&body[..limit])?;
+        if limit < body.len() {
+            write!(self.file, "\n[truncated {} bytes]", body.len() - limit)?;
+        }
+        writeln!(self.file)?;
+        Ok(())
+    }
+}
+
+/// Passes the upstream response body through while copying it into the
+/// exchange log, up to the configured body limit.
+pub(crate) struct ExchangeLogReader<R> {
+    inner: R,
+    log: Option<ExchangeLogFile>,
+    logged_bytes: usize,
+    total_bytes: usize,
+}
+
+impl<R> ExchangeLogReader<R> {
+    pub(crate) fn new(inner: R, log: Option<ExchangeLogFile>) -> Self {
+        Self {
+            inner,
+            log,
+            logged_bytes: 0,
+            total_bytes: 0,
+        }
+    }
+}
+
+impl<R: Read> Read for ExchangeLogReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = match self.inner.read(buf) {
+            Ok(read) => read,
+            Err(err) => {
+                // Includes the upstream idle timeout expiring mid-stream, which the client
+                // only sees as a truncated body.
+                if let Some(log) = self.log.as_mut() {
+                    let _ = log.write_note(&format!("upstream body read failed: {err}"));
+                }
+                return Err(err);
+            }
+        };
+        if let Some(log) = self.log.as_mut() {
+            let remaining = log
+                .max_body_bytes
+                .map_or(read, |max| max.saturating_sub(self.logged_bytes));
+            let logged = read.min(remaining);
+            // Logging is best-effort: a failed write stops logging but never the response.
+            if log.file.write_all(&buf[..logged]).is_err() {
+                self.log = None;
+            }
+            self.logged_bytes += logged;
+        }
+        self.total_bytes += read;
+        Ok(read)
+    }
+}
+
+impl<R> Drop for ExchangeLogReader<R> {
+    fn drop(&mut self) {
+        if let Some(log) = self.log.as_mut()
+            && self.total_bytes > self.logged_bytes
+        {
+            let _ = write!(
+                log.file,
+                "\n[truncated {} bytes]",
+                self.total_bytes - self.logged_bytes
+            );
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::fs::{self};
 use std::io::Write;
 use std::net::SocketAddr;
 use std::net::TcpListener;
+use std::num::NonZeroU64;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -27,7 +28,10 @@ use tiny_http::Response;
 use tiny_http::Server;
 use tiny_http::StatusCode;
 
+mod exchange_log;
 mod read_api_key;
+use exchange_log::ExchangeLog;
+use exchange_log::ExchangeLogReader;
 use read_api_key::read_auth_header_from_stdin;
 
 /// CLI arguments for the proxy.
@@ -49,6 +53,22 @@ pub struct Args {
     /// Absolute URL the proxy should forward requests to (defaults to OpenAI).
     #[arg(long, default_value = "https://api.openai.com/v1/responses")]
     pub upstream_url: String,
+
+    /// Seconds to wait for the upstream to connect and send response headers before answering
+    /// `504 Gateway Timeout`. Once the body streams, this bounds each gap between chunks rather
+    /// than the whole response.
+    #[arg(long, value_name = "SECONDS")]
+    pub upstream_timeout_sec: Option<NonZeroU64>,
+
+    /// Write each proxied request/response pair to a timestamped file in this directory.
+    /// Credential headers are omitted, but bodies are logged as-is, so only enable this when
+    /// the directory is private. Off by default.
+    #[arg(long, value_name = "DIR")]
+    pub log_dir: Option<PathBuf>,
+
+    /// Truncate logged request and response bodies to this many bytes (requires `--log-dir`).
+    #[arg(long, value_name = "BYTES", requires = "log_dir")]
+    pub log_max_body_bytes: Option<usize>,
 }
 
 #[derive(Serialize)]
@@ -60,6 +80,8 @@ struct ServerInfo {
 struct ForwardConfig {
     upstream_url: Url,
     host_header: HeaderValue,
+    upstream_timeout: Option<Duration>,
+    exchange_log: Option<ExchangeLog>,
 }
 
 /// Entry point for the library main, for parity with other crates.
@@ -78,6 +100,13 @@ pub fn run_main(args: Args) -> Result<()> {
     let forward_config = Arc::new(ForwardConfig {
         upstream_url,
         host_header,
+        upstream_timeout: args
+            .upstream_timeout_sec
+            .map(|seconds| Duration::from_secs(seconds.get())),
+        exchange_log: args
+            .log_dir
+            .map(|dir| ExchangeLog::new(dir, args.log_max_body_bytes))
+            .transpose()?,
     });
 
     let (listener, bound_addr) = bind_listener(args.port)?;
@@ -86,13 +115,7 @@ pub fn run_main(args: Args) -> Result<()> {
     }
     let server = Server::from_listener(listener, None)
         .map_err(|err| anyhow!("creating HTTP server: {err}"))?;
-    let client = Arc::new(
-        Client::builder()
-            // Disable reqwest's 30s default so long-lived response streams keep flowing.
-            .timeout(None::<Duration>)
-            .build()
-            .context("building reqwest client")?,
-    );
+    let client = Arc::new(upstream_client(forward_config.upstream_timeout)?);
 
     eprintln!("responses-api-proxy listening on {bound_addr}");
 
@@ -115,6 +138,17 @@ pub fn run_main(args: Args) -> Result<()> {
     Err(anyhow!("server stopped unexpectedly"))
 }
 
+/// Builds the upstream client. The blocking client's timeout bounds waiting for the response
+/// headers and each body read separately, so it acts as an idle timeout for streamed bodies
+/// instead of a deadline that would cut off long SSE responses. Without `timeout`, reqwest's
+/// 30s default is disabled too.
+fn upstream_client(timeout: Option<Duration>) -> Result<Client> {
+    Client::builder()
+        .timeout(timeout)
+        .build()
+        .context("building reqwest client")
+}
+
 fn bind_listener(port: Option<u16>) -> Result<(TcpListener, SocketAddr)> {
     let addr = SocketAddr::from(([127, 0, 0, 1], port.unwrap_or(0)));
     let listener = TcpListener::bind(addr).with_context(|| format!("failed to bind {addr}"))?;
@@ -162,6 +196,21 @@ fn forward_request(
     let mut reader = req.as_reader();
     std::io::Read::read_to_end(&mut reader, &mut body)?;
 
+    // Exchange logging is best-effort so a full disk never breaks proxying.
+    let mut exchange_log = config.exchange_log.as_ref().and_then(|log| {
+        let headers = req
+            .headers()
+            .iter()
+            .map(|header| (header.field.as_str().as_str(), header.value.as_bytes()));
+        match log.start(method.as_str(), &url_path, headers, &body) {
+            Ok(file) => Some(file),
+            Err(err) => {
+                eprintln!("exchange log error: {err:#}");
+                None
+            }
+        }
+    });
+
     // Build headers for upstream, forwarding everything from the incoming
     // request except Authorization (we replace it below).
     let mut headers = HeaderMap::new();
@@ -189,18 +238,50 @@ fn forward_request(
 
     headers.insert(HOST, config.host_header.clone());
 
-    let upstream_resp = client
+    // No per-request timeout: reqwest would apply it to the whole exchange, body included.
+    let upstream_request = client
         .post(config.upstream_url.clone())
         .headers(headers)
-        .body(body)
-        .send()
-        .context("forwarding request to upstream")?;
+        .body(body);
+    let upstream_resp = match (upstream_request.send(), config.upstream_timeout) {
+        (Ok(resp), _) => resp,
+        (Err(err), Some(timeout)) if err.is_timeout() => {
+            let message = format!("upstream did not respond within {}s", timeout.as_secs_f64());
+            let body = serde_json::json!({
+                "error": {
+                    "type": "upstream_timeout",
+                    "message": message,
+                }
+            });
+            let mut response =
+                Response::from_string(body.to_string()).with_status_code(StatusCode(504));
+            if let Ok(header) = Header::from_bytes("Content-Type", "application/json") {
+                response.add_header(header);
+            }
+            if let Some(log) = exchange_log.as_mut() {
+                let _ = log.write_note(&message);
+            }
+            let _ = req.respond(response);
+            return Err(anyhow!("{message}"));
+        }
+        (Err(err), _) => return Err(err).context("forwarding request to upstream"),
+    };
 
     // We have to create an adapter between a `reqwest::blocking::Response`
     // and a `tiny_http::Response`. Fortunately, `reqwest::blocking::Response`
     // implements `Read`, so we can use it directly as the body of the
     // `tiny_http::Response`.
     let status = upstream_resp.status();
+    if let Some(log) = exchange_log.as_mut() {
+        let headers = upstream_resp
+            .headers()
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_bytes()));
+        if let Err(err) = log.write_response_head(status.as_u16(), headers) {
+            eprintln!("exchange log error: {err:#}");
+            exchange_log = None;
+        }
+    }
     let mut response_headers = Vec::new();
     for (name, value) in upstream_resp.headers().iter() {
         // Skip headers that tiny_http manages itself.
@@ -227,7 +308,7 @@ fn forward_request(
     let response = Response::new(
         StatusCode(status.as_u16()),
         response_headers,
-        upstream_resp,
+        ExchangeLogReader::new(upstream_resp, exchange_log),
         content_length,
         None,
     );
@@ -235,3 +316,228 @@ fn forward_request(
     let _ = req.respond(response);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Serves one chunked response on a raw socket, sleeping `gap` before each chunk.
+    fn spawn_chunked_upstream(
+        chunks: &'static [&'static str],
+        gap: Duration,
+    ) -> Result<SocketAddr> {
+        let (listener, addr) = bind_listener(None)?;
+        std::thread::spawn(move || -> std::io::Result<()> {
+            let (mut stream, _) = listener.accept()?;
+            let mut request = [0u8; 4096];
+            let _ = std::io::Read::read(&mut stream, &mut request)?;
+            stream.write_all(
+                b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nTransfer-Encoding: chunked\r\n\r\n",
+            )?;
+            for chunk in chunks {
+                std::thread::sleep(gap);
+                write!(stream, "{:x}\r\n{chunk}\r\n", chunk.len())?;
+                stream.flush()?;
+            }
+            stream.write_all(b"0\r\n\r\n")?;
+            Ok(())
+        });
+        Ok(addr)
+    }
+
+    fn forward_once(
+        config: ForwardConfig,
+    ) -> Result<(SocketAddr, std::thread::JoinHandle<Result<()>>)> {
+        let (proxy_listener, proxy_addr) = bind_listener(None)?;
+        let proxy = Server::from_listener(proxy_listener, None)
+            .map_err(|err| anyhow!("creating proxy server: {err}"))?;
+        let proxy_thread = std::thread::spawn(move || -> Result<()> {
+            let request = proxy.recv()?;
+            forward_request(
+                &upstream_client(config.upstream_timeout)?,
+                "Bearer test-key",
+                &config,
+                request,
+            )
+        });
+        Ok((proxy_addr, proxy_thread))
+    }
+
+    #[test]
+    fn slow_upstream_returns_gateway_timeout() -> Result<()> {
+        let (upstream_listener, upstream_addr) = bind_listener(None)?;
+        let upstream = Server::from_listener(upstream_listener, None)
+            .map_err(|err| anyhow!("creating upstream server: {err}"))?;
+        std::thread::spawn(move || {
+            if let Ok(request) = upstream.recv() {
+                std::thread::sleep(Duration::from_secs(2));
+                let _ = request.respond(Response::from_string("too late"));
+            }
+        });
+
+        let (proxy_addr, proxy_thread) = forward_once(ForwardConfig {
+            upstream_url: Url::parse(&format!("http://{upstream_addr}/v1/responses"))?,
+            host_header: HeaderValue::from_str(&upstream_addr.to_string())?,
+            upstream_timeout: Some(Duration::from_millis(200)),
+            exchange_log: None,
+        })?;
+
+        let response = Client::new()
+            .post(format!("http://{proxy_addr}/v1/responses"))
+            .body("{}")
+            .send()?;
+        assert_eq!(response.status().as_u16(), 504);
+        let body: serde_json::Value = response.json()?;
+        assert_eq!(body["error"]["type"], "upstream_timeout");
+
+        let forward_result = proxy_thread
+            .join()
+            .map_err(|_| anyhow!("proxy thread panicked"))?;
+        assert_eq!(
+            forward_result.map_err(|err| err.to_string()),
+            Err("upstream did not respond within 0.2s".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn upstream_timeout_does_not_cut_off_a_streaming_body() -> Result<()> {
+        let upstream_addr = spawn_chunked_upstream(
+            &["data: 1\n\n", "data: 2\n\n", "data: 3\n\n", "data: 4\n\n"],
+            Duration::from_millis(150),
+        )?;
+        let (proxy_addr, proxy_thread) = forward_once(ForwardConfig {
+            upstream_url: Url::parse(&format!("http://{upstream_addr}/v1/responses"))?,
+            host_header: HeaderValue::from_str(&upstream_addr.to_string())?,
+            upstream_timeout: Some(Duration::from_millis(400)),
+            exchange_log: None,
+        })?;
+
+        let response = Client::new()
+            .post(format!("http://{proxy_addr}/v1/responses"))
+            .body("{}")
+            .send()?;
+        assert_eq!(
+            response.text()?,
+            "data: 1\n\ndata: 2\n\ndata: 3\n\ndata: 4\n\n"
+        );
+        proxy_thread
+            .join()
+            .map_err(|_| anyhow!("proxy thread panicked"))??;
+        Ok(())
+    }
+
+    #[test]
+    fn upstream_idle_timeout_is_recorded_in_exchange_log() -> Result<()> {
+        let log_dir = tempfile::tempdir()?;
+        let upstream_addr = spawn_chunked_upstream(&["data: 1\n\n"], Duration::from_secs(2))?;
+        let (proxy_addr, proxy_thread) = forward_once(ForwardConfig {
+            upstream_url: Url::parse(&format!("http://{upstream_addr}/v1/responses"))?,
+            host_header: HeaderValue::from_str(&upstream_addr.to_string())?,
+            upstream_timeout: Some(Duration::from_millis(200)),
+            exchange_log: Some(ExchangeLog::new(log_dir.path().to_path_buf(), None)?),
+        })?;
+
+        let response = Client::new()
+            .post(format!("http://{proxy_addr}/v1/responses"))
+            .body("{}")
+            .send()?;
+        assert_eq!(response.status().as_u16(), 200);
+        assert!(response.text().is_err());
+        proxy_thread
+            .join()
+            .map_err(|_| anyhow!("proxy thread panicked"))??;
+
+        let entries = fs::read_dir(log_dir.path())?.collect::<std::io::Result<Vec<_>>>()?;
+        assert_eq!(entries.len(), 1);
+        let contents = fs::read_to_string(entries[0].path())?;
+        assert!(contents.contains("< 200\n"), "{contents}");
+        assert!(
+            contents.contains("! upstream body read failed: "),
+            "{contents}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn log_dir_records_sanitized_exchange() -> Result<()> {
+        let log_dir = tempfile::tempdir()?;
+        let (upstream_listener, upstream_addr) = bind_listener(None)?;
+        let upstream = Server::from_listener(upstream_listener, None)
+            .map_err(|err| anyhow!("creating upstream server: {err}"))?;
+        std::thread::spawn(move || {
+            if let Ok(request) = upstream.recv() {
+                let _ = request.respond(Response::from_string("{\"id\":\"resp_1\"}"));
+            }
+        });
+
+        let (proxy_listener, proxy_addr) = bind_listener(None)?;
+        let proxy = Server::from_listener(proxy_listener, None)
+            .map_err(|err| anyhow!("creating proxy server: {err}"))?;
+        let config = ForwardConfig {
+            upstream_url: Url::parse(&format!("http://{upstream_addr}/v1/responses"))?,
+            host_header: HeaderValue::from_str(&upstream_addr.to_string())?,
+            upstream_timeout: None,
+            exchange_log: Some(ExchangeLog::new(log_dir.path().to_path_buf(), Some(8))?),
+        };
+        let proxy_thread = std::thread::spawn(move || -> Result<()> {
+            let request = proxy.recv()?;
+            forward_request(
+                &upstream_client(None)?,
+                "Bearer upstream-key",
+                &config,
+                request,
+            )
+        });
+
+        let response = Client::new()
+            .post(format!("http://{proxy_addr}/v1/responses"))
+            .header("Authorization", "Bearer client-key")
+            .header("X-Trace", "trace-1")
+            .body("{\"input\":\"hello\"}")
+            .send()?;
+        assert_eq!(response.text()?, "{\"id\":\"resp_1\"}");
+        proxy_thread
+            .join()
+            .map_err(|_| anyhow!("proxy thread panicked"))??;
+
+        let entries = fs::read_dir(log_dir.path())?.collect::<std::io::Result<Vec<_>>>()?;
+        assert_eq!(entries.len(), 1);
+        let contents = fs::read_to_string(entries[0].path())?;
+        assert!(contents.starts_with("> POST /v1/responses\n"), "{contents}");
+        assert!(contents.contains("> x-trace: trace-1\n"), "{contents}");
+        assert!(
+            contents.contains("{\"input\"\n[truncated 9 bytes]"),
+            "{contents}"
+        );
+        assert!(contents.contains("< 200\n"), "{contents}");
+        assert!(
+            contents.contains("{\"id\":\"r\n[truncated 7 bytes]"),
+            "{contents}"
+        );
+        assert!(!contents.contains("client-key"), "{contents}");
+        assert!(!contents.contains("upstream-key"), "{contents}");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn log_dir_and_files_are_private() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let root = tempfile::tempdir()?;
+        let log_dir = root.path().join("exchanges");
+        let log = ExchangeLog::new(log_dir.clone(), None)?;
+        drop(log.start("POST", "/v1/responses", std::iter::empty(), b"{}")?);
+
+        let entries = fs::read_dir(&log_dir)?.collect::<std::io::Result<Vec<_>>>()?;
+        assert_eq!(entries.len(), 1);
+        let modes = (
+            fs::metadata(&log_dir)?.permissions().mode() & 0o777,
+            fs::metadata(entries[0].path())?.permissions().mode() & 0o777,
+        );
+        assert_eq!(modes, (0o700, 0o600));
+        Ok(())
+    }
+}
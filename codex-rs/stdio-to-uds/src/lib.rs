@@ -1,10 +1,16 @@
 #![deny(clippy::print_stdout)]
 
 use std::io;
+use std::io::Read;
 use std::io::Write;
 use std::net::Shutdown;
 use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::mpsc;
 use std::thread;
+use std::time::Duration;
+use std::time::Instant;
 
 use anyhow::Context;
 use anyhow::anyhow;
@@ -15,10 +21,18 @@ use std::os::unix::net::UnixStream;
 #[cfg(windows)]
 use uds_windows::UnixStream;
 
+/// Bytes written to the socket when `keepalive` is set and stdin has been idle.
+pub const KEEPALIVE_PING: &[u8] = b"\n";
+
 /// Connects to the Unix Domain Socket at `socket_path` and relays data between
 /// standard input/output and the socket.
-pub fn run(socket_path: &Path) -> anyhow::Result<()> {
-    let mut stream = UnixStream::connect(socket_path)
+///
+/// When `keepalive` is set, [`KEEPALIVE_PING`] is written to the socket whenever
+/// nothing has been sent for that long, so idle relays are not dropped. Pings are only
+/// sent after a forwarded `\n` (or before any input), so they never split a
+/// newline-delimited message that stdin delivered in pieces.
+pub fn run(socket_path: &Path, keepalive: Option<Duration>) -> anyhow::Result<()> {
+    let stream = UnixStream::connect(socket_path)
         .with_context(|| format!("failed to connect to socket at {}", socket_path.display()))?;
 
     let mut reader = stream
@@ -33,13 +47,62 @@ pub fn run(socket_path: &Path) -> anyhow::Result<()> {
         Ok(())
     });
 
+    // The flag records whether the last forwarded byte ended a line.
+    let writer = Arc::new(Mutex::new((stream, Instant::now(), true)));
+    // Dropping `stop_tx` once stdin is exhausted wakes the keepalive thread so it can exit.
+    let (stop_tx, stop_rx) = mpsc::channel::<()>();
+    let keepalive_thread = keepalive.map(|interval| {
+        let writer = Arc::clone(&writer);
+        thread::spawn(move || -> io::Result<()> {
+            while let Err(mpsc::RecvTimeoutError::Timeout) = stop_rx.recv_timeout(interval) {
+                let mut guard = writer
+                    .lock()
+                    .map_err(|_| io::Error::other("socket writer lock poisoned"))?;
+                let (stream, last_write, at_line_boundary) = &mut *guard;
+                if *at_line_boundary && last_write.elapsed() >= interval {
+                    stream.write_all(KEEPALIVE_PING)?;
+                    *last_write = Instant::now();
+                }
+            }
+            Ok(())
+        })
+    });
+
     let stdin = io::stdin();
     {
         let mut handle = stdin.lock();
-        io::copy(&mut handle, &mut stream).context("failed to copy data from stdin to socket")?;
+        let mut buf = [0u8; 8192];
+        loop {
+            let read = match handle.read(&mut buf) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err).context("failed to read data from stdin"),
+            };
+            let mut guard = writer
+                .lock()
+                .map_err(|_| anyhow!("socket writer lock poisoned"))?;
+            let (stream, last_write, at_line_boundary) = &mut *guard;
+            stream
+                .write_all(&buf[..read])
+                .context("failed to copy data from stdin to socket")?;
+            *last_write = Instant::now();
+            *at_line_boundary = buf[read - 1] == b'\n';
+        }
+    }
+
+    drop(stop_tx);
+    if let Some(keepalive_thread) = keepalive_thread {
+        let keepalive_result = keepalive_thread
+            .join()
+            .map_err(|_| anyhow!("thread panicked while sending keepalive pings"))?;
+        keepalive_result.context("failed to send keepalive ping to socket")?;
     }
 
-    stream
+    writer
+        .lock()
+        .map_err(|_| anyhow!("socket writer lock poisoned"))?
+        .0
         .shutdown(Shutdown::Write)
         .context("failed to shutdown socket writer")?;
 
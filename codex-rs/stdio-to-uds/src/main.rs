@@ -1,19 +1,35 @@
 use std::env;
 use std::path::PathBuf;
 use std::process;
+use std::time::Duration;
 
 fn main() -> anyhow::Result<()> {
     let mut args = env::args_os().skip(1);
-    let Some(socket_path) = args.next() else {
-        eprintln!("Usage: codex-stdio-to-uds <socket-path>");
-        process::exit(1);
-    };
+    let mut keepalive = None;
+    let mut socket_path = None;
+    while let Some(arg) = args.next() {
+        if arg == "--keepalive-sec" {
+            let seconds = args
+                .next()
+                .and_then(|value| value.to_str()?.parse::<u64>().ok())
+                .filter(|seconds| *seconds > 0);
+            let Some(seconds) = seconds else {
+                eprintln!("--keepalive-sec expects a positive number of seconds");
+                process::exit(1);
+            };
+            keepalive = Some(Duration::from_secs(seconds));
+        } else if socket_path.is_none() {
+            socket_path = Some(PathBuf::from(arg));
+        } else {
+            eprintln!("Expected exactly one argument: <socket-path>");
+            process::exit(1);
+        }
+    }
 
-    if args.next().is_some() {
-        eprintln!("Expected exactly one argument: <socket-path>");
+    let Some(socket_path) = socket_path else {
+        eprintln!("Usage: codex-stdio-to-uds [--keepalive-sec <SECONDS>] <socket-path>");
         process::exit(1);
-    }
+    };
 
-    let socket_path = PathBuf::from(socket_path);
-    codex_stdio_to_uds::run(&socket_path)
+    codex_stdio_to_uds::run(&socket_path, keepalive)
 }
@@ -1,6 +1,7 @@
 use std::io::ErrorKind;
 use std::io::Read;
 use std::io::Write;
+use std::process::Stdio;
 use std::sync::mpsc;
 use std::thread;
 use std::time::Duration;
@@ -66,3 +67,109 @@ fn pipes_stdin_and_stdout_through_socket() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn keepalive_writes_pings_while_stdin_is_idle() -> anyhow::Result<()> {
+    let dir = tempfile::TempDir::new().context("failed to create temp dir")?;
+    let socket_path = dir.path().join("socket");
+    let listener = match UnixListener::bind(&socket_path) {
+        Ok(listener) => listener,
+        Err(err) if err.kind() == ErrorKind::PermissionDenied => {
+            eprintln!("skipping test: failed to bind unix socket: {err}");
+            return Ok(());
+        }
+        Err(err) => {
+            return Err(err).context("failed to bind test unix socket");
+        }
+    };
+
+    let mut child =
+        std::process::Command::new(codex_utils_cargo_bin::cargo_bin("codex-stdio-to-uds")?)
+            .args(["--keepalive-sec", "1"])
+            .arg(&socket_path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+            .context("failed to spawn codex-stdio-to-uds")?;
+
+    let (mut connection, _) = listener
+        .accept()
+        .context("failed to accept test connection")?;
+    connection
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .context("failed to set read timeout")?;
+    let mut pings = [0u8; 2];
+    let read_result = connection.read_exact(&mut pings);
+
+    // Closing stdin makes the relay shut down its writer; wait for that EOF before closing our
+    // end so the relay's stdout copy finishes and it exits.
+    drop(child.stdin.take());
+    let mut rest = Vec::new();
+    connection
+        .read_to_end(&mut rest)
+        .context("failed to drain relay after closing stdin")?;
+    drop(connection);
+    let status = child
+        .wait()
+        .context("failed to wait for codex-stdio-to-uds")?;
+
+    read_result.context("no keepalive pings received while stdin was idle")?;
+    assert_eq!(&pings, b"\n\n");
+    assert!(status.success());
+
+    Ok(())
+}
+
+#[test]
+fn keepalive_waits_for_line_boundary() -> anyhow::Result<()> {
+    let dir = tempfile::TempDir::new().context("failed to create temp dir")?;
+    let socket_path = dir.path().join("socket");
+    let listener = match UnixListener::bind(&socket_path) {
+        Ok(listener) => listener,
+        Err(err) if err.kind() == ErrorKind::PermissionDenied => {
+            eprintln!("skipping test: failed to bind unix socket: {err}");
+            return Ok(());
+        }
+        Err(err) => {
+            return Err(err).context("failed to bind test unix socket");
+        }
+    };
+
+    let mut child =
+        std::process::Command::new(codex_utils_cargo_bin::cargo_bin("codex-stdio-to-uds")?)
+            .args(["--keepalive-sec", "1"])
+            .arg(&socket_path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+            .context("failed to spawn codex-stdio-to-uds")?;
+
+    let (mut connection, _) = listener
+        .accept()
+        .context("failed to accept test connection")?;
+    let mut stdin = child.stdin.take().context("relay stdin is not piped")?;
+    stdin
+        .write_all(b"{\"id\":")
+        .context("failed to write the first half of the message")?;
+    stdin.flush().context("failed to flush relay stdin")?;
+    // Stall mid-message for longer than the keepalive interval.
+    thread::sleep(Duration::from_millis(2500));
+    stdin
+        .write_all(b"1}\n")
+        .context("failed to write the rest of the message")?;
+    drop(stdin);
+
+    let mut received = Vec::new();
+    connection
+        .read_to_end(&mut received)
+        .context("failed to read relayed data")?;
+    drop(connection);
+    let status = child
+        .wait()
+        .context("failed to wait for codex-stdio-to-uds")?;
+
+    assert_eq!(received, b"{\"id\":1}\n");
+    assert!(status.success());
+
+    Ok(())
+}
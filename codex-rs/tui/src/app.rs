@@ -118,6 +118,9 @@ pub struct AppExitInfo {
     pub token_usage: TokenUsage,
     pub thread_id: Option<ThreadId>,
     pub thread_name: Option<String>,
+    /// False when the session was not persisted (for example `ephemeral`), so there is nothing
+    /// to resume and the exit summary omits the resume hint.
+    pub resumable: bool,
     pub update_action: Option<UpdateAction>,
     pub exit_reason: ExitReason,
 }
@@ -128,6 +131,7 @@ impl AppExitInfo {
             token_usage: TokenUsage::default(),
             thread_id: None,
             thread_name: None,
+            resumable: false,
             update_action: None,
             exit_reason: ExitReason::Fatal(message.into()),
         }
@@ -511,6 +515,7 @@ async fn handle_model_migration_prompt_if_needed(
                     token_usage: TokenUsage::default(),
                     thread_id: None,
                     thread_name: None,
+                    resumable: false,
                     update_action: None,
                     exit_reason: ExitReason::UserRequested,
                 });
@@ -884,6 +889,8 @@ impl App {
         let init = self.chatwidget_init_for_forked_or_resumed_thread(tui, self.config.clone());
         let codex_op_tx = crate::chatwidget::spawn_op_forwarder(thread);
         self.chat_widget = ChatWidget::new_with_op_sender(init, codex_op_tx);
+        self.chat_widget
+            .set_pinned_ramp(self.xcodex_state.pinned_ramp);
 
         self.reset_for_thread_switch(tui)?;
         self.replay_thread_snapshot(snapshot);
@@ -1223,6 +1230,7 @@ impl App {
                     token_usage: app.token_usage(),
                     thread_id: app.effective_thread_id(),
                     thread_name: app.effective_thread_name(),
+                    resumable: !app.config.ephemeral,
                     update_action: app.pending_update_action,
                     exit_reason,
                 });
@@ -1297,6 +1305,7 @@ impl App {
             token_usage: app.token_usage(),
             thread_id: app.effective_thread_id(),
             thread_name: app.effective_thread_name(),
+            resumable: !app.config.ephemeral,
             update_action: app.pending_update_action,
             exit_reason,
         })
@@ -1396,6 +1405,8 @@ impl App {
                     otel_manager: self.otel_manager.clone(),
                 };
                 self.chat_widget = ChatWidget::new(init, self.server.clone());
+                self.chat_widget
+                    .set_pinned_ramp(self.xcodex_state.pinned_ramp);
                 let tx = self.app_event_tx.clone();
                 let cwd = self.config.cwd.clone();
                 tokio::spawn(async move {
@@ -1490,6 +1501,8 @@ impl App {
                                     resumed.thread,
                                     resumed.session_configured,
                                 );
+                                self.chat_widget
+                                    .set_pinned_ramp(self.xcodex_state.pinned_ramp);
                                 self.reset_thread_event_state();
                                 if let Some(summary) = summary {
                                     let base_style = crate::theme::transcript_style();
@@ -1553,6 +1566,8 @@ impl App {
                                     forked.thread,
                                     forked.session_configured,
                                 );
+                                self.chat_widget
+                                    .set_pinned_ramp(self.xcodex_state.pinned_ramp);
                                 self.reset_thread_event_state();
                                 if let Some(summary) = summary {
                                     let mut lines: Vec<Line<'static>> =
@@ -1655,6 +1670,9 @@ impl App {
             AppEvent::CommitTick => {
                 self.chat_widget.on_commit_tick();
             }
+            AppEvent::RequestUserInputTimeout { call_id } => {
+                self.chat_widget.on_request_user_input_timeout(&call_id);
+            }
             AppEvent::CodexEvent(event) => {
                 self.enqueue_primary_event(event).await?;
             }
@@ -2359,6 +2377,35 @@ impl App {
                     }
                 }
             }
+            AppEvent::PersistTuiToggles(toggles) => {
+                let profile = self.active_profile.as_deref();
+                match ConfigEditsBuilder::new(&self.config.codex_home)
+                    .with_profile(profile)
+                    .with_edits(
+                        toggles
+                            .into_iter()
+                            .map(|(key, enabled)| ConfigEdit::SetPath {
+                                segments: vec!["tui".to_string(), key.to_string()],
+                                value: toml_edit::value(enabled),
+                            }),
+                    )
+                    .apply()
+                    .await
+                {
+                    Ok(()) => {}
+                    Err(err) => {
+                        tracing::error!(error = %err, "failed to persist settings toggles");
+                        if let Some(profile) = profile {
+                            self.chat_widget.add_error_message(format!(
+                                "Failed to save settings for profile `{profile}`: {err}"
+                            ));
+                        } else {
+                            self.chat_widget
+                                .add_error_message(format!("Failed to save settings: {err}"));
+                        }
+                    }
+                }
+            }
             AppEvent::PersistMinimalComposer(enabled) => {
                 let profile = self.active_profile.as_deref();
                 match ConfigEditsBuilder::new(&self.config.codex_home)
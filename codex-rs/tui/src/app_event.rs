@@ -356,6 +356,12 @@ pub(crate) enum AppEvent {
     StopCommitAnimation,
     CommitTick,
 
+    /// The `timeout_sec` of a request_user_input prompt elapsed; the overlay
+    /// showing `call_id` submits its current answers.
+    RequestUserInputTimeout {
+        call_id: String,
+    },
+
     /// Update the current reasoning effort in the running app and widget.
     UpdateReasoningEffort(Option<ReasoningEffort>),
 
@@ -405,6 +411,9 @@ pub(crate) enum AppEvent {
     /// Persist whether the active composer uses minimal borders.
     PersistMinimalComposer(bool),
 
+    /// Persist several boolean `[tui]` settings (by key) in a single config edit.
+    PersistTuiToggles(Vec<(&'static str, bool)>),
+
     /// Persist whether user prompts are highlighted in the transcript.
     PersistTranscriptUserPromptHighlight(bool),
 
@@ -437,6 +446,9 @@ pub(crate) enum AppEvent {
     /// Open the in-TUI theme help view.
     OpenThemeHelp,
 
+    /// Re-detect the terminal background and re-apply the config-selected theme.
+    RefreshTheme,
+
     /// Persist xcodex ramp settings.
     PersistRampsConfig {
         rotate: bool,
@@ -447,6 +459,9 @@ pub(crate) enum AppEvent {
     /// Open the xcodex ramp settings view.
     OpenRampsSettingsView,
 
+    /// Fix the ramp used for every turn (`Some`) or resume rotation (`None`).
+    SetPinnedRamp(Option<crate::ramps::RampId>),
+
     /// Persist `worktrees.shared_dirs` to config.
     PersistWorktreesSharedDirs {
         shared_dirs: Vec<String>,
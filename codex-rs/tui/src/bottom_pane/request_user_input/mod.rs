@@ -9,6 +9,7 @@
 use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
@@ -28,6 +29,7 @@ use crate::bottom_pane::scroll_state::ScrollState;
 use crate::bottom_pane::selection_popup_common::GenericDisplayRow;
 use crate::bottom_pane::selection_popup_common::measure_rows_height;
 use crate::history_cell;
+use crate::markdown_render::render_markdown_text_with_width;
 use crate::render::renderable::Renderable;
 
 use codex_core::protocol::Op;
@@ -35,6 +37,8 @@ use codex_protocol::request_user_input::RequestUserInputAnswer;
 use codex_protocol::request_user_input::RequestUserInputEvent;
 use codex_protocol::request_user_input::RequestUserInputResponse;
 use codex_protocol::user_input::TextElement;
+use ratatui::text::Line;
+use regex_lite::Regex;
 use unicode_width::UnicodeWidthStr;
 
 const NOTES_PLACEHOLDER: &str = "Add notes";
@@ -141,6 +145,8 @@ pub(crate) struct RequestUserInputOverlay {
     pending_submission_draft: Option<ComposerDraft>,
     confirm_unanswered: Option<ScrollState>,
     confirm_review: Option<ScrollState>,
+    // Message from the last failed answer validation, cleared on edit or navigation.
+    validation_error: Option<String>,
 }
 
 impl RequestUserInputOverlay {
@@ -177,6 +183,7 @@ impl RequestUserInputOverlay {
             pending_submission_draft: None,
             confirm_unanswered: None,
             confirm_review: None,
+            validation_error: None,
         };
         overlay.reset_for_request();
         overlay.ensure_focus_available();
@@ -277,13 +284,12 @@ impl RequestUserInputOverlay {
             .is_some_and(|answer| answer.notes_visible || self.notes_has_content(idx))
     }
 
-    pub(super) fn wrapped_question_lines(&self, width: u16) -> Vec<String> {
+    /// Question body rendered as markdown (inline code, emphasis, links) and
+    /// wrapped to `width`. Headers stay plain text.
+    pub(super) fn wrapped_question_lines(&self, width: u16) -> Vec<Line<'static>> {
         self.current_question()
             .map(|q| {
-                textwrap::wrap(&q.question, width.max(1) as usize)
-                    .into_iter()
-                    .map(|line| line.to_string())
-                    .collect::<Vec<_>>()
+                render_markdown_text_with_width(&q.question, Some(width.max(1) as usize)).lines
             })
             .unwrap_or_default()
     }
@@ -461,6 +467,7 @@ impl RequestUserInputOverlay {
             answer.notes_visible = true;
         }
         self.pending_submission_draft = None;
+        self.validation_error = None;
         self.composer
             .set_text_content(String::new(), Vec::new(), Vec::new());
         self.composer.move_cursor_to_end();
@@ -469,6 +476,9 @@ impl RequestUserInputOverlay {
 
     fn footer_tips(&self) -> Vec<FooterTip> {
         let mut tips = Vec::new();
+        if let Some(error) = &self.validation_error {
+            tips.push(FooterTip::highlighted(error.clone()));
+        }
         let notes_visible = self.notes_ui_visible();
         if self.has_options() {
             tips.push(FooterTip::new("space selects option"));
@@ -598,9 +608,17 @@ impl RequestUserInputOverlay {
                 if has_options {
                     options_state.selected_idx = Some(0);
                 }
+                // A valid default starts out selected, so the question already counts as answered.
+                let selected_option_indices = match question.default_option_index {
+                    Some(idx) if has_options && idx < Self::options_len_for_question(question) => {
+                        options_state.selected_idx = Some(idx);
+                        vec![idx]
+                    }
+                    Some(_) | None => Vec::new(),
+                };
                 AnswerState {
                     options_state,
-                    selected_option_indices: Vec::new(),
+                    selected_option_indices,
                     draft: ComposerDraft::default(),
                     answer_committed: false,
                     notes_visible: !has_options,
@@ -608,13 +626,30 @@ impl RequestUserInputOverlay {
             })
             .collect();
 
-        self.current_idx = 0;
+        // Start on the first question whose `show_if` is already satisfied (e.g. by defaults).
+        self.current_idx = (0..self.question_count())
+            .find(|idx| self.is_question_visible(*idx))
+            .unwrap_or(0);
         self.focus = Focus::Options;
         self.composer
             .set_text_content(String::new(), Vec::new(), Vec::new());
         self.confirm_unanswered = None;
         self.confirm_review = None;
         self.pending_submission_draft = None;
+        self.validation_error = None;
+
+        // Without a runtime (unit tests) no timer runs; the tick can still be
+        // delivered through `on_user_input_timeout`.
+        if let Some(timeout_sec) = self.request.timeout_sec
+            && let Ok(handle) = tokio::runtime::Handle::try_current()
+        {
+            let tx = self.app_event_tx.clone();
+            let call_id = self.request.call_id.clone();
+            handle.spawn(async move {
+                tokio::time::sleep(Duration::from_secs(u64::from(timeout_sec))).await;
+                tx.send(AppEvent::RequestUserInputTimeout { call_id });
+            });
+        }
     }
 
     fn options_len_for_question(
@@ -656,7 +691,44 @@ impl RequestUserInputOverlay {
         None
     }
 
-    /// Move to the next/previous question, wrapping in either direction.
+    /// Whether the question's `show_if` condition (and those of the questions
+    /// it depends on) is satisfied by the current answers.
+    fn is_question_visible(&self, idx: usize) -> bool {
+        let mut idx = idx;
+        // Each step follows one condition, so a cycle is cut off after visiting
+        // every question once.
+        for _ in 0..=self.question_count() {
+            let Some(question) = self.request.questions.get(idx) else {
+                return false;
+            };
+            let Some(condition) = question.show_if.as_ref() else {
+                return true;
+            };
+            let Some(parent_idx) = self
+                .request
+                .questions
+                .iter()
+                .position(|q| q.id == condition.question_id)
+            else {
+                return false;
+            };
+            let parent = &self.request.questions[parent_idx];
+            let option_matches = self.answers.get(parent_idx).is_some_and(|answer| {
+                answer
+                    .selected_option_indices
+                    .iter()
+                    .filter_map(|option_idx| Self::option_label_for_index(parent, *option_idx))
+                    .any(|label| label == condition.equals)
+            });
+            if !option_matches && self.notes_text_for_question(parent_idx) != condition.equals {
+                return false;
+            }
+            idx = parent_idx;
+        }
+        false
+    }
+
+    /// Move to the next/previous visible question, wrapping in either direction.
     fn move_question(&mut self, next: bool) {
         let len = self.question_count();
         if len == 0 {
@@ -664,8 +736,16 @@ impl RequestUserInputOverlay {
         }
         self.save_current_draft();
         let offset = if next { 1 } else { len.saturating_sub(1) };
-        self.current_idx = (self.current_idx + offset) % len;
+        let mut idx = self.current_idx;
+        for _ in 0..len {
+            idx = (idx + offset) % len;
+            if self.is_question_visible(idx) {
+                break;
+            }
+        }
+        self.current_idx = idx;
         self.restore_current_draft();
+        self.refresh_validation_error();
         self.ensure_focus_available();
     }
 
@@ -676,6 +756,7 @@ impl RequestUserInputOverlay {
         self.save_current_draft();
         self.current_idx = idx;
         self.restore_current_draft();
+        self.refresh_validation_error();
         self.ensure_focus_available();
     }
 
@@ -725,15 +806,77 @@ impl RequestUserInputOverlay {
         if has_options {
             self.ensure_default_option_selected();
         }
+        let valid = self
+            .validation_error_for_question(self.current_index())
+            .is_none();
         if let Some(answer) = self.current_answer_mut() {
             answer.answer_committed = if has_options {
                 !answer.selected_option_indices.is_empty()
             } else {
-                !answer.draft.text_with_pending().trim().is_empty()
+                valid && !answer.draft.text_with_pending().trim().is_empty()
             };
         }
     }
 
+    /// Check a freeform answer against its question's `validation`.
+    fn validation_error_for_question(&self, idx: usize) -> Option<String> {
+        let question = self.request.questions.get(idx)?;
+        if question
+            .options
+            .as_ref()
+            .is_some_and(|options| !options.is_empty())
+        {
+            return None;
+        }
+        let validation = question.validation.as_ref()?;
+        let text = self.notes_text_for_question(idx);
+        let len = text.chars().count();
+        let problem = if text.is_empty() {
+            validation
+                .min_len
+                .filter(|min_len| *min_len > 0)
+                .map(|_| "An answer is required.".to_string())
+        } else if let Some(min_len) = validation.min_len.filter(|min_len| len < *min_len) {
+            Some(format!("Answer must be at least {min_len} characters."))
+        } else if let Some(max_len) = validation.max_len.filter(|max_len| len > *max_len) {
+            Some(format!("Answer must be at most {max_len} characters."))
+        } else {
+            validation
+                .pattern
+                .as_deref()
+                .and_then(|pattern| match Regex::new(pattern) {
+                    Ok(regex) => {
+                        (!regex.is_match(&text)).then(|| format!("Answer must match `{pattern}`."))
+                    }
+                    Err(err) => {
+                        // A bad pattern from the model should not trap the user.
+                        tracing::warn!(
+                            "ignoring invalid request_user_input pattern {pattern:?}: {err}"
+                        );
+                        None
+                    }
+                })
+        };
+        problem.map(|problem| validation.error_message.clone().unwrap_or(problem))
+    }
+
+    /// Re-check the question just navigated to, so its message reflects its
+    /// saved answer. Empty answers are only flagged when the user advances.
+    fn refresh_validation_error(&mut self) {
+        let idx = self.current_index();
+        self.validation_error = if self.notes_text_for_question(idx).is_empty() {
+            None
+        } else {
+            self.validation_error_for_question(idx)
+        };
+    }
+
+    fn first_invalid_index(&self) -> Option<usize> {
+        (0..self.question_count()).find(|idx| {
+            self.is_question_visible(*idx) && self.validation_error_for_question(*idx).is_some()
+        })
+    }
+
     /// Clear the current option selection and hide notes when empty.
     fn clear_selection(&mut self) {
         if !self.has_options() {
@@ -778,23 +921,37 @@ impl RequestUserInputOverlay {
         self.sync_composer_placeholder();
     }
 
-    /// Advance to next question, or submit when on the last one.
+    /// Advance to the next visible question, or submit when there is none left.
+    /// Stays on the current question while its answer fails validation, and
+    /// jumps back to the first invalid answer instead of submitting.
     fn go_next_or_submit(&mut self) {
-        if self.current_index() + 1 >= self.question_count() {
+        self.validation_error = self.validation_error_for_question(self.current_index());
+        if self.validation_error.is_some() {
+            return;
+        }
+        let next_visible = (self.current_index() + 1..self.question_count())
+            .find(|idx| self.is_question_visible(*idx));
+        if let Some(idx) = next_visible {
+            self.jump_to_question(idx);
+        } else if let Some(idx) = self.first_invalid_index() {
+            self.jump_to_question(idx);
+            self.validation_error = self.validation_error_for_question(idx);
+        } else {
             self.save_current_draft();
             if self.unanswered_count() > 0 {
                 self.open_unanswered_confirmation();
             } else {
                 self.open_review_confirmation();
             }
-        } else {
-            self.move_question(true);
         }
     }
 
     fn collect_answers(&self) -> HashMap<String, RequestUserInputAnswer> {
         let mut answers = HashMap::new();
         for (idx, question) in self.request.questions.iter().enumerate() {
+            if !self.is_question_visible(idx) {
+                continue;
+            }
             let answer_state = &self.answers[idx];
             let options = question.options.as_ref();
             let selected_indices = if options.is_some_and(|opts| !opts.is_empty())
@@ -828,7 +985,9 @@ impl RequestUserInputOverlay {
         self.request
             .questions
             .iter()
-            .map(|question| {
+            .enumerate()
+            .filter(|(idx, _)| self.is_question_visible(*idx))
+            .map(|(_, question)| {
                 let joined = answers
                     .get(&question.id)
                     .map(|answer| answer.answers.clone())
@@ -889,6 +1048,14 @@ impl RequestUserInputOverlay {
         self.confirm_review = None;
         self.save_current_draft();
         let answers = self.collect_answers();
+        let questions = self
+            .request
+            .questions
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| self.is_question_visible(*idx))
+            .map(|(_, question)| question.clone())
+            .collect();
         self.app_event_tx
             .send(AppEvent::CodexOp(Op::UserInputAnswer {
                 id: self.request.call_id.clone(),
@@ -898,7 +1065,7 @@ impl RequestUserInputOverlay {
             }));
         self.app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
             history_cell::RequestUserInputResultCell {
-                questions: self.request.questions.clone(),
+                questions,
                 answers,
                 interrupted: false,
             },
@@ -942,7 +1109,7 @@ impl RequestUserInputOverlay {
             .questions
             .iter()
             .enumerate()
-            .find(|(idx, _)| !self.is_question_answered(*idx))
+            .find(|(idx, _)| self.is_question_visible(*idx) && !self.is_question_answered(*idx))
             .map(|(idx, _)| idx)
     }
 
@@ -995,13 +1162,15 @@ impl RequestUserInputOverlay {
         }
     }
 
-    /// Count questions that would submit an empty answer list.
+    /// Count visible questions that would submit an empty answer list.
     fn unanswered_count(&self) -> usize {
         self.request
             .questions
             .iter()
             .enumerate()
-            .filter(|(idx, _question)| !self.is_question_answered(*idx))
+            .filter(|(idx, _question)| {
+                self.is_question_visible(*idx) && !self.is_question_answered(*idx)
+            })
             .count()
     }
 
@@ -1308,6 +1477,16 @@ impl BottomPaneView for RequestUserInputOverlay {
                 }
             }
             Focus::Notes => {
+                if let KeyEvent {
+                    code: KeyCode::Char('u'),
+                    modifiers: KeyModifiers::CONTROL,
+                    ..
+                } = key_event
+                {
+                    // Clear the whole notes field rather than just the current line.
+                    self.clear_notes_draft();
+                    return;
+                }
                 let notes_empty = self.composer.current_text_with_pending().trim().is_empty();
                 if self.has_options() && matches!(key_event.code, KeyCode::Tab) {
                     self.clear_notes_and_focus_options();
@@ -1380,10 +1559,11 @@ impl BottomPaneView for RequestUserInputOverlay {
                 let submitted = self.handle_composer_input_result(result);
                 if !submitted {
                     let after = self.capture_composer_draft();
-                    if before != after
-                        && let Some(answer) = self.current_answer_mut()
-                    {
-                        answer.answer_committed = false;
+                    if before != after {
+                        self.validation_error = None;
+                        if let Some(answer) = self.current_answer_mut() {
+                            answer.answer_committed = false;
+                        }
                     }
                 }
             }
@@ -1419,6 +1599,41 @@ impl BottomPaneView for RequestUserInputOverlay {
         self.done
     }
 
+    fn on_user_input_timeout(&mut self, call_id: &str) {
+        // Ignore timers for requests that were already answered.
+        if self.done || self.request.call_id != call_id {
+            return;
+        }
+        // Answers the prompt would reject fall back to the question's default
+        // (no answer), and the user is told which ones were dropped.
+        self.save_current_draft();
+        let invalid = (0..self.question_count())
+            .filter(|idx| {
+                self.is_question_visible(*idx) && self.validation_error_for_question(*idx).is_some()
+            })
+            .collect::<Vec<_>>();
+        if !invalid.is_empty() {
+            let headers = invalid
+                .iter()
+                .map(|idx| self.request.questions[*idx].header.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            for idx in &invalid {
+                if let Some(answer) = self.answers.get_mut(*idx) {
+                    answer.draft = ComposerDraft::default();
+                    answer.answer_committed = false;
+                }
+            }
+            self.restore_current_draft();
+            self.app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
+                history_cell::new_warning_event(format!(
+                    "Input request timed out; submitted without the invalid answers to: {headers}"
+                )),
+            )));
+        }
+        self.submit_answers();
+    }
+
     fn handle_paste(&mut self, pasted: String) -> bool {
         if pasted.is_empty() {
             return false;
@@ -1458,8 +1673,10 @@ mod tests {
     use crate::bottom_pane::selection_popup_common::assert_popup_surface_bg;
     use crate::bottom_pane::selection_popup_common::menu_surface_inset;
     use crate::render::renderable::Renderable;
+    use codex_protocol::request_user_input::RequestUserInputCondition;
     use codex_protocol::request_user_input::RequestUserInputQuestion;
     use codex_protocol::request_user_input::RequestUserInputQuestionOption;
+    use codex_protocol::request_user_input::RequestUserInputValidation;
     use pretty_assertions::assert_eq;
     use ratatui::buffer::Buffer;
     use ratatui::layout::Rect;
@@ -1508,6 +1725,7 @@ mod tests {
                     description: "Third choice.".to_string(),
                 },
             ]),
+            ..Default::default()
         }
     }
 
@@ -1532,6 +1750,7 @@ mod tests {
                     description: "Third choice.".to_string(),
                 },
             ]),
+            ..Default::default()
         }
     }
 
@@ -1581,6 +1800,7 @@ mod tests {
                             .to_string(),
                 },
             ]),
+            ..Default::default()
         }
     }
 
@@ -1601,6 +1821,7 @@ mod tests {
                     description: "Simpler labels with less detail for quick rollouts.".to_string(),
                 },
             ]),
+            ..Default::default()
         }
     }
 
@@ -1632,6 +1853,7 @@ mod tests {
                         "Use this only if the previous long-form options do not apply.".to_string(),
                 },
             ]),
+            ..Default::default()
         }
     }
 
@@ -1643,6 +1865,7 @@ mod tests {
             is_other: false,
             is_secret: false,
             options: None,
+            ..Default::default()
         }
     }
 
@@ -1654,6 +1877,7 @@ mod tests {
             call_id: "call-1".to_string(),
             turn_id: turn_id.to_string(),
             questions,
+            timeout_sec: None,
         }
     }
 
@@ -1701,6 +1925,71 @@ mod tests {
         assert_eq!(overlay.request.turn_id, "turn-3");
     }
 
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn timeout_tick_submits_current_answers() {
+        let (tx, mut rx) = test_sender();
+        let mut with_default = question_with_options("q1", "Pick one");
+        with_default.default_option_index = Some(2);
+        let mut request = request_event("turn-1", vec![with_default]);
+        request.timeout_sec = Some(600);
+        let mut overlay = RequestUserInputOverlay::new(request, tx, true, false, false);
+
+        // Paused time auto-advances to the timer, so this returns immediately.
+        let Some(AppEvent::RequestUserInputTimeout { call_id }) = rx.recv().await else {
+            panic!("expected RequestUserInputTimeout");
+        };
+        assert_eq!(call_id, "call-1");
+
+        overlay.on_user_input_timeout("other-call");
+        assert!(
+            rx.try_recv().is_err(),
+            "unexpected AppEvent for another call"
+        );
+        assert!(!overlay.is_complete());
+
+        overlay.on_user_input_timeout(&call_id);
+        let event = rx.try_recv().expect("expected AppEvent");
+        let AppEvent::CodexOp(Op::UserInputAnswer { id, response }) = event else {
+            panic!("expected UserInputAnswer");
+        };
+        assert_eq!(id, "call-1");
+        let answer = response.answers.get("q1").expect("answer missing");
+        assert_eq!(answer.answers, vec!["Option 3".to_string()]);
+        assert!(overlay.is_complete());
+    }
+
+    #[test]
+    fn timeout_drops_invalid_answers_and_reports_them() {
+        let (tx, mut rx) = test_sender();
+        let mut numeric = question_without_options("q1", "Count");
+        numeric.validation = Some(RequestUserInputValidation {
+            pattern: Some(r"^\d+$".to_string()),
+            ..Default::default()
+        });
+        let mut overlay = RequestUserInputOverlay::new(
+            request_event("turn-1", vec![numeric]),
+            tx,
+            true,
+            false,
+            false,
+        );
+        for ch in "abc".chars() {
+            overlay.handle_key_event(KeyEvent::from(KeyCode::Char(ch)));
+        }
+
+        overlay.on_user_input_timeout("call-1");
+
+        let Ok(AppEvent::InsertHistoryCell(_)) = rx.try_recv() else {
+            panic!("expected a warning history cell");
+        };
+        let Ok(AppEvent::CodexOp(Op::UserInputAnswer { response, .. })) = rx.try_recv() else {
+            panic!("expected UserInputAnswer");
+        };
+        let answer = response.answers.get("q1").expect("answer missing");
+        assert_eq!(answer.answers, Vec::<String>::new());
+        assert!(overlay.is_complete());
+    }
+
     #[test]
     fn interrupt_discards_queued_requests_and_emits_interrupt() {
         let (tx, mut rx) = test_sender();
@@ -1715,11 +2004,13 @@ mod tests {
             call_id: "call-2".to_string(),
             turn_id: "turn-2".to_string(),
             questions: vec![question_with_options("q2", "Second")],
+            timeout_sec: None,
         });
         overlay.try_consume_user_input_request(RequestUserInputEvent {
             call_id: "call-3".to_string(),
             turn_id: "turn-3".to_string(),
             questions: vec![question_with_options("q3", "Third")],
+            timeout_sec: None,
         });
 
         overlay.handle_key_event(KeyEvent::from(KeyCode::Esc));
@@ -1849,6 +2140,142 @@ mod tests {
         assert_eq!(answer.answers, vec!["Option 2".to_string()]);
     }
 
+    #[test]
+    fn show_if_reveals_and_hides_dependent_question() {
+        let (tx, _rx) = test_sender();
+        let mut dependent = question_with_options("q2", "Follow-up");
+        dependent.show_if = Some(RequestUserInputCondition {
+            question_id: "q1".to_string(),
+            equals: "Option 1".to_string(),
+        });
+        let mut overlay = RequestUserInputOverlay::new(
+            request_event(
+                "turn-1",
+                vec![
+                    question_with_options("q1", "Pick one"),
+                    dependent,
+                    question_with_options("q3", "Last"),
+                ],
+            ),
+            tx,
+            true,
+            false,
+            false,
+        );
+
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Char('1')));
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(overlay.current_index(), 1);
+        assert_eq!(overlay.unanswered_count(), 2);
+
+        overlay.jump_to_question(0);
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Char('2')));
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(overlay.current_index(), 2);
+        assert_eq!(overlay.unanswered_count(), 1);
+
+        overlay.move_question(false);
+        assert_eq!(overlay.current_index(), 0);
+
+        let answers = overlay.collect_answers();
+        assert!(!answers.contains_key("q2"));
+        assert_eq!(
+            answers.get("q1").map(|answer| answer.answers.clone()),
+            Some(vec!["Option 2".to_string()])
+        );
+    }
+
+    #[test]
+    fn initial_question_skips_hidden_questions() {
+        let (tx, _rx) = test_sender();
+        let mut hidden = question_with_options("q1", "Follow-up");
+        hidden.show_if = Some(RequestUserInputCondition {
+            question_id: "q2".to_string(),
+            equals: "Option 1".to_string(),
+        });
+        let overlay = RequestUserInputOverlay::new(
+            request_event(
+                "turn-1",
+                vec![hidden, question_with_options("q2", "Pick one")],
+            ),
+            tx,
+            true,
+            false,
+            false,
+        );
+
+        assert_eq!(overlay.current_index(), 1);
+    }
+
+    #[test]
+    fn progress_label_shows_position_total_and_answered() {
+        assert_eq!(
+            render::progress_label(3, 7, 2),
+            "Question 3 of 7 · 2 answered"
+        );
+        assert_eq!(
+            render::progress_label(1, 1, 0),
+            "Question 1 of 1 · 0 answered"
+        );
+    }
+
+    #[test]
+    fn default_option_counts_as_answered_without_input() {
+        let (tx, mut rx) = test_sender();
+        let mut with_default = question_with_options("q1", "Pick one");
+        with_default.default_option_index = Some(1);
+        let mut overlay = RequestUserInputOverlay::new(
+            request_event(
+                "turn-1",
+                vec![with_default, question_with_options("q2", "No default")],
+            ),
+            tx,
+            true,
+            false,
+            false,
+        );
+
+        assert!(overlay.is_question_answered(0));
+        assert!(!overlay.is_question_answered(1));
+        assert_eq!(overlay.unanswered_count(), 1);
+        assert_eq!(overlay.selected_option_index(), Some(1));
+
+        overlay.submit_answers();
+        let event = rx.try_recv().expect("expected AppEvent");
+        let AppEvent::CodexOp(Op::UserInputAnswer { response, .. }) = event else {
+            panic!("expected UserInputAnswer");
+        };
+        let answer = response.answers.get("q1").expect("answer missing");
+        assert_eq!(answer.answers, vec!["Option 2".to_string()]);
+    }
+
+    #[test]
+    fn question_body_renders_inline_code_as_markdown() {
+        let (tx, _rx) = test_sender();
+        let mut question = question_without_options("q1", "Command");
+        question.question = "Run `cargo test` before continuing.".to_string();
+        let overlay = RequestUserInputOverlay::new(
+            request_event("turn-1", vec![question]),
+            tx,
+            true,
+            false,
+            false,
+        );
+
+        let lines = overlay.wrapped_question_lines(80);
+        let code_span = lines
+            .iter()
+            .flat_map(|line| line.spans.iter())
+            .find(|span| span.content == "cargo test")
+            .expect("inline code span");
+        assert_ne!(code_span.style, ratatui::style::Style::default());
+        assert!(
+            !lines
+                .iter()
+                .any(|line| { line.spans.iter().any(|span| span.content.contains('`')) })
+        );
+    }
+
     #[test]
     fn selecting_new_option_replaces_previous_selection() {
         let (tx, mut rx) = test_sender();
@@ -1969,6 +2396,151 @@ mod tests {
         assert_eq!(overlay.composer.current_text_with_pending(), "h i");
     }
 
+    #[test]
+    fn ctrl_u_clears_freeform_notes_and_uncommits_answer() {
+        let (tx, _rx) = test_sender();
+        let mut overlay = RequestUserInputOverlay::new(
+            request_event("turn-1", vec![question_without_options("q1", "Notes")]),
+            tx,
+            true,
+            false,
+            false,
+        );
+
+        for ch in "first line".chars() {
+            overlay.handle_key_event(KeyEvent::from(KeyCode::Char(ch)));
+        }
+        overlay.save_current_draft();
+        overlay.commit_current_question();
+        assert!(overlay.answers[0].answer_committed);
+
+        overlay.handle_key_event(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+
+        assert_eq!(overlay.composer.current_text_with_pending(), "");
+        assert!(!overlay.is_question_answered(0));
+        assert!(!overlay.answers[0].answer_committed);
+        assert_eq!(overlay.unanswered_count(), 1);
+    }
+
+    #[test]
+    fn validation_pattern_mismatch_blocks_advance() {
+        let (tx, _rx) = test_sender();
+        let mut numeric = question_without_options("q1", "Count");
+        numeric.validation = Some(RequestUserInputValidation {
+            pattern: Some(r"^\d+$".to_string()),
+            ..Default::default()
+        });
+        let mut overlay = RequestUserInputOverlay::new(
+            request_event(
+                "turn-1",
+                vec![numeric, question_without_options("q2", "Details")],
+            ),
+            tx,
+            true,
+            false,
+            false,
+        );
+
+        for ch in "abc".chars() {
+            overlay.handle_key_event(KeyEvent::from(KeyCode::Char(ch)));
+        }
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Enter));
+
+        assert_eq!(overlay.current_index(), 0);
+        assert_eq!(
+            overlay.validation_error.as_deref(),
+            Some(r"Answer must match `^\d+$`.")
+        );
+        assert!(!overlay.answers[0].answer_committed);
+        assert_eq!(
+            overlay.footer_tips().first().map(|tip| tip.text.clone()),
+            Some(r"Answer must match `^\d+$`.".to_string())
+        );
+    }
+
+    #[test]
+    fn validation_passing_answer_advances() {
+        let (tx, _rx) = test_sender();
+        let mut numeric = question_without_options("q1", "Count");
+        numeric.validation = Some(RequestUserInputValidation {
+            pattern: Some(r"^\d+$".to_string()),
+            max_len: Some(3),
+            error_message: Some("Enter a number.".to_string()),
+            ..Default::default()
+        });
+        let mut overlay = RequestUserInputOverlay::new(
+            request_event(
+                "turn-1",
+                vec![numeric, question_without_options("q2", "Details")],
+            ),
+            tx,
+            true,
+            false,
+            false,
+        );
+
+        for ch in "1234".chars() {
+            overlay.handle_key_event(KeyEvent::from(KeyCode::Char(ch)));
+        }
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(overlay.current_index(), 0);
+        assert_eq!(overlay.validation_error.as_deref(), Some("Enter a number."));
+
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Backspace));
+        assert_eq!(overlay.validation_error, None);
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Enter));
+
+        assert_eq!(overlay.current_index(), 1);
+        assert_eq!(overlay.validation_error, None);
+        assert!(overlay.answers[0].answer_committed);
+    }
+
+    #[test]
+    fn submit_jumps_back_to_first_invalid_answer() {
+        let (tx, _rx) = test_sender();
+        let mut numeric = question_without_options("q1", "Count");
+        numeric.validation = Some(RequestUserInputValidation {
+            pattern: Some(r"^\d+$".to_string()),
+            ..Default::default()
+        });
+        let mut overlay = RequestUserInputOverlay::new(
+            request_event(
+                "turn-1",
+                vec![numeric, question_without_options("q2", "Details")],
+            ),
+            tx,
+            true,
+            false,
+            false,
+        );
+
+        for ch in "abc".chars() {
+            overlay.handle_key_event(KeyEvent::from(KeyCode::Char(ch)));
+        }
+        overlay.move_question(true);
+        assert_eq!(overlay.current_index(), 1);
+        assert_eq!(overlay.validation_error, None);
+
+        overlay.move_question(false);
+        assert_eq!(
+            overlay.validation_error.as_deref(),
+            Some(r"Answer must match `^\d+$`.")
+        );
+
+        overlay.move_question(true);
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Char('x')));
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Enter));
+
+        assert_eq!(overlay.current_index(), 0);
+        assert!(!overlay.confirm_review_active());
+        assert!(!overlay.confirm_unanswered_active());
+        assert_eq!(
+            overlay.validation_error.as_deref(),
+            Some(r"Answer must match `^\d+$`.")
+        );
+        assert!(!overlay.is_complete());
+    }
+
     #[test]
     fn h_l_move_between_questions_in_options() {
         let (tx, _rx) = test_sender();
@@ -3072,6 +3644,7 @@ mod tests {
                             description: "Finalize and open a PR.".to_string(),
                         },
                     ]),
+                    ..Default::default()
                 }],
             ),
             tx,
@@ -3124,6 +3697,7 @@ mod tests {
                             description: "Finalize and open a PR.".to_string(),
                         },
                     ]),
+                    ..Default::default()
                 }],
             ),
             tx,
@@ -448,20 +448,21 @@ impl RequestUserInputOverlay {
         }
         let sections = self.layout_sections(content_area);
         let notes_visible = self.notes_ui_visible();
-        let unanswered = self.unanswered_count();
 
         // Progress header keeps the user oriented across multiple questions.
-        let progress_line = if self.question_count() > 0 {
-            let idx = self.current_index() + 1;
-            let total = self.question_count();
-            let base = format!("Question {idx}/{total}");
-            if unanswered > 0 {
-                Line::from(format!("{base} ({unanswered} unanswered)").dim())
-            } else {
-                Line::from(base.dim())
-            }
-        } else {
+        // Questions hidden by `show_if` are left out of both counts.
+        let visible = (0..self.question_count())
+            .filter(|idx| self.is_question_visible(*idx))
+            .collect::<Vec<_>>();
+        let progress_line = if visible.is_empty() {
             Line::from("No questions".dim())
+        } else {
+            let position = visible
+                .iter()
+                .position(|idx| *idx == self.current_index())
+                .map_or(1, |position| position + 1);
+            let answered = visible.len().saturating_sub(self.unanswered_count());
+            Line::from(progress_label(position, visible.len(), answered).dim())
         };
         Paragraph::new(progress_line).render(sections.progress_area, buf);
 
@@ -475,9 +476,9 @@ impl RequestUserInputOverlay {
                 break;
             }
             let question_line = if answered {
-                Line::from(line.clone())
+                line.clone()
             } else {
-                Line::from(line.clone()).style(crate::theme::accent_style())
+                line.clone().patch_style(crate::theme::accent_style())
             };
             Paragraph::new(question_line).render(
                 Rect {
@@ -613,6 +614,11 @@ impl RequestUserInputOverlay {
     }
 }
 
+/// Progress header text, e.g. "Question 3 of 7 · 2 answered".
+pub(super) fn progress_label(position: usize, total: usize, answered: usize) -> String {
+    format!("Question {position} of {total} · {answered} answered")
+}
+
 fn line_width(line: &Line<'_>) -> usize {
     line.iter()
         .map(|span| UnicodeWidthStr::width(span.content.as_ref()))
@@ -128,6 +128,14 @@ const THEME_SUBCOMMANDS: &[SubcommandNode] = &[
         insert_trailing_space: false,
         children: &[],
     },
+    SubcommandNode {
+        token: "refresh",
+        full_name: "theme refresh",
+        description: "re-detect the terminal background and re-apply the theme",
+        run_on_enter: true,
+        insert_trailing_space: false,
+        children: &[],
+    },
 ];
 
 const MCP_RETRY_CHILDREN: &[SubcommandNode] = &[SubcommandNode {
@@ -395,6 +403,13 @@ fn plugin_subcommand_list_hint(root: &PluginSubcommandRoot) -> Option<String> {
         .map(|node| node.token)
         .collect::<Vec<_>>();
     if let Some(order) = root.list_hint_order {
+        debug_assert!(
+            order
+                .iter()
+                .all(|entry| root.children.iter().any(|node| node.token == entry.token)),
+            "list_hint_order for /{} references unknown subcommand tokens",
+            root.root
+        );
         children.sort_by_key(|token| {
             order
                 .iter()
@@ -13,8 +13,8 @@ use ratatui::widgets::Block;
 use ratatui::widgets::Paragraph;
 use ratatui::widgets::Widget;
 
-use codex_common::fuzzy_match::fuzzy_match;
 use codex_core::config::types::XtremeMode;
+use codex_utils_fuzzy_match::fuzzy_match;
 
 use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
@@ -51,6 +51,7 @@ pub(crate) struct StatusMenuView {
     selected_settings_row: usize,
     selected_tools_row: usize,
     tools_search_query: String,
+    confirm_bulk_disable: bool,
     app_event_tx: AppEventSender,
     status_cell: Box<dyn HistoryCell>,
 }
@@ -92,6 +93,7 @@ impl StatusMenuView {
             selected_settings_row: 0,
             selected_tools_row: 0,
             tools_search_query: String::new(),
+            confirm_bulk_disable: false,
             app_event_tx,
             status_cell,
         }
@@ -155,12 +157,8 @@ impl StatusMenuView {
     }
 
     fn tools_row_count(&self) -> usize {
-        if !self.tools_search_query.is_empty() {
-            let actions = tools_quick_actions(codex_core::config::is_xcodex_invocation());
-            return self.filtered_quick_action_indices(&actions).len();
-        }
-
-        2 + tools_quick_actions(codex_core::config::is_xcodex_invocation()).len()
+        let actions = tools_quick_actions(codex_core::config::is_xcodex_invocation());
+        self.filtered_quick_action_indices(&actions).len()
     }
 
     fn clamp_selected_row(&mut self) {
@@ -351,16 +349,36 @@ impl StatusMenuView {
         }
 
         lines.push(Line::from(""));
-        lines.push(
-            vec![
-                Span::from("Tip: ").set_style(crate::theme::dim_style()),
-                Span::from("Tab")
-                    .set_style(crate::theme::accent_style().add_modifier(Modifier::BOLD)),
-                Span::from(" ").set_style(crate::theme::dim_style()),
-                Span::from("to ⚡Tools.").set_style(crate::theme::dim_style()),
-            ]
-            .into(),
-        );
+        let key_style = crate::theme::accent_style().add_modifier(Modifier::BOLD);
+        if self.confirm_bulk_disable
+            && let Some(section) = self.focused_settings_section()
+        {
+            lines.push(
+                vec![
+                    Span::from(format!("Disable all {} settings? Press ", section.label()))
+                        .set_style(crate::theme::warning_style()),
+                    Span::from("d").set_style(key_style),
+                    Span::from(" again to confirm, ").set_style(crate::theme::warning_style()),
+                    Span::from("Esc").set_style(key_style),
+                    Span::from(" to cancel.").set_style(crate::theme::warning_style()),
+                ]
+                .into(),
+            );
+        } else {
+            lines.push(
+                vec![
+                    Span::from("Tip: ").set_style(crate::theme::dim_style()),
+                    Span::from("Tab").set_style(key_style),
+                    Span::from(" to ⚡Tools, ").set_style(crate::theme::dim_style()),
+                    Span::from("a").set_style(key_style),
+                    Span::from("/").set_style(crate::theme::dim_style()),
+                    Span::from("d").set_style(key_style),
+                    Span::from(" to enable/disable this section.")
+                        .set_style(crate::theme::dim_style()),
+                ]
+                .into(),
+            );
+        }
 
         lines
     }
@@ -393,85 +411,44 @@ impl StatusMenuView {
 
         let actions = tools_quick_actions(codex_core::config::is_xcodex_invocation());
         let filtered = self.filtered_quick_action_indices(&actions);
-
-        if self.tools_search_query.is_empty() {
-            // Row 0: toggle xtreme mode.
-            {
-                let selected = self.selected_tools_row == 0;
-                lines.push(
-                    vec![
-                        selected_prefix(selected),
-                        checkbox(self.xtreme_ui_enabled),
-                        "Xtreme mode ".into(),
-                        Span::from(format!("({xtreme_mode_label})"))
-                            .set_style(crate::theme::dim_style()),
-                    ]
-                    .into(),
-                );
-            }
-
-            // Row 1: toggle verbose tool output.
-            {
-                let selected = self.selected_tools_row == 1;
-                lines.push(
-                    vec![
-                        selected_prefix(selected),
-                        checkbox(self.verbose_tool_output),
-                        "Verbose tool output".into(),
-                    ]
-                    .into(),
-                );
-            }
-        }
-
-        let base_row = if self.tools_search_query.is_empty() {
-            2
-        } else {
-            0
-        };
-        for (visible_idx, action_idx) in filtered.iter().enumerate() {
-            let label = actions
-                .get(*action_idx)
-                .map(|item| item.label)
-                .unwrap_or("Unknown");
-            let row = base_row + visible_idx;
+        for (row, action_idx) in filtered.iter().enumerate() {
+            let Some(item) = actions.get(*action_idx) else {
+                continue;
+            };
             let selected = self.selected_tools_row == row;
-            lines.push(vec![selected_prefix(selected), label.into()].into());
+            let line = match item.action {
+                ToolsAction::ToggleXtremeMode => vec![
+                    selected_prefix(selected),
+                    checkbox(self.xtreme_ui_enabled),
+                    format!("{} ", item.label).into(),
+                    Span::from(format!("({xtreme_mode_label})"))
+                        .set_style(crate::theme::dim_style()),
+                ],
+                ToolsAction::ToggleVerboseToolOutput => vec![
+                    selected_prefix(selected),
+                    checkbox(self.verbose_tool_output),
+                    item.label.into(),
+                ],
+                _ => vec![selected_prefix(selected), item.label.into()],
+            };
+            lines.push(line.into());
         }
 
         lines
     }
 
     fn selected_tool_hint_lines(&self) -> Vec<Line<'static>> {
-        let ramps_supported = codex_core::config::is_xcodex_invocation();
-        let actions = tools_quick_actions(ramps_supported);
+        let actions = tools_quick_actions(codex_core::config::is_xcodex_invocation());
         let filtered = self.filtered_quick_action_indices(&actions);
-        if self.tools_search_query.is_empty() {
-            let hint = match (ramps_supported, self.selected_tools_row) {
-                (true, 0) | (false, 0) => "Toggle xtreme UI styling (persists).",
-                (true, 1) | (false, 1) => {
-                    "Toggle verbose tool output in the transcript (persists)."
-                }
-                (true, 2) | (false, 2) => "Review your changes and spot issues fast.",
-                (true, 3) | (false, 3) => "Pick a model and reasoning effort.",
-                (true, 4) | (false, 4) => "Review and adjust approval/sandbox presets.",
-                (true, 5) => "Customize xcodex’s per-turn ramp rotation.",
-                (true, 6) | (false, 5) => "Switch worktrees and manage shared dirs.",
-                (true, 7) | (false, 6) => "Review and update exclusions for this session.",
-                (true, 8) | (false, 7) => "Automate xcodex with hooks.",
-                (true, 9) | (false, 8) => "Open the full transcript in a scrollable view.",
-                (true, 10) | (false, 9) => "Pick a previous session to continue.",
-                _ => return Vec::new(),
-            };
-            return vec![vec![Span::from(hint).set_style(crate::theme::dim_style())].into()];
-        }
-
-        let selected_idx = self.selected_tools_row;
-        let action_idx = filtered.get(selected_idx).copied();
-        let hint = action_idx
-            .and_then(|idx| actions.get(idx))
-            .map(|item| item.hint)
-            .unwrap_or("Type to search tools.");
+        let hint = filtered
+            .get(self.selected_tools_row)
+            .and_then(|idx| actions.get(*idx))
+            .map(|item| item.hint);
+        let hint = match hint {
+            Some(hint) => hint,
+            None if self.tools_search_query.is_empty() => return Vec::new(),
+            None => "Type to search tools.",
+        };
         vec![vec![Span::from(hint).set_style(crate::theme::dim_style())].into()]
     }
 
@@ -500,18 +477,59 @@ impl StatusMenuView {
                     .map(|(_indices, score)| (idx, score))
             })
             .collect();
-        matches.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        matches.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
         matches.into_iter().map(|(idx, _)| idx).collect()
     }
 
-    fn toggle_selected_search(&mut self) {
+    fn selected_tools_action_idx(&self) -> Option<usize> {
         let actions = tools_quick_actions(codex_core::config::is_xcodex_invocation());
-        let filtered = self.filtered_quick_action_indices(&actions);
-        let selected = filtered.get(self.selected_tools_row).copied();
-        let Some(action) = selected.and_then(|idx| actions.get(idx).map(|item| item.action)) else {
+        self.filtered_quick_action_indices(&actions)
+            .get(self.selected_tools_row)
+            .copied()
+    }
+
+    /// Applies a search edit. Typing jumps to the best match; clearing the
+    /// filter puts the selection back on the row that was selected.
+    fn edit_tools_search(&mut self, edit: impl FnOnce(&mut String)) {
+        let selected = self.selected_tools_action_idx();
+        edit(&mut self.tools_search_query);
+        self.selected_tools_row = match selected {
+            Some(idx) if self.tools_search_query.is_empty() => idx,
+            _ => 0,
+        };
+        self.clamp_selected_row();
+    }
+
+    fn run_selected_tools_action(&mut self) {
+        let actions = tools_quick_actions(codex_core::config::is_xcodex_invocation());
+        let Some(action) = self
+            .selected_tools_action_idx()
+            .and_then(|idx| actions.get(idx).map(|item| item.action))
+        else {
             return;
         };
         match action {
+            ToolsAction::ToggleXtremeMode => {
+                self.xtreme_ui_enabled = !self.xtreme_ui_enabled;
+                self.xtreme_mode = if self.xtreme_ui_enabled {
+                    XtremeMode::On
+                } else {
+                    XtremeMode::Off
+                };
+                self.app_event_tx
+                    .send(AppEvent::UpdateXtremeMode(self.xtreme_mode));
+                self.app_event_tx
+                    .send(AppEvent::PersistXtremeMode(self.xtreme_mode));
+                return;
+            }
+            ToolsAction::ToggleVerboseToolOutput => {
+                self.verbose_tool_output = !self.verbose_tool_output;
+                self.app_event_tx
+                    .send(AppEvent::UpdateVerboseToolOutput(self.verbose_tool_output));
+                self.app_event_tx
+                    .send(AppEvent::PersistVerboseToolOutput(self.verbose_tool_output));
+                return;
+            }
             ToolsAction::Review => self.app_event_tx.send(AppEvent::DispatchSlashCommand(
                 crate::slash_command::SlashCommand::Review,
             )),
@@ -535,6 +553,95 @@ impl StatusMenuView {
         self.complete = true;
     }
 
+    fn settings_toggle_value(&self, toggle: SettingsToggle) -> bool {
+        match toggle {
+            SettingsToggle::StatusBarGitBranch => self.status_bar_show_git_branch,
+            SettingsToggle::StatusBarWorktree => self.status_bar_show_worktree,
+            SettingsToggle::TranscriptSyntaxHighlight => self.transcript_syntax_highlight,
+            SettingsToggle::TranscriptDiffHighlight => self.transcript_diff_highlight,
+            SettingsToggle::TranscriptSideBySide => self.transcript_side_by_side,
+            SettingsToggle::TranscriptUserPromptHighlight => self.transcript_user_prompt_highlight,
+            SettingsToggle::MinimalComposer => self.minimal_composer,
+        }
+    }
+
+    /// Section of the selected Settings row, or `None` when it is not a toggle.
+    fn focused_settings_section(&self) -> Option<SettingsSection> {
+        SettingsToggle::ALL
+            .get(self.selected_settings_row)
+            .map(|toggle| toggle.section())
+    }
+
+    /// Toggles in the focused section that would change if set to `enabled`.
+    fn bulk_settings_changes(&self, enabled: bool) -> Vec<SettingsToggle> {
+        let Some(section) = self.focused_settings_section() else {
+            return Vec::new();
+        };
+        SettingsToggle::ALL
+            .into_iter()
+            .filter(|toggle| {
+                toggle.section() == section && self.settings_toggle_value(*toggle) != enabled
+            })
+            .collect()
+    }
+
+    /// Applies `enabled` to the focused section's toggles and persists them in a single config
+    /// edit.
+    fn apply_bulk_settings(&mut self, enabled: bool) {
+        let changes = self.bulk_settings_changes(enabled);
+        if changes.is_empty() {
+            return;
+        }
+        for toggle in &changes {
+            match toggle {
+                SettingsToggle::StatusBarGitBranch => self.status_bar_show_git_branch = enabled,
+                SettingsToggle::StatusBarWorktree => self.status_bar_show_worktree = enabled,
+                SettingsToggle::TranscriptSyntaxHighlight => {
+                    self.transcript_syntax_highlight = enabled;
+                    self.app_event_tx
+                        .send(AppEvent::UpdateTranscriptSyntaxHighlight(enabled));
+                }
+                SettingsToggle::TranscriptDiffHighlight => {
+                    self.transcript_diff_highlight = enabled;
+                    self.app_event_tx
+                        .send(AppEvent::UpdateTranscriptDiffHighlight(enabled));
+                }
+                SettingsToggle::TranscriptSideBySide => {
+                    self.transcript_side_by_side = enabled;
+                    self.app_event_tx
+                        .send(AppEvent::UpdateTranscriptSideBySide(enabled));
+                }
+                SettingsToggle::TranscriptUserPromptHighlight => {
+                    self.transcript_user_prompt_highlight = enabled;
+                    self.app_event_tx
+                        .send(AppEvent::UpdateTranscriptUserPromptHighlight(enabled));
+                }
+                SettingsToggle::MinimalComposer => {
+                    self.minimal_composer = enabled;
+                    self.app_event_tx
+                        .send(AppEvent::UpdateMinimalComposer(enabled));
+                }
+            }
+        }
+        if changes.iter().any(|toggle| {
+            matches!(
+                toggle,
+                SettingsToggle::StatusBarGitBranch | SettingsToggle::StatusBarWorktree
+            )
+        }) {
+            self.app_event_tx.send(AppEvent::UpdateStatusBarGitOptions {
+                show_git_branch: self.status_bar_show_git_branch,
+                show_worktree: self.status_bar_show_worktree,
+            });
+        }
+        self.app_event_tx.send(AppEvent::PersistTuiToggles(
+            changes
+                .into_iter()
+                .map(|toggle| (toggle.config_key(), enabled))
+                .collect(),
+        ));
+    }
+
     fn switch_tab(&mut self) {
         self.tab = match self.tab {
             StatusMenuTab::Status => StatusMenuTab::Settings,
@@ -676,71 +783,7 @@ impl StatusMenuView {
                 }
                 _ => {}
             },
-            StatusMenuTab::Tools => {
-                if !self.tools_search_query.is_empty() {
-                    self.toggle_selected_search();
-                    return;
-                }
-                let ramps_supported = codex_core::config::is_xcodex_invocation();
-                let ramps_row = ramps_supported.then_some(5);
-                let worktrees_row = if ramps_supported { 6 } else { 5 };
-                let exclusions_row = if ramps_supported { 7 } else { 6 };
-                let hooks_row = if ramps_supported { 8 } else { 7 };
-                let transcript_row = if ramps_supported { 9 } else { 8 };
-                let resume_row = if ramps_supported { 10 } else { 9 };
-
-                match self.selected_tools_row {
-                    0 => {
-                        self.xtreme_ui_enabled = !self.xtreme_ui_enabled;
-                        self.xtreme_mode = if self.xtreme_ui_enabled {
-                            XtremeMode::On
-                        } else {
-                            XtremeMode::Off
-                        };
-                        self.app_event_tx
-                            .send(AppEvent::UpdateXtremeMode(self.xtreme_mode));
-                        self.app_event_tx
-                            .send(AppEvent::PersistXtremeMode(self.xtreme_mode));
-                    }
-                    1 => {
-                        self.verbose_tool_output = !self.verbose_tool_output;
-                        self.app_event_tx
-                            .send(AppEvent::UpdateVerboseToolOutput(self.verbose_tool_output));
-                        self.app_event_tx
-                            .send(AppEvent::PersistVerboseToolOutput(self.verbose_tool_output));
-                    }
-                    2 => self.app_event_tx.send(AppEvent::DispatchSlashCommand(
-                        crate::slash_command::SlashCommand::Review,
-                    )),
-                    3 => self.app_event_tx.send(AppEvent::DispatchSlashCommand(
-                        crate::slash_command::SlashCommand::Model,
-                    )),
-                    4 => self.app_event_tx.send(AppEvent::OpenApprovalsPopup),
-                    row if ramps_row.is_some_and(|r| row == r) => {
-                        self.app_event_tx.send(AppEvent::OpenRampsSettingsView);
-                    }
-                    row if row == worktrees_row => self
-                        .app_event_tx
-                        .send(AppEvent::WorktreeDetect { open_picker: true }),
-                    row if row == exclusions_row => {
-                        self.app_event_tx.send(AppEvent::DispatchSlashCommand(
-                            crate::slash_command::SlashCommand::Exclusions,
-                        ))
-                    }
-                    row if row == hooks_row => self.app_event_tx.send(
-                        AppEvent::DispatchSlashCommand(crate::slash_command::SlashCommand::Hooks),
-                    ),
-                    row if row == transcript_row => {
-                        self.app_event_tx.send(AppEvent::OpenTranscriptOverlay)
-                    }
-                    row if row == resume_row => self.app_event_tx.send(AppEvent::OpenResumePicker),
-                    _ => {}
-                }
-
-                if self.selected_tools_row >= 2 {
-                    self.complete = true;
-                }
-            }
+            StatusMenuTab::Tools => self.run_selected_tools_action(),
             StatusMenuTab::Status => {}
         }
     }
@@ -748,24 +791,43 @@ impl StatusMenuView {
 
 impl BottomPaneView for StatusMenuView {
     fn handle_key_event(&mut self, key_event: KeyEvent) {
+        // Any key other than a second `d` cancels a pending bulk disable.
+        let confirming_bulk_disable = std::mem::take(&mut self.confirm_bulk_disable);
         match key_event {
+            KeyEvent {
+                code: KeyCode::Char('a'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } if matches!(self.tab, StatusMenuTab::Settings) => self.apply_bulk_settings(true),
+            KeyEvent {
+                code: KeyCode::Char('d'),
+                modifiers: KeyModifiers::NONE,
+                ..
+            } if matches!(self.tab, StatusMenuTab::Settings) => {
+                if confirming_bulk_disable {
+                    self.apply_bulk_settings(false);
+                } else {
+                    self.confirm_bulk_disable = !self.bulk_settings_changes(false).is_empty();
+                }
+            }
+            KeyEvent {
+                code: KeyCode::Esc, ..
+            } if confirming_bulk_disable => {}
             KeyEvent {
                 code: KeyCode::Backspace,
                 modifiers: KeyModifiers::NONE,
                 ..
             } if matches!(self.tab, StatusMenuTab::Tools) => {
-                self.tools_search_query.pop();
-                self.selected_tools_row = 0;
-                self.clamp_selected_row();
+                self.edit_tools_search(|query| {
+                    query.pop();
+                });
             }
             KeyEvent {
                 code: KeyCode::Char(ch),
                 modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
                 ..
             } if matches!(self.tab, StatusMenuTab::Tools) => {
-                self.tools_search_query.push(ch);
-                self.selected_tools_row = 0;
-                self.clamp_selected_row();
+                self.edit_tools_search(|query| query.push(ch));
             }
             KeyEvent {
                 code: KeyCode::Tab,
@@ -837,6 +899,13 @@ impl BottomPaneView for StatusMenuView {
                 modifiers: KeyModifiers::NONE,
                 ..
             } => self.toggle_selected(),
+            KeyEvent {
+                code: KeyCode::Esc, ..
+            } if matches!(self.tab, StatusMenuTab::Tools)
+                && !self.tools_search_query.is_empty() =>
+            {
+                self.edit_tools_search(String::clear);
+            }
             KeyEvent {
                 code: KeyCode::Esc, ..
             } => {
@@ -930,8 +999,78 @@ impl Renderable for StatusMenuView {
     }
 }
 
-#[derive(Clone, Copy)]
+/// Boolean `[tui]` settings shown as checkboxes on the Settings tab.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SettingsToggle {
+    StatusBarGitBranch,
+    StatusBarWorktree,
+    TranscriptSyntaxHighlight,
+    TranscriptDiffHighlight,
+    TranscriptSideBySide,
+    TranscriptUserPromptHighlight,
+    MinimalComposer,
+}
+
+impl SettingsToggle {
+    const ALL: [SettingsToggle; 7] = [
+        SettingsToggle::StatusBarGitBranch,
+        SettingsToggle::StatusBarWorktree,
+        SettingsToggle::TranscriptSyntaxHighlight,
+        SettingsToggle::TranscriptDiffHighlight,
+        SettingsToggle::TranscriptSideBySide,
+        SettingsToggle::TranscriptUserPromptHighlight,
+        SettingsToggle::MinimalComposer,
+    ];
+
+    fn section(self) -> SettingsSection {
+        match self {
+            SettingsToggle::StatusBarGitBranch | SettingsToggle::StatusBarWorktree => {
+                SettingsSection::StatusBar
+            }
+            SettingsToggle::TranscriptSyntaxHighlight
+            | SettingsToggle::TranscriptDiffHighlight
+            | SettingsToggle::TranscriptSideBySide
+            | SettingsToggle::TranscriptUserPromptHighlight => SettingsSection::Transcript,
+            SettingsToggle::MinimalComposer => SettingsSection::Composer,
+        }
+    }
+
+    /// Key under `[tui]` in `config.toml`.
+    fn config_key(self) -> &'static str {
+        match self {
+            SettingsToggle::StatusBarGitBranch => "status_bar_show_git_branch",
+            SettingsToggle::StatusBarWorktree => "status_bar_show_worktree",
+            SettingsToggle::TranscriptSyntaxHighlight => "transcript_syntax_highlight",
+            SettingsToggle::TranscriptDiffHighlight => "transcript_diff_highlight",
+            SettingsToggle::TranscriptSideBySide => "transcript_side_by_side",
+            SettingsToggle::TranscriptUserPromptHighlight => "transcript_user_prompt_highlight",
+            SettingsToggle::MinimalComposer => "minimal_composer",
+        }
+    }
+}
+
+/// Group of Settings rows that the bulk enable/disable keys act on together.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SettingsSection {
+    StatusBar,
+    Transcript,
+    Composer,
+}
+
+impl SettingsSection {
+    fn label(self) -> &'static str {
+        match self {
+            SettingsSection::StatusBar => "status bar",
+            SettingsSection::Transcript => "transcript",
+            SettingsSection::Composer => "composer",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum ToolsAction {
+    ToggleXtremeMode,
+    ToggleVerboseToolOutput,
     Review,
     Model,
     Approvals,
@@ -952,6 +1091,16 @@ struct ToolsQuickAction {
 
 fn tools_quick_actions(ramps_supported: bool) -> Vec<ToolsQuickAction> {
     let mut items = vec![
+        ToolsQuickAction {
+            label: "Xtreme mode",
+            hint: "Toggle xtreme UI styling (persists).",
+            action: ToolsAction::ToggleXtremeMode,
+        },
+        ToolsQuickAction {
+            label: "Verbose tool output",
+            hint: "Toggle verbose tool output in the transcript (persists).",
+            action: ToolsAction::ToggleVerboseToolOutput,
+        },
         ToolsQuickAction {
             label: "Review…",
             hint: "Review your changes and spot issues fast.",
@@ -995,7 +1144,7 @@ fn tools_quick_actions(ramps_supported: bool) -> Vec<ToolsQuickAction> {
     ];
     if ramps_supported {
         items.insert(
-            3,
+            5,
             ToolsQuickAction {
                 label: "Ramps…",
                 hint: "Customize xcodex’s per-turn ramp rotation.",
@@ -1011,6 +1160,7 @@ mod tests {
     use super::*;
     use crate::app_event::AppEvent;
     use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
     use ratatui::layout::Rect;
     use tokio::sync::mpsc::unbounded_channel;
 
@@ -1150,4 +1300,162 @@ mod tests {
         ));
         assert!(rx.try_recv().is_err());
     }
+
+    fn tools_view() -> StatusMenuView {
+        let (tx_raw, _rx) = unbounded_channel::<AppEvent>();
+        let status_cell = Box::new(crate::history_cell::new_info_event(
+            "Status card".to_string(),
+            None,
+        ));
+        StatusMenuView::new(
+            StatusMenuTab::Tools,
+            AppEventSender::new(tx_raw),
+            status_cell,
+            true,
+            false,
+            true,
+            false,
+            true,
+            false,
+            false,
+            XtremeMode::On,
+            false,
+        )
+    }
+
+    fn type_query(view: &mut StatusMenuView, query: &str) {
+        for ch in query.chars() {
+            view.handle_key_event(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+    }
+
+    #[test]
+    fn tools_search_filters_rows_to_ranked_subset() {
+        let mut view = tools_view();
+        type_query(&mut view, "re");
+
+        let actions = tools_quick_actions(codex_core::config::is_xcodex_invocation());
+        let labels = view
+            .filtered_quick_action_indices(&actions)
+            .into_iter()
+            .map(|idx| actions[idx].label)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            vec![
+                "Review…",
+                "Resume…",
+                "Xtreme mode",
+                "Verbose tool output",
+                "Worktrees…",
+            ]
+        );
+    }
+
+    #[test]
+    fn tools_search_keeps_selection_when_cleared() {
+        let mut view = tools_view();
+        type_query(&mut view, "hoo");
+        let actions = tools_quick_actions(codex_core::config::is_xcodex_invocation());
+        let hooks_idx = actions
+            .iter()
+            .position(|item| item.action == ToolsAction::Hooks);
+        assert_eq!(view.selected_tools_action_idx(), hooks_idx);
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+
+        assert!(view.tools_search_query.is_empty());
+        assert!(!view.is_complete());
+        assert_eq!(view.selected_tools_action_idx(), hooks_idx);
+    }
+
+    #[test]
+    fn bulk_disable_only_touches_focused_section() {
+        let (tx_raw, mut rx) = unbounded_channel::<AppEvent>();
+        let status_cell = Box::new(crate::history_cell::new_info_event(
+            "Status card".to_string(),
+            None,
+        ));
+        let mut view = StatusMenuView::new(
+            StatusMenuTab::Settings,
+            AppEventSender::new(tx_raw),
+            status_cell,
+            true,
+            false,
+            true,
+            false,
+            true,
+            false,
+            false,
+            XtremeMode::On,
+            false,
+        );
+        view.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        view.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        assert_eq!(
+            view.focused_settings_section(),
+            Some(SettingsSection::Transcript)
+        );
+        assert_eq!(
+            view.bulk_settings_changes(false),
+            vec![
+                SettingsToggle::TranscriptSyntaxHighlight,
+                SettingsToggle::TranscriptSideBySide,
+            ]
+        );
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE));
+        assert!(rx.try_recv().is_err());
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE));
+
+        let persisted = std::iter::from_fn(|| rx.try_recv().ok())
+            .filter_map(|event| match event {
+                AppEvent::PersistTuiToggles(edits) => Some(edits),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            persisted,
+            vec![vec![
+                ("transcript_syntax_highlight", false),
+                ("transcript_side_by_side", false),
+            ]]
+        );
+        assert_eq!(view.bulk_settings_changes(false), Vec::new());
+        assert!(view.status_bar_show_git_branch);
+    }
+
+    #[test]
+    fn bulk_toggles_ignore_non_toggle_rows() {
+        let mut view = tools_view();
+        view.switch_tab();
+        view.switch_tab();
+        for _ in 0..SettingsToggle::ALL.len() {
+            view.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        }
+        assert_eq!(view.focused_settings_section(), None);
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE));
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE));
+
+        assert!(!view.status_bar_show_worktree);
+        assert!(view.status_bar_show_git_branch);
+        assert!(!view.confirm_bulk_disable);
+    }
+
+    #[test]
+    fn bulk_disable_is_cancelled_by_esc() {
+        let mut view = tools_view();
+        view.switch_tab();
+        view.switch_tab();
+        assert_eq!(view.tab, StatusMenuTab::Settings);
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE));
+        view.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE));
+
+        assert!(!view.is_complete());
+        assert!(view.status_bar_show_git_branch);
+        assert!(view.confirm_bulk_disable);
+    }
 }
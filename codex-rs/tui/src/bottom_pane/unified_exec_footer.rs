@@ -15,6 +15,7 @@ use crate::render::renderable::Renderable;
 
 /// Tracks active unified-exec processes and renders a compact summary.
 pub(crate) struct UnifiedExecFooter {
+    /// Event type of each running hook process.
     hooks: Vec<String>,
     processes: Vec<String>,
 }
@@ -66,7 +67,14 @@ impl UnifiedExecFooter {
         let hook_count = self.hooks.len();
         if hook_count > 0 {
             let plural = if hook_count == 1 { "" } else { "s" };
-            parts.push(format!("{hook_count} hook{plural} running"));
+            let mut event_types: Vec<&str> = Vec::new();
+            for event_type in &self.hooks {
+                if !event_types.contains(&event_type.as_str()) {
+                    event_types.push(event_type);
+                }
+            }
+            let event_types = event_types.join(", ");
+            parts.push(format!("{hook_count} hook{plural} running ({event_types})"));
         }
         if count > 0 {
             parts.push(String::from("/ps to view"));
@@ -145,11 +153,27 @@ mod tests {
     #[test]
     fn render_hooks_only() {
         let mut footer = UnifiedExecFooter::new();
-        footer.set_hooks(vec!["agent-turn-complete · hook.sh".to_string()]);
-        let width = 50;
+        footer.set_hooks(vec!["agent-turn-complete".to_string()]);
+        let width = 60;
         let height = footer.desired_height(width);
         let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
         footer.render(Rect::new(0, 0, width, height), &mut buf);
         assert_snapshot!("render_hooks_only", format!("{buf:?}"));
     }
+
+    #[test]
+    fn summary_lists_distinct_hook_event_types() {
+        let mut footer = UnifiedExecFooter::new();
+        footer.set_hooks(vec![
+            "tool-call-finished".to_string(),
+            "session-start".to_string(),
+            "tool-call-finished".to_string(),
+        ]);
+        assert_eq!(
+            footer.summary_text(),
+            Some(
+                "3 hooks running (tool-call-finished, session-start) · /hooks to view".to_string()
+            )
+        );
+    }
 }
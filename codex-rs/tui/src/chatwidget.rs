@@ -2264,7 +2264,7 @@ impl ChatWidget {
             .iter()
             .map(|process| process.command_display.clone())
             .collect();
-        let hooks = self.hook_processes.command_displays();
+        let hooks = self.hook_processes.event_types();
         self.bottom_pane.set_unified_exec_activity(processes, hooks);
     }
 
@@ -2273,12 +2273,22 @@ impl ChatWidget {
         let command_display = strip_bash_lc_and_escape(&ev.command);
         let event_type = ev.event_type;
         let command_display = format!("{event_type} · {command_display}");
-        self.hook_processes.begin(key, command_display);
+        let timeout = ev.timeout_ms.map(Duration::from_millis);
+        self.hook_processes
+            .begin(key, event_type, command_display, Instant::now(), timeout);
         self.sync_unified_exec_footer();
     }
 
     fn on_hook_process_end(&mut self, ev: HookProcessEndEvent) {
         let key = ev.hook_id.to_string();
+        if let Some(output) = ev.output.filter(|output| !output.trim().is_empty())
+            && let Some(command_display) = self.hook_processes.command_display(&key)
+        {
+            self.add_to_history(xcodex_plugins::history_cell::new_hook_output(
+                command_display,
+                &output,
+            ));
+        }
         if self.hook_processes.end(&key) {
             self.sync_unified_exec_footer();
         }
@@ -5412,7 +5422,7 @@ impl ChatWidget {
                 )
             })
             .collect();
-        let hooks = self.hook_processes.entries();
+        let hooks = self.hook_processes.entries(Instant::now());
         self.add_to_history(
             xcodex_plugins::history_cell::new_unified_exec_processes_output(processes, hooks),
         );
@@ -7338,6 +7348,14 @@ impl ChatWidget {
         )
     }
 
+    pub(crate) fn ramp_status(&self) -> &RampStatusController {
+        &self.ramp_status
+    }
+
+    pub(crate) fn set_pinned_ramp(&mut self, pinned: Option<crate::ramps::RampId>) {
+        self.ramp_status.set_pinned(pinned);
+    }
+
     pub(crate) fn ramp_status_enabled(&self) -> bool {
         xcodex_plugins::ramps::status_enabled(&self.ramp_status)
     }
@@ -7920,6 +7938,11 @@ impl ChatWidget {
             .map(str::to_string)
     }
 
+    /// Forward an elapsed request_user_input timeout to the bottom pane.
+    pub(crate) fn on_request_user_input_timeout(&mut self, call_id: &str) {
+        self.bottom_pane.on_user_input_timeout(call_id);
+    }
+
     /// Forward file-search results to the bottom pane.
     pub(crate) fn apply_file_search_result(&mut self, query: String, matches: Vec<FileMatch>) {
         self.bottom_pane.on_file_search_result(query, matches);
@@ -3516,6 +3516,7 @@ async fn ctrl_c_shutdown_requires_confirmation_when_hooks_running() {
         payload_event_id: uuid::Uuid::nil(),
         event_type: "agent-turn-complete".to_string(),
         command: vec!["echo".to_string(), "hook".to_string()],
+        timeout_ms: None,
     });
 
     chat.handle_key_event(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
@@ -5469,6 +5470,15 @@ async fn slash_theme_help_opens_help_view() {
     assert_matches!(rx.try_recv(), Ok(AppEvent::OpenThemeHelp));
 }
 
+#[tokio::test]
+async fn slash_theme_refresh_requests_theme_refresh() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.dispatch_command_with_args(SlashCommand::Theme, "refresh".to_string(), Vec::new());
+
+    assert_matches!(rx.try_recv(), Ok(AppEvent::RefreshTheme));
+}
+
 #[tokio::test]
 async fn slash_theme_template_writes_examples() {
     let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
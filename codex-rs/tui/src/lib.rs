@@ -44,6 +44,7 @@ use codex_utils_oss::get_default_model_for_oss_provider;
 use cwd_prompt::CwdPromptAction;
 use cwd_prompt::CwdSelection;
 use std::fs::OpenOptions;
+use std::io::IsTerminal;
 use std::path::Path;
 use std::path::PathBuf;
 use tracing::error;
@@ -131,34 +132,49 @@ pub use public_widgets::composer_input::ComposerAction;
 pub use public_widgets::composer_input::ComposerInput;
 // (tests access modules directly within the crate)
 
+/// Reads the `--file` prompt, taking it from stdin when `path` is `-`. CRLF/CR are normalized
+/// to LF so file contents can't leak `\r` into TUI state.
+fn read_prompt_file(
+    path: &Path,
+    read_stdin: impl FnOnce() -> std::io::Result<String>,
+) -> std::io::Result<String> {
+    if path.as_os_str() == "-" {
+        let prompt = read_stdin().map_err(|err| {
+            std::io::Error::other(format!("Failed to read prompt from stdin: {err}"))
+        })?;
+        if prompt.trim().is_empty() {
+            return Err(std::io::Error::other("No prompt provided via stdin."));
+        }
+        return Ok(prompt.replace("\r\n", "\n").replace('\r', "\n"));
+    }
+
+    let prompt = std::fs::read_to_string(path).map_err(|err| {
+        std::io::Error::other(format!(
+            "Failed to read prompt file {}: {err}",
+            path.display()
+        ))
+    })?;
+    if prompt.trim().is_empty() {
+        return Err(std::io::Error::other(format!(
+            "Prompt file {} is empty.",
+            path.display()
+        )));
+    }
+    Ok(prompt.replace("\r\n", "\n").replace('\r', "\n"))
+}
+
 pub async fn run_main(
     mut cli: Cli,
     codex_linux_sandbox_exe: Option<PathBuf>,
 ) -> std::io::Result<AppExitInfo> {
     if let Some(path) = cli.prompt_file.take() {
-        if path.as_os_str() == "-" {
-            return Err(std::io::Error::other(
-                "`--file -` is not supported in the interactive TUI. Pipe the prompt into `codex exec` instead.",
-            ));
-        }
-
-        match std::fs::read_to_string(&path) {
-            Ok(prompt) => {
-                if prompt.trim().is_empty() {
-                    return Err(std::io::Error::other(format!(
-                        "Prompt file {} is empty.",
-                        path.display()
-                    )));
-                }
-                cli.prompt = Some(prompt);
-            }
-            Err(err) => {
-                return Err(std::io::Error::other(format!(
-                    "Failed to read prompt file {}: {err}",
-                    path.display()
-                )));
+        cli.prompt = Some(read_prompt_file(&path, || {
+            let stdin = std::io::stdin();
+            if stdin.is_terminal() {
+                eprintln!("Reading prompt from stdin, end with Ctrl-D...");
             }
-        }
+            std::io::read_to_string(stdin)
+        })?);
     }
 
     let (sandbox_mode, approval_policy) = if cli.full_auto {
@@ -492,6 +508,7 @@ async fn run_ratatui_app(
                         token_usage: codex_core::protocol::TokenUsage::default(),
                         thread_id: None,
                         thread_name: None,
+                        resumable: false,
                         update_action: Some(action),
                         exit_reason: ExitReason::UserRequested,
                     });
@@ -553,6 +570,7 @@ async fn run_ratatui_app(
                 token_usage: codex_core::protocol::TokenUsage::default(),
                 thread_id: None,
                 thread_name: None,
+                resumable: false,
                 update_action: None,
                 exit_reason: ExitReason::UserRequested,
             });
@@ -593,6 +611,7 @@ async fn run_ratatui_app(
             token_usage: codex_core::protocol::TokenUsage::default(),
             thread_id: None,
             thread_name: None,
+            resumable: false,
             update_action: None,
             exit_reason: ExitReason::Fatal(format!(
                 "No saved session found with ID {id_str}. Run `xcodex {action}` without an ID to choose from existing sessions."
@@ -642,6 +661,7 @@ async fn run_ratatui_app(
                         token_usage: codex_core::protocol::TokenUsage::default(),
                         thread_id: None,
                         thread_name: None,
+                        resumable: false,
                         update_action: None,
                         exit_reason: ExitReason::UserRequested,
                     });
@@ -693,6 +713,7 @@ async fn run_ratatui_app(
                     token_usage: codex_core::protocol::TokenUsage::default(),
                     thread_id: None,
                     thread_name: None,
+                    resumable: false,
                     update_action: None,
                     exit_reason: ExitReason::UserRequested,
                 });
@@ -908,7 +929,13 @@ fn get_login_status(config: &Config) -> LoginStatus {
     }
 }
 
-pub fn themed_exit_footer(exit_info: &AppExitInfo, width: u16) -> Vec<String> {
+/// Renders the exit token usage and, when `resume_command` is set, the resume hint using the
+/// active theme.
+pub fn themed_exit_footer(
+    exit_info: &AppExitInfo,
+    resume_command: Option<&str>,
+    width: u16,
+) -> Vec<String> {
     use crate::render::line_utils::merge_span_style;
     use ratatui::style::Color;
     use ratatui::text::Span;
@@ -923,11 +950,10 @@ pub fn themed_exit_footer(exit_info: &AppExitInfo, width: u16) -> Vec<String> {
 
     let mut lines = vec![vec![Span::from(usage_line)]];
 
-    if let Some(thread_id) = exit_info.thread_id.as_ref() {
-        let command = format!("xcodex resume {thread_id}");
+    if let Some(command) = resume_command {
         lines.push(vec![
             "To continue this session, run ".into(),
-            Span::styled(command, crate::theme::accent_style()),
+            Span::styled(command.to_string(), crate::theme::accent_style()),
         ]);
     }
 
@@ -1063,6 +1089,29 @@ mod tests {
             .await
     }
 
+    #[test]
+    fn prompt_file_dash_reads_prompt_from_stdin() -> std::io::Result<()> {
+        let prompt = read_prompt_file(Path::new("-"), || Ok("fix the bug\n".to_string()))?;
+        assert_eq!(prompt, "fix the bug\n");
+
+        let err = read_prompt_file(Path::new("-"), || Ok(" \n".to_string()))
+            .expect_err("blank stdin prompt is rejected");
+        assert_eq!(err.to_string(), "No prompt provided via stdin.");
+        Ok(())
+    }
+
+    #[test]
+    fn prompt_file_contents_normalize_crlf() -> std::io::Result<()> {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("PROMPT.md");
+        std::fs::write(&path, "line one\r\nline two\rline three\r\n")?;
+
+        let prompt = read_prompt_file(&path, || unreachable!("stdin is not read"))?;
+
+        assert_eq!(prompt, "line one\nline two\nline three\n");
+        Ok(())
+    }
+
     #[tokio::test]
     #[serial]
     async fn windows_shows_trust_prompt_without_sandbox() -> std::io::Result<()> {
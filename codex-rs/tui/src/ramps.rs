@@ -23,7 +23,8 @@ pub(crate) fn baseline_ramp() -> RampId {
     RampId::Hardware
 }
 
-pub(crate) fn select_ramp(config: &Config, turn_index: u64) -> RampId {
+/// Ramps enabled in config, in rotation order. The hardware ramp is always eligible.
+pub(crate) fn eligible_ramps(config: &Config) -> Vec<RampId> {
     let mut eligible = Vec::with_capacity(3);
     eligible.push(RampId::Hardware);
     if config.xcodex.tui_ramps_build {
@@ -32,7 +33,11 @@ pub(crate) fn select_ramp(config: &Config, turn_index: u64) -> RampId {
     if config.xcodex.tui_ramps_devops {
         eligible.push(RampId::DevOps);
     }
+    eligible
+}
 
+pub(crate) fn select_ramp(config: &Config, turn_index: u64) -> RampId {
+    let eligible = eligible_ramps(config);
     if !config.xcodex.tui_ramps_rotate || eligible.len() == 1 {
         return RampId::Hardware;
     }
@@ -61,6 +66,21 @@ pub(crate) fn stage_label(ramp: RampId, stage: RampStage) -> &'static str {
     }
 }
 
+pub(crate) fn display_name(ramp: RampId) -> &'static str {
+    match ramp {
+        RampId::Hardware => "Hardware",
+        RampId::Build => "Build",
+        RampId::DevOps => "DevOps",
+    }
+}
+
+/// Parses a ramp name as typed in `/ramps pin <name>` (case-insensitive).
+pub(crate) fn parse_ramp_name(name: &str) -> Option<RampId> {
+    [RampId::Hardware, RampId::Build, RampId::DevOps]
+        .into_iter()
+        .find(|ramp| display_name(*ramp).eq_ignore_ascii_case(name))
+}
+
 pub(crate) fn completion_label(ramp: RampId) -> &'static str {
     match ramp {
         RampId::Hardware => "Overclocked",
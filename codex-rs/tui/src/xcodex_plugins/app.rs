@@ -54,6 +54,10 @@ pub(crate) async fn try_handle_event(
             crate::xcodex_plugins::theme::open_theme_help(app, tui);
             Ok(None)
         }
+        AppEvent::RefreshTheme => {
+            crate::xcodex_plugins::theme::refresh_theme(app, tui);
+            Ok(None)
+        }
         AppEvent::UpdateRampsConfig {
             rotate,
             build,
@@ -313,6 +317,12 @@ pub(crate) async fn try_handle_event(
             tui.frame_requester().schedule_frame();
             Ok(None)
         }
+        AppEvent::SetPinnedRamp(pinned) => {
+            app.xcodex_state.pinned_ramp = pinned;
+            app.chat_widget.set_pinned_ramp(pinned);
+            tui.frame_requester().schedule_frame();
+            Ok(None)
+        }
         AppEvent::WorktreeListUpdateFailed { error, open_picker } => {
             crate::xcodex_plugins::worktree::on_worktree_list_update_failed(
                 &mut app.chat_widget,
@@ -7,6 +7,8 @@ use codex_protocol::ThreadId;
 #[derive(Default)]
 pub(crate) struct XcodexAppState {
     pub(crate) shared_dirs_write_notice_shown: bool,
+    /// Ramp fixed by `/ramps pin`, if any.
+    pub(crate) pinned_ramp: Option<crate::ramps::RampId>,
     // TODO(jif) drop once new UX is here.
     // Track external agent approvals spawned via AgentControl.
     /// Map routed approval IDs to their originating external threads and original IDs.
@@ -15,7 +15,7 @@ pub(crate) struct ArgCompletion {
 }
 
 pub(crate) fn popup_plugin_commands() -> Vec<PluginSlashCommand> {
-    plugin_slash_commands().to_vec()
+    plugin_slash_commands().collect()
 }
 
 pub(crate) fn filter_prompts_for_popup(
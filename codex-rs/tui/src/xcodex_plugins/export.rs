@@ -0,0 +1,229 @@
+use crate::chatwidget::ChatWidget;
+use codex_core::protocol::EventMsg;
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::RolloutLine;
+use std::path::Path;
+
+pub(crate) fn handle_export_command(chat: &mut ChatWidget, rest: &str) {
+    let Some(rollout_path) = chat.rollout_path() else {
+        chat.add_info_message(
+            "Nothing to export yet: the session transcript is not available.".to_string(),
+            None,
+        );
+        return;
+    };
+
+    let target = match rest.trim() {
+        "" => {
+            let session = chat
+                .thread_id()
+                .map_or_else(|| "session".to_string(), |id| id.to_string());
+            chat.config_ref()
+                .codex_home
+                .join("exports")
+                .join(format!("{session}.md"))
+        }
+        path => chat.config_ref().cwd.join(path),
+    };
+
+    let items = match read_rollout_items(&rollout_path) {
+        Ok(items) => items,
+        Err(err) => {
+            chat.add_error_message(format!(
+                "Failed to read transcript `{}`: {err}",
+                rollout_path.display()
+            ));
+            return;
+        }
+    };
+    let markdown = render_transcript_markdown(&items, !chat.hide_agent_reasoning());
+
+    if let Some(parent) = target.parent()
+        && let Err(err) = std::fs::create_dir_all(parent)
+    {
+        chat.add_error_message(format!(
+            "Failed to create directory `{}`: {err}",
+            parent.display()
+        ));
+        return;
+    }
+    if let Err(err) = std::fs::write(&target, markdown) {
+        chat.add_error_message(format!("Failed to write `{}`: {err}", target.display()));
+        return;
+    }
+
+    chat.add_info_message(
+        format!("Exported transcript to `{}`.", target.display()),
+        None,
+    );
+}
+
+fn read_rollout_items(path: &Path) -> std::io::Result<Vec<RolloutItem>> {
+    let contents = std::fs::read_to_string(path)?;
+    Ok(contents
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RolloutLine>(line).ok())
+        .map(|line| line.item)
+        .collect())
+}
+
+/// Renders user/assistant turns, tool calls (with their outputs), and optionally reasoning as
+/// Markdown. Injected context messages (instructions, environment) are skipped because only the
+/// `UserMessage`/`AgentMessage` events are used for conversation turns.
+fn render_transcript_markdown(items: &[RolloutItem], include_reasoning: bool) -> String {
+    let mut out = String::from("# xcodex transcript\n");
+    for item in items {
+        match item {
+            RolloutItem::EventMsg(EventMsg::UserMessage(event)) => {
+                push_section(&mut out, "## User", &event.message);
+            }
+            RolloutItem::EventMsg(EventMsg::AgentMessage(event)) => {
+                push_section(&mut out, "## Assistant", &event.message);
+            }
+            RolloutItem::EventMsg(EventMsg::AgentReasoning(event)) if include_reasoning => {
+                push_section(&mut out, "### Reasoning", &event.text);
+            }
+            RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+                name, arguments, ..
+            }) => {
+                let arguments = serde_json::from_str::<serde_json::Value>(arguments)
+                    .ok()
+                    .and_then(|value| serde_json::to_string_pretty(&value).ok())
+                    .unwrap_or_else(|| arguments.clone());
+                push_section(&mut out, &format!("### Tool call: `{name}`"), "");
+                push_code_block(&mut out, "json", &arguments);
+            }
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCall { name, input, .. }) => {
+                push_section(&mut out, &format!("### Tool call: `{name}`"), "");
+                push_code_block(&mut out, "", input);
+            }
+            RolloutItem::ResponseItem(ResponseItem::LocalShellCall {
+                action: LocalShellAction::Exec(exec),
+                ..
+            }) => {
+                push_section(&mut out, "### Tool call: `shell`", "");
+                push_code_block(&mut out, "sh", &exec.command.join(" "));
+            }
+            RolloutItem::ResponseItem(ResponseItem::FunctionCallOutput { output, .. }) => {
+                let text = output.body.text_content().unwrap_or("(non-text output)");
+                out.push_str("\nOutput:\n");
+                push_code_block(&mut out, "", text);
+            }
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCallOutput { output, .. }) => {
+                out.push_str("\nOutput:\n");
+                push_code_block(&mut out, "", output);
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+fn push_section(out: &mut String, heading: &str, body: &str) {
+    out.push('\n');
+    out.push_str(heading);
+    out.push('\n');
+    let body = body.trim_end();
+    if !body.is_empty() {
+        out.push('\n');
+        out.push_str(body);
+        out.push('\n');
+    }
+}
+
+fn push_code_block(out: &mut String, info: &str, body: &str) {
+    // Use a fence longer than any backtick run in the body so embedded fences stay intact.
+    let longest_run = body.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    out.push('\n');
+    out.push_str(&format!("{fence}{info}\n{}\n{fence}\n", body.trim_end()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_core::protocol::AgentMessageEvent;
+    use codex_core::protocol::AgentReasoningEvent;
+    use codex_core::protocol::UserMessageEvent;
+    use codex_protocol::models::FunctionCallOutputBody;
+    use codex_protocol::models::FunctionCallOutputPayload;
+    use pretty_assertions::assert_eq;
+
+    fn transcript() -> Vec<RolloutItem> {
+        vec![
+            RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+                message: "list the files".to_string(),
+                images: None,
+                local_images: Vec::new(),
+                text_elements: Vec::new(),
+            })),
+            RolloutItem::EventMsg(EventMsg::AgentReasoning(AgentReasoningEvent {
+                text: "I should run ls.".to_string(),
+            })),
+            RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                arguments: r#"{"command":["ls"]}"#.to_string(),
+                call_id: "call-1".to_string(),
+            }),
+            RolloutItem::ResponseItem(ResponseItem::FunctionCallOutput {
+                call_id: "call-1".to_string(),
+                output: FunctionCallOutputPayload {
+                    body: FunctionCallOutputBody::Text("Cargo.toml\nsrc".to_string()),
+                    success: Some(true),
+                },
+            }),
+            RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+                message: "There are two entries.".to_string(),
+            })),
+        ]
+    }
+
+    #[test]
+    fn renders_turns_and_tool_call_with_reasoning() {
+        let markdown = render_transcript_markdown(&transcript(), true);
+        assert_eq!(
+            markdown,
+            r#"# xcodex transcript
+
+## User
+
+list the files
+
+### Reasoning
+
+I should run ls.
+
+### Tool call: `shell`
+
+```json
+{
+  "command": [
+    "ls"
+  ]
+}
+```
+
+Output:
+
+```
+Cargo.toml
+src
+```
+
+## Assistant
+
+There are two entries.
+"#
+        );
+    }
+
+    #[test]
+    fn omits_reasoning_when_thoughts_hidden() {
+        let markdown = render_transcript_markdown(&transcript(), false);
+        assert!(!markdown.contains("### Reasoning"));
+        assert!(!markdown.contains("I should run ls."));
+    }
+}
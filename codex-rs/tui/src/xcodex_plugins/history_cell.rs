@@ -528,6 +528,25 @@ pub(crate) fn new_unified_exec_processes_output(
     CompositeHistoryCell::new(vec![Box::new(command), Box::new(summary)])
 }
 
+/// Renders the captured stdout/stderr of a hook process (`hooks.echo_to_ui`).
+pub(crate) fn new_hook_output(command_display: String, output: &str) -> PlainHistoryCell {
+    let mut lines: Vec<Line<'static>> = vec![
+        vec![
+            "• ".dim(),
+            "Hook output".bold(),
+            " · ".dim(),
+            command_display.dim(),
+        ]
+        .into(),
+    ];
+    lines.extend(
+        output
+            .lines()
+            .map(|line| Line::from(format!("  {line}").dim())),
+    );
+    PlainHistoryCell::new(lines)
+}
+
 /// Render MCP tools grouped by connection using the fully-qualified tool names.
 pub(crate) fn new_mcp_tools_output(
     config: &Config,
@@ -1386,6 +1405,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hook_output_cell_tags_output_with_command() {
+        let cell = new_hook_output(
+            "session-start · setup.sh".to_string(),
+            "installed deps\nready\n",
+        );
+        assert_snapshot!(render_lines(&cell.display_lines(80)).join("\n"));
+    }
+
     fn relevant_header_lines(lines: &[Line<'static>]) -> String {
         render_lines(lines)
             .into_iter()
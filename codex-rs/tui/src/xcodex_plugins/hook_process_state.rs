@@ -1,3 +1,6 @@
+use std::time::Duration;
+use std::time::Instant;
+
 use crate::xcodex_plugins::history_cell::BackgroundActivityEntry;
 
 #[derive(Debug, Default)]
@@ -8,7 +11,18 @@ pub(crate) struct HookProcessState {
 #[derive(Debug)]
 struct HookProcessSummary {
     key: String,
+    event_type: String,
     command_display: String,
+    started_at: Instant,
+    timeout: Option<Duration>,
+}
+
+impl HookProcessSummary {
+    /// Whether the hook has outlived its configured timeout as of `now`.
+    fn is_overdue(&self, now: Instant) -> bool {
+        self.timeout
+            .is_some_and(|timeout| now.saturating_duration_since(self.started_at) > timeout)
+    }
 }
 
 impl HookProcessState {
@@ -16,13 +30,26 @@ impl HookProcessState {
         self.hooks.is_empty()
     }
 
-    pub(crate) fn begin(&mut self, key: String, command_display: String) {
+    pub(crate) fn begin(
+        &mut self,
+        key: String,
+        event_type: String,
+        command_display: String,
+        started_at: Instant,
+        timeout: Option<Duration>,
+    ) {
         if let Some(existing) = self.hooks.iter_mut().find(|hook| hook.key == key) {
+            existing.event_type = event_type;
             existing.command_display = command_display;
+            existing.started_at = started_at;
+            existing.timeout = timeout;
         } else {
             self.hooks.push(HookProcessSummary {
                 key,
+                event_type,
                 command_display,
+                started_at,
+                timeout,
             });
         }
     }
@@ -37,19 +64,118 @@ impl HookProcessState {
         self.hooks.clear();
     }
 
-    pub(crate) fn command_displays(&self) -> Vec<String> {
+    pub(crate) fn command_display(&self, key: &str) -> Option<String> {
         self.hooks
             .iter()
+            .find(|hook| hook.key == key)
             .map(|hook| hook.command_display.clone())
+    }
+
+    /// Event type of each running hook process, in start order.
+    pub(crate) fn event_types(&self) -> Vec<String> {
+        self.hooks
+            .iter()
+            .map(|hook| hook.event_type.clone())
             .collect()
     }
 
-    pub(crate) fn entries(&self) -> Vec<BackgroundActivityEntry> {
+    /// Entries for `/ps`; hooks running past their timeout as of `now` are marked overdue.
+    pub(crate) fn entries(&self, now: Instant) -> Vec<BackgroundActivityEntry> {
         self.hooks
             .iter()
             .map(|hook| {
-                BackgroundActivityEntry::new(hook.key.clone(), hook.command_display.clone())
+                let command_display = match hook.timeout {
+                    Some(timeout) if hook.is_overdue(now) => {
+                        let timeout_sec = timeout.as_secs();
+                        let command_display = &hook.command_display;
+                        format!("{command_display} · overdue (timeout {timeout_sec}s)")
+                    }
+                    _ => hook.command_display.clone(),
+                };
+                BackgroundActivityEntry::new(hook.key.clone(), command_display)
             })
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn begin_and_end_track_running_hooks() {
+        let mut state = HookProcessState::default();
+        let now = Instant::now();
+        state.begin(
+            "hook-1".to_string(),
+            "session-start".to_string(),
+            "session-start · setup.sh".to_string(),
+            now,
+            None,
+        );
+        state.begin(
+            "hook-2".to_string(),
+            "tool-call-finished".to_string(),
+            "tool-call-finished · notify.sh".to_string(),
+            now,
+            None,
+        );
+        assert_eq!(
+            state.event_types(),
+            vec![
+                "session-start".to_string(),
+                "tool-call-finished".to_string()
+            ]
+        );
+
+        assert!(state.end("hook-1"));
+        assert!(!state.end("hook-1"));
+        assert_eq!(state.event_types(), vec!["tool-call-finished".to_string()]);
+
+        assert!(state.end("hook-2"));
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn entries_mark_hooks_running_past_their_timeout() {
+        let mut state = HookProcessState::default();
+        let started_at = Instant::now();
+        state.begin(
+            "hook-1".to_string(),
+            "session-start".to_string(),
+            "session-start · setup.sh".to_string(),
+            started_at,
+            Some(Duration::from_secs(30)),
+        );
+        state.begin(
+            "hook-2".to_string(),
+            "session-start".to_string(),
+            "session-start · legacy.sh".to_string(),
+            started_at,
+            None,
+        );
+
+        let displays = |now| {
+            state
+                .entries(now)
+                .into_iter()
+                .map(|entry| entry.command_display)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            displays(started_at + Duration::from_secs(30)),
+            vec![
+                "session-start · setup.sh".to_string(),
+                "session-start · legacy.sh".to_string(),
+            ]
+        );
+        assert_eq!(
+            displays(started_at + Duration::from_secs(31)),
+            vec![
+                "session-start · setup.sh · overdue (timeout 30s)".to_string(),
+                "session-start · legacy.sh".to_string(),
+            ]
+        );
+    }
+}
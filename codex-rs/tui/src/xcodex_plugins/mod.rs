@@ -2,6 +2,7 @@ pub(crate) mod app;
 mod app_state;
 pub(crate) mod command_popup;
 pub(crate) mod exclusions;
+mod export;
 pub(crate) mod help;
 pub(crate) mod history_cell;
 mod hook_process_state;
@@ -29,28 +30,41 @@ pub(crate) use ramp_status_state::RampStatusController;
 use rand::Rng;
 pub(crate) use worktree_list_state::WorktreeListState;
 
-#[derive(Clone, Copy, Debug)]
-pub(crate) struct PluginSlashCommand {
-    pub(crate) name: &'static str,
-    pub(crate) description: &'static str,
-    pub(crate) run_on_enter: bool,
-    pub(crate) insert_trailing_space: bool,
-}
+include!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../xcodex/tui_plugin_commands.rs"
+));
 
-const PLUGIN_COMMANDS: [PluginSlashCommand; 2] = [
-    PluginSlashCommand {
-        name: "thoughts",
-        description: "toggle showing agent thoughts/reasoning (persists)",
-        run_on_enter: true,
-        insert_trailing_space: false,
-    },
-    PluginSlashCommand {
-        name: "xtreme",
-        description: "open the ⚡Tools control panel",
-        run_on_enter: true,
-        insert_trailing_space: false,
-    },
-];
+/// This frontend's handlers for the shared `PLUGIN_COMMANDS` table.
+mod handlers {
+    use crate::chatwidget::ChatWidget;
+
+    pub(super) use super::exclusions::handle_exclusions_command as exclusion;
+    pub(super) use super::ramps::handle_ramps_command as ramps;
+    pub(super) use super::settings::handle_settings_command as settings;
+    pub(super) use super::thoughts::handle as thoughts;
+    pub(super) use super::xtreme::handle as xtreme;
+
+    pub(super) fn export(chat: &mut ChatWidget, rest: &str) -> bool {
+        super::export::handle_export_command(chat, rest);
+        true
+    }
+
+    pub(super) fn help(chat: &mut ChatWidget, rest: &str) -> bool {
+        super::help::handle_help_command(chat, rest);
+        true
+    }
+
+    pub(super) fn plan(chat: &mut ChatWidget, rest: &str) -> bool {
+        super::plan::handle_plan_command(chat, rest);
+        true
+    }
+
+    pub(super) fn hooks(chat: &mut ChatWidget, rest: &str) -> bool {
+        super::hooks::handle_hooks_command(chat, rest);
+        true
+    }
+}
 
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct PluginSubcommandNode {
@@ -83,36 +97,10 @@ const PLUGIN_SUBCOMMAND_ROOTS: &[PluginSubcommandRoot] = &[
     worktree::WORKTREE_SUBCOMMAND_ROOT,
 ];
 
-pub(crate) fn plugin_slash_commands() -> &'static [PluginSlashCommand] {
-    &PLUGIN_COMMANDS
-}
-
 pub(crate) fn plugin_subcommand_roots() -> &'static [PluginSubcommandRoot] {
     PLUGIN_SUBCOMMAND_ROOTS
 }
 
-pub(crate) fn try_handle_slash_command(chat: &mut ChatWidget, name: &str, rest: &str) -> bool {
-    match name {
-        "thoughts" => thoughts::handle(chat, rest),
-        "xtreme" => xtreme::handle(chat, rest),
-        "exclusion" => exclusions::handle_exclusions_command(chat, rest),
-        "settings" => settings::handle_settings_command(chat, rest),
-        "help" => {
-            help::handle_help_command(chat, rest);
-            true
-        }
-        "plan" => {
-            plan::handle_plan_command(chat, rest);
-            true
-        }
-        "hooks" => {
-            hooks::handle_hooks_command(chat, rest);
-            true
-        }
-        _ => false,
-    }
-}
-
 pub(crate) fn try_handle_mcp_subcommand(chat: &mut ChatWidget, args: &[&str]) -> bool {
     mcp::try_handle_subcommand(chat, args)
 }
@@ -159,3 +147,29 @@ pub(crate) fn ramps_rotation_hint() -> &'static str {
 pub(crate) fn format_edit_approval_message(target: String) -> String {
     format!("xcodex wants to edit {target}")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn list_hint_order_tokens_match_root_children() {
+        for root in plugin_subcommand_roots() {
+            let Some(order) = root.list_hint_order else {
+                continue;
+            };
+            let unknown = order
+                .iter()
+                .map(|entry| entry.token)
+                .filter(|token| !root.children.iter().any(|node| node.token == *token))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                unknown,
+                Vec::<&str>::new(),
+                "/{} list_hint_order references unknown subcommands",
+                root.root
+            );
+        }
+    }
+}
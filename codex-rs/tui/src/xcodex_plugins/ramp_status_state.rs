@@ -2,6 +2,7 @@ use codex_core::config::Config;
 
 #[derive(Debug)]
 pub(crate) struct RampStatusState {
+    enabled: bool,
     selected: crate::ramps::RampId,
     stage: crate::ramps::RampStage,
     context: Option<String>,
@@ -10,6 +11,7 @@ pub(crate) struct RampStatusState {
 impl Default for RampStatusState {
     fn default() -> Self {
         Self {
+            enabled: crate::ramps::ramps_enabled(),
             selected: crate::ramps::baseline_ramp(),
             stage: crate::ramps::RampStage::Waiting,
             context: None,
@@ -20,18 +22,25 @@ impl Default for RampStatusState {
 #[derive(Debug, Default)]
 pub(crate) struct RampStatusController {
     turn_index: u64,
+    /// Ramp fixed by `/ramps pin`; bypasses rotation while set.
+    pinned: Option<crate::ramps::RampId>,
     state: RampStatusState,
 }
 
 impl RampStatusState {
-    pub(crate) fn reset_for_turn(&mut self, config: &Config, turn_index: u64) {
-        self.selected = crate::ramps::select_ramp(config, turn_index);
+    pub(crate) fn reset_for_turn(
+        &mut self,
+        config: &Config,
+        turn_index: u64,
+        pinned: Option<crate::ramps::RampId>,
+    ) {
+        self.selected = pinned.unwrap_or_else(|| crate::ramps::select_ramp(config, turn_index));
         self.stage = crate::ramps::RampStage::Waiting;
         self.context = None;
     }
 
     pub(crate) fn is_enabled(&self) -> bool {
-        crate::ramps::ramps_enabled()
+        self.enabled
     }
 
     pub(crate) fn is_active(&self, task_running: bool) -> bool {
@@ -103,12 +112,27 @@ impl RampStatusController {
             .then(|| self.state.header_string())
     }
 
+    /// The ramp `start_turn` would pick for the next turn, without advancing the rotation.
+    pub(crate) fn preview_next(&self, config: &Config) -> crate::ramps::RampId {
+        self.pinned
+            .unwrap_or_else(|| crate::ramps::select_ramp(config, self.turn_index.saturating_add(1)))
+    }
+
+    pub(crate) fn pinned(&self) -> Option<crate::ramps::RampId> {
+        self.pinned
+    }
+
+    pub(crate) fn set_pinned(&mut self, pinned: Option<crate::ramps::RampId>) {
+        self.pinned = pinned;
+    }
+
     pub(crate) fn start_turn(&mut self, config: &Config, task_running: bool) -> Option<String> {
         if !self.is_enabled() {
             return None;
         }
         self.turn_index = self.turn_index.saturating_add(1);
-        self.state.reset_for_turn(config, self.turn_index);
+        self.state
+            .reset_for_turn(config, self.turn_index, self.pinned);
         self.header_if_active(task_running)
     }
 
@@ -136,3 +160,59 @@ impl RampStatusController {
         self.state.completion_label()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_core::config::ConfigBuilder;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn preview_matches_the_ramp_selected_for_the_next_turn() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let mut config = ConfigBuilder::default()
+            .codex_home(codex_home.path().to_path_buf())
+            .build()
+            .await
+            .expect("config");
+        config.xcodex.tui_ramps_rotate = true;
+        config.xcodex.tui_ramps_build = true;
+        config.xcodex.tui_ramps_devops = true;
+
+        let mut controller = RampStatusController {
+            state: RampStatusState {
+                enabled: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        for _ in 0..5 {
+            let preview = controller.preview_next(&config);
+            controller.start_turn(&config, false);
+            assert_eq!(preview, controller.state.selected);
+        }
+    }
+
+    #[tokio::test]
+    async fn pinned_ramp_is_selected_for_every_turn() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let mut config = ConfigBuilder::default()
+            .codex_home(codex_home.path().to_path_buf())
+            .build()
+            .await
+            .expect("config");
+        config.xcodex.tui_ramps_rotate = true;
+        config.xcodex.tui_ramps_build = true;
+        config.xcodex.tui_ramps_devops = true;
+
+        let mut state = RampStatusState::default();
+        let selected = (1..=6)
+            .map(|turn_index| {
+                state.reset_for_turn(&config, turn_index, Some(crate::ramps::RampId::DevOps));
+                state.selected
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(selected, vec![crate::ramps::RampId::DevOps; 6]);
+    }
+}
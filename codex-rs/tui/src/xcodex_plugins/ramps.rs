@@ -11,6 +11,8 @@ use codex_core::config::Config;
 use ratatui::style::Stylize;
 use ratatui::text::Line;
 
+const RAMPS_USAGE: &str = "Usage: /ramps [preview|pin <name>|unpin]";
+
 pub(crate) enum RampStatusUpdate {
     Context(String),
     Stage(ramps::RampStage),
@@ -87,6 +89,99 @@ pub(crate) fn task_start_header(
     }
 }
 
+pub(crate) fn handle_ramps_command(chat: &mut ChatWidget, rest: &str) -> bool {
+    let args: Vec<&str> = rest.split_whitespace().collect();
+    match args.as_slice() {
+        [] => open_settings_view(chat),
+        ["preview"] => add_preview_output(chat),
+        ["pin", name] => pin_ramp(chat, name),
+        ["unpin"] => {
+            if !chat.ramp_status_enabled() {
+                chat.add_info_message(super::ramps_unavailable_message().to_string(), None);
+            } else if chat.ramp_status().pinned().is_none() {
+                chat.add_info_message("No ramp is pinned.".to_string(), None);
+            } else {
+                chat.app_event_tx().send(AppEvent::SetPinnedRamp(None));
+                chat.add_info_message(
+                    "Unpinned; ramp rotation resumes next turn.".to_string(),
+                    None,
+                );
+            }
+        }
+        _ => chat.add_info_message(RAMPS_USAGE.to_string(), None),
+    }
+    true
+}
+
+fn pin_ramp(chat: &mut ChatWidget, name: &str) {
+    if !chat.ramp_status_enabled() {
+        chat.add_info_message(super::ramps_unavailable_message().to_string(), None);
+        return;
+    }
+    let eligible = ramps::eligible_ramps(chat.config_ref());
+    let Some(ramp) = ramps::parse_ramp_name(name).filter(|ramp| eligible.contains(ramp)) else {
+        let names = eligible
+            .into_iter()
+            .map(ramps::display_name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        chat.add_error_message(format!(
+            "Unknown or disabled ramp `{name}`. Eligible ramps: {names}"
+        ));
+        return;
+    };
+    chat.app_event_tx()
+        .send(AppEvent::SetPinnedRamp(Some(ramp)));
+    chat.add_info_message(
+        format!(
+            "Pinned the {} ramp for this session.",
+            ramps::display_name(ramp)
+        ),
+        Some("Run /ramps unpin to resume rotation.".to_string()),
+    );
+}
+
+/// Read-only view of the eligible ramps and the one the next turn will use.
+fn add_preview_output(chat: &mut ChatWidget) {
+    if !chat.ramp_status_enabled() {
+        chat.add_info_message(super::ramps_unavailable_message().to_string(), None);
+        return;
+    }
+
+    let config = chat.config_ref();
+    let next = chat.ramp_status().preview_next(config);
+    let pinned = chat.ramp_status().pinned();
+    let rotating = pinned.is_none() && config.xcodex.tui_ramps_rotate;
+    // Without rotation only the next ramp can run, so listing the others would mislead.
+    let eligible = if rotating {
+        ramps::eligible_ramps(config)
+            .into_iter()
+            .map(ramps::display_name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    } else {
+        ramps::display_name(next).to_string()
+    };
+    let rotation = match pinned {
+        Some(pinned) => format!("pinned to {}", ramps::display_name(pinned)),
+        None if rotating => "on".to_string(),
+        None => "off".to_string(),
+    };
+    let lines: Vec<Line<'static>> = vec![
+        vec!["/ramps preview".magenta()].into(),
+        vec!["Eligible: ".dim(), eligible.into()].into(),
+        vec!["Rotation: ".dim(), rotation.into()].into(),
+        vec![
+            "Next turn: ".dim(),
+            ramps::display_name(next).cyan(),
+            " — ".dim(),
+            ramps::preview_flow(next).dim(),
+        ]
+        .into(),
+    ];
+    chat.add_plain_history_lines(lines);
+}
+
 pub(crate) fn open_settings_view(chat: &mut ChatWidget) {
     if !chat.ramp_status_enabled() {
         chat.add_info_message(super::ramps_unavailable_message().to_string(), None);
@@ -1,8 +1,256 @@
+use crate::app_event::AppEvent;
 use crate::chatwidget::ChatWidget;
 use crate::history_cell::CompositeHistoryCell;
 use crate::history_cell::PlainHistoryCell;
+use codex_core::config::Config;
+use codex_core::config::types::ExclusionConfig;
+use codex_core::themes::ThemeCatalog;
+use codex_core::themes::ThemeVariant;
 use ratatui::style::Stylize;
 use ratatui::text::Line;
+use serde::Deserialize;
+use serde::Serialize;
+use std::path::Path;
+use std::path::PathBuf;
+
+const SETTINGS_USAGE: &str =
+    "Usage: /settings [status-bar|transcript|worktrees|export [path]|import <path>]";
+
+/// Portable subset of user-facing settings written by `/settings export`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SettingsExport {
+    #[serde(default)]
+    pub(crate) theme: ThemeSettings,
+    pub(crate) thoughts: ThoughtsSettings,
+    pub(crate) ramps: RampsSettings,
+    pub(crate) status_bar: StatusBarSettings,
+    pub(crate) transcript: TranscriptSettings,
+    pub(crate) exclusion: ExclusionConfig,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ThemeSettings {
+    pub(crate) light: Option<String>,
+    pub(crate) dark: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ThoughtsSettings {
+    pub(crate) hidden: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RampsSettings {
+    pub(crate) rotate: bool,
+    pub(crate) build: bool,
+    pub(crate) devops: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct StatusBarSettings {
+    pub(crate) show_git_branch: bool,
+    pub(crate) show_worktree: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct TranscriptSettings {
+    pub(crate) diff_highlight: bool,
+    pub(crate) side_by_side: bool,
+    pub(crate) user_prompt_highlight: bool,
+    pub(crate) syntax_highlight: bool,
+}
+
+impl SettingsExport {
+    pub(crate) fn from_config(config: &Config) -> Self {
+        Self {
+            theme: ThemeSettings {
+                light: config.xcodex.themes.light.clone(),
+                dark: config.xcodex.themes.dark.clone(),
+            },
+            thoughts: ThoughtsSettings {
+                hidden: config.hide_agent_reasoning,
+            },
+            ramps: RampsSettings {
+                rotate: config.xcodex.tui_ramps_rotate,
+                build: config.xcodex.tui_ramps_build,
+                devops: config.xcodex.tui_ramps_devops,
+            },
+            status_bar: StatusBarSettings {
+                show_git_branch: config.tui_status_bar_show_git_branch,
+                show_worktree: config.tui_status_bar_show_worktree,
+            },
+            transcript: TranscriptSettings {
+                diff_highlight: config.tui_transcript_diff_highlight,
+                side_by_side: config.tui_transcript_side_by_side,
+                user_prompt_highlight: config.tui_transcript_user_prompt_highlight,
+                syntax_highlight: config.tui_transcript_syntax_highlight,
+            },
+            exclusion: config.exclusion.clone(),
+        }
+    }
+}
+
+pub(crate) fn write_settings_export(path: &Path, settings: &SettingsExport) -> Result<(), String> {
+    let contents = toml::to_string_pretty(settings)
+        .map_err(|err| format!("Failed to serialize settings: {err}"))?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|err| format!("Failed to create directory `{}`: {err}", parent.display()))?;
+    }
+    std::fs::write(path, contents)
+        .map_err(|err| format!("Failed to write `{}`: {err}", path.display()))
+}
+
+/// Reads a settings file and checks that every referenced theme exists in `themes`.
+pub(crate) fn read_settings_export(
+    path: &Path,
+    themes: &ThemeCatalog,
+) -> Result<SettingsExport, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read `{}`: {err}", path.display()))?;
+    let settings: SettingsExport = toml::from_str(&contents)
+        .map_err(|err| format!("Invalid settings file `{}`: {err}", path.display()))?;
+    for name in [&settings.theme.light, &settings.theme.dark]
+        .into_iter()
+        .flatten()
+    {
+        if name != "default" && themes.get(name).is_none() {
+            return Err(format!(
+                "Invalid settings file `{}`: unknown theme `{name}`",
+                path.display()
+            ));
+        }
+    }
+    Ok(settings)
+}
+
+fn settings_path(chat: &ChatWidget, arg: &str) -> PathBuf {
+    match arg {
+        "" => chat
+            .config_ref()
+            .codex_home
+            .join("exports")
+            .join("settings.toml"),
+        path => chat.config_ref().cwd.join(path),
+    }
+}
+
+fn export_settings(chat: &mut ChatWidget, arg: &str) {
+    let target = settings_path(chat, arg);
+    let settings = SettingsExport::from_config(chat.config_ref());
+    match write_settings_export(&target, &settings) {
+        Ok(()) => chat.add_info_message(
+            format!("Exported settings to `{}`.", target.display()),
+            None,
+        ),
+        Err(err) => chat.add_error_message(err),
+    }
+}
+
+fn import_settings(chat: &mut ChatWidget, arg: &str) {
+    if arg.is_empty() {
+        chat.add_info_message("Usage: /settings import <path>".to_string(), None);
+        return;
+    }
+    let source = settings_path(chat, arg);
+    let themes = match ThemeCatalog::load(chat.config_ref()) {
+        Ok(themes) => themes,
+        Err(err) => {
+            chat.add_error_message(format!("Failed to load themes: {err}"));
+            return;
+        }
+    };
+    let settings = match read_settings_export(&source, &themes) {
+        Ok(settings) => settings,
+        Err(err) => {
+            chat.add_error_message(err);
+            return;
+        }
+    };
+
+    let SettingsExport {
+        theme,
+        thoughts,
+        ramps,
+        status_bar,
+        transcript,
+        exclusion,
+    } = settings;
+    for (variant, name) in [
+        (ThemeVariant::Light, theme.light),
+        (ThemeVariant::Dark, theme.dark),
+    ] {
+        chat.send_app_event(AppEvent::PersistThemeSelection {
+            variant,
+            theme: name.unwrap_or_else(|| "default".to_string()),
+        });
+    }
+    chat.set_hide_agent_reasoning(thoughts.hidden);
+    chat.send_app_event(AppEvent::UpdateHideAgentReasoning(thoughts.hidden));
+    chat.send_app_event(AppEvent::PersistHideAgentReasoning(thoughts.hidden));
+    chat.send_app_event(AppEvent::UpdateRampsConfig {
+        rotate: ramps.rotate,
+        build: ramps.build,
+        devops: ramps.devops,
+    });
+    chat.send_app_event(AppEvent::PersistRampsConfig {
+        rotate: ramps.rotate,
+        build: ramps.build,
+        devops: ramps.devops,
+    });
+    chat.send_app_event(AppEvent::UpdateStatusBarGitOptions {
+        show_git_branch: status_bar.show_git_branch,
+        show_worktree: status_bar.show_worktree,
+    });
+    chat.send_app_event(AppEvent::PersistStatusBarGitOptions {
+        show_git_branch: status_bar.show_git_branch,
+        show_worktree: status_bar.show_worktree,
+    });
+    chat.send_app_event(AppEvent::UpdateTranscriptDiffHighlight(
+        transcript.diff_highlight,
+    ));
+    chat.send_app_event(AppEvent::PersistTranscriptDiffHighlight(
+        transcript.diff_highlight,
+    ));
+    chat.send_app_event(AppEvent::UpdateTranscriptSideBySide(
+        transcript.side_by_side,
+    ));
+    chat.send_app_event(AppEvent::PersistTranscriptSideBySide(
+        transcript.side_by_side,
+    ));
+    chat.send_app_event(AppEvent::UpdateTranscriptUserPromptHighlight(
+        transcript.user_prompt_highlight,
+    ));
+    chat.send_app_event(AppEvent::PersistTranscriptUserPromptHighlight(
+        transcript.user_prompt_highlight,
+    ));
+    chat.send_app_event(AppEvent::UpdateTranscriptSyntaxHighlight(
+        transcript.syntax_highlight,
+    ));
+    chat.send_app_event(AppEvent::PersistTranscriptSyntaxHighlight(
+        transcript.syntax_highlight,
+    ));
+    let hooks_sanitize_payloads = exclusion.layer_hook_sanitization_enabled();
+    chat.send_app_event(AppEvent::UpdateExclusionSettings {
+        exclusion: exclusion.clone(),
+        hooks_sanitize_payloads,
+    });
+    chat.send_app_event(AppEvent::PersistExclusionSettings {
+        exclusion,
+        hooks_sanitize_payloads,
+    });
+
+    chat.add_info_message(
+        format!("Imported settings from `{}`.", source.display()),
+        None,
+    );
+}
 
 pub(crate) fn add_settings_output_with_values(
     chat: &mut ChatWidget,
@@ -35,6 +283,22 @@ pub(crate) fn handle_settings_command(chat: &mut ChatWidget, rest: &str) -> bool
     let current_user_prompt_highlight = chat.config_ref().tui_transcript_user_prompt_highlight;
     let current_syntax_highlight = chat.config_ref().tui_transcript_syntax_highlight;
 
+    let (subcommand, subcommand_arg) = rest
+        .trim()
+        .split_once(char::is_whitespace)
+        .unwrap_or((rest.trim(), ""));
+    match subcommand {
+        "export" => {
+            export_settings(chat, subcommand_arg.trim());
+            return true;
+        }
+        "import" => {
+            import_settings(chat, subcommand_arg.trim());
+            return true;
+        }
+        _ => {}
+    }
+
     let (section, item, action) = match args.as_slice() {
         [] | ["status-bar"] | ["transcript"] => {
             add_settings_output_with_values(
@@ -57,10 +321,7 @@ pub(crate) fn handle_settings_command(chat: &mut ChatWidget, rest: &str) -> bool
         ["transcript", item] => ("transcript", *item, None),
         ["transcript", item, action] => ("transcript", *item, Some(*action)),
         _ => {
-            chat.add_info_message(
-                "Usage: /settings [status-bar|transcript|worktrees]".to_string(),
-                None,
-            );
+            chat.add_info_message(SETTINGS_USAGE.to_string(), None);
             return true;
         }
     };
@@ -239,3 +500,55 @@ pub(crate) fn handle_settings_command(chat: &mut ChatWidget, rest: &str) -> bool
     );
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_core::config::ConfigBuilder;
+    use pretty_assertions::assert_eq;
+
+    async fn test_config(codex_home: &Path) -> Config {
+        ConfigBuilder::default()
+            .codex_home(codex_home.to_path_buf())
+            .build()
+            .await
+            .expect("config")
+    }
+
+    #[tokio::test]
+    async fn settings_round_trip_through_export_and_import() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let config = test_config(codex_home.path()).await;
+        let mut settings = SettingsExport::from_config(&config);
+        settings.thoughts.hidden = true;
+        settings.ramps.devops = false;
+        settings.transcript.side_by_side = false;
+        settings.exclusion.paranoid_mode = true;
+
+        let path = codex_home.path().join("exports").join("settings.toml");
+        write_settings_export(&path, &settings).expect("export");
+        let themes = ThemeCatalog::load(&config).expect("themes");
+        let imported = read_settings_export(&path, &themes).expect("import");
+
+        assert_eq!(imported, settings);
+    }
+
+    #[tokio::test]
+    async fn import_rejects_unknown_theme_and_keys() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let config = test_config(codex_home.path()).await;
+        let themes = ThemeCatalog::load(&config).expect("themes");
+        let mut settings = SettingsExport::from_config(&config);
+        settings.theme.dark = Some("no-such-theme".to_string());
+        let path = codex_home.path().join("settings.toml");
+        write_settings_export(&path, &settings).expect("export");
+
+        let err = read_settings_export(&path, &themes).expect_err("unknown theme");
+        assert!(err.ends_with("unknown theme `no-such-theme`"), "{err}");
+
+        let contents = std::fs::read_to_string(&path).expect("read");
+        std::fs::write(&path, format!("bogus = 1\n{contents}")).expect("write");
+        let err = read_settings_export(&path, &themes).expect_err("unknown key");
+        assert!(err.contains("bogus"), "{err}");
+    }
+}
@@ -31,6 +31,14 @@ const THEME_SUBCOMMANDS: &[PluginSubcommandNode] = &[
         insert_trailing_space: false,
         children: &[],
     },
+    PluginSubcommandNode {
+        token: "refresh",
+        full_name: "theme refresh",
+        description: "re-detect the terminal background and re-apply the theme",
+        run_on_enter: true,
+        insert_trailing_space: false,
+        children: &[],
+    },
 ];
 
 pub(crate) const THEME_SUBCOMMAND_ROOT: PluginSubcommandRoot = PluginSubcommandRoot {
@@ -58,6 +66,10 @@ pub(crate) fn try_handle_subcommand(chat: &mut ChatWidget, args: &str) -> bool {
             write_theme_templates(chat);
             true
         }
+        "refresh" => {
+            chat.send_app_event(AppEvent::RefreshTheme);
+            true
+        }
         _ => false,
     }
 }
@@ -72,6 +84,20 @@ pub(crate) fn cancel_theme_preview(app: &mut App, tui: &mut Tui) {
     tui.frame_requester().schedule_frame();
 }
 
+pub(crate) fn refresh_theme(app: &mut App, tui: &mut Tui) {
+    terminal_palette::requery_default_colors();
+    let terminal_bg = terminal_palette::default_bg();
+    crate::theme::apply_from_config(&app.config, terminal_bg);
+
+    let label = match crate::theme::active_variant(&app.config, terminal_bg) {
+        ThemeVariant::Light => "Light",
+        ThemeVariant::Dark => "Dark",
+    };
+    app.chat_widget
+        .add_info_message(format!("Theme refreshed ({label} mode)."), None);
+    tui.frame_requester().schedule_frame();
+}
+
 pub(crate) async fn persist_theme_selection(
     app: &mut App,
     tui: &mut Tui,
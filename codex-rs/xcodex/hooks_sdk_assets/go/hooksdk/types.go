@@ -22,10 +22,15 @@ type HookPayload struct {
 	ApprovalPolicy any `json:"approval_policy"`
 	Attempt *int `json:"attempt"`
 	CallId *string `json:"call_id"`
+	Category *NotificationCategory `json:"category"`
 	Command []string `json:"command"`
 	Cwd string `json:"cwd"`
 	DurationMs *int `json:"duration_ms"`
 	EventId string `json:"event_id"`
+	GitBranch *string `json:"git_branch"`
+	GitCommit *string `json:"git_commit"`
+	GitDirty *bool `json:"git_dirty"`
+	GitRemote *string `json:"git_remote"`
 	GrantRoot *string `json:"grant_root"`
 	HasOutputSchema *bool `json:"has_output_schema"`
 	HookEventName string `json:"hook_event_name"`
@@ -34,6 +39,7 @@ type HookPayload struct {
 	Kind *string `json:"kind"`
 	LastAssistantMessage *string `json:"last_assistant_message"`
 	Message *string `json:"message"`
+	MessagesDropped *int `json:"messages_dropped"`
 	Model *string `json:"model"`
 	ModelRequestId *string `json:"model_request_id"`
 	NeedsFollowUp *bool `json:"needs_follow_up"`
@@ -43,23 +49,31 @@ type HookPayload struct {
 	ParallelToolCalls *bool `json:"parallel_tool_calls"`
 	Paths []string `json:"paths"`
 	PermissionMode string `json:"permission_mode"`
+	Priority *NotificationPriority `json:"priority"`
 	Prompt *string `json:"prompt"`
 	ProposedExecpolicyAmendment []string `json:"proposed_execpolicy_amendment"`
 	Provider *string `json:"provider"`
+	RateLimits any `json:"rate_limits"`
 	Reason *string `json:"reason"`
 	RequestId *string `json:"request_id"`
 	ResponseId *string `json:"response_id"`
+	ResultSummary *string `json:"result_summary"`
 	SandboxPolicy any `json:"sandbox_policy"`
 	SchemaVersion int `json:"schema_version"`
 	ServerName *string `json:"server_name"`
 	SessionId string `json:"session_id"`
 	SessionSource *string `json:"session_source"`
+	SessionStats any `json:"session_stats"`
 	Status *string `json:"status"`
 	Subagent *string `json:"subagent"`
+	SubagentId *string `json:"subagent_id"`
+	SubagentName *string `json:"subagent_name"`
 	Success *bool `json:"success"`
 	Timestamp string `json:"timestamp"`
 	Title *string `json:"title"`
 	TokenUsage any `json:"token_usage"`
+	TokensAfter *int `json:"tokens_after"`
+	TokensBefore *int `json:"tokens_before"`
 	ToolCount *int `json:"tool_count"`
 	ToolInput any `json:"tool_input"`
 	ToolName *string `json:"tool_name"`
@@ -96,3 +110,19 @@ func ParseHookPayload(data []byte) (*HookPayload, error) {
 	}
 	return &p, nil
 }
+
+// Routing category for `notification` events, so hooks can branch without parsing text.
+type NotificationCategory string
+
+const (
+	NotificationCategoryInfo NotificationCategory = "info"
+	NotificationCategoryApprovalNeeded NotificationCategory = "approval-needed"
+)
+
+type NotificationPriority string
+
+const (
+	NotificationPriorityLow NotificationPriority = "low"
+	NotificationPriorityNormal NotificationPriority = "normal"
+	NotificationPriorityHigh NotificationPriority = "high"
+)
@@ -18,10 +18,15 @@ export type HookPayload = {
   approval_policy?: "untrusted" | "on-failure" | "on-request" | "never" | null;
   attempt?: null | number;
   call_id?: null | string;
+  category?: "info" | "approval-needed" | null;
   command?: null | string[];
   cwd: string;
   duration_ms?: null | number;
   event_id: string;
+  git_branch?: null | string;
+  git_commit?: null | string;
+  git_dirty?: boolean | null;
+  git_remote?: null | string;
   grant_root?: null | string;
   has_output_schema?: boolean | null;
   hook_event_name: string;
@@ -30,6 +35,7 @@ export type HookPayload = {
   kind?: null | string;
   last_assistant_message?: null | string;
   message?: null | string;
+  messages_dropped?: null | number;
   model?: null | string;
   model_request_id?: null | string;
   needs_follow_up?: boolean | null;
@@ -39,23 +45,31 @@ export type HookPayload = {
   parallel_tool_calls?: boolean | null;
   paths?: null | string[];
   permission_mode: string;
+  priority?: "low" | "normal" | "high" | null;
   prompt?: null | string;
   proposed_execpolicy_amendment?: null | string[];
   provider?: null | string;
+  rate_limits?: null | unknown;
   reason?: null | string;
   request_id?: null | string;
   response_id?: null | string;
+  result_summary?: null | string;
   sandbox_policy?: null | unknown;
   schema_version: number;
   server_name?: null | string;
   session_id: string;
   session_source?: null | string;
+  session_stats?: null | unknown;
   status?: null | string;
   subagent?: null | string;
+  subagent_id?: null | string;
+  subagent_name?: null | string;
   success?: boolean | null;
   timestamp: string;
   title?: null | string;
   token_usage?: null | unknown;
+  tokens_after?: null | number;
+  tokens_before?: null | number;
   tool_count?: null | number;
   tool_input?: unknown;
   tool_name?: null | string;
@@ -16,13 +16,23 @@ Usage (example):
 
 Protocol (v1):
 
-  One JSON object per line. For hook events:
+  One JSON object per line. On startup xcodex sends a handshake:
+    {"type":"hello","supported_schema_versions":[1]}
+  and the host replies on stdout with the payload schema version it understands:
+    {"type":"hello","schema_version":1}
+  This host replies before importing the hook module, so slow imports do not delay it.
+
+  When `hooks.host.ready_timeout_sec` is set, xcodex then sends {"type":"ping"} and waits for
+  {"type":"pong"} before streaming events. This host pongs once the hook module is imported.
+
+  For hook events:
     {"schema_version":1,"type":"hook-event","seq":123,"event":{...hook payload...}}
 
 The `event` payload uses the same schema as external hooks (see `docs/xcodex/hooks.md`).
 """
 
 import importlib.util
+import itertools
 import json
 import pathlib
 import sys
@@ -30,6 +40,8 @@ import traceback
 from types import ModuleType
 from typing import Any, Dict
 
+SCHEMA_VERSION = 1
+
 
 def _load_module_from_path(path: str) -> ModuleType:
     module_path = pathlib.Path(path)
@@ -53,6 +65,19 @@ def _resolve_event_payload(event_or_envelope: Any) -> Dict[str, Any]:
     return {}
 
 
+def _is_hello(raw_line: str) -> bool:
+    try:
+        msg = json.loads(raw_line)
+    except Exception:
+        return False
+    return isinstance(msg, dict) and msg.get("type") == "hello"
+
+
+def _reply_hello() -> None:
+    sys.stdout.write(json.dumps({"type": "hello", "schema_version": SCHEMA_VERSION}) + "\n")
+    sys.stdout.flush()
+
+
 def main() -> int:
     if len(sys.argv) != 2:
         sys.stderr.write(
@@ -61,6 +86,13 @@ def main() -> int:
         )
         return 2
 
+    # Answer the handshake before importing the hook module so slow imports do not make xcodex
+    # give up waiting for the `hello` reply.
+    first_line = sys.stdin.readline()
+    if _is_hello(first_line):
+        _reply_hello()
+        first_line = ""
+
     module_path = sys.argv[1]
     module = _load_module_from_path(module_path)
     on_event = getattr(module, "on_event", None)
@@ -68,7 +100,7 @@ def main() -> int:
         sys.stderr.write(f"{module_path} must define a callable on_event(event: dict)\n")
         return 2
 
-    for raw_line in sys.stdin:
+    for raw_line in itertools.chain([first_line], sys.stdin):
         raw_line = raw_line.strip()
         if not raw_line:
             continue
@@ -80,6 +112,15 @@ def main() -> int:
             sys.stderr.write(raw_line + "\n")
             continue
 
+        if msg.get("type") == "hello":
+            _reply_hello()
+            continue
+
+        if msg.get("type") == "ping":
+            sys.stdout.write(json.dumps({"type": "pong"}) + "\n")
+            sys.stdout.flush()
+            continue
+
         if msg.get("type") != "hook-event":
             continue
 
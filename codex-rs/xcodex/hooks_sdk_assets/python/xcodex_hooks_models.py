@@ -96,8 +96,13 @@ class HookPayload:
     approval_policy: Optional[Any] = None
     attempt: Optional[Any] = None
     call_id: Optional[Any] = None
+    category: Optional[Any] = None
     command: Optional[Any] = None
     duration_ms: Optional[Any] = None
+    git_branch: Optional[Any] = None
+    git_commit: Optional[Any] = None
+    git_dirty: Optional[Any] = None
+    git_remote: Optional[Any] = None
     grant_root: Optional[Any] = None
     has_output_schema: Optional[Any] = None
     input_item_count: Optional[Any] = None
@@ -105,6 +110,7 @@ class HookPayload:
     kind: Optional[Any] = None
     last_assistant_message: Optional[Any] = None
     message: Optional[Any] = None
+    messages_dropped: Optional[Any] = None
     model: Optional[Any] = None
     model_request_id: Optional[Any] = None
     needs_follow_up: Optional[Any] = None
@@ -113,20 +119,28 @@ class HookPayload:
     output_preview: Optional[Any] = None
     parallel_tool_calls: Optional[Any] = None
     paths: Optional[Any] = None
+    priority: Optional[Any] = None
     prompt: Optional[Any] = None
     proposed_execpolicy_amendment: Optional[Any] = None
     provider: Optional[Any] = None
+    rate_limits: Optional[Any] = None
     reason: Optional[Any] = None
     request_id: Optional[Any] = None
     response_id: Optional[Any] = None
+    result_summary: Optional[Any] = None
     sandbox_policy: Optional[Any] = None
     server_name: Optional[Any] = None
     session_source: Optional[Any] = None
+    session_stats: Optional[Any] = None
     status: Optional[Any] = None
     subagent: Optional[Any] = None
+    subagent_id: Optional[Any] = None
+    subagent_name: Optional[Any] = None
     success: Optional[Any] = None
     title: Optional[Any] = None
     token_usage: Optional[Any] = None
+    tokens_after: Optional[Any] = None
+    tokens_before: Optional[Any] = None
     tool_count: Optional[Any] = None
     tool_input: Optional[Any] = None
     tool_name: Optional[Any] = None
@@ -146,10 +160,15 @@ def parse_hook_payload(payload: Mapping[str, Any]) -> HookPayload:
         "approval_policy",
         "attempt",
         "call_id",
+        "category",
         "command",
         "cwd",
         "duration_ms",
         "event_id",
+        "git_branch",
+        "git_commit",
+        "git_dirty",
+        "git_remote",
         "grant_root",
         "has_output_schema",
         "hook_event_name",
@@ -158,6 +177,7 @@ def parse_hook_payload(payload: Mapping[str, Any]) -> HookPayload:
         "kind",
         "last_assistant_message",
         "message",
+        "messages_dropped",
         "model",
         "model_request_id",
         "needs_follow_up",
@@ -167,23 +187,31 @@ def parse_hook_payload(payload: Mapping[str, Any]) -> HookPayload:
         "parallel_tool_calls",
         "paths",
         "permission_mode",
+        "priority",
         "prompt",
         "proposed_execpolicy_amendment",
         "provider",
+        "rate_limits",
         "reason",
         "request_id",
         "response_id",
+        "result_summary",
         "sandbox_policy",
         "schema_version",
         "server_name",
         "session_id",
         "session_source",
+        "session_stats",
         "status",
         "subagent",
+        "subagent_id",
+        "subagent_name",
         "success",
         "timestamp",
         "title",
         "token_usage",
+        "tokens_after",
+        "tokens_before",
         "tool_count",
         "tool_input",
         "tool_name",
@@ -200,10 +228,15 @@ def parse_hook_payload(payload: Mapping[str, Any]) -> HookPayload:
         approval_policy=lambda x: x(raw.get("approval_policy")),
         attempt=lambda x: x(raw.get("attempt")),
         call_id=lambda x: x(raw.get("call_id")),
+        category=lambda x: x(raw.get("category")),
         command=lambda x: x(raw.get("command")),
         cwd=_as_str(raw.get("cwd")),
         duration_ms=lambda x: x(raw.get("duration_ms")),
         event_id=_as_str(raw.get("event_id")),
+        git_branch=lambda x: x(raw.get("git_branch")),
+        git_commit=lambda x: x(raw.get("git_commit")),
+        git_dirty=lambda x: x(raw.get("git_dirty")),
+        git_remote=lambda x: x(raw.get("git_remote")),
         grant_root=lambda x: x(raw.get("grant_root")),
         has_output_schema=lambda x: x(raw.get("has_output_schema")),
         hook_event_name=_as_str(raw.get("hook_event_name")),
@@ -212,6 +245,7 @@ def parse_hook_payload(payload: Mapping[str, Any]) -> HookPayload:
         kind=lambda x: x(raw.get("kind")),
         last_assistant_message=lambda x: x(raw.get("last_assistant_message")),
         message=lambda x: x(raw.get("message")),
+        messages_dropped=lambda x: x(raw.get("messages_dropped")),
         model=lambda x: x(raw.get("model")),
         model_request_id=lambda x: x(raw.get("model_request_id")),
         needs_follow_up=lambda x: x(raw.get("needs_follow_up")),
@@ -221,23 +255,31 @@ def parse_hook_payload(payload: Mapping[str, Any]) -> HookPayload:
         parallel_tool_calls=lambda x: x(raw.get("parallel_tool_calls")),
         paths=lambda x: x(raw.get("paths")),
         permission_mode=_as_str(raw.get("permission_mode")),
+        priority=lambda x: x(raw.get("priority")),
         prompt=lambda x: x(raw.get("prompt")),
         proposed_execpolicy_amendment=lambda x: x(raw.get("proposed_execpolicy_amendment")),
         provider=lambda x: x(raw.get("provider")),
+        rate_limits=lambda x: x(raw.get("rate_limits")),
         reason=lambda x: x(raw.get("reason")),
         request_id=lambda x: x(raw.get("request_id")),
         response_id=lambda x: x(raw.get("response_id")),
+        result_summary=lambda x: x(raw.get("result_summary")),
         sandbox_policy=lambda x: x(raw.get("sandbox_policy")),
         schema_version=_as_int(raw.get("schema_version")),
         server_name=lambda x: x(raw.get("server_name")),
         session_id=_as_str(raw.get("session_id")),
         session_source=lambda x: x(raw.get("session_source")),
+        session_stats=lambda x: x(raw.get("session_stats")),
         status=lambda x: x(raw.get("status")),
         subagent=lambda x: x(raw.get("subagent")),
+        subagent_id=lambda x: x(raw.get("subagent_id")),
+        subagent_name=lambda x: x(raw.get("subagent_name")),
         success=lambda x: x(raw.get("success")),
         timestamp=_as_str(raw.get("timestamp")),
         title=lambda x: x(raw.get("title")),
         token_usage=lambda x: x(raw.get("token_usage")),
+        tokens_after=lambda x: x(raw.get("tokens_after")),
+        tokens_before=lambda x: x(raw.get("tokens_before")),
         tool_count=lambda x: x(raw.get("tool_count")),
         tool_input=lambda x: x(raw.get("tool_input")),
         tool_name=lambda x: x(raw.get("tool_name")),
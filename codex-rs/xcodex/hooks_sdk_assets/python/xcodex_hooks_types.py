@@ -37,10 +37,15 @@ HookPayload = TypedDict(
         "approval_policy": NotRequired[Union[None, Union[Literal["never"], Literal["on-failure"], Literal["on-request"], Literal["untrusted"]]]],
         "attempt": NotRequired[Union[None, int]],
         "call_id": NotRequired[Union[None, str]],
+        "category": NotRequired[Union[None, Union[Literal["approval-needed"], Literal["info"]]]],
         "command": NotRequired[Union[List[str], None]],
         "cwd": Required[str],
         "duration_ms": NotRequired[Union[None, int]],
         "event_id": Required[str],
+        "git_branch": NotRequired[Union[None, str]],
+        "git_commit": NotRequired[Union[None, str]],
+        "git_dirty": NotRequired[Union[None, bool]],
+        "git_remote": NotRequired[Union[None, str]],
         "grant_root": NotRequired[Union[None, str]],
         "has_output_schema": NotRequired[Union[None, bool]],
         "hook_event_name": Required[str],
@@ -49,6 +54,7 @@ HookPayload = TypedDict(
         "kind": NotRequired[Union[None, str]],
         "last_assistant_message": NotRequired[Union[None, str]],
         "message": NotRequired[Union[None, str]],
+        "messages_dropped": NotRequired[Union[None, int]],
         "model": NotRequired[Union[None, str]],
         "model_request_id": NotRequired[Union[None, str]],
         "needs_follow_up": NotRequired[Union[None, bool]],
@@ -58,23 +64,31 @@ HookPayload = TypedDict(
         "parallel_tool_calls": NotRequired[Union[None, bool]],
         "paths": NotRequired[Union[List[str], None]],
         "permission_mode": Required[str],
+        "priority": NotRequired[Union[None, Union[Literal["high"], Literal["low"], Literal["normal"]]]],
         "prompt": NotRequired[Union[None, str]],
         "proposed_execpolicy_amendment": NotRequired[Union[List[str], None]],
         "provider": NotRequired[Union[None, str]],
+        "rate_limits": NotRequired[Union[Any, None]],
         "reason": NotRequired[Union[None, str]],
         "request_id": NotRequired[Union[None, str]],
         "response_id": NotRequired[Union[None, str]],
+        "result_summary": NotRequired[Union[None, str]],
         "sandbox_policy": NotRequired[Union[Any, None]],
         "schema_version": Required[int],
         "server_name": NotRequired[Union[None, str]],
         "session_id": Required[str],
         "session_source": NotRequired[Union[None, str]],
+        "session_stats": NotRequired[Union[Any, None]],
         "status": NotRequired[Union[None, str]],
         "subagent": NotRequired[Union[None, str]],
+        "subagent_id": NotRequired[Union[None, str]],
+        "subagent_name": NotRequired[Union[None, str]],
         "success": NotRequired[Union[None, bool]],
         "timestamp": Required[str],
         "title": NotRequired[Union[None, str]],
         "token_usage": NotRequired[Union[Any, None]],
+        "tokens_after": NotRequired[Union[None, int]],
+        "tokens_before": NotRequired[Union[None, int]],
         "tool_count": NotRequired[Union[None, int]],
         "tool_input": NotRequired[Any],
         "tool_name": NotRequired[Union[None, str]],
@@ -109,6 +109,9 @@ use crate::history_cell::WhatsNewHistoryCell;
 pub struct AppExitInfo {
     pub token_usage: TokenUsage,
     pub conversation_id: Option<ThreadId>,
+    /// False when the session was not persisted (for example `ephemeral`), so there is nothing
+    /// to resume and the exit summary omits the resume hint.
+    pub resumable: bool,
     pub update_action: Option<UpdateAction>,
     pub exit_reason: ExitReason,
     /// ANSI-styled transcript lines to print after the TUI exits.
@@ -141,6 +144,7 @@ impl From<AppExitInfo> for codex_tui::AppExitInfo {
             token_usage: info.token_usage,
             thread_id: info.conversation_id,
             thread_name: None,
+            resumable: info.resumable,
             update_action: info.update_action.map(Into::into),
             exit_reason,
         }
@@ -355,6 +359,7 @@ async fn handle_model_migration_prompt_if_needed(
                 return Some(AppExitInfo {
                     token_usage: TokenUsage::default(),
                     conversation_id: None,
+                    resumable: false,
                     update_action: None,
                     exit_reason: ExitReason::UserRequested,
                     session_lines: Vec::new(),
@@ -679,6 +684,7 @@ impl App {
                 return Ok(AppExitInfo {
                     token_usage: app.token_usage(),
                     conversation_id: app.effective_conversation_id(),
+                    resumable: !app.config.ephemeral,
                     update_action: app.pending_update_action,
                     exit_reason,
                     session_lines: Vec::new(),
@@ -755,6 +761,7 @@ impl App {
         Ok(AppExitInfo {
             token_usage: app.token_usage(),
             conversation_id: app.effective_conversation_id(),
+            resumable: !app.config.ephemeral,
             update_action: app.pending_update_action,
             exit_reason,
             session_lines,
@@ -2003,6 +2010,9 @@ impl App {
             AppEvent::CommitTick => {
                 self.chat_widget.on_commit_tick();
             }
+            AppEvent::RequestUserInputTimeout { call_id } => {
+                self.chat_widget.on_request_user_input_timeout(&call_id);
+            }
             AppEvent::CodexEvent(event) => {
                 if let EventMsg::SessionConfigured(session) = &event.msg {
                     self.last_known_conversation_id = Some(session.session_id);
@@ -291,6 +291,12 @@ pub(crate) enum AppEvent {
     StopCommitAnimation,
     CommitTick,
 
+    /// The `timeout_sec` of a request_user_input prompt elapsed; the overlay
+    /// showing `call_id` submits its current answers.
+    RequestUserInputTimeout {
+        call_id: String,
+    },
+
     /// Update the current reasoning effort in the running app and widget.
     UpdateReasoningEffort(Option<ReasoningEffort>),
 
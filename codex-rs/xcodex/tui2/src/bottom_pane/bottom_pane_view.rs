@@ -56,6 +56,10 @@ pub(crate) trait BottomPaneView: Renderable {
         Some(request)
     }
 
+    /// Called when the `timeout_sec` of the request_user_input prompt with
+    /// `call_id` elapses.
+    fn on_user_input_timeout(&mut self, _call_id: &str) {}
+
     /// Forward asynchronous file-search results to views that provide `@` search UI.
     fn on_file_search_result(&mut self, _query: String, _matches: Vec<FileMatch>) {}
 }
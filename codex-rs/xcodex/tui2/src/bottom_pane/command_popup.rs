@@ -89,7 +89,7 @@ impl CommandPopup {
             .filter(|(_, cmd)| skills_enabled || *cmd != SlashCommand::Skills)
             .filter(|(_, cmd)| allow_elevate_sandbox || *cmd != SlashCommand::ElevateSandbox)
             .collect();
-        let plugin_commands: Vec<PluginSlashCommand> = plugin_slash_commands().to_vec();
+        let plugin_commands: Vec<PluginSlashCommand> = plugin_slash_commands().collect();
         // Exclude prompts that collide with builtin command names and sort by name.
         let exclude: HashSet<String> = builtins
             .iter()
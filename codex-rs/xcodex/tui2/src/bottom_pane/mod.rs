@@ -914,6 +914,20 @@ impl BottomPane {
         }
     }
 
+    /// Deliver a request_user_input timeout to every stacked view, since the
+    /// overlay may sit below another modal when it fires.
+    pub(crate) fn on_user_input_timeout(&mut self, call_id: &str) {
+        for view in &mut self.view_stack {
+            view.on_user_input_timeout(call_id);
+        }
+        let views_before = self.view_stack.len();
+        self.view_stack.retain(|view| !view.is_complete());
+        if views_before != self.view_stack.len() && self.view_stack.is_empty() {
+            self.on_active_view_complete();
+        }
+        self.request_redraw();
+    }
+
     pub(crate) fn on_file_search_result(&mut self, query: String, matches: Vec<FileMatch>) {
         if let Some(view) = self.active_view_mut() {
             view.on_file_search_result(query.clone(), matches.clone());
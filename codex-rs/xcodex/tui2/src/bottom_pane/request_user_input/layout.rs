@@ -32,7 +32,11 @@ impl RequestUserInputOverlay {
         let has_options = self.has_options();
         let mut notes_input_height = self.notes_input_height(area.width);
         // Keep the question + options visible first; notes and hints collapse as space shrinks.
-        let footer_lines = if self.unanswered_count() > 0 { 2 } else { 1 };
+        let footer_lines = if self.validation_error.is_some() || self.unanswered_count() > 0 {
+            2
+        } else {
+            1
+        };
         let mut notes_title_height = if has_options { 1 } else { 0 };
 
         let mut cursor_y = area.y;
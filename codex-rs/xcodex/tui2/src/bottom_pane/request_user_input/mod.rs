@@ -8,6 +8,7 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::collections::VecDeque;
+use std::time::Duration;
 
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
@@ -29,6 +30,7 @@ use codex_core::protocol::Op;
 use codex_protocol::request_user_input::RequestUserInputAnswer;
 use codex_protocol::request_user_input::RequestUserInputEvent;
 use codex_protocol::request_user_input::RequestUserInputResponse;
+use regex_lite::Regex;
 
 const NOTES_PLACEHOLDER: &str = "Add notes (optional)";
 const ANSWER_PLACEHOLDER: &str = "Type your answer (optional)";
@@ -85,6 +87,8 @@ pub(crate) struct RequestUserInputOverlay {
     done: bool,
     confirm_unanswered: Option<ScrollState>,
     confirm_review: Option<ScrollState>,
+    // Message from the last failed answer validation, cleared on edit or navigation.
+    validation_error: Option<String>,
 }
 
 impl RequestUserInputOverlay {
@@ -99,6 +103,7 @@ impl RequestUserInputOverlay {
             done: false,
             confirm_unanswered: None,
             confirm_review: None,
+            validation_error: None,
         };
         overlay.reset_for_request();
         overlay.ensure_focus_available();
@@ -223,34 +228,100 @@ impl RequestUserInputOverlay {
             .iter()
             .map(|question| {
                 let mut option_state = ScrollState::new();
-                if let Some(options) = question.options.as_ref()
-                    && !options.is_empty()
-                {
+                let options_len = question.options.as_ref().map_or(0, Vec::len);
+                if options_len > 0 {
                     option_state.selected_idx = Some(0);
                 }
+                // A valid default starts out selected, so the question already counts as answered.
+                let selected_option_indices = match question.default_option_index {
+                    Some(idx) if idx < options_len => {
+                        option_state.selected_idx = Some(idx);
+                        vec![idx]
+                    }
+                    Some(_) | None => Vec::new(),
+                };
                 AnswerState {
                     option_state,
-                    selected_option_indices: Vec::new(),
+                    selected_option_indices,
                     notes: NotesEntry::new(),
                     answer_committed: false,
                 }
             })
             .collect();
 
-        self.current_idx = 0;
+        // Start on the first question whose `show_if` is already satisfied (e.g. by defaults).
+        self.current_idx = (0..self.question_count())
+            .find(|idx| self.is_question_visible(*idx))
+            .unwrap_or(0);
         self.focus = Focus::Options;
         self.confirm_unanswered = None;
         self.confirm_review = None;
+        self.validation_error = None;
+
+        // Without a runtime (unit tests) no timer runs; the tick can still be
+        // delivered through `on_user_input_timeout`.
+        if let Some(timeout_sec) = self.request.timeout_sec
+            && let Ok(handle) = tokio::runtime::Handle::try_current()
+        {
+            let tx = self.app_event_tx.clone();
+            let call_id = self.request.call_id.clone();
+            handle.spawn(async move {
+                tokio::time::sleep(Duration::from_secs(u64::from(timeout_sec))).await;
+                tx.send(AppEvent::RequestUserInputTimeout { call_id });
+            });
+        }
     }
 
+    /// Whether the question's `show_if` condition (and those of the questions
+    /// it depends on) is satisfied by the current answers.
+    fn is_question_visible(&self, idx: usize) -> bool {
+        let mut idx = idx;
+        // Each step follows one condition, so a cycle is cut off after visiting
+        // every question once.
+        for _ in 0..=self.question_count() {
+            let Some(question) = self.request.questions.get(idx) else {
+                return false;
+            };
+            let Some(condition) = question.show_if.as_ref() else {
+                return true;
+            };
+            let Some(parent_idx) = self
+                .request
+                .questions
+                .iter()
+                .position(|q| q.id == condition.question_id)
+            else {
+                return false;
+            };
+            let Some(answer) = self.answers.get(parent_idx) else {
+                return false;
+            };
+            let parent_options = self.request.questions[parent_idx].options.as_ref();
+            let option_matches = answer
+                .selected_option_indices
+                .iter()
+                .filter_map(|option_idx| parent_options.and_then(|opts| opts.get(*option_idx)))
+                .any(|option| option.label == condition.equals);
+            if !option_matches && answer.notes.text.text().trim() != condition.equals {
+                return false;
+            }
+            idx = parent_idx;
+        }
+        false
+    }
+
+    /// Move to the next/previous visible question, staying put at either end.
     fn move_question(&mut self, forward: bool) {
         let next = if forward {
-            self.current_idx.saturating_add(1)
+            (self.current_idx + 1..self.question_count()).find(|idx| self.is_question_visible(*idx))
         } else {
-            self.current_idx.saturating_sub(1)
+            (0..self.current_idx)
+                .rev()
+                .find(|idx| self.is_question_visible(*idx))
         };
-        if next < self.question_count() {
+        if let Some(next) = next {
             self.current_idx = next;
+            self.refresh_validation_error();
             self.ensure_focus_available();
         }
     }
@@ -258,6 +329,7 @@ impl RequestUserInputOverlay {
     fn jump_to_question(&mut self, idx: usize) {
         if idx < self.question_count() {
             self.current_idx = idx;
+            self.refresh_validation_error();
             self.ensure_focus_available();
         }
     }
@@ -299,19 +371,90 @@ impl RequestUserInputOverlay {
         if has_options {
             self.ensure_default_option_selected();
         }
-
+        let valid = self
+            .validation_error_for_question(self.current_index())
+            .is_none();
         if let Some(answer) = self.current_answer_mut() {
             answer.answer_committed = if has_options {
                 !answer.selected_option_indices.is_empty()
             } else {
-                !answer.notes.text.text().trim().is_empty()
+                valid && !answer.notes.text.text().trim().is_empty()
             };
         }
     }
 
+    fn notes_text_for_question(&self, idx: usize) -> String {
+        self.answers
+            .get(idx)
+            .map(|answer| answer.notes.text.text().trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Check a freeform answer against its question's `validation`.
+    fn validation_error_for_question(&self, idx: usize) -> Option<String> {
+        let question = self.request.questions.get(idx)?;
+        if question
+            .options
+            .as_ref()
+            .is_some_and(|options| !options.is_empty())
+        {
+            return None;
+        }
+        let validation = question.validation.as_ref()?;
+        let text = self.notes_text_for_question(idx);
+        let len = text.chars().count();
+        let problem = if text.is_empty() {
+            validation
+                .min_len
+                .filter(|min_len| *min_len > 0)
+                .map(|_| "An answer is required.".to_string())
+        } else if let Some(min_len) = validation.min_len.filter(|min_len| len < *min_len) {
+            Some(format!("Answer must be at least {min_len} characters."))
+        } else if let Some(max_len) = validation.max_len.filter(|max_len| len > *max_len) {
+            Some(format!("Answer must be at most {max_len} characters."))
+        } else {
+            validation
+                .pattern
+                .as_deref()
+                .and_then(|pattern| match Regex::new(pattern) {
+                    Ok(regex) => {
+                        (!regex.is_match(&text)).then(|| format!("Answer must match `{pattern}`."))
+                    }
+                    Err(err) => {
+                        // A bad pattern from the model should not trap the user.
+                        tracing::warn!(
+                            "ignoring invalid request_user_input pattern {pattern:?}: {err}"
+                        );
+                        None
+                    }
+                })
+        };
+        problem.map(|problem| validation.error_message.clone().unwrap_or(problem))
+    }
+
+    /// Re-check the question just navigated to, so its message reflects its
+    /// saved answer. Empty answers are only flagged when the user advances.
+    fn refresh_validation_error(&mut self) {
+        let idx = self.current_index();
+        self.validation_error = if self.notes_text_for_question(idx).is_empty() {
+            None
+        } else {
+            self.validation_error_for_question(idx)
+        };
+    }
+
+    fn first_invalid_index(&self) -> Option<usize> {
+        (0..self.question_count()).find(|idx| {
+            self.is_question_visible(*idx) && self.validation_error_for_question(*idx).is_some()
+        })
+    }
+
     fn collect_answers(&self) -> HashMap<String, RequestUserInputAnswer> {
         let mut answers = HashMap::new();
         for (idx, question) in self.request.questions.iter().enumerate() {
+            if !self.is_question_visible(idx) {
+                continue;
+            }
             let answer_state = &self.answers[idx];
             let options = question.options.as_ref();
             let selected_indices = if options.is_some_and(|opts| !opts.is_empty())
@@ -354,7 +497,9 @@ impl RequestUserInputOverlay {
         self.request
             .questions
             .iter()
-            .map(|question| {
+            .enumerate()
+            .filter(|(idx, _)| self.is_question_visible(*idx))
+            .map(|(_, question)| {
                 let answer_values = answers
                     .get(&question.id)
                     .map(|answer| answer.answers.clone())
@@ -459,7 +604,7 @@ impl RequestUserInputOverlay {
             .questions
             .iter()
             .enumerate()
-            .find(|(idx, _)| !self.is_question_answered(*idx))
+            .find(|(idx, _)| self.is_question_visible(*idx) && !self.is_question_answered(*idx))
             .map(|(idx, _)| idx)
     }
 
@@ -519,13 +664,13 @@ impl RequestUserInputOverlay {
         }
     }
 
-    /// Count questions that would submit an empty answer list.
+    /// Count visible questions that would submit an empty answer list.
     fn unanswered_count(&self) -> usize {
         self.request
             .questions
             .iter()
             .enumerate()
-            .filter(|(idx, _)| !self.is_question_answered(*idx))
+            .filter(|(idx, _)| self.is_question_visible(*idx) && !self.is_question_answered(*idx))
             .count()
     }
 
@@ -539,15 +684,25 @@ impl RequestUserInputOverlay {
         text_height.saturating_add(2).clamp(3, 8)
     }
 
+    /// Advance to the next visible question, or confirm when there is none left.
+    /// Stays on the current question while its answer fails validation, and
+    /// jumps back to the first invalid answer instead of confirming.
     fn go_next_or_submit(&mut self) {
-        if self.current_index() + 1 >= self.question_count() {
-            if self.unanswered_count() > 0 {
-                self.open_unanswered_confirmation();
-            } else {
-                self.open_review_confirmation();
-            }
+        self.validation_error = self.validation_error_for_question(self.current_index());
+        if self.validation_error.is_some() {
+            return;
+        }
+        let next_visible = (self.current_index() + 1..self.question_count())
+            .find(|idx| self.is_question_visible(*idx));
+        if let Some(idx) = next_visible {
+            self.jump_to_question(idx);
+        } else if let Some(idx) = self.first_invalid_index() {
+            self.jump_to_question(idx);
+            self.validation_error = self.validation_error_for_question(idx);
+        } else if self.unanswered_count() > 0 {
+            self.open_unanswered_confirmation();
         } else {
-            self.move_question(true);
+            self.open_review_confirmation();
         }
     }
 
@@ -743,7 +898,11 @@ impl BottomPaneView for RequestUserInputOverlay {
                     answer.answer_committed = false;
                 }
                 if let Some(entry) = self.current_notes_entry_mut() {
+                    let before = entry.text.text().to_string();
                     entry.text.input(key_event);
+                    if entry.text.text() != before {
+                        self.validation_error = None;
+                    }
                 }
             }
         }
@@ -770,6 +929,39 @@ impl BottomPaneView for RequestUserInputOverlay {
         self.done
     }
 
+    fn on_user_input_timeout(&mut self, call_id: &str) {
+        // Ignore timers for requests that were already answered.
+        if self.done || self.request.call_id != call_id {
+            return;
+        }
+        // Answers the prompt would reject fall back to the question's default
+        // (no answer), and the user is told which ones were dropped.
+        let invalid = (0..self.question_count())
+            .filter(|idx| {
+                self.is_question_visible(*idx) && self.validation_error_for_question(*idx).is_some()
+            })
+            .collect::<Vec<_>>();
+        if !invalid.is_empty() {
+            let headers = invalid
+                .iter()
+                .map(|idx| self.request.questions[*idx].header.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            for idx in &invalid {
+                if let Some(answer) = self.answers.get_mut(*idx) {
+                    answer.notes = NotesEntry::new();
+                    answer.answer_committed = false;
+                }
+            }
+            self.app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
+                crate::history_cell::new_warning_event(format!(
+                    "Input request timed out; submitted without the invalid answers to: {headers}"
+                )),
+            )));
+        }
+        self.submit_answers();
+    }
+
     fn handle_paste(&mut self, pasted: String) -> bool {
         if pasted.is_empty() {
             return false;
@@ -780,6 +972,7 @@ impl BottomPaneView for RequestUserInputOverlay {
         if let Some(answer) = self.current_answer_mut() {
             answer.answer_committed = false;
         }
+        self.validation_error = None;
         if let Some(entry) = self.current_notes_entry_mut() {
             entry.text.insert_str(&pasted);
             return true;
@@ -837,6 +1030,7 @@ mod tests {
                     description: "Third choice.".to_string(),
                 },
             ]),
+            ..Default::default()
         }
     }
 
@@ -848,6 +1042,7 @@ mod tests {
             call_id: "call-1".to_string(),
             turn_id: turn_id.to_string(),
             questions,
+            timeout_sec: None,
         }
     }
 
@@ -862,7 +1057,265 @@ mod tests {
             is_other: false,
             is_secret: false,
             options: None,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn show_if_reveals_and_hides_dependent_question() {
+        let (tx, _rx) = test_sender();
+        let mut dependent = question_with_options("q2", "Follow-up");
+        dependent.show_if = Some(
+            codex_protocol::request_user_input::RequestUserInputCondition {
+                question_id: "q1".to_string(),
+                equals: "Option 1".to_string(),
+            },
+        );
+        let mut overlay = RequestUserInputOverlay::new(
+            request_event(
+                "turn-1",
+                vec![
+                    question_with_options("q1", "Pick one"),
+                    dependent,
+                    question_with_options("q3", "Last"),
+                ],
+            ),
+            tx,
+        );
+
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Char('1')));
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(overlay.current_index(), 1);
+        assert_eq!(overlay.unanswered_count(), 2);
+
+        overlay.jump_to_question(0);
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Char('2')));
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(overlay.current_index(), 2);
+        assert_eq!(overlay.unanswered_count(), 1);
+
+        overlay.move_question(false);
+        assert_eq!(overlay.current_index(), 0);
+
+        let answers = overlay.collect_answers();
+        assert!(!answers.contains_key("q2"));
+        assert_eq!(
+            answers.get("q1").map(|answer| answer.answers.clone()),
+            Some(vec!["Option 2".to_string()])
+        );
+    }
+
+    #[test]
+    fn initial_question_skips_hidden_questions() {
+        let (tx, _rx) = test_sender();
+        let mut hidden = question_with_options("q1", "Follow-up");
+        hidden.show_if = Some(
+            codex_protocol::request_user_input::RequestUserInputCondition {
+                question_id: "q2".to_string(),
+                equals: "Option 1".to_string(),
+            },
+        );
+        let overlay = RequestUserInputOverlay::new(
+            request_event(
+                "turn-1",
+                vec![hidden, question_with_options("q2", "Pick one")],
+            ),
+            tx,
+        );
+
+        assert_eq!(overlay.current_index(), 1);
+    }
+
+    #[test]
+    fn validation_pattern_mismatch_blocks_advance() {
+        let (tx, _rx) = test_sender();
+        let mut numeric = question_without_options("q1", "Count");
+        numeric.validation = Some(
+            codex_protocol::request_user_input::RequestUserInputValidation {
+                pattern: Some(r"^\d+$".to_string()),
+                ..Default::default()
+            },
+        );
+        let mut overlay = RequestUserInputOverlay::new(
+            request_event(
+                "turn-1",
+                vec![numeric, question_without_options("q2", "Details")],
+            ),
+            tx,
+        );
+
+        for ch in "abc".chars() {
+            overlay.handle_key_event(KeyEvent::from(KeyCode::Char(ch)));
+        }
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Enter));
+
+        assert_eq!(overlay.current_index(), 0);
+        assert_eq!(
+            overlay.validation_error.as_deref(),
+            Some(r"Answer must match `^\d+$`.")
+        );
+        assert!(!overlay.answers[0].answer_committed);
+    }
+
+    #[test]
+    fn validation_passing_answer_advances() {
+        let (tx, _rx) = test_sender();
+        let mut numeric = question_without_options("q1", "Count");
+        numeric.validation = Some(
+            codex_protocol::request_user_input::RequestUserInputValidation {
+                pattern: Some(r"^\d+$".to_string()),
+                max_len: Some(3),
+                error_message: Some("Enter a number.".to_string()),
+                ..Default::default()
+            },
+        );
+        let mut overlay = RequestUserInputOverlay::new(
+            request_event(
+                "turn-1",
+                vec![numeric, question_without_options("q2", "Details")],
+            ),
+            tx,
+        );
+
+        for ch in "1234".chars() {
+            overlay.handle_key_event(KeyEvent::from(KeyCode::Char(ch)));
         }
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(overlay.current_index(), 0);
+        assert_eq!(overlay.validation_error.as_deref(), Some("Enter a number."));
+
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Backspace));
+        assert_eq!(overlay.validation_error, None);
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Enter));
+
+        assert_eq!(overlay.current_index(), 1);
+        assert_eq!(overlay.validation_error, None);
+        assert!(overlay.answers[0].answer_committed);
+    }
+
+    #[test]
+    fn confirm_jumps_back_to_first_invalid_answer() {
+        let (tx, _rx) = test_sender();
+        let mut numeric = question_without_options("q1", "Count");
+        numeric.validation = Some(
+            codex_protocol::request_user_input::RequestUserInputValidation {
+                pattern: Some(r"^\d+$".to_string()),
+                ..Default::default()
+            },
+        );
+        let mut overlay = RequestUserInputOverlay::new(
+            request_event(
+                "turn-1",
+                vec![numeric, question_without_options("q2", "Details")],
+            ),
+            tx,
+        );
+
+        for ch in "abc".chars() {
+            overlay.handle_key_event(KeyEvent::from(KeyCode::Char(ch)));
+        }
+        overlay.move_question(true);
+        assert_eq!(overlay.current_index(), 1);
+        assert_eq!(overlay.validation_error, None);
+
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Char('x')));
+        overlay.handle_key_event(KeyEvent::from(KeyCode::Enter));
+
+        assert_eq!(overlay.current_index(), 0);
+        assert!(!overlay.confirm_review_active());
+        assert!(!overlay.confirm_unanswered_active());
+        assert_eq!(
+            overlay.validation_error.as_deref(),
+            Some(r"Answer must match `^\d+$`.")
+        );
+    }
+
+    #[test]
+    fn default_option_counts_as_answered_without_input() {
+        let (tx, mut rx) = test_sender();
+        let mut with_default = question_with_options("q1", "Pick one");
+        with_default.default_option_index = Some(1);
+        let mut overlay = RequestUserInputOverlay::new(
+            request_event(
+                "turn-1",
+                vec![with_default, question_with_options("q2", "No default")],
+            ),
+            tx,
+        );
+
+        assert!(overlay.is_question_answered(0));
+        assert!(!overlay.is_question_answered(1));
+        assert_eq!(overlay.unanswered_count(), 1);
+        assert_eq!(overlay.selected_option_index(), Some(1));
+
+        overlay.submit_answers();
+        let event = rx.try_recv().expect("expected AppEvent");
+        let AppEvent::CodexOp(Op::UserInputAnswer { response, .. }) = event else {
+            panic!("expected UserInputAnswer");
+        };
+        let answer = response.answers.get("q1").expect("answer missing");
+        assert_eq!(answer.answers, vec!["Option 2".to_string()]);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn timeout_tick_submits_current_answers() {
+        let (tx, mut rx) = test_sender();
+        let mut with_default = question_with_options("q1", "Pick one");
+        with_default.default_option_index = Some(2);
+        let mut request = request_event("turn-1", vec![with_default]);
+        request.timeout_sec = Some(600);
+        let mut overlay = RequestUserInputOverlay::new(request, tx);
+
+        // Paused time auto-advances to the timer, so this returns immediately.
+        let Some(AppEvent::RequestUserInputTimeout { call_id }) = rx.recv().await else {
+            panic!("expected RequestUserInputTimeout");
+        };
+        assert_eq!(call_id, "call-1");
+
+        overlay.on_user_input_timeout("other-call");
+        assert!(
+            rx.try_recv().is_err(),
+            "unexpected AppEvent for another call"
+        );
+        assert!(!overlay.is_complete());
+
+        overlay.on_user_input_timeout(&call_id);
+        let event = rx.try_recv().expect("expected AppEvent");
+        let AppEvent::CodexOp(Op::UserInputAnswer { id, response }) = event else {
+            panic!("expected UserInputAnswer");
+        };
+        assert_eq!(id, "call-1");
+        let answer = response.answers.get("q1").expect("answer missing");
+        assert_eq!(answer.answers, vec!["Option 3".to_string()]);
+        assert!(overlay.is_complete());
+    }
+
+    #[test]
+    fn timeout_drops_invalid_answers_and_reports_them() {
+        let (tx, mut rx) = test_sender();
+        let mut numeric = question_without_options("q1", "Count");
+        numeric.validation = Some(
+            codex_protocol::request_user_input::RequestUserInputValidation {
+                pattern: Some(r"^\d+$".to_string()),
+                ..Default::default()
+            },
+        );
+        let mut overlay = RequestUserInputOverlay::new(request_event("turn-1", vec![numeric]), tx);
+        for ch in "abc".chars() {
+            overlay.handle_key_event(KeyEvent::from(KeyCode::Char(ch)));
+        }
+
+        overlay.on_user_input_timeout("call-1");
+
+        let Ok(AppEvent::InsertHistoryCell(_)) = rx.try_recv() else {
+            panic!("expected a warning history cell");
+        };
+        let Ok(AppEvent::CodexOp(Op::UserInputAnswer { response, .. })) = rx.try_recv() else {
+            panic!("expected UserInputAnswer");
+        };
+        let answer = response.answers.get("q1").expect("answer missing");
+        assert_eq!(answer.answers, Vec::<String>::new());
+        assert!(overlay.is_complete());
     }
 
     #[test]
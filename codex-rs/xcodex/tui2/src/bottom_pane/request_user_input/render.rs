@@ -482,11 +482,16 @@ impl RequestUserInputOverlay {
             .y
             .saturating_add(sections.notes_area.height);
         if sections.footer_lines == 2 {
-            let warning = format!(
-                "Unanswered: {} | Will submit as skipped",
-                self.unanswered_count()
-            );
-            Paragraph::new(Line::from(warning.dim())).render(
+            // A failed validation takes the warning line until the answer is edited.
+            let warning = match &self.validation_error {
+                Some(error) => Span::styled(error.clone(), crate::theme::error_style()),
+                None => format!(
+                    "Unanswered: {} | Will submit as skipped",
+                    self.unanswered_count()
+                )
+                .dim(),
+            };
+            Paragraph::new(Line::from(warning)).render(
                 Rect {
                     x: area.x,
                     y: footer_y,
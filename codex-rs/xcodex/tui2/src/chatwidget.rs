@@ -3057,24 +3057,6 @@ impl ChatWidget {
             return;
         }
 
-        if image_paths.is_empty()
-            && text.lines().count() == 1
-            && let Some((name, rest)) = parse_slash_name(text.as_str())
-            && name == "help"
-        {
-            xcodex_plugins::help::handle_help_command(self, rest);
-            return;
-        }
-
-        if image_paths.is_empty()
-            && text.lines().count() == 1
-            && let Some((name, rest)) = parse_slash_name(text.as_str())
-            && name == "hooks"
-        {
-            xcodex_plugins::hooks::handle_hooks_command(self, rest);
-            return;
-        }
-
         if image_paths.is_empty()
             && text.lines().count() == 1
             && let Some((name, rest)) = parse_slash_name(text.as_str())
@@ -5831,6 +5813,11 @@ impl ChatWidget {
         }
     }
 
+    /// Forward an elapsed request_user_input timeout to the bottom pane.
+    pub(crate) fn on_request_user_input_timeout(&mut self, call_id: &str) {
+        self.bottom_pane.on_user_input_timeout(call_id);
+    }
+
     /// Forward file-search results to the bottom pane.
     pub(crate) fn apply_file_search_result(&mut self, query: String, matches: Vec<FileMatch>) {
         self.bottom_pane.on_file_search_result(query, matches);
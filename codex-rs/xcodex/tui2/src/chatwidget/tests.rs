@@ -786,6 +786,72 @@ async fn thoughts_slash_command_status_reports_without_updates() {
     assert_snapshot!("thoughts_status_hidden", history_lines.join("\n"));
 }
 
+#[tokio::test]
+async fn help_slash_command_searches_plugin_commands() {
+    let (mut chat, mut rx, _ops) = make_chatwidget_manual(None).await;
+
+    chat.submit_user_message(UserMessage::from("/help thoughts".to_string()));
+
+    let rendered = drain_insert_history(&mut rx)
+        .iter()
+        .map(|lines| lines_to_single_string(lines))
+        .collect::<String>();
+    assert!(
+        rendered.contains("/help thoughts") && rendered.contains("/thoughts — toggle showing"),
+        "expected /help search results, got: {rendered}"
+    );
+}
+
+#[tokio::test]
+async fn hooks_slash_command_routes_subcommands() {
+    let (mut chat, mut rx, _ops) = make_chatwidget_manual(None).await;
+
+    chat.submit_user_message(UserMessage::from("/hooks init".to_string()));
+
+    let rendered = drain_insert_history(&mut rx)
+        .iter()
+        .map(|lines| lines_to_single_string(lines))
+        .collect::<String>();
+    assert!(
+        rendered.contains("Choose a hook mode:"),
+        "expected /hooks init output, got: {rendered}"
+    );
+}
+
+#[tokio::test]
+async fn settings_slash_command_updates_and_persists_transcript_option() {
+    let (mut chat, mut rx, _ops) = make_chatwidget_manual(None).await;
+
+    chat.submit_user_message(UserMessage::from(
+        "/settings transcript side-by-side off".to_string(),
+    ));
+
+    let mut update_events = Vec::new();
+    let mut persist_events = Vec::new();
+    while let Ok(ev) = rx.try_recv() {
+        match ev {
+            AppEvent::UpdateTranscriptSideBySide(enabled) => update_events.push(enabled),
+            AppEvent::PersistTranscriptSideBySide(enabled) => persist_events.push(enabled),
+            _ => {}
+        }
+    }
+
+    assert_eq!(update_events, vec![false]);
+    assert_eq!(persist_events, vec![false]);
+}
+
+#[tokio::test]
+async fn exclusion_slash_command_opens_exclusions_menu() {
+    let (mut chat, _rx, _ops) = make_chatwidget_manual(None).await;
+
+    chat.submit_user_message(UserMessage::from("/exclusion".to_string()));
+
+    assert!(
+        !chat.bottom_pane.no_modal_or_popup_active(),
+        "expected the exclusions menu to open"
+    );
+}
+
 #[tokio::test]
 async fn mcp_slash_command_timeout_persists_and_retries() {
     let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual(None).await;
@@ -1916,6 +1982,7 @@ async fn ctrl_c_shutdown_requires_confirmation_when_hooks_running() {
         payload_event_id: uuid::Uuid::nil(),
         event_type: "agent-turn-complete".to_string(),
         command: vec!["echo".to_string(), "hook".to_string()],
+        timeout_ms: None,
     });
 
     chat.handle_key_event(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
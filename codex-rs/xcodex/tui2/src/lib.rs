@@ -30,6 +30,8 @@ use codex_protocol::config_types::SandboxMode;
 use codex_protocol::config_types::WindowsSandboxLevel;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use std::fs::OpenOptions;
+use std::io::IsTerminal;
+use std::path::Path;
 use std::path::PathBuf;
 use tracing::error;
 use tracing_appender::non_blocking;
@@ -122,34 +124,49 @@ use std::io::Write as _;
 
 // (tests access modules directly within the crate)
 
+/// Reads the `--file` prompt, taking it from stdin when `path` is `-`. CRLF/CR are normalized
+/// to LF so file contents can't leak `\r` into TUI state.
+fn read_prompt_file(
+    path: &Path,
+    read_stdin: impl FnOnce() -> std::io::Result<String>,
+) -> std::io::Result<String> {
+    if path.as_os_str() == "-" {
+        let prompt = read_stdin().map_err(|err| {
+            std::io::Error::other(format!("Failed to read prompt from stdin: {err}"))
+        })?;
+        if prompt.trim().is_empty() {
+            return Err(std::io::Error::other("No prompt provided via stdin."));
+        }
+        return Ok(prompt.replace("\r\n", "\n").replace('\r', "\n"));
+    }
+
+    let prompt = std::fs::read_to_string(path).map_err(|err| {
+        std::io::Error::other(format!(
+            "Failed to read prompt file {}: {err}",
+            path.display()
+        ))
+    })?;
+    if prompt.trim().is_empty() {
+        return Err(std::io::Error::other(format!(
+            "Prompt file {} is empty.",
+            path.display()
+        )));
+    }
+    Ok(prompt.replace("\r\n", "\n").replace('\r', "\n"))
+}
+
 pub async fn run_main(
     mut cli: Cli,
     codex_linux_sandbox_exe: Option<PathBuf>,
 ) -> std::io::Result<AppExitInfo> {
     if let Some(path) = cli.prompt_file.take() {
-        if path.as_os_str() == "-" {
-            return Err(std::io::Error::other(
-                "`--file -` is not supported in the interactive TUI. Pipe the prompt into `codex exec` instead.",
-            ));
-        }
-
-        match std::fs::read_to_string(&path) {
-            Ok(prompt) => {
-                if prompt.trim().is_empty() {
-                    return Err(std::io::Error::other(format!(
-                        "Prompt file {} is empty.",
-                        path.display()
-                    )));
-                }
-                cli.prompt = Some(prompt);
-            }
-            Err(err) => {
-                return Err(std::io::Error::other(format!(
-                    "Failed to read prompt file {}: {err}",
-                    path.display()
-                )));
+        cli.prompt = Some(read_prompt_file(&path, || {
+            let stdin = std::io::stdin();
+            if stdin.is_terminal() {
+                eprintln!("Reading prompt from stdin, end with Ctrl-D...");
             }
-        }
+            std::io::read_to_string(stdin)
+        })?);
     }
 
     let (sandbox_mode, approval_policy) = if cli.full_auto {
@@ -439,6 +456,7 @@ async fn run_ratatui_app(
                     return Ok(AppExitInfo {
                         token_usage: codex_core::protocol::TokenUsage::default(),
                         conversation_id: None,
+                        resumable: false,
                         update_action: Some(action),
                         exit_reason: ExitReason::UserRequested,
                         session_lines: Vec::new(),
@@ -493,6 +511,7 @@ async fn run_ratatui_app(
             return Ok(AppExitInfo {
                 token_usage: codex_core::protocol::TokenUsage::default(),
                 conversation_id: None,
+                resumable: false,
                 update_action: None,
                 exit_reason: ExitReason::UserRequested,
                 session_lines: Vec::new(),
@@ -521,6 +540,7 @@ async fn run_ratatui_app(
         Ok(AppExitInfo {
             token_usage: codex_core::protocol::TokenUsage::default(),
             conversation_id: None,
+            resumable: false,
             update_action: None,
             exit_reason: ExitReason::Fatal(format!(
                 "No saved session found with ID {id_str}. Run `xcodex {action}` without an ID to choose from existing sessions."
@@ -564,6 +584,7 @@ async fn run_ratatui_app(
                     return Ok(AppExitInfo {
                         token_usage: codex_core::protocol::TokenUsage::default(),
                         conversation_id: None,
+                        resumable: false,
                         update_action: None,
                         exit_reason: ExitReason::UserRequested,
                         session_lines: Vec::new(),
@@ -609,6 +630,7 @@ async fn run_ratatui_app(
                 return Ok(AppExitInfo {
                     token_usage: codex_core::protocol::TokenUsage::default(),
                     conversation_id: None,
+                    resumable: false,
                     update_action: None,
                     exit_reason: ExitReason::UserRequested,
                     session_lines: Vec::new(),
@@ -714,8 +736,11 @@ fn themed_exit_footer(exit_info: &AppExitInfo) -> Vec<Vec<ratatui::text::Span<'s
 
     let mut lines = vec![vec![Span::from(usage_line)]];
 
-    if let Some(conversation_id) = exit_info.conversation_id.as_ref() {
-        let command = format!("xcodex resume {conversation_id}");
+    if exit_info.resumable
+        && let Some(conversation_id) = exit_info.conversation_id.as_ref()
+    {
+        let bin_name = codex_core::util::resume_bin_name(std::env::args_os().next());
+        let command = format!("{bin_name} resume {conversation_id}");
         lines.push(vec![
             "To continue this session, run ".into(),
             Span::styled(command, crate::theme::accent_style()),
@@ -863,6 +888,29 @@ mod tests {
             .await
     }
 
+    #[test]
+    fn prompt_file_dash_reads_prompt_from_stdin() -> std::io::Result<()> {
+        let prompt = read_prompt_file(Path::new("-"), || Ok("fix the bug\n".to_string()))?;
+        assert_eq!(prompt, "fix the bug\n");
+
+        let err = read_prompt_file(Path::new("-"), || Ok(" \n".to_string()))
+            .expect_err("blank stdin prompt is rejected");
+        assert_eq!(err.to_string(), "No prompt provided via stdin.");
+        Ok(())
+    }
+
+    #[test]
+    fn prompt_file_contents_normalize_crlf() -> std::io::Result<()> {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("PROMPT.md");
+        std::fs::write(&path, "line one\r\nline two\rline three\r\n")?;
+
+        let prompt = read_prompt_file(&path, || unreachable!("stdin is not read"))?;
+
+        assert_eq!(prompt, "line one\nline two\nline three\n");
+        Ok(())
+    }
+
     #[tokio::test]
     #[serial]
     async fn windows_skips_trust_prompt_without_sandbox() -> std::io::Result<()> {
@@ -1120,7 +1120,7 @@ impl ThemePreviewOverlay {
 
         let terminal_bg = self.terminal_bg;
         let variant = crate::theme::active_variant(&self.preview.config, terminal_bg);
-        let terminal_background_is_light = terminal_bg.is_some_and(crate::color::is_light);
+        let terminal_background_is_light = terminal_bg.map(crate::color::is_light);
 
         let Ok(catalog) = ThemeCatalog::load(&self.preview.config) else {
             return;
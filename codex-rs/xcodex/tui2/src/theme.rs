@@ -76,7 +76,7 @@ pub(crate) fn init(config: &Config, terminal_bg: Option<(u8, u8, u8)>) {
 }
 
 pub(crate) fn apply_from_config(config: &Config, terminal_bg: Option<(u8, u8, u8)>) {
-    let terminal_background_is_light = terminal_bg.is_some_and(is_light);
+    let terminal_background_is_light = terminal_bg.map(is_light);
     let auto_variant = os_theme_variant();
     let terminal_fg = crate::terminal_palette::default_fg();
 
@@ -96,7 +96,7 @@ pub(crate) fn apply_from_config(config: &Config, terminal_bg: Option<(u8, u8, u8
 }
 
 pub(crate) fn preview(config: &Config, terminal_bg: Option<(u8, u8, u8)>, theme_name: &str) {
-    let terminal_background_is_light = terminal_bg.is_some_and(is_light);
+    let terminal_background_is_light = terminal_bg.map(is_light);
     let auto_variant = os_theme_variant();
     let terminal_fg = crate::terminal_palette::default_fg();
 
@@ -138,19 +138,11 @@ pub(crate) fn set_test_surface_styles(transcript: Style, composer: Style) {
 }
 
 pub(crate) fn active_variant(config: &Config, terminal_bg: Option<(u8, u8, u8)>) -> ThemeVariant {
-    let terminal_background_is_light = terminal_bg.is_some_and(is_light);
-    let auto_variant = os_theme_variant();
-    match config.xcodex.themes.theme_mode {
-        codex_core::config::types::ThemeMode::Light => ThemeVariant::Light,
-        codex_core::config::types::ThemeMode::Dark => ThemeVariant::Dark,
-        codex_core::config::types::ThemeMode::Auto => auto_variant.unwrap_or({
-            if terminal_background_is_light {
-                ThemeVariant::Light
-            } else {
-                ThemeVariant::Dark
-            }
-        }),
-    }
+    codex_core::themes::resolve_theme_variant(
+        &config.xcodex.themes,
+        os_theme_variant(),
+        terminal_bg.map(is_light),
+    )
 }
 
 pub(crate) fn option_style(is_selected: bool, dim: bool) -> Style {
@@ -363,7 +355,7 @@ pub(crate) fn preview_lines(
     use ratatui::text::Line;
     use ratatui::text::Span;
 
-    let terminal_background_is_light = terminal_bg.is_some_and(is_light);
+    let terminal_background_is_light = terminal_bg.map(is_light);
     let auto_variant = os_theme_variant();
 
     let catalog = match ThemeCatalog::load(config) {
@@ -9,6 +9,7 @@ use crate::tui::FrameRequester;
 use crate::tui::Tui;
 use crate::tui::TuiEvent;
 use codex_core::config::Config;
+use codex_core::config::mark_xcodex_first_run_complete;
 use codex_core::config::should_run_xcodex_first_run_wizard;
 use codex_core::config::xcodex_first_run_wizard_marker_path;
 use color_eyre::Result;
@@ -262,7 +263,7 @@ enum ApplyEvent {
     Finished(std::io::Result<()>),
 }
 
-const DEFAULT_CONFIG_TOML_STUB: &str = "# Generated by xcodex first-run setup wizard.\n# Set CODEX_HOME to explicitly choose a shared home.\n";
+const DEFAULT_CONFIG_TOML_STUB: &str = codex_core::config::XCODEX_DEFAULT_CONFIG_TOML_STUB;
 
 fn default_upstream_codex_home_candidate() -> Option<PathBuf> {
     dirs::home_dir().map(|p| p.join(".codex"))
@@ -276,15 +277,6 @@ fn marker_path(codex_home: &Path) -> PathBuf {
     xcodex_first_run_wizard_marker_path(codex_home)
 }
 
-fn write_marker_only(codex_home: &Path) -> std::io::Result<()> {
-    std::fs::create_dir_all(codex_home)?;
-    let marker = marker_path(codex_home);
-    if !marker.exists() {
-        std::fs::write(marker, "")?;
-    }
-    Ok(())
-}
-
 fn merge_mcp_servers_add_missing(src_path: &Path, dest_path: &Path) -> std::io::Result<usize> {
     let src = std::fs::read_to_string(src_path)?;
     let dest = std::fs::read_to_string(dest_path)?;
@@ -815,7 +807,7 @@ impl XcodexFirstRunWizardScreen {
 
     fn finish_dont_show_again(&mut self) {
         if !self.dry_run
-            && let Err(err) = write_marker_only(&self.dest_home)
+            && let Err(err) = mark_xcodex_first_run_complete(&self.dest_home)
         {
             self.error_message = Some(err.to_string());
             self.step = Step::Error;
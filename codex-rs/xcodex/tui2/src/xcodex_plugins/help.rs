@@ -1,36 +1,114 @@
 use crate::chatwidget::ChatWidget;
 use crate::history_cell::CompositeHistoryCell;
 use crate::history_cell::PlainHistoryCell;
+use codex_common::fuzzy_match::fuzzy_match;
 use ratatui::style::Styled as _;
 use ratatui::style::Stylize;
 use ratatui::text::Line;
 use ratatui::text::Span;
 
+use super::PluginSubcommandNode;
+
+/// Added to description-only match scores so command-name matches rank first.
+const DESCRIPTION_MATCH_PENALTY: i32 = 1_000;
+const MAX_HELP_SEARCH_RESULTS: usize = 8;
+
+/// A `/help <query>` hit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct HelpSearchResult {
+    /// Slash command text, including the leading `/`.
+    pub(crate) command: String,
+    pub(crate) description: &'static str,
+}
+
 pub(crate) fn handle_help_command(chat: &mut ChatWidget, rest: &str) {
-    let args: Vec<&str> = rest.split_whitespace().collect();
-    let topic = match args.as_slice() {
-        [] => {
-            add_help_topics_output(chat);
-            return;
-        }
-        [topic] => topic.to_ascii_lowercase(),
-        _ => {
-            chat.add_info_message("Usage: /help <topic>".to_string(), None);
-            return;
-        }
-    };
+    let query = rest.trim();
+    if query.is_empty() {
+        add_help_topics_output(chat);
+        return;
+    }
+    if query.eq_ignore_ascii_case("xcodex") {
+        add_help_xcodex_output(chat);
+        return;
+    }
 
-    match topic.as_str() {
-        "xcodex" => {
-            add_help_xcodex_output(chat);
-        }
-        _ => {
-            chat.add_info_message(
-                format!("Unknown help topic `{topic}`. Try: /help xcodex"),
-                None,
-            );
+    let results = search_help(query);
+    if results.is_empty() {
+        chat.add_info_message(
+            format!("No commands match `{query}`. Try: /help xcodex"),
+            None,
+        );
+        return;
+    }
+    add_help_search_output(chat, query, &results);
+}
+
+/// Fuzzy-matches `query` against plugin command names and descriptions, best match first.
+pub(crate) fn search_help(query: &str) -> Vec<HelpSearchResult> {
+    fn collect_nodes(
+        nodes: &[PluginSubcommandNode],
+        entries: &mut Vec<(&'static str, &'static str)>,
+    ) {
+        for node in nodes {
+            entries.push((node.full_name, node.description));
+            collect_nodes(node.children, entries);
         }
     }
+
+    let mut entries = super::plugin_slash_commands()
+        .map(|command| (command.name, command.description))
+        .collect::<Vec<_>>();
+    for root in super::plugin_subcommand_roots() {
+        entries.push((root.root, root.anchor.description()));
+        collect_nodes(root.children, &mut entries);
+    }
+
+    let mut ranked = entries
+        .into_iter()
+        .filter_map(|(name, description)| {
+            let name_score = fuzzy_match(name, query).map(|(_, score)| score);
+            let description_score = fuzzy_match(description, query)
+                .map(|(_, score)| score.saturating_add(DESCRIPTION_MATCH_PENALTY));
+            let score = match (name_score, description_score) {
+                (Some(name_score), Some(description_score)) => name_score.min(description_score),
+                (Some(score), None) | (None, Some(score)) => score,
+                (None, None) => return None,
+            };
+            Some((score, name, description))
+        })
+        .collect::<Vec<_>>();
+    ranked.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1)));
+    ranked.dedup_by(|a, b| a.1 == b.1);
+    ranked
+        .into_iter()
+        .take(MAX_HELP_SEARCH_RESULTS)
+        .map(|(_, name, description)| HelpSearchResult {
+            command: format!("/{name}"),
+            description,
+        })
+        .collect()
+}
+
+fn add_help_search_output(chat: &mut ChatWidget, query: &str, results: &[HelpSearchResult]) {
+    let command = PlainHistoryCell::new(vec![Line::from(vec![format!("/help {query}").magenta()])]);
+    let body = PlainHistoryCell::new(
+        results
+            .iter()
+            .map(|result| {
+                vec![
+                    "• ".dim(),
+                    result.command.clone().cyan(),
+                    " — ".dim(),
+                    result.description.into(),
+                ]
+                .into()
+            })
+            .collect(),
+    );
+    chat.add_to_history(CompositeHistoryCell::new(vec![
+        Box::new(command),
+        Box::new(body),
+    ]));
 }
 
 pub(crate) fn add_help_topics_output(chat: &mut ChatWidget) {
@@ -46,6 +124,11 @@ pub(crate) fn add_help_topics_output(chat: &mut ChatWidget) {
             Span::from("/help xcodex").set_style(crate::theme::accent_style()),
         ]
         .into(),
+        vec![
+            "Search: ".dim(),
+            Span::from("/help <query>").set_style(crate::theme::accent_style()),
+        ]
+        .into(),
     ]);
     chat.add_to_history(CompositeHistoryCell::new(vec![
         Box::new(command),
@@ -150,6 +233,20 @@ pub(crate) fn add_help_xcodex_output(chat: &mut ChatWidget) {
             "(configure shared dirs)".dim(),
         ]
         .into(),
+        vec![
+            "• ".dim(),
+            "/ps".cyan(),
+            " — ".dim(),
+            "list background terminals + hooks".into(),
+        ]
+        .into(),
+        vec![
+            "• ".dim(),
+            "/ps-kill".cyan(),
+            " — ".dim(),
+            "terminate background terminals".into(),
+        ]
+        .into(),
         vec![
             "• ".dim(),
             "/thoughts".cyan(),
@@ -157,10 +254,41 @@ pub(crate) fn add_help_xcodex_output(chat: &mut ChatWidget) {
             "show/hide agent reasoning".into(),
         ]
         .into(),
-        vec!["  ".into(), "Try: ".dim(), "/thoughts toggle".cyan()].into(),
     ]);
     chat.add_to_history(CompositeHistoryCell::new(vec![
         Box::new(command),
         Box::new(body),
     ]));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn search_ranks_command_name_matches_first() {
+        let results = search_help("thoughts");
+        assert_eq!(
+            results.first(),
+            Some(&HelpSearchResult {
+                command: "/thoughts".to_string(),
+                description: "toggle showing agent thoughts/reasoning (persists)",
+            })
+        );
+    }
+
+    #[test]
+    fn search_matches_subcommand_descriptions() {
+        let results = search_help("failed to start");
+        assert_eq!(
+            results.first().map(|result| result.command.as_str()),
+            Some("/mcp retry failed")
+        );
+    }
+
+    #[test]
+    fn search_without_matches_is_empty() {
+        assert_eq!(search_help("zzzzqqq"), Vec::new());
+    }
+}
@@ -23,28 +23,49 @@ pub(crate) use ramp_status_state::RampStatusController;
 use rand::Rng;
 pub(crate) use worktree_list_state::WorktreeListState;
 
-#[derive(Clone, Copy, Debug)]
-pub(crate) struct PluginSlashCommand {
-    pub(crate) name: &'static str,
-    pub(crate) description: &'static str,
-    pub(crate) run_on_enter: bool,
-    pub(crate) insert_trailing_space: bool,
-}
+include!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../tui_plugin_commands.rs"
+));
+
+/// This frontend's handlers for the shared `PLUGIN_COMMANDS` table.
+mod handlers {
+    use crate::chatwidget::ChatWidget;
+
+    pub(super) use super::exclusions::handle_exclusions_command as exclusion;
+    pub(super) use super::thoughts::handle as thoughts;
+    pub(super) use super::xtreme::handle as xtreme;
+
+    pub(super) fn ramps(chat: &mut ChatWidget, _rest: &str) -> bool {
+        chat.add_info_message("/ramps is not available in tui2 yet.".to_string(), None);
+        true
+    }
 
-const PLUGIN_COMMANDS: [PluginSlashCommand; 2] = [
-    PluginSlashCommand {
-        name: "thoughts",
-        description: "toggle showing agent thoughts/reasoning (persists)",
-        run_on_enter: true,
-        insert_trailing_space: false,
-    },
-    PluginSlashCommand {
-        name: "xtreme",
-        description: "open the ⚡Tools control panel",
-        run_on_enter: true,
-        insert_trailing_space: false,
-    },
-];
+    pub(super) fn export(chat: &mut ChatWidget, _rest: &str) -> bool {
+        chat.add_info_message("/export is not available in tui2 yet.".to_string(), None);
+        true
+    }
+
+    /// tui2 opens its settings menu from the built-in `SlashCommand::Settings`.
+    pub(super) fn settings(_chat: &mut ChatWidget, _rest: &str) -> bool {
+        false
+    }
+
+    pub(super) fn help(chat: &mut ChatWidget, rest: &str) -> bool {
+        super::help::handle_help_command(chat, rest);
+        true
+    }
+
+    pub(super) fn plan(chat: &mut ChatWidget, rest: &str) -> bool {
+        super::plan::handle_plan_command(chat, rest);
+        true
+    }
+
+    pub(super) fn hooks(chat: &mut ChatWidget, rest: &str) -> bool {
+        super::hooks::handle_hooks_command(chat, rest);
+        true
+    }
+}
 
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct PluginSubcommandNode {
@@ -77,27 +98,10 @@ const PLUGIN_SUBCOMMAND_ROOTS: &[PluginSubcommandRoot] = &[
     worktree::WORKTREE_SUBCOMMAND_ROOT,
 ];
 
-pub(crate) fn plugin_slash_commands() -> &'static [PluginSlashCommand] {
-    &PLUGIN_COMMANDS
-}
-
 pub(crate) fn plugin_subcommand_roots() -> &'static [PluginSubcommandRoot] {
     PLUGIN_SUBCOMMAND_ROOTS
 }
 
-pub(crate) fn try_handle_slash_command(chat: &mut ChatWidget, name: &str, rest: &str) -> bool {
-    match name {
-        "thoughts" => thoughts::handle(chat, rest),
-        "xtreme" => xtreme::handle(chat, rest),
-        "exclusion" => exclusions::handle_exclusions_command(chat, rest),
-        "plan" => {
-            plan::handle_plan_command(chat, rest);
-            true
-        }
-        _ => false,
-    }
-}
-
 pub(crate) fn try_handle_mcp_subcommand(chat: &mut ChatWidget, args: &[&str]) -> bool {
     mcp::try_handle_subcommand(chat, args)
 }
@@ -0,0 +1,160 @@
+// Slash commands implemented by the xcodex plugin layer, shared by tui and tui2.
+//
+// Each frontend includes this file from its `xcodex_plugins` module and provides a
+// `handlers` module with one function per entry below.
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PluginSlashCommand {
+    pub(crate) name: &'static str,
+    pub(crate) description: &'static str,
+    pub(crate) run_on_enter: bool,
+    pub(crate) insert_trailing_space: bool,
+}
+
+/// Handles `/name rest`; returns `false` when the command was not consumed.
+type PluginCommandHandler = fn(&mut ChatWidget, &str) -> bool;
+
+/// A slash command whose behavior is implemented by the plugin layer.
+#[derive(Clone, Copy, Debug)]
+struct PluginCommand {
+    name: &'static str,
+    kind: PluginCommandKind,
+    handler: PluginCommandHandler,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum PluginCommandKind {
+    /// A [`SlashCommand`] variant; the popup already lists it.
+    Builtin,
+    /// A plugin-only command that the popup lists alongside the built-ins.
+    Plugin {
+        description: &'static str,
+        run_on_enter: bool,
+        insert_trailing_space: bool,
+    },
+}
+
+const PLUGIN_COMMANDS: &[PluginCommand] = &[
+    PluginCommand {
+        name: "thoughts",
+        kind: PluginCommandKind::Plugin {
+            description: "toggle showing agent thoughts/reasoning (persists)",
+            run_on_enter: true,
+            insert_trailing_space: false,
+        },
+        handler: handlers::thoughts,
+    },
+    PluginCommand {
+        name: "xtreme",
+        kind: PluginCommandKind::Plugin {
+            description: "open the ⚡Tools control panel",
+            run_on_enter: true,
+            insert_trailing_space: false,
+        },
+        handler: handlers::xtreme,
+    },
+    PluginCommand {
+        name: "ramps",
+        kind: PluginCommandKind::Plugin {
+            description: "configure, preview, or pin per-turn ramps (/ramps [preview|pin|unpin])",
+            run_on_enter: true,
+            insert_trailing_space: false,
+        },
+        handler: handlers::ramps,
+    },
+    PluginCommand {
+        name: "export",
+        kind: PluginCommandKind::Plugin {
+            description: "export this session transcript to Markdown (/export [path])",
+            run_on_enter: true,
+            insert_trailing_space: false,
+        },
+        handler: handlers::export,
+    },
+    PluginCommand {
+        name: "exclusion",
+        kind: PluginCommandKind::Builtin,
+        handler: handlers::exclusion,
+    },
+    PluginCommand {
+        name: "settings",
+        kind: PluginCommandKind::Builtin,
+        handler: handlers::settings,
+    },
+    PluginCommand {
+        name: "help",
+        kind: PluginCommandKind::Builtin,
+        handler: handlers::help,
+    },
+    PluginCommand {
+        name: "plan",
+        kind: PluginCommandKind::Builtin,
+        handler: handlers::plan,
+    },
+    PluginCommand {
+        name: "hooks",
+        kind: PluginCommandKind::Builtin,
+        handler: handlers::hooks,
+    },
+];
+
+pub(crate) fn plugin_slash_commands() -> impl Iterator<Item = PluginSlashCommand> {
+    PLUGIN_COMMANDS
+        .iter()
+        .filter_map(|command| match command.kind {
+            PluginCommandKind::Builtin => None,
+            PluginCommandKind::Plugin {
+                description,
+                run_on_enter,
+                insert_trailing_space,
+            } => Some(PluginSlashCommand {
+                name: command.name,
+                description,
+                run_on_enter,
+                insert_trailing_space,
+            }),
+        })
+}
+
+pub(crate) fn try_handle_slash_command(chat: &mut ChatWidget, name: &str, rest: &str) -> bool {
+    PLUGIN_COMMANDS
+        .iter()
+        .find(|command| command.name == name)
+        .is_some_and(|command| (command.handler)(chat, rest))
+}
+
+#[cfg(test)]
+mod plugin_command_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::str::FromStr;
+
+    #[test]
+    fn plugin_command_names_match_slash_command_enum() {
+        let mismatched = PLUGIN_COMMANDS
+            .iter()
+            .filter(|command| {
+                let is_builtin = matches!(command.kind, PluginCommandKind::Builtin);
+                SlashCommand::from_str(command.name).is_ok() != is_builtin
+            })
+            .map(|command| command.name)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            mismatched,
+            Vec::<&str>::new(),
+            "builtin entries must name a SlashCommand and plugin entries must not shadow one"
+        );
+    }
+
+    #[test]
+    fn plugin_command_names_are_unique() {
+        let mut names = PLUGIN_COMMANDS
+            .iter()
+            .map(|command| command.name)
+            .collect::<Vec<_>>();
+        let total = names.len();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), total);
+    }
+}